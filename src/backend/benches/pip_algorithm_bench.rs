@@ -0,0 +1,180 @@
+//! Point-in-polygon algorithms side by side at `MAX_VERTICES = 6`: native
+//! call, constraint generation, and a Groth16 proof of a circuit holding
+//! only the point-in-polygon check.
+//!
+//! Every algorithm gets the same polygon, drawn from `POLYGON_SEED`. Run
+//! with `cargo bench --bench pip_algorithm_bench`.
+
+use std::{f64::consts::TAU, hint::black_box, time::Duration};
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar, prelude::Boolean};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use ark_std::rand::{Rng, SeedableRng, rngs::StdRng};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use backend::zk::{
+    circuit::{
+        BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, is_point_in_polygon,
+        is_point_in_polygon_gadget, is_point_in_polygon_gadget_const_n,
+    },
+    point_2d::{Point2DDec, Point2DDecVar},
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX: usize = CIRCUIT_MAX_VERTICES;
+const POLYGON_SEED: u64 = 42;
+
+#[derive(Clone, Copy, Debug)]
+enum Algorithm {
+    /// `is_point_in_polygon_gadget`, `num_vertices` a witness
+    CrossProduct,
+    /// `is_point_in_polygon_gadget_const_n`, `N = MAX`
+    CrossProductConstN,
+}
+
+const ALGORITHMS: [Algorithm; 2] = [Algorithm::CrossProduct, Algorithm::CrossProductConstN];
+
+// star-shaped around the origin: sorted angles, radii in [50, 150)
+fn seeded_polygon() -> [Point2DDec<F, PREC>; MAX] {
+    let mut rng = StdRng::seed_from_u64(POLYGON_SEED);
+    let mut angles: Vec<f64> = (0..MAX).map(|_| rng.gen_range(0.0..TAU)).collect();
+    angles.sort_by(f64::total_cmp);
+    core::array::from_fn(|k| {
+        let r = rng.gen_range(50.0..150.0);
+        Point2DDec::from_f64(r * angles[k].cos(), r * angles[k].sin())
+    })
+}
+
+/// Only the point-in-polygon check, its result a public input.
+#[derive(Clone)]
+struct PipCircuit {
+    algorithm: Algorithm,
+    point: Point2DDec<F, PREC>,
+    polygon: [Point2DDec<F, PREC>; MAX],
+    inside: bool,
+}
+
+impl PipCircuit {
+    fn new(algorithm: Algorithm) -> Self {
+        let point = Point2DDec::from_f64(1.0, 1.0);
+        let polygon = seeded_polygon();
+        let inside = is_point_in_polygon::<F, PREC, MAX>(
+            &point,
+            &polygon,
+            MAX,
+            BoundaryBehavior::CountAsInside,
+        );
+        Self {
+            algorithm,
+            point,
+            polygon,
+            inside,
+        }
+    }
+}
+
+impl ConstraintSynthesizer<F> for PipCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let point = Point2DDecVar::new_witness(cs.clone(), || Ok(self.point))?;
+        let polygon: [Point2DDecVar<F, PREC>; MAX] = core::array::from_fn(|i| {
+            Point2DDecVar::new_witness(cs.clone(), || Ok(self.polygon[i])).unwrap()
+        });
+        let inside = match self.algorithm {
+            Algorithm::CrossProduct => {
+                let n = FpVar::new_witness(cs.clone(), || Ok(F::from(MAX as u64)))?;
+                is_point_in_polygon_gadget::<F, PREC, MAX>(
+                    &point,
+                    &polygon,
+                    &n,
+                    BoundaryBehavior::CountAsInside,
+                )?
+            }
+            Algorithm::CrossProductConstN => {
+                is_point_in_polygon_gadget_const_n::<F, PREC, MAX, MAX>(&point, &polygon)?
+            }
+        };
+        Boolean::new_input(cs, || Ok(self.inside))?.enforce_equal(&inside)
+    }
+}
+
+fn num_constraints(algorithm: Algorithm) -> usize {
+    let cs = ConstraintSystem::<F>::new_ref();
+    PipCircuit::new(algorithm)
+        .generate_constraints(cs.clone())
+        .unwrap();
+    cs.num_constraints()
+}
+
+fn configured() -> Criterion {
+    Criterion::default()
+        .warm_up_time(Duration::from_millis(50))
+        .sample_size(100)
+}
+
+fn bench_native(c: &mut Criterion) {
+    let circuit = PipCircuit::new(Algorithm::CrossProduct);
+    // the native check is the same function for both gadgets
+    c.bench_function("pip_algorithm/native/cross_product", |b| {
+        b.iter(|| {
+            is_point_in_polygon::<F, PREC, MAX>(
+                black_box(&circuit.point),
+                &circuit.polygon,
+                MAX,
+                BoundaryBehavior::CountAsInside,
+            )
+        })
+    });
+}
+
+fn bench_constraints(c: &mut Criterion) {
+    let baseline = num_constraints(Algorithm::CrossProduct);
+    for algorithm in ALGORITHMS {
+        let n = num_constraints(algorithm);
+        println!(
+            "{algorithm:?}: {n} constraints ({:+} vs CrossProduct)",
+            n as i64 - baseline as i64
+        );
+    }
+
+    let mut group = c.benchmark_group("pip_algorithm/constraints");
+    for algorithm in ALGORITHMS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{algorithm:?}")),
+            &algorithm,
+            |b, &algorithm| b.iter(|| num_constraints(algorithm)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_prove(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pip_algorithm/prove");
+    group.sample_size(10);
+    for algorithm in ALGORITHMS {
+        let circuit = PipCircuit::new(algorithm);
+        let mut rng = StdRng::seed_from_u64(0);
+        let (pk, _vk) =
+            Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut rng).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{algorithm:?}")),
+            &circuit,
+            |b, circuit| {
+                b.iter(|| Groth16::<Bn254>::prove(&pk, circuit.clone(), &mut rng).unwrap())
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = configured();
+    targets = bench_native, bench_constraints, bench_prove
+}
+criterion_main!(benches);
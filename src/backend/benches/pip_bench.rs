@@ -0,0 +1,268 @@
+//! Point-in-polygon, polygon-hash, constraint-synthesis and end-to-end
+//! proving benchmarks.
+//!
+//! Run with `cargo bench --bench pip_bench` (or `make bench`).
+
+use std::{f64::consts::TAU, hint::black_box, time::Duration};
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_ff::Zero;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+use backend::{
+    state::{CELL_BOUNDARY_CACHE_CAPACITY, CellBoundaryCache},
+    zk::{
+        batch_prove::{prove_batch, prove_batch_cached},
+        circuit::{
+            BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, PointInMapCircuit,
+            hash_polygon_gadget, is_point_in_polygon, is_point_in_polygon_gadget,
+            is_point_in_polygon_gadget_const_n,
+        },
+        fixed_point_decimal::DecVar,
+        point_2d::{Point2DDec, Point2DDecVar},
+        polygon_ops::{hash_polygon, polygon_from_h3_cell},
+        poseidon::{default_poseidon_config, poseidon_config},
+    },
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX: usize = CIRCUIT_MAX_VERTICES;
+// small public-input vector so setup does not dominate the run
+const BENCH_MAX_HASHES: usize = 4;
+// map size for the synthesis benchmark, where the public hashes dominate
+const SYNTHESIS_MAX_HASHES: usize = 1024;
+
+// ------- helper: regular CCW n-gon of radius 100 around the origin -------
+fn regular_polygon(n: usize) -> [Point2DDec<F, PREC>; MAX] {
+    let mut arr = [Point2DDec::from_f64(0.0, 0.0); MAX];
+    for (k, p) in arr.iter_mut().take(n).enumerate() {
+        let theta = TAU * k as f64 / n as f64;
+        *p = Point2DDec::from_f64(100.0 * theta.cos(), 100.0 * theta.sin());
+    }
+    arr
+}
+
+// ------- helper: allocate polygon + point + n as witnesses -------
+fn alloc_inputs(
+    cs: ConstraintSystemRef<F>,
+    point: &Point2DDec<F, PREC>,
+    poly: &[Point2DDec<F, PREC>; MAX],
+    n: usize,
+) -> (
+    Point2DDecVar<F, PREC>,
+    [Point2DDecVar<F, PREC>; MAX],
+    FpVar<F>,
+) {
+    let point_var = Point2DDecVar {
+        x: DecVar::new_witness(cs.clone(), || Ok(point.x)).unwrap(),
+        y: DecVar::new_witness(cs.clone(), || Ok(point.y)).unwrap(),
+    };
+    let poly_var = core::array::from_fn(|i| {
+        let p = &poly[i];
+        Point2DDecVar {
+            x: DecVar::new_witness(cs.clone(), || Ok(p.x)).unwrap(),
+            y: DecVar::new_witness(cs.clone(), || Ok(p.y)).unwrap(),
+        }
+    });
+    let n_var = FpVar::<F>::new_witness(cs, || Ok(F::from(n as u64))).unwrap();
+    (point_var, poly_var, n_var)
+}
+
+fn configured() -> Criterion {
+    // ~50 warm-up iterations for the µs-scale native calls, 100 samples each
+    Criterion::default()
+        .warm_up_time(Duration::from_millis(50))
+        .sample_size(100)
+}
+
+fn bench_point_in_polygon(c: &mut Criterion) {
+    let point = Point2DDec::<F, PREC>::from_f64(1.0, 1.0);
+
+    let mut group = c.benchmark_group("is_point_in_polygon");
+    for n in 3..=MAX {
+        let poly = regular_polygon(n);
+        group.bench_with_input(BenchmarkId::new("native", n), &n, |b, &n| {
+            b.iter(|| {
+                is_point_in_polygon::<F, PREC, MAX>(
+                    black_box(&point),
+                    &poly,
+                    n,
+                    BoundaryBehavior::CountAsInside,
+                )
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("gadget_constraints", n), &n, |b, &n| {
+            b.iter(|| {
+                let cs = ConstraintSystem::<F>::new_ref();
+                let (pt, pv, nv) = alloc_inputs(cs, &point, &poly, n);
+                is_point_in_polygon_gadget::<F, PREC, MAX>(
+                    &pt,
+                    &pv,
+                    &nv,
+                    BoundaryBehavior::CountAsInside,
+                )
+                .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+
+    // N = 6 known at compile time vs. `num_vertices` as a witness. Each
+    // reports its constraint count as throughput, so criterion shows how the
+    // two compare alongside the time
+    let poly = regular_polygon(MAX);
+    let constraints = |const_n: bool| {
+        let cs = ConstraintSystem::<F>::new_ref();
+        let (pt, pv, nv) = alloc_inputs(cs.clone(), &point, &poly, MAX);
+        let _inside = if const_n {
+            is_point_in_polygon_gadget_const_n::<F, PREC, MAX, MAX>(&pt, &pv)
+        } else {
+            is_point_in_polygon_gadget::<F, PREC, MAX>(
+                &pt,
+                &pv,
+                &nv,
+                BoundaryBehavior::CountAsInside,
+            )
+        }
+        .unwrap();
+        cs.num_constraints()
+    };
+    let mut group = c.benchmark_group("is_point_in_polygon_n");
+    for (name, const_n) in [("variable", false), ("const", true)] {
+        group.throughput(Throughput::Elements(constraints(const_n) as u64));
+        group.bench_function(BenchmarkId::new(name, MAX), |b| {
+            b.iter(|| constraints(black_box(const_n)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_hash_polygon(c: &mut Criterion) {
+    let cfg = default_poseidon_config();
+    let point = Point2DDec::<F, PREC>::from_f64(0.0, 0.0);
+    let poly = regular_polygon(MAX);
+
+    let mut group = c.benchmark_group("hash_polygon");
+    group.bench_function("native", |b| {
+        b.iter(|| hash_polygon::<F, PREC, MAX>(black_box(&poly), MAX, 0, &cfg))
+    });
+    group.bench_function("gadget_constraints", |b| {
+        b.iter(|| {
+            let cs = ConstraintSystem::<F>::new_ref();
+            let (_, pv, nv) = alloc_inputs(cs.clone(), &point, &poly, MAX);
+            let mode = FpVar::<F>::new_witness(cs, || Ok(F::zero())).unwrap();
+            hash_polygon_gadget::<F, PREC, MAX>(&pv, &nv, &mode, &cfg).unwrap()
+        })
+    });
+
+    // rate 4 (width 5) hashes each 4-element vertex leaf in one permutation
+    let cfg4 = poseidon_config(4, 1);
+    group.bench_function("gadget_constraints_rate4", |b| {
+        b.iter(|| {
+            let cs = ConstraintSystem::<F>::new_ref();
+            let (_, pv, nv) = alloc_inputs(cs.clone(), &point, &poly, MAX);
+            let mode = FpVar::<F>::new_witness(cs, || Ok(F::zero())).unwrap();
+            hash_polygon_gadget::<F, PREC, MAX>(&pv, &nv, &mode, &cfg4).unwrap()
+        })
+    });
+    group.finish();
+}
+
+fn bench_cell_boundary(c: &mut Criterion) {
+    let cell = h3o::LatLng::new(13.7563, 100.5018)
+        .unwrap()
+        .to_cell(h3o::Resolution::Seven);
+    let cache = CellBoundaryCache::new(CELL_BOUNDARY_CACHE_CAPACITY);
+    cache.get(cell);
+
+    let mut group = c.benchmark_group("cell_boundary");
+    group.bench_function("polygon_from_h3_cell", |b| {
+        b.iter(|| polygon_from_h3_cell::<F, PREC, MAX>(black_box(cell)))
+    });
+    group.bench_function("cache_hit", |b| b.iter(|| cache.get(black_box(cell))));
+    group.finish();
+}
+
+fn bench_synthesis(c: &mut Criterion) {
+    type Circuit = PointInMapCircuit<F, PREC, MAX, SYNTHESIS_MAX_HASHES>;
+
+    let cfg = default_poseidon_config();
+    let poly = regular_polygon(MAX);
+    let point = Point2DDec::<F, PREC>::from_f64(1.0, 1.0);
+
+    let mut hashes = [F::zero(); SYNTHESIS_MAX_HASHES];
+    hashes[0] = hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg);
+    let circuit = || Circuit::new(point, poly, MAX as u64, true, hashes, 0, cfg.clone(), false);
+
+    let mut group = c.benchmark_group("synthesis");
+    group.sample_size(10);
+    group.bench_function(
+        BenchmarkId::new("generate_constraints", SYNTHESIS_MAX_HASHES),
+        |b| {
+            b.iter(|| {
+                let cs = ConstraintSystem::<F>::new_ref();
+                circuit().generate_constraints(cs.clone()).unwrap();
+                cs.num_constraints()
+            })
+        },
+    );
+    group.finish();
+}
+
+fn bench_groth16_prove(c: &mut Criterion) {
+    type Circuit = PointInMapCircuit<F, PREC, MAX, BENCH_MAX_HASHES>;
+
+    let cfg = default_poseidon_config();
+    let poly = regular_polygon(MAX);
+    let point = Point2DDec::<F, PREC>::from_f64(1.0, 1.0);
+
+    let mut hashes = [F::zero(); BENCH_MAX_HASHES];
+    hashes[0] = hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg);
+
+    let circuit = || Circuit::new(point, poly, MAX as u64, true, hashes, 0, cfg.clone(), false);
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+    let (pk, _vk) = Groth16::<Bn254>::circuit_specific_setup(circuit(), &mut rng).unwrap();
+
+    let mut group = c.benchmark_group("groth16");
+    // one proof takes seconds – criterion's minimum sample count is plenty
+    group.sample_size(10);
+    group.bench_function("prove", |b| {
+        b.iter(|| Groth16::<Bn254>::prove(&pk, circuit(), &mut rng).unwrap())
+    });
+    for batch in [1, 10, 50] {
+        group.bench_with_input(
+            BenchmarkId::new("prove_batch", batch),
+            &batch,
+            |b, &batch| {
+                b.iter(|| prove_batch((0..batch).map(|_| circuit()).collect(), &pk, &mut rng))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("prove_batch_cached", batch),
+            &batch,
+            |b, &batch| {
+                b.iter(|| {
+                    let circuits = (0..batch).map(|_| circuit()).collect();
+                    prove_batch_cached(circuit(), circuits, &pk, &mut rng, true).unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = configured();
+    targets = bench_point_in_polygon, bench_hash_polygon, bench_cell_boundary, bench_synthesis,
+        bench_groth16_prove
+}
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+//! Polygon hashing under a width-3 (rate 2) and a width-4 (rate 3)
+//! Poseidon sponge, natively and as constraint synthesis.
+//!
+//! Run with `cargo bench --bench poseidon_bench`. Constraint counts are
+//! printed once per rate; timings are recorded like every other bench and
+//! never gate the build.
+//!
+//! There is no checked-in baseline: timings depend on the machine, and the
+//! rate-2 count the server uses is already pinned exactly by
+//! `HASH_POLYGON_CONSTRAINTS` in `tests/constraint_count_regression.rs`.
+
+use std::{f64::consts::TAU, hint::black_box, time::Duration};
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::Zero;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::ConstraintSystem;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use backend::zk::{
+    circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, hash_polygon_gadget},
+    point_2d::{Point2DDec, Point2DDecVar},
+    polygon_ops::hash_polygon,
+    poseidon::poseidon_config,
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX: usize = CIRCUIT_MAX_VERTICES;
+
+// regular hexagon of radius 100 around the origin
+fn hexagon() -> [Point2DDec<F, PREC>; MAX] {
+    core::array::from_fn(|k| {
+        let theta = TAU * k as f64 / MAX as f64;
+        Point2DDec::from_f64(100.0 * theta.cos(), 100.0 * theta.sin())
+    })
+}
+
+// synthesise `hash_polygon_gadget` once; returns the constraint count
+fn synthesize(poly: &[Point2DDec<F, PREC>; MAX], cfg: &PoseidonConfig<F>) -> usize {
+    let cs = ConstraintSystem::<F>::new_ref();
+    let vars = poly.map(|p| Point2DDecVar::new_witness(cs.clone(), || Ok(p)).unwrap());
+    let n = FpVar::new_witness(cs.clone(), || Ok(F::from(MAX as u64))).unwrap();
+    let mode = FpVar::new_witness(cs.clone(), || Ok(F::zero())).unwrap();
+    hash_polygon_gadget::<F, PREC, MAX>(&vars, &n, &mode, cfg).unwrap();
+    cs.num_constraints()
+}
+
+fn bench_poseidon_rate(c: &mut Criterion) {
+    let poly = hexagon();
+
+    let mut group = c.benchmark_group("hash_polygon_rate");
+    for rate in [2, 3] {
+        let cfg = poseidon_config(rate, 1);
+        println!(
+            "hash_polygon_gadget N={MAX}, rate {rate}: {} constraints",
+            synthesize(&poly, &cfg)
+        );
+
+        group.bench_with_input(BenchmarkId::new("native", rate), &cfg, |b, cfg| {
+            b.iter(|| hash_polygon::<F, PREC, MAX>(black_box(&poly), MAX, 0, cfg))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("gadget_constraints", rate),
+            &cfg,
+            |b, cfg| b.iter(|| synthesize(black_box(&poly), cfg)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().warm_up_time(Duration::from_millis(50));
+    targets = bench_poseidon_rate
+}
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! `hash_polygon` and `hash_polygon_gadget` must agree on every polygon,
+//! including past `num_vertices` and at the extremes of the fixed-point range.
+//!
+//! `cargo fuzz run hash_differential -- -max_total_time=60`
+
+#![no_main]
+
+use ark_bn254::Fr;
+use ark_r1cs_std::{R1CSVar, alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::ConstraintSystem;
+use libfuzzer_sys::fuzz_target;
+
+use backend::zk::{
+    circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, hash_polygon_gadget},
+    fixed_point_decimal::Dec,
+    point_2d::{Point2DDec, Point2DDecVar},
+    polygon_ops::hash_polygon,
+    poseidon::default_poseidon_config,
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX: usize = CIRCUIT_MAX_VERTICES;
+
+// a fixed-point integer as a `Dec`: magnitude and sign
+fn dec(v: i64) -> Dec<F, PREC> {
+    Dec {
+        val: F::from(v.unsigned_abs()),
+        neg: v < 0,
+    }
+}
+
+fuzz_target!(|input: (Vec<(i64, i64)>, u8)| {
+    let (vertices, count) = input;
+
+    // missing slots stay at the origin; `count` may leave real ones unused
+    let mut polygon = [Point2DDec::<F, PREC> {
+        x: dec(0),
+        y: dec(0),
+    }; MAX];
+    for (slot, &(x, y)) in polygon.iter_mut().zip(&vertices) {
+        *slot = Point2DDec {
+            x: dec(x),
+            y: dec(y),
+        };
+    }
+    let n = count as usize % (MAX + 1);
+
+    let cfg = default_poseidon_config();
+    let native = hash_polygon::<F, PREC, MAX>(&polygon, n, 0, &cfg);
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let poly_var: [Point2DDecVar<F, PREC>; MAX] = core::array::from_fn(|i| {
+        Point2DDecVar::new_witness(cs.clone(), || Ok(polygon[i])).unwrap()
+    });
+    let n_var = FpVar::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+    let mode_var = FpVar::new_witness(cs.clone(), || Ok(F::from(0u64))).unwrap();
+    let gadget = hash_polygon_gadget::<F, PREC, MAX>(&poly_var, &n_var, &mode_var, &cfg)
+        .unwrap()
+        .value()
+        .unwrap();
+
+    assert!(
+        cs.is_satisfied().unwrap(),
+        "gadget constraints unsatisfied, n = {n}"
+    );
+    assert_eq!(native, gadget, "hash mismatch, n = {n}");
+});
@@ -0,0 +1,136 @@
+use anyhow::{bail, Result};
+use ark_bn254::Fr;
+use ark_ff::Zero;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisMode};
+use backend::{
+    keys::SUPPORTED_HASH_COUNTS,
+    zk::{circuit::PointInMapCircuit, point_2d::Point2DDec, poseidon::default_poseidon_config},
+};
+use clap::Parser;
+
+/// Vertex counts and precisions the estimator has a circuit for; the
+/// circuit takes both as const generics.
+const SUPPORTED_VERTICES: [usize; 3] = [4, 6, 8];
+const SUPPORTED_PRECISIONS: [u32; 2] = [6, 8];
+
+// uncompressed BN254 points, as `params/*.bin` stores them
+const G1_BYTES: usize = 64;
+const G2_BYTES: usize = 128;
+
+/// Predict the size of a `PointInMapCircuit` and of its Groth16 keys
+/// without running the setup.
+#[derive(Parser)]
+struct Args {
+    /// `MAX_VERTICES` of the circuit
+    #[arg(long, default_value_t = 6)]
+    max_vertices: usize,
+
+    /// `MAX_HASHES` (map cells) of the circuit
+    #[arg(long, default_value_t = 1024)]
+    max_hashes: usize,
+
+    /// decimal places of the fixed-point coordinates (`PREC`)
+    #[arg(long, default_value_t = 8)]
+    precision: u32,
+
+    /// proving speed in constraints per second; 3000 is a rough estimate for
+    /// a 4-core machine, not a measurement
+    #[arg(long, default_value_t = 3000.0)]
+    cpu_speed: f64,
+}
+
+/* ------------ constraint counts ------------------------------------------ */
+
+struct Counts {
+    num_constraints: usize,
+    /// including the constant one
+    num_instance_variables: usize,
+    num_witness_variables: usize,
+}
+
+// synthesise the dummy circuit `setup_keys` uses, in setup mode
+fn count<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>() -> Result<Counts> {
+    let zero_pt = Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0);
+    let circuit = PointInMapCircuit::<Fr, PREC, MAX_VERTS, MAX_HASHES>::new(
+        zero_pt,
+        [zero_pt; MAX_VERTS],
+        0,
+        false,
+        [Fr::zero(); MAX_HASHES],
+        0,
+        default_poseidon_config(),
+        false,
+    );
+
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    cs.set_mode(SynthesisMode::Setup);
+    circuit.generate_constraints(cs.clone())?;
+    Ok(Counts {
+        num_constraints: cs.num_constraints(),
+        num_instance_variables: cs.num_instance_variables(),
+        num_witness_variables: cs.num_witness_variables(),
+    })
+}
+
+fn count_for_hashes<const PREC: u32, const MAX_VERTS: usize>(max_hashes: usize) -> Result<Counts> {
+    match max_hashes {
+        64 => count::<PREC, MAX_VERTS, 64>(),
+        256 => count::<PREC, MAX_VERTS, 256>(),
+        512 => count::<PREC, MAX_VERTS, 512>(),
+        1024 => count::<PREC, MAX_VERTS, 1024>(),
+        4096 => count::<PREC, MAX_VERTS, 4096>(),
+        other => bail!("--max-hashes must be one of {SUPPORTED_HASH_COUNTS:?}, got {other}"),
+    }
+}
+
+fn count_for_vertices<const PREC: u32>(max_vertices: usize, max_hashes: usize) -> Result<Counts> {
+    match max_vertices {
+        4 => count_for_hashes::<PREC, 4>(max_hashes),
+        6 => count_for_hashes::<PREC, 6>(max_hashes),
+        8 => count_for_hashes::<PREC, 8>(max_hashes),
+        other => bail!("--max-vertices must be one of {SUPPORTED_VERTICES:?}, got {other}"),
+    }
+}
+
+/* ------------ key sizes -------------------------------------------------- */
+
+// Groth16 PK: A and B queries over every variable (B in G1 and G2), the H
+// query over the QAP domain and the L query over the witness
+fn pk_bytes(c: &Counts) -> usize {
+    let num_variables = c.num_instance_variables + c.num_witness_variables;
+    let domain = (c.num_constraints + c.num_instance_variables).next_power_of_two();
+    G1_BYTES * (2 * num_variables + (domain - 1) + c.num_witness_variables)
+        + G2_BYTES * num_variables
+}
+
+// Groth16 VK: α in G1, β, γ, δ in G2, one G1 point per instance variable
+fn vk_bytes(c: &Counts) -> usize {
+    G1_BYTES * (1 + c.num_instance_variables) + 3 * G2_BYTES
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let counts = match args.precision {
+        6 => count_for_vertices::<6>(args.max_vertices, args.max_hashes)?,
+        8 => count_for_vertices::<8>(args.max_vertices, args.max_hashes)?,
+        other => bail!("--precision must be one of {SUPPORTED_PRECISIONS:?}, got {other}"),
+    };
+
+    println!("num_constraints:         {}", counts.num_constraints);
+    println!("num_instance_variables:  {}", counts.num_instance_variables);
+    println!("num_witness_variables:   {}", counts.num_witness_variables);
+    println!(
+        "estimated_pk_mb:         {:.1}",
+        pk_bytes(&counts) as f64 / (1024.0 * 1024.0)
+    );
+    println!(
+        "estimated_vk_kb:         {:.1}",
+        vk_bytes(&counts) as f64 / 1024.0
+    );
+    println!(
+        "estimated_prove_seconds: {:.1}",
+        counts.num_constraints as f64 / args.cpu_speed
+    );
+    Ok(())
+}
@@ -1,1226 +0,0 @@
-// Generated by arkworks-solidity-verifier
-
-// This section is MIT Licensed.
-//
-// Copyright 2017 Christian Reitwiessner
-// Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the "Software"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
-// The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
-// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-pragma solidity ^0.8.0;
-library Pairing {
-    struct G1Point {
-        uint X;
-        uint Y;
-    }
-    // Encoding of field elements is: X[0] * z + X[1]
-    struct G2Point {
-        uint[2] X;
-        uint[2] Y;
-    }
-    /// @return the generator of G1
-    function P1() pure internal returns (G1Point memory) {
-        return G1Point(1, 2);
-    }
-    /// @return the generator of G2
-    function P2() pure internal returns (G2Point memory) {
-        return G2Point(
-            [10857046999023057135944570762232829481370756359578518086990519993285655852781,
-             11559732032986387107991004021392285783925812861821192530917403151452391805634],
-            [8495653923123431417604973247489272438418190587263600148770280649306958101930,
-             4082367875863433681332203403145435568316851327593401208105741076214120093531]
-        );
-    }
-    /// @return the negation of p, i.e. p.addition(p.negate()) should be zero.
-    function negate(G1Point memory p) pure internal returns (G1Point memory) {
-        // The prime q in the base field F_q for G1
-        uint q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
-        if (p.X == 0 && p.Y == 0)
-            return G1Point(0, 0);
-        return G1Point(p.X, q - (p.Y % q));
-    }
-    /// @return r the sum of two points of G1
-    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {
-        uint[4] memory input;
-        input[0] = p1.X;
-        input[1] = p1.Y;
-        input[2] = p2.X;
-        input[3] = p2.Y;
-        bool success;
-        assembly {
-            success := staticcall(sub(gas(), 2000), 6, input, 0xc0, r, 0x60)
-            // Use "invalid" to make gas estimation work
-            switch success case 0 { invalid() }
-        }
-        require(success);
-    }
-    /// @return r the product of a point on G1 and a scalar, i.e.
-    /// p == p.scalar_mul(1) and p.addition(p) == p.scalar_mul(2) for all points p.
-    function scalar_mul(G1Point memory p, uint s) internal view returns (G1Point memory r) {
-        uint[3] memory input;
-        input[0] = p.X;
-        input[1] = p.Y;
-        input[2] = s;
-        bool success;
-        assembly {
-            success := staticcall(sub(gas(), 2000), 7, input, 0x80, r, 0x60)
-            // Use "invalid" to make gas estimation work
-            switch success case 0 { invalid() }
-        }
-        require (success);
-    }
-    /// @return the result of computing the pairing check
-    /// e(p1[0], p2[0]) *  .... * e(p1[n], p2[n]) == 1
-    /// For example pairing([P1(), P1().negate()], [P2(), P2()]) should
-    /// return true.
-    function pairing(G1Point[] memory p1, G2Point[] memory p2) internal view returns (bool) {
-        require(p1.length == p2.length);
-        uint elements = p1.length;
-        uint inputSize = elements * 6;
-        uint[] memory input = new uint[](inputSize);
-        for (uint i = 0; i < elements; i++)
-        {
-            input[i * 6 + 0] = p1[i].X;
-            input[i * 6 + 1] = p1[i].Y;
-            input[i * 6 + 2] = p2[i].X[1];
-            input[i * 6 + 3] = p2[i].X[0];
-            input[i * 6 + 4] = p2[i].Y[1];
-            input[i * 6 + 5] = p2[i].Y[0];
-        }
-        uint[1] memory out;
-        bool success;
-        assembly {
-            success := staticcall(sub(gas(), 2000), 8, add(input, 0x20), mul(inputSize, 0x20), out, 0x20)
-            // Use "invalid" to make gas estimation work
-            switch success case 0 { invalid() }
-        }
-        require(success);
-        return out[0] != 0;
-    }
-    /// Convenience method for a pairing check for two pairs.
-    function pairingProd2(G1Point memory a1, G2Point memory a2, G1Point memory b1, G2Point memory b2) internal view returns (bool) {
-        G1Point[] memory p1 = new G1Point[](2);
-        G2Point[] memory p2 = new G2Point[](2);
-        p1[0] = a1;
-        p1[1] = b1;
-        p2[0] = a2;
-        p2[1] = b2;
-        return pairing(p1, p2);
-    }
-    /// Convenience method for a pairing check for three pairs.
-    function pairingProd3(
-            G1Point memory a1, G2Point memory a2,
-            G1Point memory b1, G2Point memory b2,
-            G1Point memory c1, G2Point memory c2
-    ) internal view returns (bool) {
-        G1Point[] memory p1 = new G1Point[](3);
-        G2Point[] memory p2 = new G2Point[](3);
-        p1[0] = a1;
-        p1[1] = b1;
-        p1[2] = c1;
-        p2[0] = a2;
-        p2[1] = b2;
-        p2[2] = c2;
-        return pairing(p1, p2);
-    }
-    /// Convenience method for a pairing check for four pairs.
-    function pairingProd4(
-            G1Point memory a1, G2Point memory a2,
-            G1Point memory b1, G2Point memory b2,
-            G1Point memory c1, G2Point memory c2,
-            G1Point memory d1, G2Point memory d2
-    ) internal view returns (bool) {
-        G1Point[] memory p1 = new G1Point[](4);
-        G2Point[] memory p2 = new G2Point[](4);
-        p1[0] = a1;
-        p1[1] = b1;
-        p1[2] = c1;
-        p1[3] = d1;
-        p2[0] = a2;
-        p2[1] = b2;
-        p2[2] = c2;
-        p2[3] = d2;
-        return pairing(p1, p2);
-    }
-
-}
-
-contract Verifier {
-    using Pairing for *;
-    struct VerifyingKey {
-        Pairing.G1Point alpha;
-        Pairing.G2Point beta;
-        Pairing.G2Point gamma;
-        Pairing.G2Point delta;
-        Pairing.G1Point[] gamma_abc;
-    }
-    struct Proof {
-        Pairing.G1Point a;
-        Pairing.G2Point b;
-        Pairing.G1Point c;
-    }
-    function verifyingKey() pure internal returns (VerifyingKey memory vk) {
-        vk.alpha = Pairing.G1Point(12482315145605634215648334706638549483275507710751263711046035768487804433381, 232585616273116244141968972397055400365968707694484139778424435636102707697);
-        vk.beta = Pairing.G2Point([20506536018984934809364196265542121829923090628662338845191224312266767951134, 10050690556184755524369731779316315771339825162592538108842741812853889104290], [13545582153576464774395402627100348460763153963959447750683600977224007444051, 2245699774199344401400093923406931940683489803720702752129705800095012052383]);
-        vk.gamma = Pairing.G2Point([12037645698608300624859857232711896945628064680938530968830528353051387749399, 15606854251108311624932492540729942072377571241162164974029808788155296745147], [12195029540569318793893576638245528390757467619377681266812242894838876342754, 10688328431330588095108391394620617003386696564554555570650218145941401356965]);
-        vk.delta = Pairing.G2Point([21572750832331394747762895622244011235662410533561193673486770426374412277063, 13742385414561786092052320351024841237159696142927471384985407793986173414974], [9635192938797755277021317769355417597822986759504392285121582485653220331047, 2630285547725763610525614752605783915666329478961957201327904340043410387211]);
-        vk.gamma_abc = new Pairing.G1Point[](1026);
-        vk.gamma_abc[0] = Pairing.G1Point(18986930352513477340891399126733251473459824074810557718080412354425648486879, 5616575326405318489930190306758220919795567308284181238548478976861376123008);
-        vk.gamma_abc[1] = Pairing.G1Point(2769928439907392142968484160020631928471507623790685204391673858830746200596, 4643027518372591102781693247466855867300444205266875008801316594049525710752);
-        vk.gamma_abc[2] = Pairing.G1Point(1193151720196433991514381289390956144374066158582170203299026162227868330185, 2463664350980523252697896109326775258235514239559494593922503187029902757970);
-        vk.gamma_abc[3] = Pairing.G1Point(18877757203483639135595823850440068663264009712243897351737292274227448430644, 13392174824060271136278461156431407439385852116785807556194770193625354759189);
-        vk.gamma_abc[4] = Pairing.G1Point(7623464385763970296675779484564049801695412258900627257989572101325060100252, 10256680753108623031535495873657756135394639137140835057606207945588909160907);
-        vk.gamma_abc[5] = Pairing.G1Point(1583686442786246086401258367068250556834945677099785790263267234388833441649, 11072818680784426492875826266043735102644701810678753579877926233594638812378);
-        vk.gamma_abc[6] = Pairing.G1Point(16118577738609358559620856423923844313619719315714731634373629153188290337749, 3073499804783885869889710811596690848385213048544000751490627886818946121009);
-        vk.gamma_abc[7] = Pairing.G1Point(62741009183675918210772073396955667169282601782230255682718420868505930078, 12384799337732662633472969797717184283854880239509180620951227473469865722883);
-        vk.gamma_abc[8] = Pairing.G1Point(12323186636222496513493840272580663111745411458765015934854589854770591659125, 11929924174676647164990857531824526262410086128447551562354648991995692059024);
-        vk.gamma_abc[9] = Pairing.G1Point(7203825610261531197768945682178877197154577723740360863100320790339071098876, 14876679633103623455430152004047897558548526696483081326529674811047385627578);
-        vk.gamma_abc[10] = Pairing.G1Point(13042818933125454312173416571278792200468786923101446496746102766691670965804, 14411362709231833878365937129531256830512463850556656330582380281983922683955);
-        vk.gamma_abc[11] = Pairing.G1Point(7148823492042989056921694486413724752016577062952134941534214065915839493880, 4593388158900435033303018613370018807173363432859892117789628389029754319347);
-        vk.gamma_abc[12] = Pairing.G1Point(2817795008899424312645502443965895308165584475147931811173144055544034459084, 12695286205320508498318065792803557179666886622022946173204988942082018863213);
-        vk.gamma_abc[13] = Pairing.G1Point(3588608624445968907867873679882075770651195717407971446068111427173521673148, 12679579058587511591522835080408596678296775582795315506451870686474179538608);
-        vk.gamma_abc[14] = Pairing.G1Point(14007339592239614019602378808142126227567223256418547094084065452159863079618, 7605452152593819742967699179803023730946473401666730606863232762526288355932);
-        vk.gamma_abc[15] = Pairing.G1Point(9559145154152171663734835974108105118176841549869257047468807059497279624707, 11796276849902823848810362037855220474726266730231897124381290507100190292277);
-        vk.gamma_abc[16] = Pairing.G1Point(10900905948186346305424377141931447244086661082680578656715209059496573978625, 1775149943943464066540605373749181801760315743754238541069593325662977828910);
-        vk.gamma_abc[17] = Pairing.G1Point(9127030687213146348581734070354457492204292730381383976341327168479042193494, 11193914019680696362604518781470423480652219155441951810237300504631646147229);
-        vk.gamma_abc[18] = Pairing.G1Point(12505713810119264692156862583072304003015219201278274348228983749176633290696, 9820270630831935787812489564279774512053546117845354086704769824970866593424);
-        vk.gamma_abc[19] = Pairing.G1Point(10112132104274495938132281896645629470451561607454714657154883489233296707089, 4920260313159128104664096658637149700031178175754652896007112062376240245820);
-        vk.gamma_abc[20] = Pairing.G1Point(13649701005889835881041548117325780829668077643820902055656619339673184346655, 11361560817479235578816802193960159453640687046255406018864740427492674683484);
-        vk.gamma_abc[21] = Pairing.G1Point(3915147447073150095876206709506623462677276151228785509636401844268553582949, 8429361855450189347364813295985704126811903722923317584101353937880281231472);
-        vk.gamma_abc[22] = Pairing.G1Point(739784447331773625096617571162937687262092231039921670152633141960860660305, 21759297606396561143891690170183400053447630758461313371445761618482070433692);
-        vk.gamma_abc[23] = Pairing.G1Point(9842750237073034272429218638813828896152882009414879207196236360753320753204, 17989355867408986290325307435952552047558732255861637041969473471791473320368);
-        vk.gamma_abc[24] = Pairing.G1Point(6594000200303752376413931906565514940934245283697814157714366219475939874426, 11002165065700412463047831762665742227115570575184498022578594065884364641580);
-        vk.gamma_abc[25] = Pairing.G1Point(9387975286273855610888058825372230528043100489760599343396082577606356275258, 12921506749109904340553758281892127792099548822622344287945681983568973260019);
-        vk.gamma_abc[26] = Pairing.G1Point(6261351909152385554297414627942562998899235333054419782301354997353201106148, 11468606800309492732163553688707046588336399852917380163589647666809555660937);
-        vk.gamma_abc[27] = Pairing.G1Point(21887582837997540905931564855641244308201806611609679124205419017046742668827, 12875419541974656703171403431920438176903464096961084006560634844686236850593);
-        vk.gamma_abc[28] = Pairing.G1Point(19460424857154606987474787195917727654069726293195709150378591973792249224926, 5825850714979087518459663797119232035523973605998005349761734083314839766021);
-        vk.gamma_abc[29] = Pairing.G1Point(20622042869922313190758783804074846016362575584984665798188677916935162144783, 8382896120199024507221103282339308632462535650000502595011675968499319772760);
-        vk.gamma_abc[30] = Pairing.G1Point(6943293808593539455295094981494766624763284056549461445342290008876818064452, 8256960183525183388728621665483513486422160025537008404430474874344347687204);
-        vk.gamma_abc[31] = Pairing.G1Point(20881929091116189077016586204475986330337516240121056450537597931193545664490, 19961913504312514615931233011862052582702591012616042771245154943535550054822);
-        vk.gamma_abc[32] = Pairing.G1Point(4170500335658677110900617602817750998269743353684075648235171678142555720824, 8778587192605178133439680731527911696400685294517822855245993716686974697447);
-        vk.gamma_abc[33] = Pairing.G1Point(16907735006213203318780859677165575815051047781517832912835779234133676839354, 15570149468423818562607546639996651052664528953505511552163328634485332379202);
-        vk.gamma_abc[34] = Pairing.G1Point(17760046851319999907224610933122238313422770341786428162935995501034014900278, 18516138212894743550195492489000732547172329571617916965385989139074645250276);
-        vk.gamma_abc[35] = Pairing.G1Point(20410818158424513380118108358333093506189017383454484248603967376923621836040, 1293408834342386847208694217654339641638515712400221688368093261164947738808);
-        vk.gamma_abc[36] = Pairing.G1Point(1625347895109248011573747157074693485313450612186489719362001166564027964125, 20690280990852794299650615108470105058114045140378886911116334649381746325060);
-        vk.gamma_abc[37] = Pairing.G1Point(8913301618721539813266722490927710577197409367139519293991166773976945413293, 21661762836188207843289371050381289083216907559237557414585276250658581038709);
-        vk.gamma_abc[38] = Pairing.G1Point(10449569270506217291324297883707224106888551062331914654668401832076935327338, 8349560544330688725041589679814372507591052754783962768338034093049429881474);
-        vk.gamma_abc[39] = Pairing.G1Point(4181817586717766021935252217501958357473070333376058053628119940544662004652, 8043944320513036711993176871584922233470208017608608539038472510289670690127);
-        vk.gamma_abc[40] = Pairing.G1Point(13233256095796588750260160693345242528673383157485747451047325622586107778681, 13872187352176208152108988525861108662202314801726907098693487868118108695073);
-        vk.gamma_abc[41] = Pairing.G1Point(18232289241347356642287406798026883109197336272945138704975391182325700826648, 13610363757507418979498287279704683479565162549766476850755674261019913630188);
-        vk.gamma_abc[42] = Pairing.G1Point(9956828529798626839488660290002679601788978402567407493606033935706521763685, 1980826182676164533858402956742884063060679918190604806188671870230667742590);
-        vk.gamma_abc[43] = Pairing.G1Point(5827177956220569143939970289864659086891607432464235030154651044156781831079, 4146325782520128926899929458673211813532954033743358792176453987279006878346);
-        vk.gamma_abc[44] = Pairing.G1Point(5328337402846418411939510739468320157289589088731260407784637857527263760494, 8083593760087455166839492203112989694186235820051457754200163339205562330008);
-        vk.gamma_abc[45] = Pairing.G1Point(3895919666233810568084435308097074533492235462535208633597390702721154488184, 1089602046147505031095932696172712526213463748278703937035801011299505677525);
-        vk.gamma_abc[46] = Pairing.G1Point(20452131883950714397178145436547224485927990765519514182497122790992037164397, 17351525168282304769774922611396932541480240673758773627036022350502902623399);
-        vk.gamma_abc[47] = Pairing.G1Point(927159229152796797218350931601730563709286774235594322040549874016232599040, 8382090617146769942810372172019395795908919602107472831901918517795871287559);
-        vk.gamma_abc[48] = Pairing.G1Point(14122739155794465858268004642021601080501625576295962120809641677089769883682, 9795446179846035261414749739893947012534913078293628923809034579293213655793);
-        vk.gamma_abc[49] = Pairing.G1Point(3755648209035965171691692576676420301912180620955775668326806351282721641139, 9710893474937593998699480172204627703945412235851571470633456529661696162564);
-        vk.gamma_abc[50] = Pairing.G1Point(16027150090411222974182233811638216072026930127349300352799761196153712866986, 15928796398056641516228051942281622086724746384176030681933622779641045588798);
-        vk.gamma_abc[51] = Pairing.G1Point(13212130362176746618020715507341944877320283781480451163601623122443043484081, 19394203077454976359543298231717440971926222098976278340702924863447861978892);
-        vk.gamma_abc[52] = Pairing.G1Point(9203117582536716300654050442279338505362299159474643375178215415196280601231, 3483390613757829523632906180276052980570824058364923756958875977830376174310);
-        vk.gamma_abc[53] = Pairing.G1Point(2371740747734308545225450081871887169827170134190895819120655416194636955558, 17006747133034432554989256358702953257081318296666851315117945471934269021097);
-        vk.gamma_abc[54] = Pairing.G1Point(10115221012860421531945839263163829069953767046448809668504257074409215332812, 14685011139394578827909788691482244409244176708966579184917663211105815436645);
-        vk.gamma_abc[55] = Pairing.G1Point(14663146025731224341168384867341435691323916905338119678395798179893099931873, 2574209712410051442884993449080483275110138255614775614735631025631546204707);
-        vk.gamma_abc[56] = Pairing.G1Point(19269575542274375499506799893427037946578905835922715958787084793375293682473, 446951664918096289594153777276993394009939515489705738908173363807694751193);
-        vk.gamma_abc[57] = Pairing.G1Point(15500885686647462793288204400015533582925337503993968323273241508332871748146, 9036861518629826612321712817722546898956450115383911226335150899403930939575);
-        vk.gamma_abc[58] = Pairing.G1Point(11961914394582028860990340470735081364976008935657765836803248074164879515267, 18830117561556110431102370439649562068088649174497010422164103291868942602685);
-        vk.gamma_abc[59] = Pairing.G1Point(21047011137579188599520592558467807746573372505414690541485156554433459246987, 17706040091632490983057693067894044401058590928102029571391336808728380987436);
-        vk.gamma_abc[60] = Pairing.G1Point(9429669129087287475413988677085150535973613246655265332454935889044975835489, 11895412705158806205619165122359062645779394186325941291889236795928191859885);
-        vk.gamma_abc[61] = Pairing.G1Point(13273073208930283571714538663784002782040844415711840763311866458166993357375, 13051880202350195962419685789488836993356001321864310413226810351170807412508);
-        vk.gamma_abc[62] = Pairing.G1Point(10666909805366773657618413322908933295415215034162653303320835532765614495445, 1279777862818318430353409368958876754060928959491221113570596188935721607793);
-        vk.gamma_abc[63] = Pairing.G1Point(4434323142075595587652854055645457235529506271942592495962989570408765905273, 4849171619955669448937844955583121588013419464987351975184354881403323453224);
-        vk.gamma_abc[64] = Pairing.G1Point(9644125437928230644003747681126489197439923744311771511125258927958477544539, 2458937504752898613058852816283578110929459913240987629169977805108825530063);
-        vk.gamma_abc[65] = Pairing.G1Point(19385347425285128868582403789251892428389565515339751248412692737196144890135, 18468338437760868594358990548165936420855942933916728907326595626006511400966);
-        vk.gamma_abc[66] = Pairing.G1Point(1854543877659450987417522133618417002688847464565170548126242470220110461631, 15108308245451540650978471430953033781410037756484954668670115025596662184380);
-        vk.gamma_abc[67] = Pairing.G1Point(20364358524098235424626780435166676906853803836871015607630816243448806045444, 12900685462274042360484305827998806602464580737879012099070825840848847629203);
-        vk.gamma_abc[68] = Pairing.G1Point(5544114583289832086442576505367036009198918272509423186460639447178922820135, 19905659282526424601769496992857355209481897383602497009430815845666414414911);
-        vk.gamma_abc[69] = Pairing.G1Point(7758564449073194797429803762879275525040342627556484727660632678869332055503, 1080034370306912640809917986823968224900632544947104943897318586489362243630);
-        vk.gamma_abc[70] = Pairing.G1Point(2920676081590881967697685836512206855451600526891267123031002992444548049530, 16132542986137514280688290235924660598178936675384964543869064970410474365817);
-        vk.gamma_abc[71] = Pairing.G1Point(21520343084265088700276655667439658455004096541975428960447699514618290259088, 2246831921518531577326266911867400406101712876058906422327317266905765175353);
-        vk.gamma_abc[72] = Pairing.G1Point(9610809567249720086760955857709757912829406428960754030490554964809269814622, 2277888797574242274683114645705674228229462127980972776049522356209667820507);
-        vk.gamma_abc[73] = Pairing.G1Point(16135335465998937346520397930778446798637093347798930331149630271713057924057, 8385040965472548739979415305838890835058089025941577406957179439723506669322);
-        vk.gamma_abc[74] = Pairing.G1Point(13745081124648802796477357074642688268014492914291380617820749512607005177257, 17498672662664729281023944266573447920589885370242439970076465643134978801032);
-        vk.gamma_abc[75] = Pairing.G1Point(12255146775482186870632948107214662800518158318434963286223197628365060565568, 5868110095846564610304225196368580038257345188015597700968722384570240291566);
-        vk.gamma_abc[76] = Pairing.G1Point(12104917030798585707320026238928049871490233368078636041162009109360345956690, 8693838073042683984292858387447691966567234323925616208212466712790238992676);
-        vk.gamma_abc[77] = Pairing.G1Point(1267285691530218742783852763452159399910084701360852141632803248379106482468, 6101558807655664371680296613471715325430561954702067202940492831373853219221);
-        vk.gamma_abc[78] = Pairing.G1Point(8588380612921154115557085292375550698827589357736582505005373870080825313618, 9987193008314747293408894296252307067506799174149538008246033772689935894891);
-        vk.gamma_abc[79] = Pairing.G1Point(8468202815712997386308586392816322723680550268163050418523696674849563205566, 11654655321541577421071594854337136398925517496674682707616706707803635452774);
-        vk.gamma_abc[80] = Pairing.G1Point(3129882351590851072923911216033314010997775128006882253440719017656717213299, 6609383288609748753255787093121924372866958283338951478972547516933958093827);
-        vk.gamma_abc[81] = Pairing.G1Point(10146538579989667007450578095589938026172548777317725862378333021672425292242, 7191189585945686012563025352521720779484192402178573478515736040022471537604);
-        vk.gamma_abc[82] = Pairing.G1Point(16454550836634767820977052421488062754301754573662146959649394027943417892093, 21186440027107078308676915616147836438059272212688268094413496156043298016191);
-        vk.gamma_abc[83] = Pairing.G1Point(17671063339824267729723861798391092150732526578363080235573490960086591750622, 2450701194031540677234270199964591273720674749287282843725094577084960939914);
-        vk.gamma_abc[84] = Pairing.G1Point(20409813491455401597563319095552989097065765120115936768646876217016155569842, 17247411311872092900839749538423277199498007188904675212725402061410491298521);
-        vk.gamma_abc[85] = Pairing.G1Point(20730980048977766414667267498631356931097768761462852539305617406102719638008, 5153317654084559973348490990389360469996025297173101973577764394069835692360);
-        vk.gamma_abc[86] = Pairing.G1Point(4701837132642100615500051651662722604403804801509601204825952069529207230526, 16397687702479585276874900416216685299986076506104366121536015077323190292231);
-        vk.gamma_abc[87] = Pairing.G1Point(7054259072209612115940493795360337837236735831598010532342824761372921360042, 1304948285010209733557694940395365472754029337923520198474873227221881009288);
-        vk.gamma_abc[88] = Pairing.G1Point(9906964092042931793638610525520227275598197913522800350731732682466782251016, 18914566301864599823072075692422430541612338495453711835390249226403182875473);
-        vk.gamma_abc[89] = Pairing.G1Point(20547138746563236883021419296632161517003253227404137153118700759280853444288, 8398765181590016836024729916432435306684083123795700502034654004507403002348);
-        vk.gamma_abc[90] = Pairing.G1Point(2127850513306818790851253524939949024505125456936394003987348309561877358205, 1673627670033002531708013622046001865233503713785000744965022809619858432622);
-        vk.gamma_abc[91] = Pairing.G1Point(2326872539988513009184939141897157142211516185300049128987405822484052973012, 3451379945541959724525027206337142852422220399547767571939232624071549315517);
-        vk.gamma_abc[92] = Pairing.G1Point(15423102398250360338753484900211858303662892557034928674836657619299567519432, 9786674548898352844001891765975326047491273586268053846378878237470034867402);
-        vk.gamma_abc[93] = Pairing.G1Point(9476916299270003765831360629296345788150974065924601013421631939147653003349, 2427256308054385461407174665597812882011983486723292180166748312987502142254);
-        vk.gamma_abc[94] = Pairing.G1Point(20927887828927492709314214400704467291533537197207492118774656861150054813709, 17759056822829983252265351710004026734884906983666791402063333947557434073160);
-        vk.gamma_abc[95] = Pairing.G1Point(16206266862134597522659802183750786614125437174174716818123163528794262205198, 9774610364029753019101138315271036605517981446183350807889741226517710065979);
-        vk.gamma_abc[96] = Pairing.G1Point(11555842548776245249031904366132290456895240824184206563391003718124731788401, 10241188272251308171285099557006620009530242884325029211847201381153573008482);
-        vk.gamma_abc[97] = Pairing.G1Point(7255526827739092406530511642006543739972829193749777344370881759100902572432, 20198951088119977372951098838795960937293620104239948394366046671762197539604);
-        vk.gamma_abc[98] = Pairing.G1Point(2401363114225535481928031396098840317277719515060557987263121088769097271144, 5833912108968896971125853777554171347434903040567452524777002062510752687529);
-        vk.gamma_abc[99] = Pairing.G1Point(15648352486067476303908488204856779461556205270834207124678742602699959725231, 18445920832545940770130546153383051575041821191052824276671287713690781576501);
-        vk.gamma_abc[100] = Pairing.G1Point(6907990614018468869035756214660334785388962813977270194320165922894699376692, 16784463914324126988353857767687541804156279749434605408863807052647440922175);
-        vk.gamma_abc[101] = Pairing.G1Point(21519975737609277127942587780589640887585971511232035554377587072099556730051, 7381526131666306144582595772288687594151767685083810124401884101187757160336);
-        vk.gamma_abc[102] = Pairing.G1Point(4188797525859483136746831089544587032397930553352840278193696476813027866249, 1179762959374971197159251066618915533045658819238116202438886827382946868662);
-        vk.gamma_abc[103] = Pairing.G1Point(16291545569914896317483282266680665112003410804701413452364421424151552375672, 19078946009647464394261506807381777365227659402286033727525079307760211662615);
-        vk.gamma_abc[104] = Pairing.G1Point(20639331905302344868848998809941283791533412737688661413918831406294862714357, 11652544830074514953961467098413276632294089667190156685339629258043700948841);
-        vk.gamma_abc[105] = Pairing.G1Point(12057776845165371830886879895003412393219039999591104818129502278937439735592, 2452399669491194169253098261381717604768587169058318331774237734765464482772);
-        vk.gamma_abc[106] = Pairing.G1Point(21502997445351244631596291304340054327901792623784770407743019343985007675455, 20261862483695519121041588037980172472832555076013310886398797736942434715387);
-        vk.gamma_abc[107] = Pairing.G1Point(10519448055811365393715524012917764642938168666336288395082321807683421572497, 8312785603402945350607095303072997175377061878247617426719002810268593213426);
-        vk.gamma_abc[108] = Pairing.G1Point(1122413910000252828677444447361848813752094298611140273838274899381690758328, 9164980955029080661094330149724853571695078149153235753763631592223549923499);
-        vk.gamma_abc[109] = Pairing.G1Point(10764719871560240923702531581598092027602207801304700061300684652284709166699, 11316484464924752601093439288034119777744664045033217043435099347370611353171);
-        vk.gamma_abc[110] = Pairing.G1Point(4108665899053097225388320839524931209083142323502671735611617335404977791328, 8946447772697240540381801071300664495925420969511263720358021095562687123223);
-        vk.gamma_abc[111] = Pairing.G1Point(21190671297830100840922762946257479213551854719114987584857766493409036290652, 3736281199606731533039048897938973425011507276957696642022117247144545949724);
-        vk.gamma_abc[112] = Pairing.G1Point(19367215966439009096307967839754467602243460033765446574949979638743693956274, 145391785180612975969463925425278915226583965478519739557721990017523956508);
-        vk.gamma_abc[113] = Pairing.G1Point(180052962576174380691208211277794678581367161203987155320007692815539339868, 14215706368420379712473522322652610826625958789783081522666846653966276994969);
-        vk.gamma_abc[114] = Pairing.G1Point(9666220394697953824784935058092975217069133694598748702378731435015350052391, 5819379944941741131530109345321173279026750779522775008649304442371072161714);
-        vk.gamma_abc[115] = Pairing.G1Point(19888135014949084150388153262206937900167615695741007659130856124570408641351, 504237853475590437254666932564019510369399497165910576784014408800600145281);
-        vk.gamma_abc[116] = Pairing.G1Point(20122799647814263194903796220357223195326463478351332418043288505064830218709, 13008409621642901771155158151179831087512065513693890551673344343466394775481);
-        vk.gamma_abc[117] = Pairing.G1Point(8857909579739144048241457897591767140852915209732376809679640900335079452948, 11577921777530507086575512621295599936994915921864083145469778516282117496247);
-        vk.gamma_abc[118] = Pairing.G1Point(12140281514633955413049379095171734145308684886017597818569030503567253981848, 16058627527006359257523331604091176295685996014843855144148287095610266243218);
-        vk.gamma_abc[119] = Pairing.G1Point(21430409719256857723707312869935168342003743160868687067890257313361867811681, 1454102325197570839014382015502979167477012746024027648154484115637817461869);
-        vk.gamma_abc[120] = Pairing.G1Point(7097576538512734362930186049357756775791042829565900236187575015632527343052, 1561968604106914827290019560797791542621096347184738721582993559266619926453);
-        vk.gamma_abc[121] = Pairing.G1Point(3119069293379372121017639597586694676965387984775864491237052856356990227521, 17530118800511219635186268574466958952060312146539272010274718059167240270304);
-        vk.gamma_abc[122] = Pairing.G1Point(16448035658624245901268057886898212211087913641089478861049416235050856962941, 19190982523913327278973990292610393134780839715259633672560662531181380588558);
-        vk.gamma_abc[123] = Pairing.G1Point(21579924941389433356763636982296852577569241782825353890873529098008222681897, 3889243067487187277484547962208258238551786634126590479291479168009164488145);
-        vk.gamma_abc[124] = Pairing.G1Point(20782469641970002612273257781989132560444146550348283318476013769997971869279, 3688483912713981495352258772248928004293723563395312002833555577428740735950);
-        vk.gamma_abc[125] = Pairing.G1Point(3075403267102412891966028693703416423890971808472714456346398057118649955413, 714595708761203691853745426429685314485757610322535335749853325546306631210);
-        vk.gamma_abc[126] = Pairing.G1Point(13570285848266919847272153592277357301588799408971584118006270457205953060263, 12492090084651102356490184572437268886524160018711800165611866839716064292051);
-        vk.gamma_abc[127] = Pairing.G1Point(11224636430511346732447969178465906579982460975969767557205929879559856560772, 16925554025238755956586846633856679142387287360906586966041673794733781570126);
-        vk.gamma_abc[128] = Pairing.G1Point(11230247155418417649872324980640853724897894915059331399483824851928644794454, 7360412972400936273406008050980096905016185194458728670229739410886238454493);
-        vk.gamma_abc[129] = Pairing.G1Point(18122485221994603850601924818737118724069341044098214077952064624432814957642, 3545154794840685042878098019836155968766420839777065527050903184224534529546);
-        vk.gamma_abc[130] = Pairing.G1Point(14230724387644663639359887593025249117023891110958794193820274888077016532284, 9461593834379358466589619016884029300659296016134525902430411344898216637792);
-        vk.gamma_abc[131] = Pairing.G1Point(14118391069431756674861757478761734717104275699066825666237953896401946392574, 21139143731512230866605335872785623108548239557465978264018454004458034756624);
-        vk.gamma_abc[132] = Pairing.G1Point(7744563361721862220622432774718980722980864194438744213697885023650243114103, 8838225104126754339848088116533049690075168560135519173933326041719367581783);
-        vk.gamma_abc[133] = Pairing.G1Point(11330645618325847586837982793039593178799222276300954262458339303210797978263, 18870185242961814421803196620440018595589959411316103025691814010346708348545);
-        vk.gamma_abc[134] = Pairing.G1Point(9370949860659555635466253082983350950887882876724389436449176944990999150113, 12248031686471029024432392949908966832093959623710884425828491093896924998798);
-        vk.gamma_abc[135] = Pairing.G1Point(7255515305291549948216038397544467213332137761553069442719365988782241648961, 1263541856223674134003707728739738330296526721275463811019918842944685907465);
-        vk.gamma_abc[136] = Pairing.G1Point(20428620265430119699772714267355563666003520555972708381484316649178352968614, 16854135291639038257843370350258604008317449926819804161442016941841660079295);
-        vk.gamma_abc[137] = Pairing.G1Point(16099749842947722883584721692790917754814188223057686276276803854625088909030, 10967076292559741305508741996115307346860023161383606096215344527282748242664);
-        vk.gamma_abc[138] = Pairing.G1Point(21826506455506701225203807122838495745334932916534451220489734263004665338845, 19627557677321572262886825907450202828993753259731159655892039248015278971963);
-        vk.gamma_abc[139] = Pairing.G1Point(16806519441900208279781236938668907704389228819130121775564523471628578465536, 2008318314582837700500766667065403724294225437822069568428101531317442348010);
-        vk.gamma_abc[140] = Pairing.G1Point(20939783321340011668721424874834090626473418234680913629448915864102321198166, 16430730781051401310754690358559978781920322060800851403789920119609503866235);
-        vk.gamma_abc[141] = Pairing.G1Point(1047246349753096980603926832444300256069819399107472224670894012310544751393, 12658296713018110472715118519845543473743164271659071931958914487679448091211);
-        vk.gamma_abc[142] = Pairing.G1Point(10701037797318561845051692216215060032969677068947592534160801622222095537190, 17458588499943708693679711438781376643771274653468418320431781864956581168172);
-        vk.gamma_abc[143] = Pairing.G1Point(5990792684606088725248575516560457675697658518336802624327735984171621026172, 7669125894109464153346096268050204077938754325247157342531159306300681564499);
-        vk.gamma_abc[144] = Pairing.G1Point(839189625057920331646279033078473362293941646448479613775521530329983156590, 3356006919536817756498271119091153267027309427313237425022429778497557215032);
-        vk.gamma_abc[145] = Pairing.G1Point(10130832181453295815152041326480842714594217408213648932726921941666018696624, 6330830664376994878635551000588650102823521590564884336691209353336215353546);
-        vk.gamma_abc[146] = Pairing.G1Point(12159011614724779729350557742204532308316942771208297102893456337454818413056, 4198577857458321013640096160529586306683454252191597913253279500791301853193);
-        vk.gamma_abc[147] = Pairing.G1Point(13001502790019334663467070835297693624271230980520709620065144090980923917974, 20766555703214441875803530616324159645300708988456167940856124897283739040312);
-        vk.gamma_abc[148] = Pairing.G1Point(14589529596305536195581363642126446625758689140803430015710604106139255108406, 18966585915907763378726260876911287189701709447527844782096046081120299432231);
-        vk.gamma_abc[149] = Pairing.G1Point(15596297808318224686474545889199530785003008227241951902546253585436896932612, 19964342974700672318990276897671593303700197360242506916900604600159262388249);
-        vk.gamma_abc[150] = Pairing.G1Point(18027241678584816682170614670098095516783174595320023611691251543850560144765, 11462682801214858719218387541598692074371109361676328479531085148443130709938);
-        vk.gamma_abc[151] = Pairing.G1Point(15893267947276931412704481300871223559471829992361863681159997362066168513681, 9038513357349461170276821645456431430647477283208727564948125475387556488812);
-        vk.gamma_abc[152] = Pairing.G1Point(19854410533416807778080403647095853525327462335382495969594250328458336348960, 17014929499828361610079845484468664564989141053234433368235930405174345384608);
-        vk.gamma_abc[153] = Pairing.G1Point(18849946016284419535590289287537053504168644354622074208925700395929210751629, 12447425143680447212924056457447707107567030750755680535506463701563482445411);
-        vk.gamma_abc[154] = Pairing.G1Point(19867099759457787166270322732813552793272031138384900515212681955763661261069, 19377524603777268615588786764925192776351421825375506018757858717926355623482);
-        vk.gamma_abc[155] = Pairing.G1Point(17984434850220871408681289064245029066838221777103746540219637201825235595966, 12233716978789886932622906526406605949167854610620284474264084137788519737122);
-        vk.gamma_abc[156] = Pairing.G1Point(9422960952002808517236829005767668058333260870294384296719551946381442039396, 19312638446615988496722800101222371279132325263103895197055441777953002922918);
-        vk.gamma_abc[157] = Pairing.G1Point(11158307091930449451106080299599249223524678133587218391961267760443864460201, 18683788977173514449177033523103374547704632829899689502968165998437838251874);
-        vk.gamma_abc[158] = Pairing.G1Point(14706736271524998046530091984537451105732313786942186096837318895656323358458, 8645127512767728362098531957448523206215118009317144461472210003885978334714);
-        vk.gamma_abc[159] = Pairing.G1Point(17234543039166501271523603332771849573245663971889029161884503762392461334703, 7830352533147328477928509586043380695827608670076728872783883252871591099107);
-        vk.gamma_abc[160] = Pairing.G1Point(301051451035711865683438447855648307425248523166970756352557168846558653140, 6603782358240954057209966871913356738499467652410400367525426697703729063384);
-        vk.gamma_abc[161] = Pairing.G1Point(3662907511225635821950076321856564773672854901470253121515161508871295143100, 20852862909014704940560754660255408034770770086189266460437185872070878915265);
-        vk.gamma_abc[162] = Pairing.G1Point(7485011906634891387414172380693142049681302918871043474878412443962626583926, 20054566064438108292886861165324252429367630508995138389181714972950446559742);
-        vk.gamma_abc[163] = Pairing.G1Point(5592498738120243558923246082110719298850240881882573813975815846494227831324, 19837756899729293568333766483389185704285967592981834301599614695264685723546);
-        vk.gamma_abc[164] = Pairing.G1Point(17713336268287532365098059122194417900378861360118218035795825741163115396787, 9069931919133791224377552776725576787826500088680461977400281231942406531842);
-        vk.gamma_abc[165] = Pairing.G1Point(11641532730208117692525546047341138718621389609948982824914562839307650965308, 20213992998661349105083270212085703948092477536882610598549487669839204180356);
-        vk.gamma_abc[166] = Pairing.G1Point(14941554984612461857375586883574785483559232550653865744410450279073546439650, 8262706653422975729202609877505038863033691971124094589251204779538400723093);
-        vk.gamma_abc[167] = Pairing.G1Point(8502439092189614243851180932727300657659893821267125140724824937094018565823, 11292330495375831574911658853400183773155166829490629359063450170196396022757);
-        vk.gamma_abc[168] = Pairing.G1Point(7126064798798945180755444174781259729814903179053449001795830750196713465303, 116194800673411997282155750617838342858273856638678979057387602266759985720);
-        vk.gamma_abc[169] = Pairing.G1Point(20617535685223902341130302374765844115511569848854292003713824638041622558238, 11565120299988266448170340166431330603776670667572076011057017888853881085903);
-        vk.gamma_abc[170] = Pairing.G1Point(12520021837077920906262485763423812995625404751699904702189926300841632574594, 20046018535651024468948083372698865298447583015494936034900257239582527639771);
-        vk.gamma_abc[171] = Pairing.G1Point(12822268491866953269060150795312136988721684658761120284543203761964313119946, 8289603449706219593641534157561666135180010702142618769777885371385235439868);
-        vk.gamma_abc[172] = Pairing.G1Point(16870801475358867213302627539163408233733303001465644320692260911797589027589, 140481944325101811770616608373182110348388617277468176907128858656562780835);
-        vk.gamma_abc[173] = Pairing.G1Point(9056492320774788060506495276088720366794531304663136192716980715852866889306, 14570450905830142989463573368367299319926088075283939810405602360608510304397);
-        vk.gamma_abc[174] = Pairing.G1Point(247060543828515512866504887516795055672998312773554467190967114984500800539, 18226601903395593859365131828180240452739590809760960544312323310332237421912);
-        vk.gamma_abc[175] = Pairing.G1Point(16631427196333624705124694537191731066986510332506438807560588644516634231256, 12119489727251219607993109768825268921885624008714982501586856000407415621623);
-        vk.gamma_abc[176] = Pairing.G1Point(21622682747372945583659770455901663755391219427354646208751563944281680944073, 20269071942552283167982711076348973280769475768642043130700867833006153724874);
-        vk.gamma_abc[177] = Pairing.G1Point(16056037716016977452435233009020157186982367565116787060300743595839771417052, 18010716228347274409874822706454127740710312293353767837085838923031131149796);
-        vk.gamma_abc[178] = Pairing.G1Point(21042387119039257330591061119792181357753607864564327669875646798100284836769, 3121819252485218661319694314558190381227920229879483355931293044803511714545);
-        vk.gamma_abc[179] = Pairing.G1Point(16256903056694355444376309820434863004110404435296943029397853238931748405073, 10678836723302325113032266953631263302112634289336418975514672514706669326104);
-        vk.gamma_abc[180] = Pairing.G1Point(624221652271288279925458489459119187193189098050894745006451078417889711300, 8532087725710543382370887980201598448188463343590209609329437185303107889838);
-        vk.gamma_abc[181] = Pairing.G1Point(15428253873866322893582958916249171456890718851402506087009083506200974380242, 16887683916022212330387921330495605980128363550128570453042857316954081918239);
-        vk.gamma_abc[182] = Pairing.G1Point(8618155863585832004083881127323997337594941247028508337438895417502934526218, 3561195110897493318075432610393602717580931932685312170694398706546464765170);
-        vk.gamma_abc[183] = Pairing.G1Point(14763869305803165278665604957872740004660502081558740829321023595091916456297, 8040395293123732670388686540869432375646849861809489882209569804844683813819);
-        vk.gamma_abc[184] = Pairing.G1Point(1315061294925765626361772943890872561473431662059077105721765200452404389543, 12006266981144335286032918920985048496973955831232761014475725121162333650646);
-        vk.gamma_abc[185] = Pairing.G1Point(3455282074071981090955769235034119020793610708564646710695349198773301383504, 12650465629484213385742202481910426958491352759155374530155561484168521296077);
-        vk.gamma_abc[186] = Pairing.G1Point(17607200411790295885784537697022192465022545643452815723291714156343668765220, 9488748218417008247897244495336419305370108239231023667572979289039990726418);
-        vk.gamma_abc[187] = Pairing.G1Point(11021498766507469905688712601663746039708729748707613695862265039870379992453, 6223824541620038675200154497724578558379703299213821437906268961010336037283);
-        vk.gamma_abc[188] = Pairing.G1Point(1137533877453557899946009111159834977631419327406850624015710098975031161449, 13670845941336979812634054819392860300109426076599096880497095554653400907696);
-        vk.gamma_abc[189] = Pairing.G1Point(9690596017341646347455505497229886531990110791830345419814096494930491332842, 1995744121461870264025273793168414041967333653341387459385023754050545992924);
-        vk.gamma_abc[190] = Pairing.G1Point(11320306960546462579554211703274659922487572492113560702177825202313661950386, 4306713120368107535400556797543352274176429452433369780167040450436376392226);
-        vk.gamma_abc[191] = Pairing.G1Point(7382079910079259142471971935371668155726604364360431678248962326770462579339, 3841842397074074256252262432411248021294256148796199365688670914501533741829);
-        vk.gamma_abc[192] = Pairing.G1Point(18419173248555200381100752344058242501869249334803824671318723885535833224122, 12233657313557822042617588803980042039552333593532315696338798843677930346294);
-        vk.gamma_abc[193] = Pairing.G1Point(9240352862228921515800202801308957141688845770415523743104102676395757257621, 7836022107696037431863486353375843208665418995858348572351018125930201992248);
-        vk.gamma_abc[194] = Pairing.G1Point(15272320295059291087947290680341211773265147654693057378781312110523324735422, 13094842691969640616626989629001634104872888632034581695541426453435954825427);
-        vk.gamma_abc[195] = Pairing.G1Point(16673171152627456443488318997802535126503702751880538633689022152892283984690, 18148071621652755953154581381604456955366053202262333819247076102424281674512);
-        vk.gamma_abc[196] = Pairing.G1Point(20865599302489207482424931133473333670444543757695277342011894370345001681348, 12786419419944149488642218501672399679019778976962788620113723496800489250753);
-        vk.gamma_abc[197] = Pairing.G1Point(6989091610033483206586766974658063197620293356071334269299967397360566649586, 4311495222309550504335711442996141251977140140615304764766958387133691110413);
-        vk.gamma_abc[198] = Pairing.G1Point(12057510597657714048710517860958612726551075652225693236648147705266630168833, 5637289561814763068611996332786512098396902921936193302772383523596956750053);
-        vk.gamma_abc[199] = Pairing.G1Point(15543259424816933873177942764600248038523959986109812406444784481595113763860, 16550174022180748998774175354019045287134742518291271063181790527000347159265);
-        vk.gamma_abc[200] = Pairing.G1Point(15112216608125962749452676399199916898279777750423886611906104229889571650786, 12220735832598966675084667969793572165363067121481888287135702424291594340819);
-        vk.gamma_abc[201] = Pairing.G1Point(19518979851346166289730453991461365591198231905221797030159294876211030066238, 12590224167639392912244041697421204731440317807737522882557959082031753787111);
-        vk.gamma_abc[202] = Pairing.G1Point(12718847673444483442187468413849869741675478060985737931555761323373285070251, 10548235572203730802911962202359653740958759459447908666359802874945081686266);
-        vk.gamma_abc[203] = Pairing.G1Point(15214298806247271400083463025725000761090114158814700642131421412638204861839, 10769276894840675806567160028566173895604787803296678328419798640002682394898);
-        vk.gamma_abc[204] = Pairing.G1Point(5252413469921281238859842019902192338661616195092057672250512779487523630706, 12810861199656954214573432823761635995518048072587935320529767475978589860133);
-        vk.gamma_abc[205] = Pairing.G1Point(14637097115976491438109783411604013412371148868024214368496760764407894951063, 20377546894913616729196150400161248524296604633002830816253134263896432295813);
-        vk.gamma_abc[206] = Pairing.G1Point(7220505047020872276617058851933739817810492794411457720142983652325821629380, 21182691970894461896716870581754866570456920534662854685787726845528725475800);
-        vk.gamma_abc[207] = Pairing.G1Point(17485877682989054670432951977932077258870212223800704617185577690707162515739, 3569961033837314848489681268714901792809523795651836407025291864906719562729);
-        vk.gamma_abc[208] = Pairing.G1Point(20168884538272987520803616913619811915374556243253664161163237512705739200606, 21004021510821774781511935735142757617213414007401332000230240077078396400927);
-        vk.gamma_abc[209] = Pairing.G1Point(3497693190178423241679811901176640739053793957016001641723577304433211208474, 12114135088229795555083737446694355580452335700977352742448121722632823490909);
-        vk.gamma_abc[210] = Pairing.G1Point(16570144679385596725933671131208596513929074508012671702432850351728515610831, 20908596855911953596645645857658630638502062945716902868742479600935882244280);
-        vk.gamma_abc[211] = Pairing.G1Point(12016085077741623138023004834020536501293538037984784445407743077548657923086, 11124945191241552612715810907433257274673297474404056784140261428210992588920);
-        vk.gamma_abc[212] = Pairing.G1Point(14621370662914701334232146922982487750685513044319259650818554607339215090207, 5215908185299867361079162326969510744149517503086998926105626323603510972292);
-        vk.gamma_abc[213] = Pairing.G1Point(20483609210393823271782224138857984099070413546296577955074210151317236120252, 19153775411714184220736186329756741365737450455346554687255177572363178303063);
-        vk.gamma_abc[214] = Pairing.G1Point(10837616906112166899656302225848478497281948272038295198285486710560586830756, 13486869644782442084887884556429060113401731215035306714335964831866410337432);
-        vk.gamma_abc[215] = Pairing.G1Point(8380574959353646150474338476000843737823467186602872406638641139398452975044, 968842484124110376468903900796667514991374689012426263009309607458553012569);
-        vk.gamma_abc[216] = Pairing.G1Point(13392589734511724307225021026549158701533442980755307587839441655631999892735, 9207579207457984545951337738202964387862592425432449070350346621522984422850);
-        vk.gamma_abc[217] = Pairing.G1Point(10635370034200333481764794748646643372698511155393599155460737026549929891101, 21161084740194158281333698666047814392296915447729357136885853764323982671826);
-        vk.gamma_abc[218] = Pairing.G1Point(6614047294483719537464846199293169056074535031658646576872352384147639699075, 11638042760975083105050747013652554319002660097558065238066324568286793347308);
-        vk.gamma_abc[219] = Pairing.G1Point(21859311904174871722313528087128841964357129274601028292062553373674077344563, 21027013314922164666217007570975690638546073200961793922452463527874720107101);
-        vk.gamma_abc[220] = Pairing.G1Point(15531857017261211213815945124713573595653830633103933565411446760462664810284, 8381046932146147078490206221031430135395677636533660003596593058032893707506);
-        vk.gamma_abc[221] = Pairing.G1Point(18495014774717579688279831918857794092523575416951422334594822184790586744029, 13654568355301943191811893314705858375438550882122063008045591570380750088814);
-        vk.gamma_abc[222] = Pairing.G1Point(9685764403794422536534447940171848579774588690873649115977186482643139771199, 15261948956497506596392743475012687425056190852858330315315314621380534845991);
-        vk.gamma_abc[223] = Pairing.G1Point(8275395888124849039376274450493381235781257743292476125737505948577566428759, 9462373381574758997032850161206423690900089299963472468506671052304759971528);
-        vk.gamma_abc[224] = Pairing.G1Point(2947698247081306194564517768517339773408243845626374027407938020624544230214, 11272342401869866739317027801198869107468491742795708197919238565337686839690);
-        vk.gamma_abc[225] = Pairing.G1Point(13004223827560496168955373644832210307430123947443467237574215111681903084471, 4253761669270012348698216999613310870527219865740084189007393212304028783434);
-        vk.gamma_abc[226] = Pairing.G1Point(16460432557034976296077113106083457382008718136092953123109689123220557868107, 5891106845143797958177329661282482234505784023121289492083001510038526157474);
-        vk.gamma_abc[227] = Pairing.G1Point(18550829455457501485767657173606985146630417299555772095194164267545070007393, 20857137301195516414810437606394601515178763795274694217026710845690942413254);
-        vk.gamma_abc[228] = Pairing.G1Point(485380852344522928301788694624128510276595144206319539996360770682395684323, 15520941400509056713091447269715760848061045406800031701658781864899960182344);
-        vk.gamma_abc[229] = Pairing.G1Point(1637128067110866795818884215220603989454293653506006509193011272325038639206, 19209781503974257857734126100639914881974193830373756568122595272878340272348);
-        vk.gamma_abc[230] = Pairing.G1Point(1390562020965807645846127283053371291160665820263946947798861301015543415464, 5931107645806265163145554941649645918901886248144740259005102821635352163676);
-        vk.gamma_abc[231] = Pairing.G1Point(3470357959793301268812151087398875092901041104487798940241292030694960145401, 17112359431655769863913627306800440291616887708377467728809834444755570204822);
-        vk.gamma_abc[232] = Pairing.G1Point(12072795641243507087539909670975618980178238478049904064510030439075615534787, 6490716008851628854078764466836738202489635394942668045118414253758659663944);
-        vk.gamma_abc[233] = Pairing.G1Point(11910232203803672583470083371399057514689427770116398602955988229141630524882, 4865723104959179969787359084721997414529487328859189815587475250383986940111);
-        vk.gamma_abc[234] = Pairing.G1Point(2890240482184735966878773017469903842505305490917799947020586607902404681234, 10297486224921490739426478897252975648434785701902680073328741171500036185392);
-        vk.gamma_abc[235] = Pairing.G1Point(13498740430971912893741252901060251456734600679877747787066761023961220027700, 1744845591453344099875462393954311962347235519586784724013803431560329455410);
-        vk.gamma_abc[236] = Pairing.G1Point(8283750342084319560336621516649488897009147037249425130198597238429748212320, 2679977089216517105047986775431520827543019943277840134977813650381266273968);
-        vk.gamma_abc[237] = Pairing.G1Point(1157509899957250282073172577154750909048387092090806149297486727132018256756, 20411184633957051394516108790598270948401087906635712392854320272253996178791);
-        vk.gamma_abc[238] = Pairing.G1Point(14761268901632139717063742719057020072960258416918566796078513521164173884561, 19088162694996056068550904398754490471771980406125085946757663871637364176739);
-        vk.gamma_abc[239] = Pairing.G1Point(8180996009912412770270393599962234449958263923354415182025795493286784751763, 12961445021914648442583072074760386811406103351635354501639123944813333667788);
-        vk.gamma_abc[240] = Pairing.G1Point(14150611782921081770272006752353727846703601283898126273370748270275825027929, 13756475199072208720095464833080222017129183203475059010919069591473934775180);
-        vk.gamma_abc[241] = Pairing.G1Point(3864736662674845126376654002278172312246250426116857940253920973839550021904, 214484665632434464254401417170102642168574989378178913187275593348201064952);
-        vk.gamma_abc[242] = Pairing.G1Point(3253288781136677314423631496083167773457119330238722341979107154729746734087, 14687506285119472499462944711915787655333924780911612406221674378537547942550);
-        vk.gamma_abc[243] = Pairing.G1Point(1985534774067238754971400174047197080731363569733452540482486655500348649889, 5460030560152250694615751983637273632709288252078627030106216658334374303092);
-        vk.gamma_abc[244] = Pairing.G1Point(20637527095382936894591319122964232319515072223882997848268897462286177301252, 11625917425609284365236308802772412939461476675429247704436596808377118876060);
-        vk.gamma_abc[245] = Pairing.G1Point(17841025386793286880649750107513772607721860712918869221909835317698726070595, 12057391761334388711853106593620025585152247971619777654829061915866333346149);
-        vk.gamma_abc[246] = Pairing.G1Point(6821860034372088161973668014308025648911374111214070731026640333506744806764, 16978701537232675732531183943985581265327596842542860763148745803299284739514);
-        vk.gamma_abc[247] = Pairing.G1Point(3500691667879313754505440063217603059079562045478966497511828045090856494666, 19355880633481105175803765854298968654652102840227068782069770009476479654570);
-        vk.gamma_abc[248] = Pairing.G1Point(5743681242084272015501370909747494848688824422075172429117934822885388634451, 11115489143358225435121860094468191197551808697581761272408592452608854982338);
-        vk.gamma_abc[249] = Pairing.G1Point(21185791837946468169270827200043906867688814932222526512235640209431187456642, 5639651611450522067911002098607469400689980204150266600457657660778435221472);
-        vk.gamma_abc[250] = Pairing.G1Point(14344454751927654841195940418042236262096966858766907910241170543171589948338, 12614779613802318441382333828933811806554721912531761279969591935157207828265);
-        vk.gamma_abc[251] = Pairing.G1Point(10726274716141512662294617039738276408578037023913255883839821372940238218689, 1744393077557874883323345444571696831550962766443084693240752154282622128343);
-        vk.gamma_abc[252] = Pairing.G1Point(18991090851576022430294930581535113371851314948656519043467877423528106497276, 12981510622874615527229592857315620774769545717043908093760929936094586915538);
-        vk.gamma_abc[253] = Pairing.G1Point(7342927895097553091459671078637266905819617359991634163420245329903225685158, 21881027012202858019240033315249149244862566205083314536157210787823608256701);
-        vk.gamma_abc[254] = Pairing.G1Point(683686660895848628774160710870277989308351099593220788657622843831026569039, 7619226635340791745592111722499790380912973800387858114520701378467879460797);
-        vk.gamma_abc[255] = Pairing.G1Point(7758900749140035789459568984954829444541141060304097289774502625624585986627, 20841132150506743329320572567145274283915903230613317285763059947707710561109);
-        vk.gamma_abc[256] = Pairing.G1Point(5935742385883311122922423891931410513694805235542837460954518216144810939225, 6249886786567677363089013371837159494359885390870513834758870590238864970295);
-        vk.gamma_abc[257] = Pairing.G1Point(16118865565738000804656064454226249932809137635121769868790907959064204092087, 8256841779498615105412741417182288881527025459126034051558987632227283312944);
-        vk.gamma_abc[258] = Pairing.G1Point(14524119561577232072039046442619052491177499699879212737801110222808903939458, 10418021668966947227208186025234666365725131791268083565772822367353418079780);
-        vk.gamma_abc[259] = Pairing.G1Point(2927179044723845938241335748858781334283406817564199773794762990938083505920, 19243885044146368186283272903865427357436722928408764396423157172603824490910);
-        vk.gamma_abc[260] = Pairing.G1Point(1194138260386050248161721894216964113025672218473399396902986110611799858650, 17969619654651297503323143599761098839300203973659836931737733537873881611975);
-        vk.gamma_abc[261] = Pairing.G1Point(18230275299888465626631932507394029922475887022084845327255404273366330598628, 18880169523470896859224984389082638986035048838599534007423388813457589578111);
-        vk.gamma_abc[262] = Pairing.G1Point(2003072328753152085549257856532578334328051499369048324287819910172594372811, 18163719278776482658324527927865922108788690955393962172940789503256514272869);
-        vk.gamma_abc[263] = Pairing.G1Point(7974503380259643924447651325077250550330025515575769695168681543623397434107, 2392945020956291892644220243594857193348354016957550059441538437367270101243);
-        vk.gamma_abc[264] = Pairing.G1Point(20306302941282205615802784137170724007995475091210908277053500202061702980492, 15766173070606845760085675371339462130917952527762921165569605004899864361308);
-        vk.gamma_abc[265] = Pairing.G1Point(6925092621359202444531438691306420370595094154960606106393223355780224771029, 10130565465761044213892587292794440183474473561190394423235927178237593405258);
-        vk.gamma_abc[266] = Pairing.G1Point(7357133405157680353000480505240042809985119479011398102394627315904068501398, 5289968055971583825374305820514156700381639885261020668411709565478773216347);
-        vk.gamma_abc[267] = Pairing.G1Point(3078809295032020653830979496284186915541605102573787101406081052455121750890, 19285507866287248163994889727049990210101502331652228557185714386909841306955);
-        vk.gamma_abc[268] = Pairing.G1Point(1418944003949868615968956916863755290311822904729135436050893436090281098870, 444913361350109848999896411282561430939323275073849620689619675069958085286);
-        vk.gamma_abc[269] = Pairing.G1Point(16854342498878125505192973643347829950133142697071560151944657980792265825587, 21668295359321461715188949664160565596440747646080965930098804043452332844181);
-        vk.gamma_abc[270] = Pairing.G1Point(690207041162408151093145118655231149327719139010114051956240599183446267186, 21028777760402902473309741951981317282000268165408073615456776953702546628437);
-        vk.gamma_abc[271] = Pairing.G1Point(1823633267311733534062285616969925299104439780762522793794590957713042715579, 13679861456602016787172845372641929733444809377712410312863781793324081401150);
-        vk.gamma_abc[272] = Pairing.G1Point(2135900384652170380048872389297846618362584142135114224301770432875547532657, 17270580384901126413944872107427315423446026294512822269812019459918002889218);
-        vk.gamma_abc[273] = Pairing.G1Point(14840162348905923497435516926086741759526975043887877427121992532140491349231, 6223087127313003407297998357113740291068379029487145926759286376069158689079);
-        vk.gamma_abc[274] = Pairing.G1Point(5701074161305386063787230711029098905611924603598741210987960080863513692801, 2902431472321593628528312135078221092987589804899300505717358293232473733957);
-        vk.gamma_abc[275] = Pairing.G1Point(18124683328153951709168846876595202690210426303841498069090529495611380849462, 15067547853956794062965948796261703825668181140981312704345805470582249373534);
-        vk.gamma_abc[276] = Pairing.G1Point(11059380964383084171333491739737763946636768635685675801207512728771168433183, 21809913388153544426500062293552902624043137270496667091307067250394170679563);
-        vk.gamma_abc[277] = Pairing.G1Point(3910964853636864294880727075104285348474857150783382601153340435214414537847, 8569805111489416985254584907009702285992034824044920243335103841310293864199);
-        vk.gamma_abc[278] = Pairing.G1Point(12756880877917387639051131333789544261588753602447838544802302608341904123439, 21881262994424239240911927418730801928498477338311703596252650223569473092220);
-        vk.gamma_abc[279] = Pairing.G1Point(21584295238906340155197181758746274526874132097564423716264706553984841678256, 16694613589460908256178505808680405076953612664920034073784003483307674375348);
-        vk.gamma_abc[280] = Pairing.G1Point(13417070194502667516273877371544139884324676547374460510586384500321253304592, 7796199300939498430805299867487074421101919192466806019495502063266411025572);
-        vk.gamma_abc[281] = Pairing.G1Point(11551693760381811915905601611408941641685322343142014028970650395520913918966, 19712562259945979560234683076201380060998284116460117193298877004332901339855);
-        vk.gamma_abc[282] = Pairing.G1Point(20053835100381929704382192260790723080382865012766037071109361285720122871971, 5802931919690152117536979784310733550373675673321156843099059585746900578189);
-        vk.gamma_abc[283] = Pairing.G1Point(2431859480659426333664995591053388364843517615878154384725459898558235557172, 8419895556198887777849405808471799281923038301237622005710998809395457079384);
-        vk.gamma_abc[284] = Pairing.G1Point(20767828444097160134579037685539276037390436390011184889025291067415903484481, 1248394103292086354642549312601620740535184279491851527130175761272284720609);
-        vk.gamma_abc[285] = Pairing.G1Point(12487005232317304790932690750354332623877098904554868458783710375819607648927, 10781514355539551922357069874827807115063883865597822612936715226478298731647);
-        vk.gamma_abc[286] = Pairing.G1Point(17486732131681839634177397627066287839602965062891070727974804991364213818713, 6266061081490326658448223197522255810740893283441801370614283805103683128471);
-        vk.gamma_abc[287] = Pairing.G1Point(12942026713237578715467618433731900337626706404677103872428928349691555146812, 18565978621529024041894700165910623579193877042295665336794035506130552942708);
-        vk.gamma_abc[288] = Pairing.G1Point(10010713242019339927419690130642530542042654459101986784995649701441856009463, 16143219074138508541436739665350571078078759097131869185561014353439602608677);
-        vk.gamma_abc[289] = Pairing.G1Point(13951248009146232057750040362215728309460462123976010335918342781348545133863, 9767918912284716042549581329490556012250285646678418493831048455318874295024);
-        vk.gamma_abc[290] = Pairing.G1Point(14528417610698930713199724429973263726532178045397457041430103610218192166616, 6704327642055145647494271679161123755540150595745104823739046121524159973803);
-        vk.gamma_abc[291] = Pairing.G1Point(921330405538870218326657657313832417242442551822884105775067352482969328543, 4570402426206335554125120150899715828827867905780204161519525207413098916687);
-        vk.gamma_abc[292] = Pairing.G1Point(11870880713551846139851578807359618209682627892736734503622504148473603914506, 19339490641708376297957077510764863804276386452347134952277157383830905740454);
-        vk.gamma_abc[293] = Pairing.G1Point(11225849235472559099960739578627911293547156283360062305796915187737285754222, 18485567364877908911051328402880419542992793841681564051693985254422126259713);
-        vk.gamma_abc[294] = Pairing.G1Point(11658353949834245936793924770676576772124420553683721401060191369948927872738, 15960206239540006495604268092406790544770885363551517215769625797493780620248);
-        vk.gamma_abc[295] = Pairing.G1Point(10666966259143425619738087831413075298449058744632883790561332515413739533245, 11944419373296437822754000603896904753617990653554874673590205449799460358605);
-        vk.gamma_abc[296] = Pairing.G1Point(13572710518506425670811303194861819920085629985579989393065808107221492076526, 11768689985773919729467563368600703231448765546441291364245400239826448531681);
-        vk.gamma_abc[297] = Pairing.G1Point(6807553523304516773754917754847506599052998358518633260527549945907677200919, 17699182628229817466209472248676611092595136190368652745887251610953923369964);
-        vk.gamma_abc[298] = Pairing.G1Point(3981722450232062980066426005486645913613756214447718495666309097837823705981, 7010734708001179474857431963342055780271647082663072643870058865054963409388);
-        vk.gamma_abc[299] = Pairing.G1Point(933287045351161526140665501550929040171707479319864784878648233754825255592, 11414616603035236752622439736776185724546933165313312680233806961565233868197);
-        vk.gamma_abc[300] = Pairing.G1Point(14633519507978976026666080406461273802842197180298360521657987758610882117368, 4733565664595466265447668768151310468116064384491757468911432270708278777151);
-        vk.gamma_abc[301] = Pairing.G1Point(20097155528843482196030502245449075633999255171972546240771614508503766897030, 7092713498053178650935127389635366972140349529130626183212684135407874840827);
-        vk.gamma_abc[302] = Pairing.G1Point(9395049506606449008925753672314168595285743727992886570645133354873864750038, 3518197024324297935714605084475242330080120342528134661890808570756826172765);
-        vk.gamma_abc[303] = Pairing.G1Point(12611594887431692726591908259478736134689477788733771107937790333273616473093, 3117209098811414567606394208999572217174837122671606653233781601940429117906);
-        vk.gamma_abc[304] = Pairing.G1Point(9827643751980977609480605542502739611081570664899857185020028347503934618506, 2061445222787464593485545801206577452404071211912182895110701353102812208796);
-        vk.gamma_abc[305] = Pairing.G1Point(5153671576268271175583319874500291225578768523103328938244120554921080336985, 13997306287921171789655735747348398399321163810658933337124859111174426393580);
-        vk.gamma_abc[306] = Pairing.G1Point(20322042635028650464552446670879879933879681938312146828844644193122676306994, 12550787129205560689716075745470886426244263311493101090605782933588405040249);
-        vk.gamma_abc[307] = Pairing.G1Point(75400541835716418122111809414305136279725222914039975121626231381017042266, 11490455052800065613168147289848549374608812071370493743931024362689927257974);
-        vk.gamma_abc[308] = Pairing.G1Point(9378889928652614792103834052395896875794886232792090566556446089885309629988, 4719581272306034818334155313590359590945598527929179497589087582693051311726);
-        vk.gamma_abc[309] = Pairing.G1Point(13908811985450121835911570603987676791005793619612697998676098426301216901103, 4262198358953414048528138098689384585400890064177359585761575211960917106622);
-        vk.gamma_abc[310] = Pairing.G1Point(5971648791832555357581738481482410577844399934781634787396014195722543271762, 1726862753744726842905125886594652774336919283438665765210504696869632155439);
-        vk.gamma_abc[311] = Pairing.G1Point(20138601696909743255336972606100687684544761100169445032046862302247834331402, 19061892178962485216774537157530907384477771195168652538306051285456266640901);
-        vk.gamma_abc[312] = Pairing.G1Point(14740226375063114750043522512254584342159588107138837488870119523098401360196, 4474126307541145870690301281199351552213635923568401147325721412944764510313);
-        vk.gamma_abc[313] = Pairing.G1Point(4073663400064433386406071604123859014974557944263919644647139215187366322147, 5227744534393411516366394254639221563310666373325627689832911375034668941522);
-        vk.gamma_abc[314] = Pairing.G1Point(9411817257346922979977578784703360966764954475498401113900963745950521093496, 20487036140393961078933220198813059043458816286305892310946962408384929140808);
-        vk.gamma_abc[315] = Pairing.G1Point(7529328683525636359618898926963885159009961948431643527857519614554207186970, 2505850739701167457753992949946751638030789911031277139853028971101140757092);
-        vk.gamma_abc[316] = Pairing.G1Point(12061062631579745170435807519139842386698441255668614511923530756268718954734, 8424848045691478754604710891644929089223037962988765174911609545624071860443);
-        vk.gamma_abc[317] = Pairing.G1Point(19490675597627243585123955093679896876680407000123526423997215530930465882141, 14694539700031821153897086170907959912103318613381406390188631040318586424191);
-        vk.gamma_abc[318] = Pairing.G1Point(1423011652842437699164900606152571619390977043576518107697238922631315090104, 12320712463865354990772197866169754242833276130012758517304202549715653441079);
-        vk.gamma_abc[319] = Pairing.G1Point(17599635035537027875381205905218936210280803776613587266404827679680979807357, 13190034516907096430207244750937926821432783873367460405440535253338856395523);
-        vk.gamma_abc[320] = Pairing.G1Point(12482987402426288007022957325823209447245989662844273700476286336245469866724, 5292684114145350431926614479881329311474290207621926263077983831573422041386);
-        vk.gamma_abc[321] = Pairing.G1Point(16983719535714447172125334524659892239786734567430473519406301208680968243942, 2952262244910003926215268039945438960712340008517050041012646406924811435479);
-        vk.gamma_abc[322] = Pairing.G1Point(958670214397556642975284629161576922751048416878327217175413466705418473423, 10007898540980830297761383595534474586454983879843375997338771942260833623956);
-        vk.gamma_abc[323] = Pairing.G1Point(13524877165648220478980623993508722186311744344362975060627553420329425520541, 14477446024962546099558368086925499729541010530752217603731812059642724639739);
-        vk.gamma_abc[324] = Pairing.G1Point(12185575882300782133048216698301174264466772680433023985679422449211250188496, 5418021727997206971409679871798377705914260573721869667813620362887724019834);
-        vk.gamma_abc[325] = Pairing.G1Point(11970815744815915036286558134739118737219261305771567597620670677635694331343, 14622672129208142527148759364865400302116251639101253586412682148260971341252);
-        vk.gamma_abc[326] = Pairing.G1Point(17729713469563840664577943077858658472099411985243198152556080661775322871479, 677301279776435002958423083464981439252050674048090021719387983892429342187);
-        vk.gamma_abc[327] = Pairing.G1Point(16822778596308336713756161161740856359508284396146265733716338583196900895791, 15199439256515815469252804398574572229800489294099920182611018355440608944172);
-        vk.gamma_abc[328] = Pairing.G1Point(9622504627018249034809205970466111701378540127231301225325467457276238950895, 19936811442323829366429484578171028380534940732031964802160053099249265175074);
-        vk.gamma_abc[329] = Pairing.G1Point(20000196293774196328136932929952446140331450299501766825443077538452226248677, 10460809779405839432686465677474863832350010925752304463076899005070892820013);
-        vk.gamma_abc[330] = Pairing.G1Point(13974302281618397553271984509007460760373744178139101224581696123298790625499, 11466368949602052045505214406040459896771088045354404404919863840852522349548);
-        vk.gamma_abc[331] = Pairing.G1Point(2007651661778167475543877540348117694829566411815888676794932438417272705635, 7819923118933270150265265288080410363193967437270237402658489609052669794235);
-        vk.gamma_abc[332] = Pairing.G1Point(9761678922067574712564321647061986930149951294035988515816271055747439281553, 17779872617187918755583196478256654566153002956570446636383240949836846341272);
-        vk.gamma_abc[333] = Pairing.G1Point(19496184718220308867943166138955310099627712449278980643802406526002467085992, 16593594436476011523410328124433953637103554367667942327880415647960091532768);
-        vk.gamma_abc[334] = Pairing.G1Point(9784758497038172237608560285432300949742687351264774666555194891025486652985, 11911256186374341304971022443769644940044858136597401131091608910876112333592);
-        vk.gamma_abc[335] = Pairing.G1Point(550987762553783448899218105869173337191079875849516478842081083697322131328, 5330327772472120021556449639434672777148296564079805939815177329215311969891);
-        vk.gamma_abc[336] = Pairing.G1Point(10497244375075585470221013381782966456137361108114907445470115902838974359622, 12369076075509689275735700737114699969415929846146852511705407009854859464174);
-        vk.gamma_abc[337] = Pairing.G1Point(13036903595627294284453598615377196018592742019157119306121040385386538091051, 18783216891916619141693555405747350444780285270620047922060969999315374001491);
-        vk.gamma_abc[338] = Pairing.G1Point(14860458160031470615092780349131025209899339206750229651649817876290012901357, 3571077957850716429815765891311842949241662067006392410919217666236372996704);
-        vk.gamma_abc[339] = Pairing.G1Point(15413880847336251056688358612105567870669649408423856579860313797719317701073, 972149882793091921308822803362717485354954578683964788559197007714131528233);
-        vk.gamma_abc[340] = Pairing.G1Point(15507495773520795296325248204276350140149254052586780194048122514056893367556, 14364617932913451049911148601247854179832689751847844665502222384301573403660);
-        vk.gamma_abc[341] = Pairing.G1Point(19786615314683232050462089103249887686830217306283430519028894106577175903636, 1478087746229885771068589935267516224823383154861900602544026493073367563063);
-        vk.gamma_abc[342] = Pairing.G1Point(2079050289498038661481408716646130394506568180960539167991627608062226558770, 6057698896256298481462099959650933142178137743798587372588907129045281170742);
-        vk.gamma_abc[343] = Pairing.G1Point(12522758475589902370624936580531996948222575257829932681492366255868139718078, 3319829162088282470331525593136633603058086877245773936498354783837421812291);
-        vk.gamma_abc[344] = Pairing.G1Point(9591288724646832422434068525136771399149300244550440143885330096888579263529, 16055368709892778118886694870938238236124291350443774454768749698975432113811);
-        vk.gamma_abc[345] = Pairing.G1Point(5415018251238920134889138981344629798071802554658376743444291044215143525247, 5670612506024006002645417538226397268426362538379894429444004857519535381324);
-        vk.gamma_abc[346] = Pairing.G1Point(9422560744731364666248565244267262129551902268066278151602030546296585601281, 1609396540339999397117012816329301919345751066103036775394638610565723320942);
-        vk.gamma_abc[347] = Pairing.G1Point(19314785685351515089503838656534090452067493725246064834961254664297412893022, 4138867146104174536147662550952828893468878787713100856932831306289352436200);
-        vk.gamma_abc[348] = Pairing.G1Point(17363703721873421325604411950704587897847087201801432558455442253603530281422, 1318663351851129817753686411910716481830258906439596898937892966740943745820);
-        vk.gamma_abc[349] = Pairing.G1Point(3289318439396143170160708120556345305176738392678774688549790481902076638573, 19274373823694285201270063597831773442264905388385807855132998053970860131277);
-        vk.gamma_abc[350] = Pairing.G1Point(21045765920029327151549020546299966341771693212930802813812965111594078908240, 664718728868971857091322431957023936336118445802355025457935562483282443656);
-        vk.gamma_abc[351] = Pairing.G1Point(17793447988237507241309507313933729802787982076986523101957951678392454663780, 20473224565746010087691541225569539201422412413998097361222574436161245290722);
-        vk.gamma_abc[352] = Pairing.G1Point(11609182551465884415828252426005691827524416753384647487344819116298071981280, 14029321227256037732459457734844260023357806158722903534985874716305641727234);
-        vk.gamma_abc[353] = Pairing.G1Point(18656741939822038721870261227039106341631250637563300398417463280830604054607, 3543341280433285072790574473182769481022543272957314865361028251954736537746);
-        vk.gamma_abc[354] = Pairing.G1Point(9779701064887198806363327146211626095234953661473347358712637734746348356718, 11935183095426283702775658325347024201103438711831185141057378475652087215062);
-        vk.gamma_abc[355] = Pairing.G1Point(1857246497970037781749793267882120457216447151758756228536578972045530214023, 13405910946526702994382861606569007305683707394101860941825193490571279479091);
-        vk.gamma_abc[356] = Pairing.G1Point(11900265900317506971630078305379497932717016835876228371697228414492585774398, 3801770511596158965425021954279893229519815794852243251397978481323281986999);
-        vk.gamma_abc[357] = Pairing.G1Point(8198347858079626045962536562572578764806939527891132629275801313937512969009, 10057321580398343776490324182104634692901914723838801055466649322262053226140);
-        vk.gamma_abc[358] = Pairing.G1Point(5615992116452717934713098959242813658445478265010536945037617507191305072403, 5688831014119341916062910650449000050623864350662876785248140368533490759398);
-        vk.gamma_abc[359] = Pairing.G1Point(16071569189464245927487947250446027108815557140551473761326826729527963247809, 4558336186552318091377155643011162787835330151642915111228374498627711402979);
-        vk.gamma_abc[360] = Pairing.G1Point(16466704057663936280375304434950750783271629110358339092651685908111887434961, 18819571582759590326006946157023191444792269255285986627170833328168992375495);
-        vk.gamma_abc[361] = Pairing.G1Point(19614756119937206959816269274506046647805235778515350454794847377092421421065, 1850335379122152618441692146327214330951419733523997766835448730994583740754);
-        vk.gamma_abc[362] = Pairing.G1Point(19357230301888746993265690061416899781814033997350628359306799038055381213075, 18193342680632594576998946841069330718385603889448839058834445255872790185310);
-        vk.gamma_abc[363] = Pairing.G1Point(19368953120945545677471425335549398811948179222717269925774156689848660423906, 14110767462299166425179778405682928913750362727923315313059778967517918857455);
-        vk.gamma_abc[364] = Pairing.G1Point(8925392307746289781020332524659291367380407905169974823686287084168497587281, 1386823250175438978850452716182932694912827794239218728107143647175096073302);
-        vk.gamma_abc[365] = Pairing.G1Point(20673883843639852733256497468367558439229963076430787078976024187363905358810, 622880382126126232112144918348362270813649882586883760764570434493346175002);
-        vk.gamma_abc[366] = Pairing.G1Point(3979628496169853029094890916342182521843073616283815043446744571807742099689, 21239310236671405290042075254898254966662132665244099973406019266676030773769);
-        vk.gamma_abc[367] = Pairing.G1Point(979322142318346264338514573166991717130429797726623249022364647811545637725, 13027973620141326860164996589301294796054369840977318042217009466228302125666);
-        vk.gamma_abc[368] = Pairing.G1Point(9030193109326483682314271398368721852374095748329613628994868293534964144387, 4223120285836923741746295819814091581525552365815008511611757634862852260027);
-        vk.gamma_abc[369] = Pairing.G1Point(14078745532524909577325458713411378783072709192559535498916864511556927024846, 9129209488205149320281076115862232101101194369175680432883313622013690718133);
-        vk.gamma_abc[370] = Pairing.G1Point(4403190982181025490456213479627398154052573002575417916083694463863826407572, 10643613281818970994288704166875127093071487780759832846701488962371505461031);
-        vk.gamma_abc[371] = Pairing.G1Point(19598354729029552324877478132842626439924303927769459701086273679745646778914, 19858222099467361001632539515840651656461040036695407052678123066814239032537);
-        vk.gamma_abc[372] = Pairing.G1Point(3446486641563745030480472213855790675520756695909197864736245962826207954597, 5298428782855199496345862784125554965735989164569696817588864231725748603306);
-        vk.gamma_abc[373] = Pairing.G1Point(5042826336976546177326625346138673274604259806874384018690490925843502539475, 11509729920882806759991646599609139481095549612412120555585126760926109396124);
-        vk.gamma_abc[374] = Pairing.G1Point(21427878045844275594991143793294581415818199921921045999106166639855624191759, 6927458811645878340781080461705200321270005469068418243558254411888756327717);
-        vk.gamma_abc[375] = Pairing.G1Point(13375554897391914773133864991681714793355817137922684501355986508002207306139, 12762248750172966101322603076640138443032376892550914231189529012116018655501);
-        vk.gamma_abc[376] = Pairing.G1Point(1624270742053086575195273553314261658665440338206224845419659453246093086114, 11313723404211929862613993403188437619089936166974656592119789362606895161807);
-        vk.gamma_abc[377] = Pairing.G1Point(18298132170999456913520602422715012297879505845938066004562108374639802687048, 9709684705645693909597272186228040667315474211133041920772929906493340752574);
-        vk.gamma_abc[378] = Pairing.G1Point(6095407562537981426725870711755403099910773693261747830394680568108226450853, 17037211704108100252994126393834780459621277984063819177803672983153012179884);
-        vk.gamma_abc[379] = Pairing.G1Point(2404377860279063728404361325099279548841295905522036563698639112751351193871, 5962458329241210280352307145755339869751572449692121895342394485730434518967);
-        vk.gamma_abc[380] = Pairing.G1Point(8010163134555263629860517254298977510830545624089865150073474847619403627803, 11122128173452867935586316323755993404489174461080729342122306454153795642129);
-        vk.gamma_abc[381] = Pairing.G1Point(17342723403628073245587569228887932610098671014687952249818326506934354785205, 2655414616544484856174580332445445994158357132836040172711621423002660031349);
-        vk.gamma_abc[382] = Pairing.G1Point(12891425915224069640756476637196829308364035780653657619363560593911236737948, 8496107370010393857747592791050240386463268807066088715784865343057573278676);
-        vk.gamma_abc[383] = Pairing.G1Point(2597651606779661835389396130220403769249632979058489872274751558660793443584, 2788278294944024159460675837870998662735353470944836844008961613450392872860);
-        vk.gamma_abc[384] = Pairing.G1Point(1360552066180023008788814213670259686227942787688850624457951134331506218743, 14780565313920346686905641631834613732332274824421647957744331000166481522814);
-        vk.gamma_abc[385] = Pairing.G1Point(4635215090716830103681635545301786374799563919665891477489934915734842349275, 19467896913611235379713851306207336542312240615046794957157203814519037861834);
-        vk.gamma_abc[386] = Pairing.G1Point(9810397660295977241832239377369509437821872261723847604212766342358623292150, 18517474058464432212225418330400520182191612064967283478182558507212985684214);
-        vk.gamma_abc[387] = Pairing.G1Point(5050885884700159535302915913543151426288604174725100083835795343708828427863, 9523118089106505708537971247288486219305054419941816963323930114111573106099);
-        vk.gamma_abc[388] = Pairing.G1Point(14249137132683571378331952460588388956525146880258122832461103114303116889770, 19132933975800978325541214397400985463519781228739271398226538182696154470810);
-        vk.gamma_abc[389] = Pairing.G1Point(19599846511693170519434663702072353613696448789262064899118447104312370826898, 18577338385117441836210636258171450598975043683287409591609181045126825542751);
-        vk.gamma_abc[390] = Pairing.G1Point(15311039134364638737980156963541244498055439778719422296723598638396623080422, 4171319645713865986465835532232325036035712699960502314608192450226715626112);
-        vk.gamma_abc[391] = Pairing.G1Point(5900059356557172738110230742851958201189789172564864421932196218422688932353, 9392436291866956609543585784972994210691006463443125257899557866531350471777);
-        vk.gamma_abc[392] = Pairing.G1Point(17459117706708366473340907929163086855747655813047329000057569935560176830039, 3371080251180680262974290706505005025271872553814772253151609905810961874157);
-        vk.gamma_abc[393] = Pairing.G1Point(21524040226993866621691007541907441228423924055519567984053157081068215556474, 14892707027789559923753995433162156595423311726285970964298055469697167980980);
-        vk.gamma_abc[394] = Pairing.G1Point(8481367929039747965975614968625074718896656807521031870898387448852613597731, 8877497812456554522504390936273561497883975442472238319152522965978752549916);
-        vk.gamma_abc[395] = Pairing.G1Point(1907134088947773165172856864837986946995828543860268413827709079260605520020, 3856052496680050751206724449591798070026288963626851030039910350274815703131);
-        vk.gamma_abc[396] = Pairing.G1Point(9466907984666397567172186412770666345723693510177204354238998705863715545645, 15829454821466454488725631470961576164372252092294545928144274320606854485392);
-        vk.gamma_abc[397] = Pairing.G1Point(11641688477399792992761189319504855296239985434572506709626043179903678249902, 13635161875981322155521989896008277509645558629171614046699369255387150360309);
-        vk.gamma_abc[398] = Pairing.G1Point(15925680017268167517726338035054703703567388926861324054597861816935739955856, 20076304039985242642804664159721873735036418477873665870971860291343324836640);
-        vk.gamma_abc[399] = Pairing.G1Point(21497446853187364575716102494468190968435138890960677571054149756721033570017, 9925256063693814571580480998041815882346188528811263178289446461738745949962);
-        vk.gamma_abc[400] = Pairing.G1Point(18910347617318549145216645370591437251271526965517321280832164785422097501507, 6435641229555188316380112183573608611240432242095372607762773667002633620083);
-        vk.gamma_abc[401] = Pairing.G1Point(14415763799749257624280511908053820291488316488761492274347497041984662734822, 525666507023262301184900457679403085184486668680863736892520031231427697714);
-        vk.gamma_abc[402] = Pairing.G1Point(21114397005691109062319155811889162838562581891065033227429517495598494881195, 8645966199453987099227202642987117254525952007572652643962609595262672311668);
-        vk.gamma_abc[403] = Pairing.G1Point(20021030595918998855735441940905815268555737089418146781818020938313538119100, 14603664364663590193001032175910274941803794101630804213242525097421654929424);
-        vk.gamma_abc[404] = Pairing.G1Point(21430744454467510139981052196390867408001145763040110968067451541613095014486, 17451954319451222638834498307072243398562212485123608858557241953080030994985);
-        vk.gamma_abc[405] = Pairing.G1Point(15775226984950306986673177519342208415475438793888010449799961069243425161892, 3598012473908532669909255903460466204490260539623940096271299913206150749472);
-        vk.gamma_abc[406] = Pairing.G1Point(6081302315789572818098794736061085239889272898115918441006093840477004184589, 88507892818183632977733413697243249880344260460821329606512189109584545110);
-        vk.gamma_abc[407] = Pairing.G1Point(17440549890990151400433834101277978738255480438825691717865317680319776359145, 15895269549072688432161532281798592170488495111728247273586999159763457287744);
-        vk.gamma_abc[408] = Pairing.G1Point(14359224939992204682881581011691064481769051271163884763394708643830641537678, 935980523054746425480970615643028555709901822544248843666547493423478211150);
-        vk.gamma_abc[409] = Pairing.G1Point(9042767535478338012746099987240848671171969605610326052514388584283661885281, 14937102035875994383664429469204393050242012013500910751492780139666496053497);
-        vk.gamma_abc[410] = Pairing.G1Point(7810847596850705410972780732089156035067572262994126289844317834797763118389, 16713590254482969752701816904579118509852724990251718997266180033621986685764);
-        vk.gamma_abc[411] = Pairing.G1Point(17365366783321829848739107775720530991197990000365281285904195636259291148555, 15689560868114758178116470671651812180292300078213641568770491281750403999608);
-        vk.gamma_abc[412] = Pairing.G1Point(15407723384907342694294154369832001508281627026140214560236847846272956311516, 9437205221652964926854197921714022623531119862837207324131177060375718611976);
-        vk.gamma_abc[413] = Pairing.G1Point(6000961587170220438621136512897980145254489238324831657686324181139108954907, 17120825843450166980239043796439395150956053600195919273831986793430339313221);
-        vk.gamma_abc[414] = Pairing.G1Point(4216065482536030307692917681701397727761720221506349114018134507138531835376, 10974849890960674999988513810520782177084963880403178971939740058507203251787);
-        vk.gamma_abc[415] = Pairing.G1Point(12606250754345537810578304422044915394277867675309329474845071112628613966154, 11508891839107970494042648806775804202758545729668399699915383081509998285587);
-        vk.gamma_abc[416] = Pairing.G1Point(13386957349603758451951693141945519532456298020083340043007875410865501938033, 17096171880987249576607498830525854949149067173680834544156563199408605121914);
-        vk.gamma_abc[417] = Pairing.G1Point(8981707121716674645847439982603164677395591637427733183278308814985411886727, 2318764610189082965661578067645984611228221962136078367911716391849223257261);
-        vk.gamma_abc[418] = Pairing.G1Point(14790483029361166969575124526105291005812479211966513988697668306889489906378, 16539762362410937651720853334421495730002227371078944460067476691548586979768);
-        vk.gamma_abc[419] = Pairing.G1Point(16442929761920513935254998458047129645469012466212972509172255236055611244658, 522081120304720208163948966302868204108275544943008997450517255508793680341);
-        vk.gamma_abc[420] = Pairing.G1Point(4038579485323643348134889513335968180773907642508982709300687122638256445587, 7948303812731544434922022515728586917005782213454993708715050323973022310076);
-        vk.gamma_abc[421] = Pairing.G1Point(12944936656935867049942397288913779679004301630897247447527512890150345537031, 15572093296952621240034938338379264465281932500027592337373694232153068583646);
-        vk.gamma_abc[422] = Pairing.G1Point(5914141340782156773850617194562502881920300902989939022008779450713154077907, 17699289435729892632088951478817937342181069007617150410362493539074967264158);
-        vk.gamma_abc[423] = Pairing.G1Point(257473196452078632808864738875313294957415303296373154016106473056211268490, 5506613415418852267083156972906785468164226754528651627799318167904750996846);
-        vk.gamma_abc[424] = Pairing.G1Point(3108133473433479403064549131145154933895427479956207533621629692141697211496, 546848470104716531978090780979412534676645211377254886086148813763383735908);
-        vk.gamma_abc[425] = Pairing.G1Point(10126169704103900209461185373765993152921018291568230751990109185098545192528, 1592928149889017902389923877487863713934797664219522747773974955981711779892);
-        vk.gamma_abc[426] = Pairing.G1Point(19119311364645693930432849625163062684481357122278698479857316068482305994911, 9371260131412134555739581933540978230063760747133339875903701218307815898395);
-        vk.gamma_abc[427] = Pairing.G1Point(14097755351977308896149161820283006376873306542265408956715001334167488472623, 5693623332774452399958479880357690724827765615176610384104425041331493041037);
-        vk.gamma_abc[428] = Pairing.G1Point(14380591740493509516359084689153527154678699208076903243266787921060124344403, 16518388335738869577804023870590533396344990099874059634138368525212877881163);
-        vk.gamma_abc[429] = Pairing.G1Point(13158672669819230911375625050042375939413831056764010299892830922631115765568, 15894528328837222009770107293332305196301522430019839097670383579650174238521);
-        vk.gamma_abc[430] = Pairing.G1Point(20241543522545422307306700244866643238677320027600226894275076795776202691341, 5346308996292864350745656741641625672318853637243968651287385530931573615691);
-        vk.gamma_abc[431] = Pairing.G1Point(21555457885428106818385741654909820046828719434242598021904242152676963724656, 5388127800429607797689022067418736323071815287815002000162587288449857865223);
-        vk.gamma_abc[432] = Pairing.G1Point(7718279120590866336545317340823908124926863572653729488697262836019300564039, 16344511119957564979156433490525615775686181647735767242447289715501193765659);
-        vk.gamma_abc[433] = Pairing.G1Point(5698908298648328664514989922753384051294462319702435594049714538879948930559, 1910740242217773113936266781763618093326763208212291614978095148250803013312);
-        vk.gamma_abc[434] = Pairing.G1Point(18652787104150229173862223689156997217855002071014386414132175633218799757896, 5906637620961015976231503645285693995679819931305765236053417109431798880568);
-        vk.gamma_abc[435] = Pairing.G1Point(16417515641880104153368082523533173080645081652951556650957116872709941689185, 15295458999203896183365353079705083416509056492034082428847328389489972320074);
-        vk.gamma_abc[436] = Pairing.G1Point(15507971317075763326340613860056120202973185651960488126315160592737279708410, 6464712650878258539769714038570496684671968185069659792985445543790535456296);
-        vk.gamma_abc[437] = Pairing.G1Point(18273385781507768205425395215958330608824663574713007220132767618933414686366, 18234233493058869979386521408661487130538106052173825697963872667010431670408);
-        vk.gamma_abc[438] = Pairing.G1Point(10538460968096186710264784617868025057086827440748146279399991561349391710829, 17014077929723166633135585557779597467739885836554634395517848849840956831158);
-        vk.gamma_abc[439] = Pairing.G1Point(21857060867399688426497807735260253285776868582418790114809429188505078141731, 2663844359198790099853726056166933731306344507643216568829685753431996548697);
-        vk.gamma_abc[440] = Pairing.G1Point(17817987144702329721008531221568568767475335572013414979872322452375086278003, 14417867283360575070039559733778664239664231666933043202637177208854461018139);
-        vk.gamma_abc[441] = Pairing.G1Point(11892610945140000920379048926143767815970363134291332736546286818236205863846, 12898582144796555804604735333153986906094456361224286488254963263798064573855);
-        vk.gamma_abc[442] = Pairing.G1Point(8410734277144771915965098315114450609004895312510779294009680776519807093294, 19171919625892139447797700526605100366574280682479254788562584063476642452147);
-        vk.gamma_abc[443] = Pairing.G1Point(16813142560175748474852959306700012224347245131645367019527226864621026937574, 21575070950651903602001911754862176547832001057037927895982189814730187191802);
-        vk.gamma_abc[444] = Pairing.G1Point(2006019222329412946651408339813977040745943256924179552971322856414524573256, 8079500651308681179810265670226340308481557813124499057892219176732575659627);
-        vk.gamma_abc[445] = Pairing.G1Point(3742433380733927673171928801732445556384427168881112291888365947709235806193, 21616168458689781762292320971940535543306538400724895552399109985909779273716);
-        vk.gamma_abc[446] = Pairing.G1Point(852625362411644465791701399024131557153746124255404765830608489850605507780, 20891455900556857867381379937492827378352226389450565664246050502127118841151);
-        vk.gamma_abc[447] = Pairing.G1Point(9022242555361241759711610269099472967858785832249628615647943878622447817495, 5216826836683284978539585394913084801913167015773998213567216619208582001969);
-        vk.gamma_abc[448] = Pairing.G1Point(6349026033912169329432353587900597363605079086563437151792553777032218575530, 13931108556772483564830417037466990325337603288193209093719769088534931322522);
-        vk.gamma_abc[449] = Pairing.G1Point(14462332147616686214799538937677038055931205853411036289022478923439974539262, 12858897386006149509380342127331040009340409490725331588863667963123290975919);
-        vk.gamma_abc[450] = Pairing.G1Point(710350410650875619885097273012359503988662388306429262863351705974536829903, 10850985749518082713369999641531161696237845418616367323835814696228641844145);
-        vk.gamma_abc[451] = Pairing.G1Point(1931326234192357581448391830169633097194176444345243218700002780954196930263, 9910416716596790275995966054843655720149067813452160727547506845496703318981);
-        vk.gamma_abc[452] = Pairing.G1Point(1593864589365418165454734286396800796828890034663074755930228060551699453735, 449222074089961954847897243731006983467969918450360123972050934861314764043);
-        vk.gamma_abc[453] = Pairing.G1Point(547353629138568339237842586728244706256833601921205995324160238465836975392, 13795777807533705861599611685811638237461206275939452312035095460914186442434);
-        vk.gamma_abc[454] = Pairing.G1Point(562732785491012109980761353075261837827131459215171223405846595423585288065, 8047719559081241174293119401488263208412285649658767057594435107665532360847);
-        vk.gamma_abc[455] = Pairing.G1Point(14510359460933292302809237591138631539177175215991866613738938884650379432646, 11710420825082014092029612340080298284649707711852314063091743686541170829469);
-        vk.gamma_abc[456] = Pairing.G1Point(20142432648190718272086343497711163823348286779645899578780094915883228764804, 7530105415778167077108530808748098756635473388209008693864712412680404201260);
-        vk.gamma_abc[457] = Pairing.G1Point(11827878599475790090327757076683609434455586682148238891811021763551873557644, 17737358741545878690708571597400366055181727168255733081023973904048363856122);
-        vk.gamma_abc[458] = Pairing.G1Point(7037312375779900262797999947122224868754466300494047550142570013926899108156, 6527429793070701590609914122686872099561601833606610639805470976238112270851);
-        vk.gamma_abc[459] = Pairing.G1Point(19923932830626911638632817185343336065328145540673582506110321468139086666649, 14344125080101010386429568372158868937046891073619040662479197274274678538849);
-        vk.gamma_abc[460] = Pairing.G1Point(20855223855368352354177840881265160818310450745329994758463844362825392085697, 7543181414756544942879876525316873927015350395247258387983695633917736924232);
-        vk.gamma_abc[461] = Pairing.G1Point(5739998385247771664659639306937432471752743499372886611039541988261573702985, 2635461520405680383251606073620640870128152353919539556146293849327640464969);
-        vk.gamma_abc[462] = Pairing.G1Point(16150149889322704317985039002431866249211837968587634487553735725052353922475, 6707871919048263571887681357043524187955154163259048969677943620672299351445);
-        vk.gamma_abc[463] = Pairing.G1Point(935047728367832179414250753059393682505923682345330618907142595645598623098, 17413377018246710928187764514990795974416856143471130133019555697621960030666);
-        vk.gamma_abc[464] = Pairing.G1Point(4052744386327529953244719281873082471215768874967878813473536903423687761176, 1127842533420213052220878483350430277177385179738321471756007901610190190563);
-        vk.gamma_abc[465] = Pairing.G1Point(10697084843983154758300328226487888117652427823095173802181844347233192440474, 177741297952184787082328559094014166782940822356098872168718066745372155655);
-        vk.gamma_abc[466] = Pairing.G1Point(1628489912753040644969926862899405174328895914131704516871578786452771964979, 14424300893825911778911022196596058234145575500328830592780965968639005722617);
-        vk.gamma_abc[467] = Pairing.G1Point(10579289726522908849236507000070524601989674850481172819202346621692018805432, 19057685112872839700015374006858568649288358780950037160081065979482517022132);
-        vk.gamma_abc[468] = Pairing.G1Point(21152738906793448091768175432767026894954854522242113372129061227889579003564, 18410899961268728684579998979984702355805175419407802734311186639448600360589);
-        vk.gamma_abc[469] = Pairing.G1Point(13553489971856239627368205524837883990525496573153073396826211803310266089115, 3675106687374063620479756474208954240417703769139237577888485580156076527886);
-        vk.gamma_abc[470] = Pairing.G1Point(17420302312562197255295175650332455175424681650815898430507434578487155237264, 14649457866190458466748678878505066866138095835409295446050826796768349250671);
-        vk.gamma_abc[471] = Pairing.G1Point(21748996964816127021720858135279081604735680424706130847482573420977762160074, 5555257467221090874209798963867464261250158325819129816469562720213767023197);
-        vk.gamma_abc[472] = Pairing.G1Point(7851346149381128283915757333158486216825797702870090895937935972545044797019, 19732176186966312542412294300682500962014020558271089864675031513149800340413);
-        vk.gamma_abc[473] = Pairing.G1Point(20544812694766461870007905219472155281532483979483509035976779496047176290050, 15775741414395782899743092314862204893069891662072808975766522588163117617223);
-        vk.gamma_abc[474] = Pairing.G1Point(4146639861915910119076105544603236832327189634171506319464326242951110331888, 12240699075211533842984084888271343711143463330982687002408238972794202921491);
-        vk.gamma_abc[475] = Pairing.G1Point(9162128453151348046978916892612241584980443792682413047113957976193608248308, 6545268351035219253840340531605267121732906639000073297439712546901053922250);
-        vk.gamma_abc[476] = Pairing.G1Point(3100909637929096305180352491467010602800286757080602236228004622688735516575, 625901940308591240141576534419415273870731189478512137671042161852206014169);
-        vk.gamma_abc[477] = Pairing.G1Point(2145263291663298574542094295883155375068778963007548658130345006469200048354, 5688918499550699329364340674057621673424103023082906023100802961847524853754);
-        vk.gamma_abc[478] = Pairing.G1Point(1397352357121462219890307515186590935088966120748992602933072765396283110875, 18073372378387241571285549951660758742784452291236119086465267325732948402828);
-        vk.gamma_abc[479] = Pairing.G1Point(20905150143378354319215651872517340878203036105042465430294225055346616635631, 2410664094819564828828383251777715180133622928342537978756417602613078926747);
-        vk.gamma_abc[480] = Pairing.G1Point(21669418129055980325143256776744217981133364776184428340883537158012156210656, 6544401635096410807258291460423230925504322861790827216944282418600437377417);
-        vk.gamma_abc[481] = Pairing.G1Point(16377390417641143622202782521255463560709448163924553611927668042888095060406, 5714460578303669021556687110805108552209367574943110413464845845023913961126);
-        vk.gamma_abc[482] = Pairing.G1Point(20113488002945930561298406285831304970238799561690524450913497538245663754371, 4430830999955183454202561288328482223295802971157349847289752840882412889924);
-        vk.gamma_abc[483] = Pairing.G1Point(15996562960771351007397155822950661543342463098047932713294977986761548179784, 17332195548319005434785675570729721800218161783469510317625779163291467466900);
-        vk.gamma_abc[484] = Pairing.G1Point(5823806348799317645017354042616679504129496833254379807203663265768312202588, 316064500057176474835842406143467144895296627080216013834980589753799404168);
-        vk.gamma_abc[485] = Pairing.G1Point(21312345430649834356354798209414214054714325242604380244190692061070994327417, 13180575981482336680869324595960219423812932740605841737442768755306336154677);
-        vk.gamma_abc[486] = Pairing.G1Point(13387836130196878350567585131344242938103334289246759723476617508923297829813, 2849500150742726310346026556097799568111243149867053748532653226725359160442);
-        vk.gamma_abc[487] = Pairing.G1Point(6016645805552009474434601765156699494756348998171353718485138907641190284127, 1309407846597050648698516091417620977894812797144488643347367385069046938213);
-        vk.gamma_abc[488] = Pairing.G1Point(13800363706418779652869713702848804338171385829522614173049751141078053533418, 13697622085451566653119602115249375509040671291337330487495020001515056066493);
-        vk.gamma_abc[489] = Pairing.G1Point(18507159517491467648826083673196640864917306088315378688032380867705926842889, 19944595373128190400611360786920956274202282891669945407250737738203099753569);
-        vk.gamma_abc[490] = Pairing.G1Point(10602102341635917004507634831542236183785141053401431843490626448491944763376, 954306846643747075650885347887170177766521035221497983460522957590611494338);
-        vk.gamma_abc[491] = Pairing.G1Point(2599965933648740532643723098946962956029450758889178639862970025005864141329, 3221504762928092323760733205062848630958130994097866032560609689542972324193);
-        vk.gamma_abc[492] = Pairing.G1Point(16918424674647419854061779216935090362014130731566001583837987886931942930389, 10862215749879525204905217987064105229137488508805193235212377835788893881513);
-        vk.gamma_abc[493] = Pairing.G1Point(8893998470164716960643237304989099833282431203744246857113959376257126306957, 5238595328460435879648464623057449342590045686625907972181313983393977612140);
-        vk.gamma_abc[494] = Pairing.G1Point(16247645795250969076086322019214424578110358180318638807061981093420821361053, 2487772998559998760264120189624868426181039882286218689364043214460211694661);
-        vk.gamma_abc[495] = Pairing.G1Point(5609710078962672529830793114715245007214299845856990457699549574164169073230, 4326340088114826763732546977027424724018211458700906245346379225423885581712);
-        vk.gamma_abc[496] = Pairing.G1Point(13795060975039814789205523827334498894814174009167155807601171204161830046339, 3801559310516449991519350571103694664182100310882676995743434442380768294020);
-        vk.gamma_abc[497] = Pairing.G1Point(16235123164523853284303114589770077400928118026679180475751007900697213301599, 5748542002255872651214918920116598743061088975398127094810940755517464182306);
-        vk.gamma_abc[498] = Pairing.G1Point(14110082701467630474683546287730334681990808387893217908141974911317155988361, 13243779733772031504422633074058629754168804632815924246363506344446088828498);
-        vk.gamma_abc[499] = Pairing.G1Point(6082376384127150998086714957842642003744875704664029042992046427877802705285, 15285920147292765362650278921305111301427788991406591520093106591414034359376);
-        vk.gamma_abc[500] = Pairing.G1Point(18444857337100336165638654886284657315577075099025848541373814736280852552326, 9703941954623065407099319465666288721332646284344990522010288374889201558667);
-        vk.gamma_abc[501] = Pairing.G1Point(18427351784529809941680180139538370310451743458507157093256474985918837847930, 13096221582495791577968462276390437329793168478206247311605408138593563817847);
-        vk.gamma_abc[502] = Pairing.G1Point(17316317861870596799556621448940392549699539418535373691207688160204266773122, 6316868508712901887864653694461042766535551527808092643215335072086090051897);
-        vk.gamma_abc[503] = Pairing.G1Point(11111916007757294914680773609777601543613948172571129581582594468961767321305, 15700301680156617881470124053695165168192424926517523650060040674148375635263);
-        vk.gamma_abc[504] = Pairing.G1Point(17987972782590368828200205151195775198164640693884665039999575281577054849978, 1672349457276910664724613449528221899468335523948531999533303090004376684888);
-        vk.gamma_abc[505] = Pairing.G1Point(13865880613626671374166872913520988712157106698946670560910651963425966062861, 17527688475475114705352397037427467548381947473293913495438437254715347902918);
-        vk.gamma_abc[506] = Pairing.G1Point(14458634730896211509285622490062554130016065308315724725798766188165583189863, 1561200633814529413096561259722292767878799384280484307062918018578903519398);
-        vk.gamma_abc[507] = Pairing.G1Point(18328051692517927272310097203192718301964588034449108487521576491500438021173, 1104094617748404077800616977825973155695669708927221085075737568909978927244);
-        vk.gamma_abc[508] = Pairing.G1Point(14002040143020117746099295803547352768654610797871964228711744236898839404132, 3198618120175532667637903883837707657883186036267402383338114772213303730485);
-        vk.gamma_abc[509] = Pairing.G1Point(20752556782330090130584222037966444666931867537654779807311114838370877670661, 18668946672723951428227150411912751868755393553836356163342293844147303952765);
-        vk.gamma_abc[510] = Pairing.G1Point(1884496257683433294359863473548815304322378569804117272302140241920917276035, 14351293694338822218676312632736178956351176885743827671092372468038162848206);
-        vk.gamma_abc[511] = Pairing.G1Point(12794237838715490532501728432879126619621958369474909221955675681851069324870, 16067075490256926695905016173511776204960262084341175446423045513724579821918);
-        vk.gamma_abc[512] = Pairing.G1Point(4849568178436618713643588824705697784048949961412982204629737742157565209483, 12129770983174348103156790359653069642507358817021647640833262233596482975881);
-        vk.gamma_abc[513] = Pairing.G1Point(18591549364615485798725014765414370678064561516888355895974118308344107085434, 657105215791977220396513585064512432098708050427946770361591801277140266847);
-        vk.gamma_abc[514] = Pairing.G1Point(9854755663918910145372215451306655502821273533899963509427131203262843213283, 11851390837608085782445429165483570147007121904535411535635113892708392583291);
-        vk.gamma_abc[515] = Pairing.G1Point(15483656672523406913211890795334523511626331593377985328367445942110230838725, 10398655375223694336206024496676452229508786624257018939621846985670494254999);
-        vk.gamma_abc[516] = Pairing.G1Point(13341840032818102498938034729225600557305857476710356264826095597515913065524, 17326613095582095325368012440425725129369168942120560590185050155493090752755);
-        vk.gamma_abc[517] = Pairing.G1Point(19810231541293229532854529410825611258345551418634777418900332675308274061179, 10405876513033569375531570125146187207476579716359724443823609159850915367891);
-        vk.gamma_abc[518] = Pairing.G1Point(7212942869007748115118654876148829874048966145275529098253598167131903917945, 1566799852473121028827942273731226404746355295289559562275399860086187465602);
-        vk.gamma_abc[519] = Pairing.G1Point(9590656230827047767014646609424533872818687703112310158416979291384022118689, 2199026556139560623954065833222101583347282376335091711480258464236497649570);
-        vk.gamma_abc[520] = Pairing.G1Point(18941571488515937436040375943023399018154794113520614256960674683130238524398, 13998827790038128746352210317663089309887895280510293449828300306142010814434);
-        vk.gamma_abc[521] = Pairing.G1Point(10438286162038545701194828614570743928467793461651266384818110908756571799347, 20909623546028611979126395943752927487897824931764071575190661952090586561975);
-        vk.gamma_abc[522] = Pairing.G1Point(5804755641204692981968598775001787412694272337673429548819129224140536326964, 1578055963281592227898095872882435671175993025294720152284831738266178807007);
-        vk.gamma_abc[523] = Pairing.G1Point(5039062763524805329332958456323474126540951111211108591885550823670314849906, 5127567915379648121587584312886398935813520262067920509667090513740511944683);
-        vk.gamma_abc[524] = Pairing.G1Point(1751980212369810438888486888346621705031527620330325353691650442387462280379, 6669359017703437601518512583353885096086721464100589235381753990956568979587);
-        vk.gamma_abc[525] = Pairing.G1Point(4592751004354913667202004218062548167231421429632732854503714766886921486487, 21842440697452070953867112985934001216722877327497568077087187408400057838729);
-        vk.gamma_abc[526] = Pairing.G1Point(13677708653221144550474753208940439332769830970533352817635359999593624257935, 277821722778476721429201492528151170400728662525946719891062955729443228105);
-        vk.gamma_abc[527] = Pairing.G1Point(1047054657728006168808349472019919546391336398464149363371386932963330558720, 11837423256533060163157402332945641434925307151394598352149669765776536198849);
-        vk.gamma_abc[528] = Pairing.G1Point(20055030496015640311815504676653893411531435192185090668895755848352473869827, 3981648700438195735282990695077039248591984374513988466771699870219520000224);
-        vk.gamma_abc[529] = Pairing.G1Point(5142710688775958229039054608483256032586259004412416719241116381773393159761, 20537019912499496918229630464196233562217741141706283133338644167096183340688);
-        vk.gamma_abc[530] = Pairing.G1Point(12036696670442393061322931580954806081945766909017405359396978684452641836372, 7475005140530930795938240713150164463836467798946568015250928937457804838293);
-        vk.gamma_abc[531] = Pairing.G1Point(20574629743823038045546515466483473454598147738473154867662910540373460062382, 2959372542696781020275951370011342656832508644275036652176545245552358607137);
-        vk.gamma_abc[532] = Pairing.G1Point(15428297373162880171015365643968157911460883809002360200899268171412410193603, 5795455916256176046313621386862984384816674738364540296328879375046075011904);
-        vk.gamma_abc[533] = Pairing.G1Point(10476582425602069067728576292512802411337817904025977968331074592830586626675, 12660968650961645466497790198028217333119856489279667667968122028789833813697);
-        vk.gamma_abc[534] = Pairing.G1Point(9119705775318534646353243792499494447093306196418451473381901056832406395402, 11251553381137260883607969560805861391836880346625485543115104545372777713662);
-        vk.gamma_abc[535] = Pairing.G1Point(13806228678684189681953596897045626955113332610941906053309671400953439353493, 10649973739842607673312014860274589925852692525905584677552501404085904932549);
-        vk.gamma_abc[536] = Pairing.G1Point(12112123075492367714041109689971217155085380934590224101648267775206480184577, 14229026166277927955547410524684700757829871212634963709197988882005532450625);
-        vk.gamma_abc[537] = Pairing.G1Point(304665687909058401420632771452882461344771952522567734882394166747898609289, 16237632592326094275716164789225994996050091934711853115314676564056597268288);
-        vk.gamma_abc[538] = Pairing.G1Point(10556850965028691750403218315303089400223537772586748596292111466624617016191, 10523222188538883190410435591993368817944712273769124495843198136815573033499);
-        vk.gamma_abc[539] = Pairing.G1Point(6536908900540008946827757973914503804075134451075104440041099320377605229611, 970675184061933956016395464715808370880748615419582582920031701678280109790);
-        vk.gamma_abc[540] = Pairing.G1Point(9194969784550036522578324744536708259047621695181759777351484549622426610477, 14727762256413559114795968388651205524864075487953441494887892420506099589824);
-        vk.gamma_abc[541] = Pairing.G1Point(13721556879082156839558278845972606910355260111019921486894975067109100686953, 4656364309416657706638067128778725894716969874885784930068131504555136265838);
-        vk.gamma_abc[542] = Pairing.G1Point(15205025991993079428279689559326416130621744584750086902870002439913664106631, 9264771545940224134486262680061756875580415399989978541426575753376727300723);
-        vk.gamma_abc[543] = Pairing.G1Point(10581891536573074732973733471261612550849688544866575080031975516075119311729, 5001717302547476345288693028391507543009653976490714648330338464591758897774);
-        vk.gamma_abc[544] = Pairing.G1Point(14734521920154601485263229993807785971845324705087185109930060498446410278864, 11450878879227852977167414772846110068968977567336930560160651737003420693217);
-        vk.gamma_abc[545] = Pairing.G1Point(1629168427129668723611109915093946029756540319521324316933502812632654946337, 12024438117063116139991545377106306246425153166230858378091489111207927389216);
-        vk.gamma_abc[546] = Pairing.G1Point(3441143009042164192387464353154094564641387876561885855783688569685795478104, 21241397087098236564270589522444061325818167697435875049321479606896140063101);
-        vk.gamma_abc[547] = Pairing.G1Point(20885133978832804644549331918986954974822415684943947505806018892937217426266, 1269592843714535867749997276469351373744784152764156668207973358154589922131);
-        vk.gamma_abc[548] = Pairing.G1Point(7698406987575890631064578499048424525594503449984360793222803964184449489150, 18264304434286852007438921047174222450454797372489052204160081194113299269094);
-        vk.gamma_abc[549] = Pairing.G1Point(10417977018604134043107270522606496505585884382127867884338711259950340340717, 153984592948434835633785959416343927131025855051265916858166968277532005147);
-        vk.gamma_abc[550] = Pairing.G1Point(11294990211884164115408811113221213043083786732984148141101388573597200405234, 10375018452698251653302652412375745545315902598862478982817351302310712505056);
-        vk.gamma_abc[551] = Pairing.G1Point(8562210238008708170246200973466222195482806407403789182396078601605176940683, 18710074244664676076538952976744481188899442948274763266640613816531926729570);
-        vk.gamma_abc[552] = Pairing.G1Point(6874102701154848080741497096066305843174308802872859518559690554848586994151, 17248942346974788082861138486490667095875343074998634380223967426295327752336);
-        vk.gamma_abc[553] = Pairing.G1Point(10927947046961264841687141226897276622214936303528292139541390016965536603630, 15561179125327733879500470361334805584714315561148065097392852081253317065587);
-        vk.gamma_abc[554] = Pairing.G1Point(10649067752691621883676502504389517988812234080693375946521910788726586709185, 1673211647313854167235222194190997365163544983427674366134354006213914102728);
-        vk.gamma_abc[555] = Pairing.G1Point(5981875092001096185920211502653827896230838197298102110651540190743227467059, 1211817043764365874135414703137455067857320180134965105955432129209382378990);
-        vk.gamma_abc[556] = Pairing.G1Point(20950200398950031932437703811565383434194385410088458582672692981014388207393, 16729437073805968752111002923904859722783702709276034739589832428041552641860);
-        vk.gamma_abc[557] = Pairing.G1Point(12371198097528660280747110033080480073453192248912265636030883118897115760628, 18692665561694485643241220180326644498519632427960248644525192291286218739728);
-        vk.gamma_abc[558] = Pairing.G1Point(10826763879231671474102474861890062703868588331815861055117926151937903189996, 17976854523753213769541758766230061788108635235534239692131141397634378746768);
-        vk.gamma_abc[559] = Pairing.G1Point(14771960507462189601554541579742259825757506581487644220725313579879715402426, 4802464453210268477512871772893931902149130398605762069455449808593790337722);
-        vk.gamma_abc[560] = Pairing.G1Point(6286143412911461537241540663016087591916148434007704590309220234151384319685, 9356291423995300843774820192813520110139281773698455939509039358244870292140);
-        vk.gamma_abc[561] = Pairing.G1Point(3474897700185753542030470427480300235301750748516901025058439151455128906901, 3388679571747000494811789461307603258191540149285980387329534493113642807626);
-        vk.gamma_abc[562] = Pairing.G1Point(13751547215643841869916561618213833843115962015235823997282317019574282187385, 809116664528731312434992912474287354305803346153665706649099156365842023535);
-        vk.gamma_abc[563] = Pairing.G1Point(11890193511147757924443215916876599083307589442631726341821654511373429587908, 17933344317562446397119599703708160562248025052073172269074040507719893225322);
-        vk.gamma_abc[564] = Pairing.G1Point(19443686234197718739485885723744334524711155703085715902982725766004631707900, 17957746198886869265341018219649989568720363396352062280046495218795178458587);
-        vk.gamma_abc[565] = Pairing.G1Point(5747167626615234579378571805689123795253854716960320788364194274722861251100, 3550718545928300314251496351573846974125620039856438816024287617321873023840);
-        vk.gamma_abc[566] = Pairing.G1Point(8243141790334161071039698194995023855021823624279722509578881672597272384877, 5715903626449950182882559051003839933636787916011042435718768313036921859618);
-        vk.gamma_abc[567] = Pairing.G1Point(1551325155360210331775268153922760434483765824211795159545964385470703651699, 19023514661302292415849939777593846914020863226264669172518448775037876642188);
-        vk.gamma_abc[568] = Pairing.G1Point(18541629637351578295989694134935821073517963791292851921080286546298545899150, 7211587166895688445324309263843401906315097819054258557116238306171465597846);
-        vk.gamma_abc[569] = Pairing.G1Point(16945675857358425446095250514077304500120629373597527822429162794290409486132, 12944301781407287178097729360037820361423328515222801634076016826374409496172);
-        vk.gamma_abc[570] = Pairing.G1Point(10156524224875905801911355005524497616302445506878675279390873814547393494390, 5787520632186536860057312938780359220603973184949958524563340119847540513801);
-        vk.gamma_abc[571] = Pairing.G1Point(19254098419542284143470572624406119124115038548667029268078502862476391118268, 10721004829311622032356810083018769437926639496830547934656677470185670258070);
-        vk.gamma_abc[572] = Pairing.G1Point(19290668637539853593141045759515790097455753199055913497170881050481277261925, 10134554009478845917635144369133223777243629618311897261195983971817842525578);
-        vk.gamma_abc[573] = Pairing.G1Point(20670320406643190714389837707208033865377795349459675337227723875417657827311, 13799827605933862526807288870818311123687045518853332169103555171456306321357);
-        vk.gamma_abc[574] = Pairing.G1Point(14260527128610466316319340434446064004736652503049063240826339884984988649463, 2335428011583228395613561289796546785614584705679782058256386805233308355131);
-        vk.gamma_abc[575] = Pairing.G1Point(19768405686663831583536923570669741462138902640940759556363176275777391606091, 20143253708465174185836087632103304459982477375185473078013408094811761717983);
-        vk.gamma_abc[576] = Pairing.G1Point(19588626337261705534213057082283661599649767034851805107843668566057302200654, 14916112346204220484129744275248468688158785274386891272025229364227452580193);
-        vk.gamma_abc[577] = Pairing.G1Point(10221960323679307503986533469987081245689977641194049113646625727646310511968, 15594801035001019075734628362218619781459650240877659944364389634868109443461);
-        vk.gamma_abc[578] = Pairing.G1Point(17112338666107047358401600007165008818180445066567617316634402226056450859302, 19261416043760047342178821541425014149722015563141756613526468377373485830738);
-        vk.gamma_abc[579] = Pairing.G1Point(3519567824441928617139198189901516780468092354137165034277572824678583069730, 46366565790707863746255444436018192762296134938795897801017240076306839963);
-        vk.gamma_abc[580] = Pairing.G1Point(9875145190668039808749645572275217802760129284131236808607982759737390096509, 9282599508013571228864492770352069456553475427678135756448937845807206422525);
-        vk.gamma_abc[581] = Pairing.G1Point(12050372873204691579428564993287938797206197388502408421137198449177861328407, 9149603573112804696104442232648544847428893138959127570400199031761357733340);
-        vk.gamma_abc[582] = Pairing.G1Point(14310064336370391679704850848563491252859223609973434476008769924299702775185, 1683270372815859928078649634870965514687999960676933258575726670168400797434);
-        vk.gamma_abc[583] = Pairing.G1Point(431731719961014986528343102138140702160221819097649568902402738443098296097, 3212642307891635311783277156267090600077382524964062204132110511590196501868);
-        vk.gamma_abc[584] = Pairing.G1Point(12851649813228149408516879379143023214674617038954424827476242926797515914835, 6819157596630435330402285932459672080212303720466780559074461581148640398051);
-        vk.gamma_abc[585] = Pairing.G1Point(9704428618070498863881643260484783452948129506388177545722658660221045715737, 8951830946038350506286592779375044279442887328678452350326949251418778830983);
-        vk.gamma_abc[586] = Pairing.G1Point(4816224947716045998628340410537474014100978580990680266328176552118787845042, 5878187153812362801848188806508552332539393585802283359847781028842860126803);
-        vk.gamma_abc[587] = Pairing.G1Point(51541719899611189063954702645998061132749778670152062670456091841729993551, 13948576981382121659182020917958839722206357420003559213791694558382295288950);
-        vk.gamma_abc[588] = Pairing.G1Point(4691104310799581301180418898710806082098044485185833211722023983441891638224, 6664134266444050213614016886325185007777896754394814949555320482581664142308);
-        vk.gamma_abc[589] = Pairing.G1Point(7281246532803436328113368866850325556001696249935405962190343440940016463310, 503878250543010963791460010631913974558750742945609106733735381562451456300);
-        vk.gamma_abc[590] = Pairing.G1Point(20681109975377067548419910329669903557994895961517812120211106602035313572595, 12338250537428601631430370388201535757246167597484998549843681947322573485364);
-        vk.gamma_abc[591] = Pairing.G1Point(17506964859166402976004964280579364542397823604583573058735625563501059259856, 7873184695348166355352983299922238579387994800047144489841862402248161947955);
-        vk.gamma_abc[592] = Pairing.G1Point(8779690037995920098387817919576983028801866997092184257572484422162148523231, 15297186378016253112066615417924812543546488773467755001456147310814679616526);
-        vk.gamma_abc[593] = Pairing.G1Point(1469044422926701264666662947933654487479692366612889234241366758060747210135, 17526352291412715281866327661618428644474345599583545916539275186509940996087);
-        vk.gamma_abc[594] = Pairing.G1Point(11407048988961464974078434529562529105805052157241837795214376197748693281069, 3174069110491756322404813337405866910339829916540040921844459254685035089515);
-        vk.gamma_abc[595] = Pairing.G1Point(4001293594375494478438046962262122726383232976846176183173025489553323807138, 21146906079612896810146308836503486011422900115406013784454464956567413171588);
-        vk.gamma_abc[596] = Pairing.G1Point(12064648538266581751609187573295481232008624176462424745286956156739972174226, 4631634466066219358762363390288935920089067519879506870429487436799376393056);
-        vk.gamma_abc[597] = Pairing.G1Point(4626336314620095071145564446868358230206153242913486420138401919301555317067, 15076367988913099242573608034303430140994173049927007297699932717441586332929);
-        vk.gamma_abc[598] = Pairing.G1Point(20491878938779841227405508318953267940669261728282949692331112260496144967272, 9369961608064491944047646091200125947234213601847634616326686117688970916080);
-        vk.gamma_abc[599] = Pairing.G1Point(12817337418203611427595263915545289591632377143237971166247132017675095143164, 15875189391709539819018704517542231935138852762948834696889595373456118794665);
-        vk.gamma_abc[600] = Pairing.G1Point(6366161114589174404814696667081319064439804415446710051279447042865264170632, 14793323328902660611270056977536392734677669304268828722234643851923098091868);
-        vk.gamma_abc[601] = Pairing.G1Point(6927253924905026391070383854880315480147327719438063576981594572454429281251, 21602696558056866115153807168905267414142062893543694684679674844709676360600);
-        vk.gamma_abc[602] = Pairing.G1Point(17748501836981871851220786439456956553294617074405926612177325390764234427139, 19688401792670499010477217651770749391838057530659572649394282173674062487190);
-        vk.gamma_abc[603] = Pairing.G1Point(2106899959354568019667195458559203633661754633570438970135186823481902059713, 20595518750119530193911513485784534173812724472876416447579890814107041502293);
-        vk.gamma_abc[604] = Pairing.G1Point(9695277305498187940679073796517140543281961674175130832368691774153868522591, 8892891999135393006377572162110870521552102917748535938068225026407441154593);
-        vk.gamma_abc[605] = Pairing.G1Point(1453856120708213209988438391700952000296177168776539161981592704519626150287, 11342939160756969717123744960811350930004701832359940374101676507629505217064);
-        vk.gamma_abc[606] = Pairing.G1Point(377764332289385060541618123749080928976166193045036429069969900478295961928, 15357816048400803808614748797384400818298602918014164149184998678450442072323);
-        vk.gamma_abc[607] = Pairing.G1Point(18244622779343864841588431417006325251937549905630525310404812340585751973435, 10311504708304276283416511914664830425073058406126073668518382629678107484528);
-        vk.gamma_abc[608] = Pairing.G1Point(10722956149095186446649990685951039851106651501650167958959846375311438452917, 10535742020167755361555155053442229430038433592183142045388374253000251578484);
-        vk.gamma_abc[609] = Pairing.G1Point(16799034010630361163161608317811297004980483655922310065873413460315006282354, 9259602089610261786355802098862246336330874144867940432796540088908820035547);
-        vk.gamma_abc[610] = Pairing.G1Point(11490052025691609082831241905047685001868454263029970016151445746783426648622, 21803454277228578397477886728086181734216759900379775146535665979747550979651);
-        vk.gamma_abc[611] = Pairing.G1Point(7922917505761891184055583015985641835634768055312239835809919134272893538196, 4729332047079279342008213685305020198881455013447117070221461043985840538875);
-        vk.gamma_abc[612] = Pairing.G1Point(14074357301899574794379994944743279263713370280243399594984449963845037438904, 390183215717786758134575249187815874275653817171788938189608566835512952129);
-        vk.gamma_abc[613] = Pairing.G1Point(12120503842188374306322264371055362227358646347847679394495862671367055247444, 11458102775978389478465209674236794742978138360553845992394356527574584573425);
-        vk.gamma_abc[614] = Pairing.G1Point(10928374277069081672088214589221307301588483352088552776456285168673411352801, 20482618993010652109923231865493804514453607163649168450403263814678574797094);
-        vk.gamma_abc[615] = Pairing.G1Point(2685612480000847193671136140748115109751100487739125040460998245798388000265, 7386464917300632576106080766136216846991193832292730925632366663337316929786);
-        vk.gamma_abc[616] = Pairing.G1Point(5815413524355831284397221428774274040700627304990338040960236827753759018066, 3637754760184783084704494926237232626901015583301848023383097849818555461102);
-        vk.gamma_abc[617] = Pairing.G1Point(11505891639797939508027287482929071037756346569032673465560049739989746230096, 10371657835873168133589923804198506178323637779172919004341908339974911745930);
-        vk.gamma_abc[618] = Pairing.G1Point(6782841632190826386974009480630216827475651042370349579737030823107713738824, 8116813519609916199893148139231863936294395257320943136077766314606444091547);
-        vk.gamma_abc[619] = Pairing.G1Point(5842994246384480897364742125752132789419275060787489277546706127742394160417, 6948566829212078212978955304463541323290982305569463818892183109334051063488);
-        vk.gamma_abc[620] = Pairing.G1Point(16925235026968614700942674621014717040408155921248792310259666210593956925649, 4059837109142132508471918040338823322654426144765915293416482736053427175317);
-        vk.gamma_abc[621] = Pairing.G1Point(2563998934519599687705403474963323830302525669227567408260555490809700450557, 3226659150997529836120216042387320583826349320287290834803279981523471659790);
-        vk.gamma_abc[622] = Pairing.G1Point(18062966123775645497595412608955432266598772102306535759954760863559063353540, 16590959229328130456432285481437199728662342150275511022742166690284862710149);
-        vk.gamma_abc[623] = Pairing.G1Point(13223259553446141835619332581746294214296667266383722202164226488337182349566, 8172165327225730958319468837162138441363470747682951308248500224634571844880);
-        vk.gamma_abc[624] = Pairing.G1Point(10525261503913354927647478280831538368129523620020292205563350730376273474870, 14289448166122777363670597968611111039079773480784100866067674267292711636908);
-        vk.gamma_abc[625] = Pairing.G1Point(352797589441035472051244996396499501647226269945579687555821684515953491659, 16974288988410075331654356390008910690968363054094049820641652876763954741464);
-        vk.gamma_abc[626] = Pairing.G1Point(7456875627876067619696476200486981293830051199961302842857466898274448174824, 21178905966591157062699748980412269952471305611357458744082573381905402930051);
-        vk.gamma_abc[627] = Pairing.G1Point(4256256009244568751458133612592346719444218836379891969001004049512220309374, 2366102606560699310817221710290973564478583991256864880134835843979317744446);
-        vk.gamma_abc[628] = Pairing.G1Point(3942942669400066488735213826550757883063110088601382624957093491543857063483, 8711277777880291689314438062993935000044383915932543966531148948906913732891);
-        vk.gamma_abc[629] = Pairing.G1Point(17706118148347511408609988329995081008815016008822878467295867997205194011926, 15073144243898464568380361245716574233564445033715344032609607318695086332994);
-        vk.gamma_abc[630] = Pairing.G1Point(5370460945036467575072479146591352736718145459190502174753158797855124157898, 5904429932815855852357533275864600419272742289187731475776758870656857895863);
-        vk.gamma_abc[631] = Pairing.G1Point(14582304205357461918130201794556538487508818075317257730178063999291572977897, 512334723056846641598794145543852574074476119346228443361025163074843003566);
-        vk.gamma_abc[632] = Pairing.G1Point(10096366067919343118093086513223946949558491860771642605327403436470058444381, 8307512910529736730322695253628246089167431505864563621582607542006809588635);
-        vk.gamma_abc[633] = Pairing.G1Point(14081223055778908940394470855840828200402701613749045619617209734507770852382, 5164356985096125628423671353622903295813600027871639014598970644833804365208);
-        vk.gamma_abc[634] = Pairing.G1Point(878616625755826253538584514744454957671740688928300804481987987923474187296, 10100195783743367623832339180029109601246321443039038800630906677965839122569);
-        vk.gamma_abc[635] = Pairing.G1Point(3347840957576303035562359920417720403162079334750429820303149267677917008829, 5008016166553032148123541425961728460065846790857918698910319211512020252524);
-        vk.gamma_abc[636] = Pairing.G1Point(18453275505766257766339775211853314387546392065835810225397440667339471554877, 327517135738711604545009775021349007205558203421344162557615262363073166035);
-        vk.gamma_abc[637] = Pairing.G1Point(21671355162579194106671188118755957402165611803534716637565461328788352587099, 4360469772813488158715171890620882612000238539353458296558605937774544040841);
-        vk.gamma_abc[638] = Pairing.G1Point(20452328095490798246398166760878241758001250461181819447996135806378935603681, 17328603944389724955788035688448130846877541912459570109325838641579147684484);
-        vk.gamma_abc[639] = Pairing.G1Point(20679265769272281956080486952776214111546775727205047176642550408208113383264, 12394892556353008451341130525101306396184464545892765564756952275301700009182);
-        vk.gamma_abc[640] = Pairing.G1Point(1381440740030077336706620609573785699587282236147005546837048548094034796853, 2461449681736326913314686813691225264546493196586166472882140456706417475644);
-        vk.gamma_abc[641] = Pairing.G1Point(5726762206688425520557873056713462533130793102702306380943188703302794555252, 4183338276962525210023580967464211695911164960828470015794493199100296443421);
-        vk.gamma_abc[642] = Pairing.G1Point(11385408738078180837988698695465987414000288534797343525781488864921152303929, 18898789687239006604567811353673993468779244176587636685760627729291490165543);
-        vk.gamma_abc[643] = Pairing.G1Point(1252710887404077930362693232712915034545019190530668435958062862511716683158, 3590883550096658136802872199918720666389745213501701021502724590540291729153);
-        vk.gamma_abc[644] = Pairing.G1Point(6440462549301688157969713878996619074313615395314507484406096046734559201006, 3406723493260993826052357817028351414132723221892193166719052630378834622311);
-        vk.gamma_abc[645] = Pairing.G1Point(15593389042244539282554014161997122691879533455885473442458825799369521555320, 13052833677478358361693396800639631070187324459581111093334287766725595657719);
-        vk.gamma_abc[646] = Pairing.G1Point(11975313459476035337209722424696906165042651301419869563319280757001192345440, 8513273334878453162554262430541312524713766573461715931014035945714702217623);
-        vk.gamma_abc[647] = Pairing.G1Point(19299838969194242446340114499788247140148118389280499994008504957872211844801, 16386375654384405908845568933563627487185893283860775000434986105926351985853);
-        vk.gamma_abc[648] = Pairing.G1Point(7111937257340373364026971186789248629035991733340792536652449281298723749678, 19459851040042824379071025688706101346172721778499269655872790051021758673028);
-        vk.gamma_abc[649] = Pairing.G1Point(18833742392589940787584212017618391528478088594252709622272602428837179416447, 2788508888873626665539422379649810093844745806754653244269416979830980571066);
-        vk.gamma_abc[650] = Pairing.G1Point(5174231133543044022586054270805546727410173087365172356783543569310521135122, 7942727754304276733980018591953705283946009283532128116930524748711239595940);
-        vk.gamma_abc[651] = Pairing.G1Point(4415155573108105682085578173150008637177675693251975201463564804771845186659, 2516737703912087856895728946509745304635443603001480296485983542127584885410);
-        vk.gamma_abc[652] = Pairing.G1Point(11665272091537502404267522877130213199811315878311420512421321216456212722881, 20062646449194199660071538375746489464849321115698054009223036722114250991240);
-        vk.gamma_abc[653] = Pairing.G1Point(14632461407987683585277418917923693424081547122567690175015912982434696130344, 10642639070083160695819873064033412856111458007466813527573035452178060349190);
-        vk.gamma_abc[654] = Pairing.G1Point(6381171221096785964212837412797612403739002694119351542920908284574585697371, 12745702062896606174223437344893349244435883979472293622077773234981461151097);
-        vk.gamma_abc[655] = Pairing.G1Point(15766338089680813542046031659498345506934937366971749133899194924703385115587, 18087250168104749796291314948258990812428478377250503664018401834503724002351);
-        vk.gamma_abc[656] = Pairing.G1Point(8460282794438618754380970012283220608295188959787901924566593890580137809826, 5541123463104144309693669278586929638482076729872550010671709442075488676224);
-        vk.gamma_abc[657] = Pairing.G1Point(20102598335074677601999251226970672066565559385374613531657640440460268469784, 15649519587505054919890728652547316776939228292245317615691416192647735877640);
-        vk.gamma_abc[658] = Pairing.G1Point(2115802511515915389495234721651673729026849852379187209004979576775556697795, 1595811752392699791294513182685529327719677980712508891859392368877412711777);
-        vk.gamma_abc[659] = Pairing.G1Point(15225557546272838133276858507765021168563094679323170272399486555500233526977, 7557914422042485867820032309354221653657897932511825431191695063297952684005);
-        vk.gamma_abc[660] = Pairing.G1Point(2029358709994821354245176031575762460584854376347667773266428919694624995588, 10033749191360652459569251581202208858231627711827841239588972775109584391746);
-        vk.gamma_abc[661] = Pairing.G1Point(1816254032228382525956542176017173619655017829987800308668927408641611903832, 7476792308149040917295686131145851047116787773277835466373868713826169591475);
-        vk.gamma_abc[662] = Pairing.G1Point(11992320571516389772705983878993184543679174580774192691175963278240270578782, 10446223958595828980322076331754090937058999808664346649992225609149355178855);
-        vk.gamma_abc[663] = Pairing.G1Point(16403442998093080711170862497584184608668126892024474386176812645919488879744, 6280090180739760856520942142928387967064982029237086751760372415267334285198);
-        vk.gamma_abc[664] = Pairing.G1Point(11996076052313625121481041871006591089614135173408826618892933340679272911649, 11148822766607208073514915050177037190288308741219211682914968608140197512752);
-        vk.gamma_abc[665] = Pairing.G1Point(19988321240556154515894754834830299802967485476263052014809511643177905573508, 21831392182344212680092526138114801649427587463261475748886542160649218410530);
-        vk.gamma_abc[666] = Pairing.G1Point(4029303991494857638166432474489424500318706549118020199327800444943601951101, 17637063721416528726563244615947185600674725668732241329507258042351018988389);
-        vk.gamma_abc[667] = Pairing.G1Point(4081382753248580382041769438854874392183181854515152056327392813779130634047, 19486268080135248191876107484716753913516274177969126035504530519274756463508);
-        vk.gamma_abc[668] = Pairing.G1Point(107345289993812495181013835758445769465720758419371221166284175540306959326, 21392156840169113184494295937408802848013662823610458688249375257910019130078);
-        vk.gamma_abc[669] = Pairing.G1Point(1242164494278222448268908391824353846886270839422070582245484037285453336901, 11263222645623024226298832839751330385513030953630619382158498391612328260294);
-        vk.gamma_abc[670] = Pairing.G1Point(5932170583457628783145243240425799235626124441402950963670552710361677704833, 14483528555192949341670685339461098575028075699372322964179804868070125726502);
-        vk.gamma_abc[671] = Pairing.G1Point(21823196786718759971691379797030508038285864325927785200921196602315914940522, 20036650171662982494601271630984412196377905815154953566111313992172550495255);
-        vk.gamma_abc[672] = Pairing.G1Point(3170404115216262889195559774262127084037540443447155468892814279322638906476, 13992860607818616573902838488316931877535978223707648453019383652531156371690);
-        vk.gamma_abc[673] = Pairing.G1Point(4122436040534192298957790786874164078871378903481515398176485889651700304612, 13348064203523694394161821768066109211311290084900744056625301556994277505243);
-        vk.gamma_abc[674] = Pairing.G1Point(8865034976095073997331326394532643636782053904841960589292377963673264286826, 21289063471164703572030995415799554265805153655407789171108419491789913341095);
-        vk.gamma_abc[675] = Pairing.G1Point(7666771230861482322908617815685152241214843569473496894987906670236289747447, 13309188290872808221021646188117982673200930622548500545591186360074936475344);
-        vk.gamma_abc[676] = Pairing.G1Point(8533373635390113121149822285250522332859254306275345606113839745375581034653, 10555148636945580288561550459492816269115128134392932064894755480789774572611);
-        vk.gamma_abc[677] = Pairing.G1Point(18223101033164230721006606983061899609561528964265549071012978353717631422198, 1751825699735716533018573733048790700298576857996355379328515066889537526699);
-        vk.gamma_abc[678] = Pairing.G1Point(15780503068618573723737602094020405014526666586192488343759561105567015226979, 13848397042507051675210653081600117402310487046969407295261468144189518311597);
-        vk.gamma_abc[679] = Pairing.G1Point(205849708002348985525440982388105294330617757897243426157003208363314454040, 8552435050992717387328476202843615475233919412133488403126446520399532998693);
-        vk.gamma_abc[680] = Pairing.G1Point(20529983833957484302638245358310474494019783477528843419070058529924338477339, 3057232876866430237640765974744136528361591911940213286113735981802778539494);
-        vk.gamma_abc[681] = Pairing.G1Point(5777221050892898969501974845590936881035051756347721421847971333892983844428, 16269379330781787445227404395123559529581322399621304124218045067560242421614);
-        vk.gamma_abc[682] = Pairing.G1Point(15418313397012039408657358856747305652804110466628708706641490085173278827306, 125377520933736900093984820195142719202941602504046605646178311556350506421);
-        vk.gamma_abc[683] = Pairing.G1Point(18302606419736998008987713067203077501679189946582930230134331918535471123771, 1839916940770256704975712869707912573327699189126966497393683733560622189572);
-        vk.gamma_abc[684] = Pairing.G1Point(4211703461522382272903942553847415412557295589939905608406360380042935788109, 18476495375289077817361389880568019695956748643139025837842921152212067158922);
-        vk.gamma_abc[685] = Pairing.G1Point(8745080952695212949566296897472918334187142977679472601217571303592390873161, 13833062620288166443805045603343086958064561542755520696547450067436944555174);
-        vk.gamma_abc[686] = Pairing.G1Point(13100689103932916785970225671324598854052742760730285574804739851022751246454, 4357405290929017469401674321699508407117394974824884511512114816498213456540);
-        vk.gamma_abc[687] = Pairing.G1Point(4443726338057521004867398113061666805017030247413865010442355290229241659414, 20398050565343624457011638229000145992205044131350495478332146793886392056262);
-        vk.gamma_abc[688] = Pairing.G1Point(12845516542100379765247090314365052035411106477548117120380584137907738617628, 5489399418568570979507084375537972302023211579014019075642457212257740751009);
-        vk.gamma_abc[689] = Pairing.G1Point(19972710090156030803140413734895280155027766296038253098404637990700756334563, 17514252076529832968722943620627796855713440930933668344162647497184201338438);
-        vk.gamma_abc[690] = Pairing.G1Point(14147039510280039743465302968536773347178225026198043007479281964904564385543, 21172246911218649856995802374691976949080678531976557651206406629651994344761);
-        vk.gamma_abc[691] = Pairing.G1Point(19660774360384914298715712142101984775004123985095963057307526806607023669904, 19760046513073052425431974100109657570994490094173682414702187546701607855147);
-        vk.gamma_abc[692] = Pairing.G1Point(6448692636362647926298859048194658484042179714899590460879550626642793737426, 1968810679164130752421606670256136821284136678864420627047582481170202769640);
-        vk.gamma_abc[693] = Pairing.G1Point(10694253839039328070316482920484319961974702051391528684626703073632921613754, 7347905757668223169522889522682409181354650960365849045861080947634825100989);
-        vk.gamma_abc[694] = Pairing.G1Point(11745820684407854581520163794759119616548096907217887266875337750494647420461, 15767851671181424416763673450048168011237489367439839750506037041708083402423);
-        vk.gamma_abc[695] = Pairing.G1Point(9746508296756659083405256953690734871279812493657823668186296481813653055336, 15655694115160608811140771486331531590510863064892086011066534524329276654442);
-        vk.gamma_abc[696] = Pairing.G1Point(10786397246827588870129271382062421028897818530023167098571564874130089797466, 2330195481594523885771760080064402425399329703854151992094830482840146138581);
-        vk.gamma_abc[697] = Pairing.G1Point(7831571306838730828649177054323312826934427307878484849280649492452354553557, 12421213453347544650944658972944290713996599545806176088746463859465038123956);
-        vk.gamma_abc[698] = Pairing.G1Point(9922166450603471869526396612386780406848994909979612003552786625083924641158, 20527416306123958074686299131473937176768173099458989652400294037905746296298);
-        vk.gamma_abc[699] = Pairing.G1Point(7279750984809030966481953645885548483354024831531244179520108902375639159014, 16887669914116121982090201697689670724095335232654243781443220937137539373230);
-        vk.gamma_abc[700] = Pairing.G1Point(14733919742229360922498340903125654463322094095885855794189546440951503947548, 2317210417369192566121980694837058565295668402059575266723537711523029514061);
-        vk.gamma_abc[701] = Pairing.G1Point(7195469954352863812315926413846879036877315485796526020620787315926073945180, 13428194398368132343303732171835964783612516906300337877074822298229089601698);
-        vk.gamma_abc[702] = Pairing.G1Point(17227210339192827041969613450852666969667658028772826671536862418024526493420, 10317775718274167969779151035827709551445364426746633190082377636233298308210);
-        vk.gamma_abc[703] = Pairing.G1Point(1433933526063080751202082621186706026116133842623108390799959497734224594499, 19600692632164893952376498868295480076925758497446015845306999482979468185456);
-        vk.gamma_abc[704] = Pairing.G1Point(11122431676211791701527870133692983624074668233035516522985104238473235528319, 793245686182780043213565215337555976787951242161998725511923851722684456525);
-        vk.gamma_abc[705] = Pairing.G1Point(13058617264048102045921814590252896359951578434915996836780399563750310112779, 1824160370002955183262659070692086990077788840718717290385883450465676376710);
-        vk.gamma_abc[706] = Pairing.G1Point(14585141476896348198844028881272731311387107175519185499473115173716219955654, 11391879914467676201024586764629662411824033490515688940595267798918149767603);
-        vk.gamma_abc[707] = Pairing.G1Point(21564826354431819278070938113968255223044201113718090764746618224216102176785, 15451994252720017878993826687427617290177237477493345894709393082027088579761);
-        vk.gamma_abc[708] = Pairing.G1Point(15440288505605312536567354657733698497527869629851876724578197344617364348031, 13501646225493634436039992401206915832107224392361619310936022225899044067881);
-        vk.gamma_abc[709] = Pairing.G1Point(4240562444978544424340720120616557235966788881284731168625067332162910793062, 21494602463417795311166414061392885135367774174293651302255534064321914942739);
-        vk.gamma_abc[710] = Pairing.G1Point(20384025166493000663091726921733063104671562527711507114034788924215229287191, 446525519820685375234368053359317892869014886299469201239837613401326365868);
-        vk.gamma_abc[711] = Pairing.G1Point(19818906158444551182088389153371521890793040292026997297885016184502627017379, 10481203068271888793089113751260464584644591666971142306193197654611749924116);
-        vk.gamma_abc[712] = Pairing.G1Point(1634705491238658488725236086860817082855989869324363117625154993107425568971, 11023105026276142641427159235949839026726744965241207199717644114542771715501);
-        vk.gamma_abc[713] = Pairing.G1Point(11203356577090688579243613631401449175906175162147217868005891123978855770251, 8268304912758915026176028005992434724905102690131100290150901074428546537981);
-        vk.gamma_abc[714] = Pairing.G1Point(10661766014385576004536971377173045918293422209475435856889870795486659783771, 7613051778955897608223185508655670439163237159244181464792319555793032042105);
-        vk.gamma_abc[715] = Pairing.G1Point(10562676886559807670581587533135328908240985708037978515448658501684605376654, 11917503615538158181505101720798398927289499032635622923778331881703544967571);
-        vk.gamma_abc[716] = Pairing.G1Point(6708415573915194955246149236175335301105024166441243509905494553973444483778, 12930000643148389368550786070200745988400851160916242211003915826999716284348);
-        vk.gamma_abc[717] = Pairing.G1Point(18072990539504308248544050581869573539894560906820589874671423128063566767820, 10471068046955612610148339943193728604406657441467945306203313744064987430403);
-        vk.gamma_abc[718] = Pairing.G1Point(18208221956158544885060188711257463062545131688738029951631956114531598663514, 3003534262099107307249951845467803432731717295759786496551416006739446716502);
-        vk.gamma_abc[719] = Pairing.G1Point(380300671567712733851016562425779014234957643700489559120987378497195288056, 2171405434437262876941798512378668171296003389069215727719506769607531476361);
-        vk.gamma_abc[720] = Pairing.G1Point(10711698939450124953238392942526965094917009543388988542063686835673759759879, 21375615888249109972382590011828088485854788577983822165572449551033807137368);
-        vk.gamma_abc[721] = Pairing.G1Point(19969044335424545371936099198686652540485225294147517100172226502133509256477, 9127482784479646856127911063525360537876350787285481931887428024050161625535);
-        vk.gamma_abc[722] = Pairing.G1Point(16827819684703137298010737518347164101827731283374609639512410004075214797830, 19724273968011556081501317853620163070084436633821565136713475431792001738202);
-        vk.gamma_abc[723] = Pairing.G1Point(18546224069365453110467497785287755544277158952307349550629012934095063220785, 14150560435521270707623110048544271549889687869981629923058833549373161718576);
-        vk.gamma_abc[724] = Pairing.G1Point(20073444296126216068402647876480944365407319728933608376885532417063938836176, 477879269094723641103426821577967707868035849674257097485293557066773980520);
-        vk.gamma_abc[725] = Pairing.G1Point(13364783857967318448620662953191062715498836568372972976067722143319150901897, 5279104657088121010468949171909471996083187910553601612888533701452375765233);
-        vk.gamma_abc[726] = Pairing.G1Point(16759089268481309175155100756780514688471516942751514296897649486974597881962, 10236197502057471950071031092487996513040608263620570626419132059540369943591);
-        vk.gamma_abc[727] = Pairing.G1Point(18007558491040754186113713522087941631785167587297738831439065043786637479999, 12924545338144814980241933396158962738026188545476125443045251686844025582457);
-        vk.gamma_abc[728] = Pairing.G1Point(6893756834053487520409028309359467798842312632225386811854975397466816782438, 8775383529795908665113754529184869084395047423537428694381409166715001742741);
-        vk.gamma_abc[729] = Pairing.G1Point(18654107270948063240887537684068305437003653298302794164685042332349086655187, 4536409892278689588345573116066733904603298349239983493631193374477598660622);
-        vk.gamma_abc[730] = Pairing.G1Point(20948534526365126812941430341301884187979907534603831387959237572312389106229, 19477632095222884795112120200065627861369669469376638925412666417663355323070);
-        vk.gamma_abc[731] = Pairing.G1Point(1893509189855768533495685306411502836277919041473142507786278627824705584087, 21044142756630389567139435276696659275545480883264504571280134959877040733562);
-        vk.gamma_abc[732] = Pairing.G1Point(14516184368215688511605661114836107983911841319915609026857926681959160507201, 3360309836153510783065220431225178436454825074612866707535284758157059406717);
-        vk.gamma_abc[733] = Pairing.G1Point(2680002397925211330395262069374130282029879104741620271002860351121742330086, 12644787921733731646912610104324238996520436835926673295136434939953729688648);
-        vk.gamma_abc[734] = Pairing.G1Point(1068436074959442717229088966910295520659320707324846143541192220563992041868, 15546253235926596691579257054697448870478134159800691717028910728293165582850);
-        vk.gamma_abc[735] = Pairing.G1Point(7359881009764392462092901820175189272726250050437872407539475682333316687015, 8371556739527967832903998880262375258665521259561465436366316630564251118790);
-        vk.gamma_abc[736] = Pairing.G1Point(13517760239527587714181692108813420331019312372163750507988752288872038803504, 1190246721848507519420109054525702265046792706338841255925773619204033273425);
-        vk.gamma_abc[737] = Pairing.G1Point(18282432803973746399237005714269863072509395397135410794549465407368829446234, 4499222274569059388633224279277846196818959491503669248946178878497987674355);
-        vk.gamma_abc[738] = Pairing.G1Point(3667397323511250957409745320721081472377843795683085066787761335465188255892, 15877649887392579690111398199454992336210808677916381349096719269608766409423);
-        vk.gamma_abc[739] = Pairing.G1Point(10232689718779106652835462634568098772180328435557520025509564989962175609591, 18814444682920992982251241617867348489252342172989884650224364339493794827596);
-        vk.gamma_abc[740] = Pairing.G1Point(19608772982733305013431631088563707240416432553032223927156374686822971625787, 15552858164333918976674582330282633944469377504174808949210478619183877385741);
-        vk.gamma_abc[741] = Pairing.G1Point(4394923743820588268209585658985555863341001482120877845332077183254698137032, 3120084764450969614379220166264059906343151520744403770128332994463798342457);
-        vk.gamma_abc[742] = Pairing.G1Point(15470944760789082656951934585567926780390506206262776046441865610097000754280, 8909621035760272651379777915228504854166344894180625584030621200771416808242);
-        vk.gamma_abc[743] = Pairing.G1Point(8222796612870986693740573017662331151114128264095589837606938940585274230780, 14791093366586765281649523435662921768099924147638456237948476049691047001826);
-        vk.gamma_abc[744] = Pairing.G1Point(14382213614471904370372747409789648229124308605997266033450278373380352287775, 2573946683169921318638530220227924818741092114868358132929492773780364464878);
-        vk.gamma_abc[745] = Pairing.G1Point(10865648980186995359965342818707039496978711756253293748420629806912830182108, 12691358623653019852878887785751626622635192439893212408822830282839831245904);
-        vk.gamma_abc[746] = Pairing.G1Point(7769599534079101382931521731264155204893052065466350305592279417879444761685, 16432835922273453366760839625809741390826860435509914909649335435846760816518);
-        vk.gamma_abc[747] = Pairing.G1Point(20078296200007095156495508177870156324184328631858688878240870404116813686644, 6994722048200525838127349884565203620167824665224652243268943301900357543882);
-        vk.gamma_abc[748] = Pairing.G1Point(12303635225355091812413968063616267581754847012713683667598002308779766450413, 10826520425207151761076847669536284270063256464385982023626709158086498740293);
-        vk.gamma_abc[749] = Pairing.G1Point(11571635915410140035906668412160867843054175179613866364799723221230600027064, 4398180707749520369598094378459975382933828363984618610801533262032577666262);
-        vk.gamma_abc[750] = Pairing.G1Point(19315732741945218126321320810372914726246658307658652078841846225761609608822, 7075775993037376681468417719468294067391429899904075009977366006120553552445);
-        vk.gamma_abc[751] = Pairing.G1Point(7670999226060990336834309932785755787312241628176745196814867186601318011905, 16839081490833984611742520255149784982429249709338263300891383324497443558160);
-        vk.gamma_abc[752] = Pairing.G1Point(11006272617599345344832489929452539054449376764626600209167934950714259970571, 18521364486283805125520843532044590299313531573118172420737430279044842085701);
-        vk.gamma_abc[753] = Pairing.G1Point(8290168598672851889926498329798707813423560057860645690434114161809660699096, 1267291197333212725991897636312039929681362455448311113669630845730394657847);
-        vk.gamma_abc[754] = Pairing.G1Point(7397890550339494789463211915303911584825186035082799383874579377674254824051, 4656023816245661760973892076169224059358418735790345507391449138535176675507);
-        vk.gamma_abc[755] = Pairing.G1Point(9011037533350361581844193748448711441158157363536925094895649988882033254458, 7330432480643055395138631191297649669223274995010887697068793966511034196345);
-        vk.gamma_abc[756] = Pairing.G1Point(18299400093222487016494556945983609558646289593901661944646866948254334768836, 3561199962074180646690001066982453453320208740204938674130022156046968615771);
-        vk.gamma_abc[757] = Pairing.G1Point(12368137238921051124150199619061972812704762970620515564836030915994477056877, 14714952064041398838834702783443744571635603999166183299766147499536344405332);
-        vk.gamma_abc[758] = Pairing.G1Point(16541984846894595563554458911187170697138583791506898917227503530188396313036, 10911531633916149611783827786501660258427810843492901730887807084112376248180);
-        vk.gamma_abc[759] = Pairing.G1Point(7272245822924271291777632796938068506738655657925561968618579227868622813158, 5527518750626560755361374524517730759953198483677944392515022394915015543083);
-        vk.gamma_abc[760] = Pairing.G1Point(12410530942431426698729919177804116603392708653684408425687302090628709588561, 10881420692830002393025558860498032106522476548954910640865450900022551105622);
-        vk.gamma_abc[761] = Pairing.G1Point(3011713832196112934698831382065525050373838190422784382085936277003925981421, 14099720302064396706478708822078071908597250929741866599136969999975458752290);
-        vk.gamma_abc[762] = Pairing.G1Point(5757780032089007006519916187566884356328599591076382869866650934947756638720, 4403076067525779899964243328004977032684819281277804310338343717057040931165);
-        vk.gamma_abc[763] = Pairing.G1Point(15404579042359953530090349526013064928019329109088759166241098122654931608201, 3685177309400298862335565767598746894051172496746378914405960783717966669655);
-        vk.gamma_abc[764] = Pairing.G1Point(3267786969684337136625430668956474906781558225147252730510334170020568797526, 8352551560302898693729698228584614416421277250524297549204229616286794511796);
-        vk.gamma_abc[765] = Pairing.G1Point(4478199252577457595495917279705886499588609459378058803192365085155676668056, 7498706134588642700605892511245573119016895791926803708110281914091128978605);
-        vk.gamma_abc[766] = Pairing.G1Point(13106573395276264414017816868762126978594419910444230541182509543101845944726, 14815252716692443487522569086738998075929598208073280186587067743727464916706);
-        vk.gamma_abc[767] = Pairing.G1Point(19535312678918762384833969760601530718423494913239552819746386225707133119932, 10848676169862901391244611279846379086294553803183593262837237855850810814812);
-        vk.gamma_abc[768] = Pairing.G1Point(5294269851955149393951438370719069837704480467377817956721603869031159690959, 14878632898996330609414738969662625331640381850196519252173125756833599792682);
-        vk.gamma_abc[769] = Pairing.G1Point(16934294129830037374365615098248216533273724503651298267009577940480199842946, 18601456622117230069641680787660395137513150286156972148355700936862011535448);
-        vk.gamma_abc[770] = Pairing.G1Point(15785678795405739631550255502311770652764803567082312320369621675143031249242, 14172049972622147120756306264058441863043665693178018901187183425323727710016);
-        vk.gamma_abc[771] = Pairing.G1Point(20783927818772046559802695182451385143619011294493037402191983279962170616538, 1483583723660463421405524301008262041011528487215507799648991793031629701024);
-        vk.gamma_abc[772] = Pairing.G1Point(4307685421495327415237789670511012607357528959633871136146462392624465224605, 7266908068953176057449323758029348428300361575932431576851643628805048466834);
-        vk.gamma_abc[773] = Pairing.G1Point(16076479938254321403858702178885160041591423145499645889551216965092947033804, 14764868492678692746557374630865214044737954444774580975823472071985969127618);
-        vk.gamma_abc[774] = Pairing.G1Point(7160406259937085827979687784493002275607087623429907816243179627819522419629, 6843619710442638785529559573694725419895730424048733638953813415601405693838);
-        vk.gamma_abc[775] = Pairing.G1Point(18937767775869202899269800321818482561629361249013656122186685144950309681938, 13770377518338350350031249222431038610677662572598724674207776927474586225128);
-        vk.gamma_abc[776] = Pairing.G1Point(14753492933669092445591246254211515534036698144167502964901325309841044243367, 14618763283040724491872732459404787441019083954481490521637749286767757324585);
-        vk.gamma_abc[777] = Pairing.G1Point(20360380443242475179479879304759687516508787073937830312658592606489219458168, 10521565134258976636965510795744028458448026929836678275726750276689893747582);
-        vk.gamma_abc[778] = Pairing.G1Point(1389511871579123699240041223228183052628777977685686410027939194230363128265, 21408330845876462312848054315777824003245314839015956356067303480776302346636);
-        vk.gamma_abc[779] = Pairing.G1Point(2101443474024054930641219253900473584069457252625423871296218277615090691716, 21216125274673032464888196647043396564826146058377785120245233139409907480161);
-        vk.gamma_abc[780] = Pairing.G1Point(9021825379880835590116626479206888654181087732006050486804832729843202294888, 13965834538037516642176512895578930541529622373994271294502511602980619002261);
-        vk.gamma_abc[781] = Pairing.G1Point(20987448912259379517997146444224837308904584216185275205080347860086879976063, 2908464001659896750694810675491817470025255781379526168095036241080235200742);
-        vk.gamma_abc[782] = Pairing.G1Point(18519050358519009334238181152219150653197291417926141556651587658945283844716, 8462512768130768745229035957041409428115692984305364826195233824143205341304);
-        vk.gamma_abc[783] = Pairing.G1Point(12059275895085594614147038700911311309261770199725586954805741200206589794128, 5654749527562433659187326390135175985654884235814663224606266733875298950800);
-        vk.gamma_abc[784] = Pairing.G1Point(8290253661898531390809027510532248758409276474286787472008241512609100606825, 17674058295229059064452943782829220662733711791881225832715997866329074659833);
-        vk.gamma_abc[785] = Pairing.G1Point(12713585780205793313805108488926894830641320501728718231824720736913364243388, 6145851086085514618367987899515744287014613169400386475526872865209147227309);
-        vk.gamma_abc[786] = Pairing.G1Point(7881377180223465542827462708933589335952838223614998702236370780453424849078, 20551752907016416383667274575683055585527725552925202339620921681910077848602);
-        vk.gamma_abc[787] = Pairing.G1Point(1003291616020955812472364098535089086410188770810770912051104406888702414788, 14594553368164920647151466429706131790498741374871102180493123518779930968164);
-        vk.gamma_abc[788] = Pairing.G1Point(9360955082852442048025895347730675819916908557026103630099198438904214671439, 552090026776131940422647911900659907212126810027341219573678879568739004620);
-        vk.gamma_abc[789] = Pairing.G1Point(45780530629396521583503686124125844057102045932487840386786001330830035029, 17590960650770782012156743334722204435531904882268537312991761787088431310318);
-        vk.gamma_abc[790] = Pairing.G1Point(9606740630590353062776608891825559359798664090573001726975100506866980311936, 4196896413538207462199189929303453340141536701727119187209601991113716065151);
-        vk.gamma_abc[791] = Pairing.G1Point(17750004849305166559080642519266595431785172413143304144095818381687494879611, 8371306747328690922622951878328437805201040138294666085871536926103661328954);
-        vk.gamma_abc[792] = Pairing.G1Point(7899432513190435497766286517455551353213195703702827357625602516580948625940, 10011484490259434336111363537000318396611444070349775709121983918112670979249);
-        vk.gamma_abc[793] = Pairing.G1Point(13817450012495078550040657417610056209778889797566620132614687840854401456939, 7143963847653301043493542438404785973969623237826159007304890248258437029833);
-        vk.gamma_abc[794] = Pairing.G1Point(3325954933533165706068612785348177297221052471691398528317155308518290004828, 15416602802447652140495196051494527728683449670169043364188030808684447358573);
-        vk.gamma_abc[795] = Pairing.G1Point(5043336826945839975249839428967348210608824852875564449678871891459129396669, 4679655714054137296262058925401022279738917205022489074353898454570015592849);
-        vk.gamma_abc[796] = Pairing.G1Point(21252113328624232570766736310273039045958061310460276457396554912980438553392, 7637374816361707075180728908999185300130972553203384359271643324573970877783);
-        vk.gamma_abc[797] = Pairing.G1Point(20712045755924147875608795408534335671771668836119158551916939118629131594710, 4612284120296170425187072021556104425056300089579534971087364293360654007878);
-        vk.gamma_abc[798] = Pairing.G1Point(3141753206803274349363827012966016937866250116159989941741885457984868505911, 21146336227328487285733631628926159874894171327890407144110835757777001669813);
-        vk.gamma_abc[799] = Pairing.G1Point(12050771992541779307516321200953637024540447473342314580622770506241788951068, 20899112267951433549189702315063224505873812231589952004551642581264178690246);
-        vk.gamma_abc[800] = Pairing.G1Point(21136050527412562039935081271316954453095783751875315235680285923510453834216, 6988321593723827523098427552008450818252508650596581641475435599559332482519);
-        vk.gamma_abc[801] = Pairing.G1Point(16727275932661411533898865625871864746693619694067702960816317321003704652369, 18359850688344321094319940742286198178719465832479402143416558699380240169827);
-        vk.gamma_abc[802] = Pairing.G1Point(8855292573143624056371876415995571846354151766456811710579054311915654343281, 19885444018649441830468916565176664264523827240139281782598493062432470256812);
-        vk.gamma_abc[803] = Pairing.G1Point(20102576414703697383850649874503259790462088762500876893800165923019388517551, 4250930843491844427322516612519477493356419125366277968245720234275094331965);
-        vk.gamma_abc[804] = Pairing.G1Point(542325497323051320163488063458926823146528929827480677457161600630490967418, 18690957652324656328269897441272920558207748069091035937099620652354575980314);
-        vk.gamma_abc[805] = Pairing.G1Point(17170848966171771529200122597274309625073959420965370146200846143704469095964, 14834159423880213470742342262472276421969402318362343291820974376746904732696);
-        vk.gamma_abc[806] = Pairing.G1Point(16159830184347167370809607349480892693903543422322194467990834015122444716832, 14335245030917048158578071607723840012275042509375686209591313506904274839085);
-        vk.gamma_abc[807] = Pairing.G1Point(13467511799850404687516012938546289121834045538886379396177077680678575851046, 17366448943923323486150892928484207526531981285012721831305813672013389839030);
-        vk.gamma_abc[808] = Pairing.G1Point(5905450665966856791383933948681880510990375143461293365278526731959862547242, 14968189101495857408181269754689239967584748999751736943607755132927785143784);
-        vk.gamma_abc[809] = Pairing.G1Point(3888451313530027522478212494475178550718298281307855865271728213595122247346, 21781071166033302776416475264793561794314663302359491739191837849108400692836);
-        vk.gamma_abc[810] = Pairing.G1Point(14238034256818526733738861198721129019580607432433444253481942730317874840360, 3906871861250368668074716653786279953669020548237502226455770502011739537752);
-        vk.gamma_abc[811] = Pairing.G1Point(19526554673681680087428791567360979073918963894307265486179046502698976782296, 5495332586025233962955537288041862496535320015949921320398721291946369884816);
-        vk.gamma_abc[812] = Pairing.G1Point(13658356608186982856018410351301271460197657616301227174967937971967332320463, 6123973710182028585865426530033829203300298795725707844925142996755255879517);
-        vk.gamma_abc[813] = Pairing.G1Point(263805331262218766338769051675225625066923869405839110366549107771116161313, 9920485335850876242744925096165471587095694768462061337545681845709591176848);
-        vk.gamma_abc[814] = Pairing.G1Point(9374776207706376834753268937634938060401236727630140058042470949753372074462, 1271154155972311615699280526916791353980483428250217688930363283301154024252);
-        vk.gamma_abc[815] = Pairing.G1Point(3699024190984732554249168533853215511332996090090216887428081213678031258059, 4446686337007002444167254699931074726184927982596002412518834064929191870227);
-        vk.gamma_abc[816] = Pairing.G1Point(10923348306498561244718309952862292041941330710082952813032671724669243041945, 7354539875646887052752409170716354914595069733353868688348723644984241125202);
-        vk.gamma_abc[817] = Pairing.G1Point(7652185944988220496244739281374349951992880495731154172003510526758305430932, 4557929705139679077571452131766322132706209683019581359466827630334521719738);
-        vk.gamma_abc[818] = Pairing.G1Point(11447934741792348290359881245418956748349647898441363066342294147003230677784, 5777694345913036734788817757710519193142878731900313637217689559799150289070);
-        vk.gamma_abc[819] = Pairing.G1Point(5411246483098651955417200594607020003875002490082299983775128574695859477157, 5618064542228561417074474589011348043665319652682571096353620653052537500886);
-        vk.gamma_abc[820] = Pairing.G1Point(10998088452379844494755309208310344945549071582214839871739240585635492755430, 1969896145028729123289199951811047898157745139126810662699113933111694581860);
-        vk.gamma_abc[821] = Pairing.G1Point(20211395963516644274088702029395335918901022672755224417369619221385385399121, 1071486835934114232717099470891175180559983250731911904890203063742997361395);
-        vk.gamma_abc[822] = Pairing.G1Point(9183490725208543277994553609931641768783069013823232618634704849281564245124, 15233063254479629638887987321080845641549851125891172259493218738786751027717);
-        vk.gamma_abc[823] = Pairing.G1Point(8923836633794038757429824028214807979589852197644085971979242357227804815017, 9986048711115967308925902318633149444586968031062005368835906033357317074364);
-        vk.gamma_abc[824] = Pairing.G1Point(659441689825282969773931009066517395250227763326720301224437523315813072971, 4008853057042561877292748187707657293355298805459094846105494449010625581949);
-        vk.gamma_abc[825] = Pairing.G1Point(8591791903216511652985413583181500491210735327244447981855678429137066107516, 5489071732795235688140285125841988990082482732037536277657715669793855837075);
-        vk.gamma_abc[826] = Pairing.G1Point(7424194763646039316574372173729426293343496189493036590931645030052822694275, 7865284209066922170579479678544890129771776294763920519470353475333289738901);
-        vk.gamma_abc[827] = Pairing.G1Point(12760810668068125202222787854745533782309461846976471583289841578522471296667, 16274038506316965421275640194322524229767113700539992031403031671997799016309);
-        vk.gamma_abc[828] = Pairing.G1Point(2023684236315351036387813982527101884756574973637256773882768533613941400324, 5903928053475868075196441978768318749497404557917621638262900178571580092342);
-        vk.gamma_abc[829] = Pairing.G1Point(14350785656258446572283973846856526508832317036910278581611552008867163847378, 6078124868006269026838183110485669498783151967357488257519491821650752502063);
-        vk.gamma_abc[830] = Pairing.G1Point(3259283709923002139307902544160192526744572881160728618665595235365744294182, 3521593166199545632316085976390295430578046577294592572704692080432580169360);
-        vk.gamma_abc[831] = Pairing.G1Point(10985829375319403207542064873144304043057501072491074226805391260264493409475, 21685204067102728117752708351176850252762337590096126311588723386527094238412);
-        vk.gamma_abc[832] = Pairing.G1Point(8477948497484272853614454953738395586994769714549778982370177810211044191793, 11162776139737345998198050690250206757731142989379426154866491349832941156966);
-        vk.gamma_abc[833] = Pairing.G1Point(19124522633284236360900733273345659469952633611195983413493605640780273613855, 2124496292399432796586080975905953603448713185651963962160812128594991344332);
-        vk.gamma_abc[834] = Pairing.G1Point(2915086720184883347498270229568937625569648692020101201389156831661121136660, 20130843833788824491766737004209433506001206155698941985631085233810154604657);
-        vk.gamma_abc[835] = Pairing.G1Point(21719942366624307841088128722320661581281348723006182036994132483615709043355, 8084898183285409815206476103256705872187110139732597721169027690829680006388);
-        vk.gamma_abc[836] = Pairing.G1Point(20557259060471549245866644355916430783357324719328155890400356442274285269502, 21260856609977408183303153080132424770392598651388187598171760813586312030334);
-        vk.gamma_abc[837] = Pairing.G1Point(674756927651630322927597357602258042710703564318670392507121841346732499373, 7706639768186852123825330925728947224006280222440591336511545020883652015480);
-        vk.gamma_abc[838] = Pairing.G1Point(3110867938476669140935868759667381216663163341656260044608573270010629276597, 10431951222042437218303961068590090683825270534017564692365192388768385081691);
-        vk.gamma_abc[839] = Pairing.G1Point(18934381155093799210021174105414216925812860721280466881042053846192672467877, 19699103833696175936741353681978964798565676640566168953822394099640184781940);
-        vk.gamma_abc[840] = Pairing.G1Point(11717941852212497024668145668248958083422882284782661237256815378892633298093, 2800395039473917103374946950806666430169565057134729277246745037028812944969);
-        vk.gamma_abc[841] = Pairing.G1Point(2766882786214113686893835849808265464268935726904815489276166565316768914044, 10871890426605707871828492871501335633350129159830016064278672782589581812884);
-        vk.gamma_abc[842] = Pairing.G1Point(3027914237634515724284280222200164546413568319247469591360815870424051460880, 9419052491469142157567352378379312095707490647883735771008443263456170979143);
-        vk.gamma_abc[843] = Pairing.G1Point(17961180456257608652893013870633262397484132009152353109163992349559181951019, 8889647502619974452582010035880464533376651990812432796872199850937357087583);
-        vk.gamma_abc[844] = Pairing.G1Point(5556110492652368088744974995370124911444000014100131025463862595842228545783, 12889319025944434615058805458714426017576975342712793611401562802275679493473);
-        vk.gamma_abc[845] = Pairing.G1Point(19588089786113736512059152755947970616655665083084796879499610332146782162769, 17142769422296264245000683634841814310295087097132883053611023524272517789485);
-        vk.gamma_abc[846] = Pairing.G1Point(5432211298686615656009434471534275798117397537001530664339263726688967964225, 13127317638465704912384728800065643496420865212891706363434894228692203544493);
-        vk.gamma_abc[847] = Pairing.G1Point(11925996422083755489078336897699568075114925093695372013010782013883523827895, 15886802098552689445648277003476388010651103417947427085885451917739784695728);
-        vk.gamma_abc[848] = Pairing.G1Point(16348433635395628839760382905449907527155601735274665244997454286764731142427, 4040624680318456455182658088363113608256507754129418901241489860156593744739);
-        vk.gamma_abc[849] = Pairing.G1Point(1628112398999559062396321531207107216988119490025836943844655309045322439567, 19422848701932261528501658945245482908264725879860562487980201124753170743936);
-        vk.gamma_abc[850] = Pairing.G1Point(18268291592222141376812842114503721198955730646993454553252036140639385623894, 18807253308686504094277562547321974112737788013744100246402763351306094158957);
-        vk.gamma_abc[851] = Pairing.G1Point(112028157551454859389668338398543613166689462046597185447331953786183372656, 11294467770224611931176471266395005478066269481901665290908723247097986124985);
-        vk.gamma_abc[852] = Pairing.G1Point(4229784465882796005739851925318245724075178177400523939421691807050518176777, 8059984624329635439590465127206419617790443035051111352904972569152767677651);
-        vk.gamma_abc[853] = Pairing.G1Point(4812584765608974763051601399434527121152698838057798181093980225530961284141, 8086828865144668145914460658290691346281551089808943479190761846807246857440);
-        vk.gamma_abc[854] = Pairing.G1Point(19420434808819074772381446485605938948261651803027174281014251430500976863504, 12995828847917351179823757751691166856624493715434269099285306472541780631174);
-        vk.gamma_abc[855] = Pairing.G1Point(2296662336230240481830233939882349101163532852981213640253709919495321278945, 4276008794182804398705587860883922809683086267180976763021945711505064444215);
-        vk.gamma_abc[856] = Pairing.G1Point(11020513705210089884537188687012046775773408602577069268354401043405989141499, 13315559119935493728539920536068835019594606084912703958346437652988804053110);
-        vk.gamma_abc[857] = Pairing.G1Point(18542761281048509277792600769372171382086088703594400928674364781592010393881, 4477750837361010865924279148062462213530259427824982918235380045668502277886);
-        vk.gamma_abc[858] = Pairing.G1Point(21309384561444441805773274702718926702719672491263922762524772820326029086775, 5433289493093713587866336390339212744497691058254880814815531195283889190320);
-        vk.gamma_abc[859] = Pairing.G1Point(3021168932149141644416864675309825768947986077836345881510081124421684074544, 9287985953753596535727704679031211190254502362233151829942520507955285439231);
-        vk.gamma_abc[860] = Pairing.G1Point(5460464247632489707715190903610322559178643304765926281773417366086771333291, 2382425530829294372484396248487795415815705339237605960569974547063322824987);
-        vk.gamma_abc[861] = Pairing.G1Point(10644211560461258193444461466791829865149955525636447621036910351482964884007, 9782724247163564532660686306455824739093772551617381470751930271936651351681);
-        vk.gamma_abc[862] = Pairing.G1Point(2795704072890552445956347915698261216377237583680634505913810033317144590773, 1293985991252936822711609131459959828380050280768414811498688158317744571313);
-        vk.gamma_abc[863] = Pairing.G1Point(9625587539508843672706090057579143380817927003415953223170333894520051121914, 10780928935184067570222843430505465228760683428197507239864881623782936039774);
-        vk.gamma_abc[864] = Pairing.G1Point(11671596115979419796865362625455422223326329489345256784720360526585624707408, 220272210397384276439785872175356111767756245062621373502971059963776162145);
-        vk.gamma_abc[865] = Pairing.G1Point(20375204967260337377983872160246066199607282286751745581725604678234622271008, 4551673609874899062563760841588740985412766786441446443648277909881851921070);
-        vk.gamma_abc[866] = Pairing.G1Point(19929496388426054386050062325605986381994039161870854777383778563598408086504, 5531163477067151024404939870594360965597693135971137813757334445320192853052);
-        vk.gamma_abc[867] = Pairing.G1Point(5504858265575656142106284170597977033073509311594400236273965874855377213162, 2071285844244759310548943892818235605357144868892525051491364559296990208835);
-        vk.gamma_abc[868] = Pairing.G1Point(5470270925784479800552242077257605295638887852812711844359567010795528884867, 21085382932868471158117497656291934055726030209138048272178113414853983529747);
-        vk.gamma_abc[869] = Pairing.G1Point(14333903546058112647604847235074694499717307527335558202869136959685023566578, 1886829968667082086373373290000684193926881855745066672054350827112225164666);
-        vk.gamma_abc[870] = Pairing.G1Point(5485293186089156886580626759004882332514397688625971883724506633369724097178, 3342366160194594311385438738155661031871582465371568237344979386742707809895);
-        vk.gamma_abc[871] = Pairing.G1Point(14541461870168176036987079143889466338394017552773052696511559385075209956068, 849270189492777719664032258754738529944612251211948203026814767939997111888);
-        vk.gamma_abc[872] = Pairing.G1Point(20675894823429759438554296589956459318118820489226434756047887505907498156717, 10024085900715833457541129261408009506266069742677060327239772195290673783474);
-        vk.gamma_abc[873] = Pairing.G1Point(15133304836984574749747475122101772827586062512934779957429591262350611441030, 16515754028252914357603520479558811522616710897699624075460861641648366764948);
-        vk.gamma_abc[874] = Pairing.G1Point(11492510769170463736544310026753750921508516556167292618505325729619262172689, 6358477191577595282375786343086576199655659558827071831179609785611250763354);
-        vk.gamma_abc[875] = Pairing.G1Point(20752570134353685309379003444623948901980983082589835727883882678076498588775, 6159519728614125076084909082217149200378723561281418957401828317970931594052);
-        vk.gamma_abc[876] = Pairing.G1Point(3543514040846356217738378159106528357069540400633005206736340825801857723134, 7302028481396571979267878392033091858197815101870466079140600889169861291152);
-        vk.gamma_abc[877] = Pairing.G1Point(20940135944934544837151191932870396442668591460787083799876419490807347471086, 5126460726311355722758827745882964663250568827468764204593079988863060064287);
-        vk.gamma_abc[878] = Pairing.G1Point(7285660240271813002652334948640347738199351781607121106429548519386546694041, 10424267106533009887076725187314102103628439962228901142023086912113263322520);
-        vk.gamma_abc[879] = Pairing.G1Point(6546731621567848662750246017493278492634532403809353607227932713336567177779, 5374241321275366677807051516056492441992555059264092874609420552929045382833);
-        vk.gamma_abc[880] = Pairing.G1Point(20386072319929615050321044075581843134331492667535406242834236466461777229868, 2728669044229053843938079537190826598085460347917055004024524250080078854639);
-        vk.gamma_abc[881] = Pairing.G1Point(3888706891374683535939480126170921028188892636569173091869561409048290640858, 4648651877531188645374295451615221752242671397166719007811514645051250538728);
-        vk.gamma_abc[882] = Pairing.G1Point(9422948393003929561530616720900732504249234358103219116360782556176480364102, 6507738482139161557475535072877389960024476928960431430864324541425643312374);
-        vk.gamma_abc[883] = Pairing.G1Point(11288046707813002711386924366325442582065232768707192007344151491992281801069, 8517919193209584629904337734905705598976770772607284150934174959818803724826);
-        vk.gamma_abc[884] = Pairing.G1Point(276076015961138242987889513210515949612579030165679226787863078045562632971, 16877524923291345750594367397751176641688922064054177715344176486183844108119);
-        vk.gamma_abc[885] = Pairing.G1Point(9030897024301981347832543493110197558892905974655410243015676126036312511298, 21712486000214971995171235707076339646350933199429194580675075750816208348230);
-        vk.gamma_abc[886] = Pairing.G1Point(19319376496752644842561639017030186232918333095192324054721106626069858450576, 10095652688577160894661552305735165212021949880518968845357510702754020121444);
-        vk.gamma_abc[887] = Pairing.G1Point(12281371470064542614657223762155337313815384900017246612872015219476796773560, 4825579066859221214736864825974993862390446090901501713341165929788404052884);
-        vk.gamma_abc[888] = Pairing.G1Point(10547285591250380401236396826615181335294200926867749314642367448303042753958, 19050334241382351611367861841690565657872904074748067995391837934523690883036);
-        vk.gamma_abc[889] = Pairing.G1Point(7487461020433430553770059948306462003841952257065370923671334586171746313812, 13367079622294578175599266345852852600691936701975875946389254125858828694297);
-        vk.gamma_abc[890] = Pairing.G1Point(509283937279360406677695620112571108861265689642778350006528273192066746389, 12580319556635668575157650920316208037995943872798567315930262636457762885697);
-        vk.gamma_abc[891] = Pairing.G1Point(17392025346173432451458666486229332053112712018953357684713376350607978340685, 2152094138107707531562684087969914063987151397140135443977511035419433200464);
-        vk.gamma_abc[892] = Pairing.G1Point(19424456113833090424433753687775648523735798403837951732364904083756709815784, 9888747941953005021375896364655463027962684095424711490551459855699298942266);
-        vk.gamma_abc[893] = Pairing.G1Point(664097756598918801951806206997417417823221591333794271815334304180206692385, 17487118193891117115887922163266198703685275991252290120437962237012957509835);
-        vk.gamma_abc[894] = Pairing.G1Point(17076294669879567590054439719138779890486161684312283272751044075034565083030, 194555310621425070573068820812631591458849710889007419770673358393046520069);
-        vk.gamma_abc[895] = Pairing.G1Point(20052433675769866718845606817625839773155849537514646576153948489027836816039, 18606167560321666378191087709639294485050444771880105000773594882225636193041);
-        vk.gamma_abc[896] = Pairing.G1Point(21885794708153399490690739052337175873999214146342324147628640179746671738941, 3235112519980623768243096858799869254419508154669736059301534332893722402547);
-        vk.gamma_abc[897] = Pairing.G1Point(4883863105067909660723998746114550075669008562265633824854886391066369226959, 18373092648537260480251427056577605520182106144646993416292758515782845662674);
-        vk.gamma_abc[898] = Pairing.G1Point(21418407400910563131764989400919076682376886472574872674473485142118430939124, 14901375229080515819102994990499118051918375692494470897420295185418962651346);
-        vk.gamma_abc[899] = Pairing.G1Point(6176712442119050622428874044592757272558866468465663695350179527002953980981, 21322225181992635258624938040412627384292682503343074648568073571015762526153);
-        vk.gamma_abc[900] = Pairing.G1Point(10526205722412674387914760584813333976238731629047947662009211843256455762929, 5687059393827222889464988894243837863318339870531886648152604664708518804492);
-        vk.gamma_abc[901] = Pairing.G1Point(17756392970044710968346168315410688420650289214042438433111970619914679930027, 11037494676153749517781461202980713819487399228129422643788664564463177399142);
-        vk.gamma_abc[902] = Pairing.G1Point(10721624557579280061033229193698426085420203728705079045444303638065416025187, 18537186001199872857006184364171487136902900937722995929802199488119378355499);
-        vk.gamma_abc[903] = Pairing.G1Point(7250204462970536357007432661028161707457262541793885315024547507940500131425, 13661933677457650923013154278368120921274228936703630010516212416268977567212);
-        vk.gamma_abc[904] = Pairing.G1Point(7556327228626141195063900174602049006634662786905776576968691870574185940189, 9902606513021446190767556795966142387977884607736114565791600925673793508632);
-        vk.gamma_abc[905] = Pairing.G1Point(17113182350387911971406744696058958129649414904457877881151143169588128901262, 5175395355496358899537379115360948267333594871559516081690243768921937000270);
-        vk.gamma_abc[906] = Pairing.G1Point(19438479249231489533280287630056186742492016300722824626139306344055465532034, 14819367673960354641503164308917291827599201289931656324661859299569551025154);
-        vk.gamma_abc[907] = Pairing.G1Point(16723320622540104195919968352366717113206400138457015832909238569641371687758, 5465054299643895193354630530763117779031742210419139143135100970675965044139);
-        vk.gamma_abc[908] = Pairing.G1Point(13644815114060275181473469249778045645885328603022028856353895904684382152029, 18526639026958475255771903329336524392188102241446402477721050044419640234375);
-        vk.gamma_abc[909] = Pairing.G1Point(9336907164598138485584917542288109492104039281469300834252318874905780573642, 13794122605275322420651563453681397953023595126218373231999693829720746665226);
-        vk.gamma_abc[910] = Pairing.G1Point(15285971104568728524735151112853851349396948034685857039837914180194131543557, 12195196325162092755659051294511421231623333638999271169185343007744217284995);
-        vk.gamma_abc[911] = Pairing.G1Point(6096110126860661017092577047740182567912831668543588429056941413523030225707, 17776574962207928908404060027523647065038750858692708351733133636318422756405);
-        vk.gamma_abc[912] = Pairing.G1Point(14955904260492200802061100147417860479025651148847565826298976561775748053014, 21215112587123393293106240163110260649954183513061908774859508177431085380519);
-        vk.gamma_abc[913] = Pairing.G1Point(2208416785983684492722672853686176560967756543870490396605295031979950305506, 12599962155301332994810464756538757217995064283751772832315274842137110967164);
-        vk.gamma_abc[914] = Pairing.G1Point(10336716422108194454986924829232096124001796750777047989597336964406066203955, 7506057748037665747732577254284974939562075736642158488139432937314457085289);
-        vk.gamma_abc[915] = Pairing.G1Point(20750223260800615101166843186487076048414498215033898182813741275997305779396, 18774088711493559225580348650178884242909666696435636875905361779078540508822);
-        vk.gamma_abc[916] = Pairing.G1Point(21277143595827229075996573521290888949101500576461156090312151804515961040279, 20072144056309868364956982598318247480770997585334537677860425097837582254282);
-        vk.gamma_abc[917] = Pairing.G1Point(6089309063814344051477470005596913715240931302848463280188042206147921601870, 19832766529312346500980825945211525846534601129417255946039359305646554554297);
-        vk.gamma_abc[918] = Pairing.G1Point(1840577975752204781546934862119750531551917406172010460085014112172935649979, 13829292483792007062867085172350026518527806939088324217109480693180418227642);
-        vk.gamma_abc[919] = Pairing.G1Point(1937702950790095900452280579977856235839420281905019527126259361366955706105, 7074198268435496707638463557252971135403324744643753447807164224338426219133);
-        vk.gamma_abc[920] = Pairing.G1Point(7684550338234936382031444341641798489494204844425429370837374220962145334728, 165396158422626237946826679105123330008437853353011097524025167655168856476);
-        vk.gamma_abc[921] = Pairing.G1Point(5291928207153520262109891610310255212278432253453613750171174784516459745118, 4329034303074808391570150864127433283086012097808934725986587138426066503947);
-        vk.gamma_abc[922] = Pairing.G1Point(149094187360209595931717687945948687674453855586420607651800270796321525245, 10353706341352615166519691894497736809588060581260758421897352169156305599343);
-        vk.gamma_abc[923] = Pairing.G1Point(19229010262920313486856281604176063244465036692709867288926336096389070438338, 9965599817659405663974411738704479071484845356339312194419518335129932419453);
-        vk.gamma_abc[924] = Pairing.G1Point(9873177112095692517813458185932479184411731074815281182744241532581900265373, 15933082467709049430348629326170984734782066258716714439749833613346391094328);
-        vk.gamma_abc[925] = Pairing.G1Point(17059050848620916287391828615029177092480798147001569920844766928569681620016, 9556084369070417655157222133713177778691579849715219867664222255983677894458);
-        vk.gamma_abc[926] = Pairing.G1Point(17439046484103416934153722492543783515439079034477926945898845391479441546808, 7114994242027989402072671982626622190341963376548853212159202157190974403988);
-        vk.gamma_abc[927] = Pairing.G1Point(17712304391346664251262860232087101272622274070024809166770627309454242671998, 7806422977718558294516770492089226191707040919397039258405646495032992149323);
-        vk.gamma_abc[928] = Pairing.G1Point(2370319644330136430690869463419774345921106079160929361915578081490737175500, 9982893799275529506779125222231304196902430794031503398852112052218776889564);
-        vk.gamma_abc[929] = Pairing.G1Point(8125788488751677520203458147276207169673988932181856626906926287136357171483, 21790005075792743204137078836123403535361505191634548876996629715020174400883);
-        vk.gamma_abc[930] = Pairing.G1Point(16935590889367304034679450776916026919012010715839907813757900275912228860371, 11663646276198574229402095643903388065478693780392826561270512394910334128707);
-        vk.gamma_abc[931] = Pairing.G1Point(20236139296198734034579367093047671034573273960089180764934200595273675466341, 14176406745943363438506216129395756761859801555656049507206980114929068056217);
-        vk.gamma_abc[932] = Pairing.G1Point(3378631379282892827700538154097226459443192080324020776962723780553383242571, 19835021205252423394394881526436784042633099689101577611860952985948689899666);
-        vk.gamma_abc[933] = Pairing.G1Point(6943400300617463302399913900543597272587448608244679085442461159392414053783, 4018387792406432202757004810872992844143017056160344173010030033153329394150);
-        vk.gamma_abc[934] = Pairing.G1Point(4917262631952875349418663797398733330149952310247806697131207460199129027775, 19245312797736610165007153540382586653045548610372440578709899410471204132411);
-        vk.gamma_abc[935] = Pairing.G1Point(20343099824602967959501796832193069673960220380422462537244273104238886982294, 2870051653150643803136880322243289091794778485937720751126569011681824831505);
-        vk.gamma_abc[936] = Pairing.G1Point(8179600256553330490814082098881928008776851181265039371490041925143784247598, 3937761403266027446096688179508180421661464139785030073079125430267810259503);
-        vk.gamma_abc[937] = Pairing.G1Point(10002448281860827854762044881810809925123379934580524379430672699230762635500, 1969516521832353079461618774724206798948766674923125237979257853460413510313);
-        vk.gamma_abc[938] = Pairing.G1Point(6662024277043503062859507808257674700715464151469037865111268499834168878831, 1892738421566558890317750563259215555399455361065451245059563507934648094055);
-        vk.gamma_abc[939] = Pairing.G1Point(21435039846395068542386092817046230103840112165790079573959412668664099743173, 6074832888919852691072672279770550098060452350227918663073287023605293702875);
-        vk.gamma_abc[940] = Pairing.G1Point(5777513949247094291527432053636798051300139479793687913601413438129189086423, 8599915056111769903396957945634152954234936714726357041044114933703320675629);
-        vk.gamma_abc[941] = Pairing.G1Point(11918312298333375093526619549811999266180672676275367431883859459337100927957, 10642594846838848262792237890092984906864797430987773077661504082133486481582);
-        vk.gamma_abc[942] = Pairing.G1Point(15445082806857065298649146803857539421232572628270634325689243779855790797762, 1615167619850894730446501677059428731932637823409775847312145362020280362737);
-        vk.gamma_abc[943] = Pairing.G1Point(16399854273505316676525168127676211197155727748287353789499446377279962107718, 6225415603081586711388950461306337692197144411345373440139495017739344302673);
-        vk.gamma_abc[944] = Pairing.G1Point(20008681012691258918750464637116673238524991307231895497715979256441032617849, 4898914502586987179461760012625613333918175208873991837566848887794944534068);
-        vk.gamma_abc[945] = Pairing.G1Point(21731514650102511472383220266492597848065799586469733947467346119280975850652, 20678281362522551628758164099041046546393226086770995745334414206819348491569);
-        vk.gamma_abc[946] = Pairing.G1Point(6265608756584389834886188364077287375363491680847601368284224249019472798377, 680447520575655527021615344711482350435871564363963796099223829444477918030);
-        vk.gamma_abc[947] = Pairing.G1Point(15832656935791368733833658339322774241676219508456296700691611974766155920576, 717633256294942620820493202216748463019942581750673998521502736815179359751);
-        vk.gamma_abc[948] = Pairing.G1Point(6233516122592523164276225286585071583108306523570855512949033104442380088279, 17988922665010521288277241276692110394867550862211818820127183704736240273487);
-        vk.gamma_abc[949] = Pairing.G1Point(14849464187042668303388095762729144476147686550315075360877264999589750608306, 355734629066530259139277336013872452731155596260443311915665898363175983078);
-        vk.gamma_abc[950] = Pairing.G1Point(8742345068701452573896412422530185602107006454699617421943164602590226998542, 10631652055633575776947411640366805871766068214635836032099605197808803220852);
-        vk.gamma_abc[951] = Pairing.G1Point(19783943511809100589019955029359860697097521508945147665613120003126057440960, 4458634606197452937056498830854186958665809085928654501615071651173282417424);
-        vk.gamma_abc[952] = Pairing.G1Point(15691329914159365007279478776902905926870220799208081067952281931676203902143, 5121021237833676568715534900249225001424692061937885100763047623627628685404);
-        vk.gamma_abc[953] = Pairing.G1Point(16072617449178666147105892789628854243912804210443008029035762688376795211407, 18424397772930435466452523968137662692373850659020550336318918727509264268434);
-        vk.gamma_abc[954] = Pairing.G1Point(18491096811810775811649226638765123460716993923400649793047320068377184637826, 3145901687427763238256895057115033631502996443822028896180767317429464603186);
-        vk.gamma_abc[955] = Pairing.G1Point(19336396731900746602269929502006721784162410704180364820649799882723413604537, 18771607813075110535400231254927036570128305324836923014276871456629612456833);
-        vk.gamma_abc[956] = Pairing.G1Point(19943011004122581378653487049113935043212300061113154191325306583289009209758, 4753549519041538936564265909976681772381683755629930917656388183209815687025);
-        vk.gamma_abc[957] = Pairing.G1Point(10090686264436723719716111489063762753228551001551633217469103299345653944643, 16501036845756971473196214398392594352470484546880442052908161022092369362344);
-        vk.gamma_abc[958] = Pairing.G1Point(9948341906911409997611765782132197284808300233595035687102760003997215886868, 2849395037544138634119844045774697998055241985134245016802448589712242977999);
-        vk.gamma_abc[959] = Pairing.G1Point(6141140924082246406727580757251093224485619130230570778626862835115102336745, 18642744253530197980787991739843240861209473840990150540833098685953290706960);
-        vk.gamma_abc[960] = Pairing.G1Point(4438300036040120737200820414388289105319606190640027619155324198007946760299, 3011272537213165540075203878798562404759649568353191611799998534597268770560);
-        vk.gamma_abc[961] = Pairing.G1Point(900145772845993281210840936346035468435291374408294286669563088675455262056, 12540394159029542071133221378018769090944592425514987930478885966697445184981);
-        vk.gamma_abc[962] = Pairing.G1Point(8229904368675982976931953641153816825944109568042163406781000261612745936469, 20345309919190699854831052746547753965107084594412630597693483418605759716674);
-        vk.gamma_abc[963] = Pairing.G1Point(18431410951087297871673918836504288617607957626608717592518351213619785758663, 975763426479410983286065793811745321255228080988543418967405494739240632275);
-        vk.gamma_abc[964] = Pairing.G1Point(20998672671536346882517393633200950578507754266802191752548351761099841197350, 18363075363314112322289878916378539673903957332578924990605116196593000530636);
-        vk.gamma_abc[965] = Pairing.G1Point(753336145314284803902254797181083397373631818099063761446048972187797872713, 18974640565708853510545075224500500827568244310717103651932720715380821861163);
-        vk.gamma_abc[966] = Pairing.G1Point(14379869995999916474811484538318616467233626902076429614771548902516487807315, 13497372542369543774669698287873261961358509814448243058478808939337282996164);
-        vk.gamma_abc[967] = Pairing.G1Point(8426216913510040223125185815039700228489622792529083246343878282110411840275, 9856951655771928116424268437578147822713263666562598716894696931441390556770);
-        vk.gamma_abc[968] = Pairing.G1Point(2549533571478886946217092389736937864226572770276040123179116920968401463645, 19019190760079185990262063902751858418531109621578780497825513524953564685700);
-        vk.gamma_abc[969] = Pairing.G1Point(15787283081105817913856996120655451894252180210497738801042903700277790445812, 18431708001755706671542875540662932860740715991282160819893890029154273218714);
-        vk.gamma_abc[970] = Pairing.G1Point(16820484032032111087203761999509143674562472341998708527651919740587985434158, 16163901242922724010515639672420085552664656459008418536702966624960220803766);
-        vk.gamma_abc[971] = Pairing.G1Point(2306378637677252989249215240462324275212448077161388002172766916819927830291, 7800503168437921239389234613219023895222495432782518395242846582866289913032);
-        vk.gamma_abc[972] = Pairing.G1Point(18635108846882802614325084250705234809346811637472614802555323074658987303699, 11318098258084427903928180890121834138019277402685136086141640946377304073604);
-        vk.gamma_abc[973] = Pairing.G1Point(9969475191900472884723043000488635462283558190718826909887194226920348738526, 15043873072208755316585033094133494014631552497269884853667302289644332936798);
-        vk.gamma_abc[974] = Pairing.G1Point(11639846384665754414233930088435970568024974225320052005031001375557826229168, 9566383297839132670950141336219335046695855652050220918335448892023679077328);
-        vk.gamma_abc[975] = Pairing.G1Point(3939676972676200542922542669992811484988329212467903945263050947177403010044, 17621696736758417575538714471343765338681455307497577012557130285222000030380);
-        vk.gamma_abc[976] = Pairing.G1Point(15614612009078036132170542122121467815223050209103059350473783131523289468604, 19111713697893165206536657681307396727189840978229786636267126394524630500483);
-        vk.gamma_abc[977] = Pairing.G1Point(14270787095402620497429734129277606690322594119580468663962806544246665850389, 5555384400798972634985500381176167284390868329720628136381454378109009320695);
-        vk.gamma_abc[978] = Pairing.G1Point(9168893354768759331338112012564077197754050583709590276216352610954137040147, 9652336591610552861708704788244345959838183335284568112015608464427864205760);
-        vk.gamma_abc[979] = Pairing.G1Point(18712763135237593338140284870005821844873442451506825455748192840728904757228, 5793882157519185704089164580431161317100544104731686752513123758605286358091);
-        vk.gamma_abc[980] = Pairing.G1Point(10188329410310097375782226349431854549742522002556280369126963257478328317959, 3414215421934614097719734161931723531962013511596833139186437611590441926020);
-        vk.gamma_abc[981] = Pairing.G1Point(16512486267068730253320614249979687120421367975385370812925910261725429672264, 18838762889943912128324286323270693663148837295670002517582697639570926072068);
-        vk.gamma_abc[982] = Pairing.G1Point(17708753890779716987780984673815987010698896577726270207540225485831973841399, 14415777443804777407690368896472585082733885115388990939689706539030942115696);
-        vk.gamma_abc[983] = Pairing.G1Point(21268119467825799993572648040498360373358913824866391080419734772529532293045, 15883479764621168968325634334380033495124783880295113734422780894757287595709);
-        vk.gamma_abc[984] = Pairing.G1Point(3198373286934142242814679149631411032918720834195676205816017260050035881601, 1690935033609181882468119888199598946400546992758112168289204355080645733059);
-        vk.gamma_abc[985] = Pairing.G1Point(16226845643543191095129838930362403489365437553062626081792599207761476497914, 9665860794268058563486099358879064015172697725200850362248291462761378000624);
-        vk.gamma_abc[986] = Pairing.G1Point(2910916276885749620127229460225616265981155885270987359659672436513801005907, 10593858475768686665560249686286752670407071969852971045485784804740470094627);
-        vk.gamma_abc[987] = Pairing.G1Point(13048535780189144681582001326083696687602712019649206682513109291966902562209, 12475441047577764066368200122148370532989621619587270476668394321349528557674);
-        vk.gamma_abc[988] = Pairing.G1Point(614961651942558983559216274494358519245613444052600536424754139835009212102, 7097312606213756023011556046777137977888445686477630993117144095715126884204);
-        vk.gamma_abc[989] = Pairing.G1Point(17587467622248378534116745451301368229393513388259098182232337203998501511829, 12712906154645773319125927054638104890952808219023015729890757953732027804997);
-        vk.gamma_abc[990] = Pairing.G1Point(7061697540095925313584127920082440806340876099093477603424437838965696627002, 3127527706959192552361496822246885882020095284920343730227379384211018894933);
-        vk.gamma_abc[991] = Pairing.G1Point(233264524217279493489891102612994978312810497794226866104452626228802854763, 18825496062871754894666805239644487014405474755551154583020002012715533180345);
-        vk.gamma_abc[992] = Pairing.G1Point(5040623460500690259672557056219231884784369866061712148463896155759757083210, 14774601072590054447471312759037503637090803027987967416943729998251369644834);
-        vk.gamma_abc[993] = Pairing.G1Point(14239794848777952156071580829799385559792655508908207736845610493785463447406, 18577766833688108656306070383455821563305728802009707311366000773535097338138);
-        vk.gamma_abc[994] = Pairing.G1Point(9926265283803727933148213830126362820232028870168665344871912613245261973688, 5580343842739853490482761369109116870051832435270378659142955904011238185916);
-        vk.gamma_abc[995] = Pairing.G1Point(9376308375873146761139421067942817380242386274098640258481719342702354160352, 4061075939143447135836855183728671924453448433788893637597542082345203751699);
-        vk.gamma_abc[996] = Pairing.G1Point(13615478646886680686574629914018815217317981900703955632173708909202105418607, 2418740046823760923736727654009904197144699249717605190081189429529057444628);
-        vk.gamma_abc[997] = Pairing.G1Point(20660248750892864499274241489105292664038234288045501774604316342803644920759, 15277001745133406995614087938677065419508119869366436804439768910813991482142);
-        vk.gamma_abc[998] = Pairing.G1Point(5426009106744290102900782937455565774840287360410006080398861425921595503895, 8356093948290913222725243333249406087838174381047814058199386286168297176192);
-        vk.gamma_abc[999] = Pairing.G1Point(20241296093068222100715129482318435111689958268808663353908702201981525723359, 2545077797582559804913297388277746240131170885448690040254803210280430555759);
-        vk.gamma_abc[1000] = Pairing.G1Point(14402568185675434414034236632665977148188036727123871835935576381452859634464, 6666258123276627176950646554436943832323170351235693990552458788165798537218);
-        vk.gamma_abc[1001] = Pairing.G1Point(8606930890481522779837065512396608486283155037611611870958710636755591326651, 5950535450049691380425298138438855925675070059240871704325718419703786240288);
-        vk.gamma_abc[1002] = Pairing.G1Point(5201149502913552291268259951613899953095116282710680086680093867031388429648, 8882432359912324242328698810040719042063599276344390641580478161002553733783);
-        vk.gamma_abc[1003] = Pairing.G1Point(17436443506514871845436317785361256733423305449081902503323026896868736606612, 388783428448463732528668432103311941022255149027564068747996384667613146666);
-        vk.gamma_abc[1004] = Pairing.G1Point(19844096793361211652159644461332976413964831082289826114343816713355286682469, 4628291788566731869766886956006553546622894460345151002286450899409176696920);
-        vk.gamma_abc[1005] = Pairing.G1Point(7336455353422802051625212302747118831310698764183637637909840797832138110871, 17122187834036432731146066795755067755590407590859056683815429930761066151627);
-        vk.gamma_abc[1006] = Pairing.G1Point(6456519729286318402405033276651836661298755127687275174983317364395639490218, 506179889816718713725711795130837169344437721803066594249154563716383800361);
-        vk.gamma_abc[1007] = Pairing.G1Point(11687915016254413401461460524853539504137181820108013981738408138481824686338, 20725255176632932324976530673431406734410185106821495163094844565197067124277);
-        vk.gamma_abc[1008] = Pairing.G1Point(20038617483448541198601104628061636719008741018920430696961675273311918384393, 15353904647971020524058566599151101924431099016787726253456262397453183061118);
-        vk.gamma_abc[1009] = Pairing.G1Point(9611152370681262623480031239472030937042265135380484565853810852481659515707, 4347855297948207378058920002540070018200185214380149396025099677665136475896);
-        vk.gamma_abc[1010] = Pairing.G1Point(21226180845350570836157402974168755107234358968751387230240140382142838675952, 16101967447145559491076226545739034997554025516132243645977015558036822101982);
-        vk.gamma_abc[1011] = Pairing.G1Point(17628385434324378521846779780027026248548204335575125426212440824333679851858, 4029433179238655696592236943181917834295989355876703660202248846978708067770);
-        vk.gamma_abc[1012] = Pairing.G1Point(16709997240665775231111528027396952940751641298850674671929883233277600952821, 15982843687913525624507808192091649167865328962393071207630752240831355857140);
-        vk.gamma_abc[1013] = Pairing.G1Point(6589644682847371594683566109274731213377316321398450292958238923536458527508, 14041911238819888560767098218957552598396950423275760180790455380720482873858);
-        vk.gamma_abc[1014] = Pairing.G1Point(19860146331962448840854706759634167537460877436989903048601668557696805874395, 19670677271270621477446620797494736177583284022853958863047617343489407018043);
-        vk.gamma_abc[1015] = Pairing.G1Point(11880713508553577982076175980358089500469870647502348681472798968838957493646, 9946954663707387828188124523040884781322772221823750285154112508819852414595);
-        vk.gamma_abc[1016] = Pairing.G1Point(10862284805616495727382717796599150734048470398762974832311468615093146880813, 5755499542426530716614729059341285978845686885163434543481247705920556639238);
-        vk.gamma_abc[1017] = Pairing.G1Point(16105767919797789095686230321739534351206909705673801362768380103240712796860, 2875130852886550397719050496377051922782751560802186753434222274428168343431);
-        vk.gamma_abc[1018] = Pairing.G1Point(17900568283395717615158762157067655037066970665973445766208383428204326553559, 2099718247920310257162006567410983686036993486679549747482588790154780316135);
-        vk.gamma_abc[1019] = Pairing.G1Point(8168090172679036487393259599948493636782364185887503273399964298236692979908, 19760258761430647685194095174187362600696505689803721857315976837830142590599);
-        vk.gamma_abc[1020] = Pairing.G1Point(13131951334988729317982848871475706436659258935229742194530154808804913103807, 14508190643567894163272773102501997406642134318812540117543741116924468454819);
-        vk.gamma_abc[1021] = Pairing.G1Point(5423162635890489800320251900202066561889244959751783380271900408110476529584, 13820464558108321522527577142895553305616820442768334236241007527334966396334);
-        vk.gamma_abc[1022] = Pairing.G1Point(12319490436401379850241711216766552175955245343962171507123326157887790725469, 19521317020125722020424290814921773002196741034234859395944597043002857214545);
-        vk.gamma_abc[1023] = Pairing.G1Point(8425960612940937553997129488851235440372143381046236063869758819405897349958, 5878356621567610932031974103825071568754687609791185429479827474717035964057);
-        vk.gamma_abc[1024] = Pairing.G1Point(1821963264438700322036152860621600760315354855443204874673517360600415665957, 10606516210234949919974181640913082516317538960594977048174398797454017422033);
-        vk.gamma_abc[1025] = Pairing.G1Point(11786311440452771426668367751478714581459627950062802286683521116334076262087, 15912410717348564146470152709604762606396348691590408258640817090986093284776);
-    }
-    function verify(uint[] memory input, Proof memory proof) internal view returns (uint) {
-        uint256 snark_scalar_field = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
-        VerifyingKey memory vk = verifyingKey();
-        require(input.length + 1 == vk.gamma_abc.length);
-        // Compute the linear combination vk_x
-        Pairing.G1Point memory vk_x = Pairing.G1Point(0, 0);
-        for (uint i = 0; i < input.length; i++) {
-            require(input[i] < snark_scalar_field);
-            vk_x = Pairing.addition(vk_x, Pairing.scalar_mul(vk.gamma_abc[i + 1], input[i]));
-        }
-        vk_x = Pairing.addition(vk_x, vk.gamma_abc[0]);
-        if(!Pairing.pairingProd4(
-             proof.a, proof.b,
-             Pairing.negate(vk_x), vk.gamma,
-             Pairing.negate(proof.c), vk.delta,
-             Pairing.negate(vk.alpha), vk.beta)) return 1;
-        return 0;
-    }
-    function verifyTx(
-            Proof memory proof, uint[1025] memory input
-        ) public view returns (bool r) {
-        uint[] memory inputValues = new uint[](1025);
-        
-        for(uint i = 0; i < input.length; i++){
-            inputValues[i] = input[i];
-        }
-        if (verify(inputValues, proof) == 0) {
-            return true;
-        } else {
-            return false;
-        }
-    }
-}
@@ -0,0 +1,205 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use ark_bn254::{Bn254, G1Affine, G2Affine};
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalDeserialize;
+use clap::Parser;
+use serde::Serialize;
+
+const VK_PREFIX: &str = "verifying_key_";
+const JSON_PREFIX: &str = "vkey_";
+
+/// Convert arkworks `verifying_key.bin` files into snarkjs `vkey.json`.
+#[derive(Parser)]
+struct Args {
+    /// single VK to export (ignored with `--vk-dir`)
+    #[arg(long, default_value = "../verifying_key.bin")]
+    vk: PathBuf,
+
+    /// output path for the single-VK export
+    #[arg(long, default_value = "vkey.json")]
+    out: PathBuf,
+
+    /// export every `verifying_key_*.bin` in this directory to `vkey_*.json`
+    #[arg(long)]
+    vk_dir: Option<PathBuf>,
+
+    /// with `--vk-dir`: only (re)write `index.json`, skip the per-VK exports
+    #[arg(long, requires = "vk_dir")]
+    manifest: bool,
+}
+
+/* ------------ snarkjs vkey layout ---------------------------------------- */
+
+#[derive(Serialize)]
+pub struct VKeyJs {
+    pub protocol: &'static str,
+    pub curve: &'static str,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: [String; 3],
+    pub vk_beta_2: [[String; 2]; 3],
+    pub vk_gamma_2: [[String; 2]; 3],
+    pub vk_delta_2: [[String; 2]; 3],
+    #[serde(rename = "IC")]
+    pub ic: Vec<[String; 3]>,
+}
+
+fn g1(p: &G1Affine) -> [String; 3] {
+    [p.x.to_string(), p.y.to_string(), "1".into()]
+}
+
+fn g2(p: &G2Affine) -> [[String; 2]; 3] {
+    [
+        [p.x.c0.to_string(), p.x.c1.to_string()],
+        [p.y.c0.to_string(), p.y.c1.to_string()],
+        ["1".into(), "0".into()],
+    ]
+}
+
+impl From<&VerifyingKey<Bn254>> for VKeyJs {
+    fn from(vk: &VerifyingKey<Bn254>) -> Self {
+        Self {
+            protocol: "groth16",
+            curve: "bn128",
+            n_public: vk.gamma_abc_g1.len() - 1,
+            vk_alpha_1: g1(&vk.alpha_g1),
+            vk_beta_2: g2(&vk.beta_g2),
+            vk_gamma_2: g2(&vk.gamma_g2),
+            vk_delta_2: g2(&vk.delta_g2),
+            ic: vk.gamma_abc_g1.iter().map(g1).collect(),
+        }
+    }
+}
+
+/* ------------ single / batch export -------------------------------------- */
+
+fn read_vk(path: &Path) -> Result<VerifyingKey<Bn254>> {
+    let bytes = fs::read(path).with_context(|| format!("read {}", path.display()))?;
+    VerifyingKey::deserialize_uncompressed(&mut Cursor::new(bytes))
+        .with_context(|| format!("deserialise {}", path.display()))
+}
+
+fn export(vk: &VerifyingKey<Bn254>, out: &Path) -> Result<VKeyJs> {
+    let js = VKeyJs::from(vk);
+    fs::write(out, serde_json::to_string_pretty(&js)?)
+        .with_context(|| format!("write {}", out.display()))?;
+    Ok(js)
+}
+
+/// `verifying_key_<tag>.bin` → `vkey_<tag>.json`
+fn json_name(vk_file: &str) -> Option<String> {
+    let tag = vk_file.strip_prefix(VK_PREFIX)?.strip_suffix(".bin")?;
+    Some(format!("{JSON_PREFIX}{tag}.json"))
+}
+
+/// Export every VK in `dir` and write `index.json` (`vkey_*.json` → `nPublic`).
+fn export_dir(dir: &Path, manifest_only: bool) -> Result<BTreeMap<String, usize>> {
+    let mut index = BTreeMap::new();
+
+    for entry in fs::read_dir(dir).with_context(|| format!("scan {}", dir.display()))? {
+        let path = entry?.path();
+        let Some(out_name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(json_name)
+        else {
+            continue;
+        };
+
+        let vk = read_vk(&path)?;
+        let n_public = if manifest_only {
+            vk.gamma_abc_g1.len() - 1
+        } else {
+            export(&vk, &dir.join(&out_name))?.n_public
+        };
+        index.insert(out_name, n_public);
+    }
+
+    fs::write(
+        dir.join("index.json"),
+        serde_json::to_string_pretty(&index)?,
+    )?;
+    Ok(index)
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    match &args.vk_dir {
+        Some(dir) => {
+            let index = export_dir(dir, args.manifest)?;
+            println!(
+                "indexed {} verifying keys in {}",
+                index.len(),
+                dir.display()
+            );
+        }
+        None => {
+            let js = export(&read_vk(&args.vk)?, &args.out)?;
+            println!("wrote {} (nPublic = {})", args.out.display(), js.n_public);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ec::AffineRepr;
+    use ark_serialize::CanonicalSerialize;
+
+    fn write_vk(dir: &Path, tag: &str, n_public: usize) {
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); n_public + 1],
+        };
+        let mut buf = Vec::new();
+        vk.serialize_uncompressed(&mut buf).unwrap();
+        fs::write(dir.join(format!("{VK_PREFIX}{tag}.bin")), buf).unwrap();
+    }
+
+    #[test]
+    fn batch_export_two_vks() {
+        let dir = std::env::temp_dir().join(format!("vk_to_snarkjs_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        write_vk(&dir, "small", 5);
+        write_vk(&dir, "large", 1025);
+        fs::write(dir.join("proving_key.bin"), b"ignored").unwrap();
+
+        let index = export_dir(&dir, false).unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(index["vkey_small.json"], 5);
+        assert_eq!(index["vkey_large.json"], 1025);
+
+        for (name, n) in [("vkey_small.json", 5), ("vkey_large.json", 1025)] {
+            let js: serde_json::Value =
+                serde_json::from_slice(&fs::read(dir.join(name)).unwrap()).unwrap();
+            assert_eq!(js["nPublic"], n);
+            assert_eq!(js["IC"].as_array().unwrap().len(), n + 1);
+            assert_eq!(js["vk_alpha_1"], serde_json::json!(["1", "2", "1"]));
+        }
+
+        // manifest-only leaves exported files untouched
+        fs::remove_file(dir.join("vkey_small.json")).unwrap();
+        let index = export_dir(&dir, true).unwrap();
+        assert_eq!(index["vkey_small.json"], 5);
+        assert!(!dir.join("vkey_small.json").exists());
+
+        let on_disk: BTreeMap<String, usize> =
+            serde_json::from_slice(&fs::read(dir.join("index.json")).unwrap()).unwrap();
+        assert_eq!(on_disk, index);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use ark_bn254::{Fq, Fr, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalDeserialize;
+use base64::{engine::general_purpose::STANDARD as B64, Engine as _};
+use clap::Parser;
+use serde::Deserialize;
+use tiny_keccak::{Hasher, Keccak};
+
+const VERIFY_PROOF_SIGNATURE: &str = "verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])";
+
+/// Turn a `/prove` response into `verifyProof` calldata for a Solidity verifier.
+#[derive(Parser)]
+struct Args {
+    /// `/prove` response JSON (base-64 `proof` and `public_inputs`)
+    #[arg(long)]
+    proof: PathBuf,
+
+    /// output path for the `0x`-prefixed calldata
+    #[arg(long, default_value = "calldata.hex")]
+    out: PathBuf,
+}
+
+/* ------------ `/prove` response layout ------------------------------------ */
+
+#[derive(Deserialize)]
+struct ProofJs {
+    a: String,
+    b: String,
+    c: String,
+}
+
+#[derive(Deserialize)]
+struct ProveResponseJs {
+    proof: ProofJs,
+    public_inputs: Vec<String>,
+}
+
+fn decode<T: CanonicalDeserialize>(field: &str, b64: &str) -> Result<T> {
+    let bytes = B64
+        .decode(b64)
+        .with_context(|| format!("`{field}` is not base-64"))?;
+    T::deserialize_uncompressed(&bytes[..]).map_err(|e| anyhow!("`{field}` does not decode: {e}"))
+}
+
+/* ------------ ABI encoding ------------------------------------------------ */
+
+// one 32-byte big-endian word
+fn word<F: PrimeField>(f: F) -> [u8; 32] {
+    let be = f.into_bigint().to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - be.len()..].copy_from_slice(&be);
+    out
+}
+
+fn usize_word(n: usize) -> [u8; 32] {
+    word(Fq::from(n as u64))
+}
+
+fn selector() -> [u8; 4] {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(VERIFY_PROOF_SIGNATURE.as_bytes());
+    keccak.finalize(&mut hash);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// `verifyProof(a, b, c, input)` calldata. `b` is written `[[x.c1, x.c0],
+/// [y.c1, y.c0]]`, the order the BN254 pairing precompile expects.
+fn calldata(a: &G1Affine, b: &G2Affine, c: &G1Affine, inputs: &[Fr]) -> Vec<u8> {
+    let head = [
+        word(a.x),
+        word(a.y),
+        word(b.x.c1),
+        word(b.x.c0),
+        word(b.y.c1),
+        word(b.y.c0),
+        word(c.x),
+        word(c.y),
+    ];
+    // the dynamic `input` array starts after the head and its own offset word
+    let offset = (head.len() + 1) * 32;
+
+    let mut out = selector().to_vec();
+    head.iter().for_each(|w| out.extend_from_slice(w));
+    out.extend_from_slice(&usize_word(offset));
+    out.extend_from_slice(&usize_word(inputs.len()));
+    inputs.iter().for_each(|f| out.extend_from_slice(&word(*f)));
+    out
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::from("0x"), |mut hex, b| {
+        hex.push_str(&format!("{b:02x}"));
+        hex
+    })
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let json = fs::read(&args.proof).with_context(|| format!("reading {:?}", args.proof))?;
+    let resp: ProveResponseJs = serde_json::from_slice(&json).context("parsing /prove response")?;
+    let a: G1Affine = decode("proof.a", &resp.proof.a)?;
+    let b: G2Affine = decode("proof.b", &resp.proof.b)?;
+    let c: G1Affine = decode("proof.c", &resp.proof.c)?;
+    let inputs = resp
+        .public_inputs
+        .iter()
+        .enumerate()
+        .map(|(i, s)| decode::<Fr>(&format!("public_inputs[{i}]"), s))
+        .collect::<Result<Vec<_>>>()?;
+
+    let hex = to_hex(&calldata(&a, &b, &c, &inputs));
+    fs::write(&args.out, &hex).with_context(|| format!("writing {:?}", args.out))?;
+    println!(
+        "wrote {} ({} public inputs, {} bytes)",
+        args.out.display(),
+        inputs.len(),
+        (hex.len() - 2) / 2
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ec::AffineRepr;
+    use ark_ff::{One, Zero};
+    use ark_serialize::CanonicalSerialize;
+
+    fn b64<T: CanonicalSerialize>(t: &T) -> String {
+        let mut buf = Vec::new();
+        t.serialize_uncompressed(&mut buf).unwrap();
+        B64.encode(buf)
+    }
+
+    #[test]
+    fn generator_proof_vector() {
+        // a = c = G1, b = G2 (EIP-197 generator), inputs [1, 0]
+        let resp = serde_json::json!({
+            "proof": {
+                "a": b64(&G1Affine::generator()),
+                "b": b64(&G2Affine::generator()),
+                "c": b64(&G1Affine::generator()),
+            },
+            "public_inputs": [b64(&Fr::one()), b64(&Fr::zero())],
+        });
+        let resp: ProveResponseJs = serde_json::from_value(resp).unwrap();
+        let inputs: Vec<Fr> = resp
+            .public_inputs
+            .iter()
+            .map(|s| decode("input", s).unwrap())
+            .collect();
+        let data = calldata(
+            &decode("a", &resp.proof.a).unwrap(),
+            &decode("b", &resp.proof.b).unwrap(),
+            &decode("c", &resp.proof.c).unwrap(),
+            &inputs,
+        );
+
+        assert_eq!(selector(), [0xc3, 0x2e, 0x37, 0x0e]);
+        assert_eq!(
+            to_hex(&data),
+            concat!(
+                "0xc32e370e",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000120",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            )
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! POST /admin/load-arena-geojson – replace the arena zones with those of a
+//! GeoJSON `FeatureCollection`; GET /admin/arena – list them. Both need
+//! `ALLOW_ADMIN_ENDPOINTS`.
+
+use actix_web::{
+    HttpResponse, Responder,
+    error::{ErrorBadRequest, ErrorForbidden},
+    get, post, web,
+};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::{
+    state::{AppState, DefaultArena},
+    zk::{
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        geo_import::load_arena_from_geojson,
+    },
+};
+
+/// Answers with the loaded zone names, sorted, and their vertex counts. A
+/// document that fails to load leaves the previous arena in place.
+#[post("/admin/load-arena-geojson")]
+pub async fn load_arena_geojson(
+    body: String,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    if !app_state.allow_admin {
+        return Err(ErrorForbidden("admin endpoints are disabled"));
+    }
+
+    let arena = load_arena_from_geojson::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(&body)
+        .map_err(ErrorBadRequest)?;
+
+    let zones = zone_list(&arena);
+    println!("🗺️  Arena loaded: {} zones", zones.len());
+
+    *app_state.arena.write().unwrap() = arena;
+    Ok(HttpResponse::Ok().json(json!({ "zones": zones })))
+}
+
+/// The zones of the loaded arena, as `/admin/load-arena-geojson` answered.
+#[get("/admin/arena")]
+pub async fn arena(
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    if !app_state.allow_admin {
+        return Err(ErrorForbidden("admin endpoints are disabled"));
+    }
+
+    let zones = zone_list(&app_state.arena.read().unwrap());
+    Ok(HttpResponse::Ok().json(json!({ "zones": zones })))
+}
+
+// names and vertex counts, sorted by name
+fn zone_list(arena: &DefaultArena) -> Vec<Value> {
+    let mut zones: Vec<_> = arena
+        .iter()
+        .map(|(name, (_, n))| json!({ "name": name, "vertices": n }))
+        .collect();
+    zones.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+    zones
+}
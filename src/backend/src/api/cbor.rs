@@ -0,0 +1,49 @@
+//! `CborJson<T>` – a request body sent as CBOR (`Content-Type:
+//! application/cbor`), for clients too small to parse JSON cheaply; any
+//! other content type is read as JSON, exactly as `web::Json` does.
+
+use actix_web::{
+    FromRequest, HttpMessage, HttpRequest,
+    dev::Payload,
+    error::{ErrorBadRequest, ErrorPayloadTooLarge},
+    web,
+};
+use futures_util::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// Largest CBOR body accepted; the same as `web::Json`'s default limit.
+pub const CBOR_LIMIT: usize = 2 * 1024 * 1024;
+
+pub struct CborJson<T>(pub T);
+
+impl<T> CborJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: DeserializeOwned + 'static> FromRequest for CborJson<T> {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        if !req.content_type().eq_ignore_ascii_case(CBOR_CONTENT_TYPE) {
+            let json = web::Json::<T>::from_request(req, payload);
+            return Box::pin(async move { Ok(Self(json.await?.into_inner())) });
+        }
+
+        let body = web::Payload::from_request(req, payload);
+        Box::pin(async move {
+            let body = body
+                .await?
+                .to_bytes_limited(CBOR_LIMIT)
+                .await
+                .map_err(|_| ErrorPayloadTooLarge(format!("body exceeds {CBOR_LIMIT} bytes")))??;
+            ciborium::de::from_reader(&body[..])
+                .map(Self)
+                .map_err(|e| ErrorBadRequest(format!("invalid CBOR body: {e}")))
+        })
+    }
+}
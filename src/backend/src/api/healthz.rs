@@ -1,5 +1,20 @@
-use actix_web::{HttpResponse, Result};
+use actix_web::{HttpResponse, Result, web};
+use serde_json::json;
+use std::sync::Arc;
 
-pub async fn healthz() -> Result<HttpResponse> {
-    Ok(HttpResponse::Ok().body("ok"))
+use crate::state::AppState;
+
+pub async fn healthz(app_state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "ok",
+        "key_version": app_state.key_version,
+    })))
+}
+
+/// `GET /`: which service answers, and its version.
+pub async fn index() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(json!({
+        "service": env!("CARGO_PKG_NAME"),
+        "version": env!("CARGO_PKG_VERSION"),
+    })))
 }
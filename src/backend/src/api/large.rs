@@ -0,0 +1,174 @@
+//! POST /prove-large – `/prove` over custom arena zones of up to
+//! `CIRCUIT_MAX_VERTICES_LARGE` vertices instead of H3 cells.
+
+use actix_web::{HttpResponse, Responder, error::ErrorNotFound, post, web};
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::{snark::SNARK, sponge::poseidon::PoseidonConfig};
+use ark_ff::{One, Zero};
+use ark_groth16::{Groth16, Proof, ProvingKey};
+use ark_std::rand::rngs::OsRng;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+    api::types::ProveResponse,
+    shutdown::InFlightGuard,
+    state::AppState,
+    zk::{
+        circuit::{
+            BoundaryBehavior, CIRCUIT_MAX_VERTICES_LARGE, CIRCUIT_PRECISION, PointInMapCircuit,
+            is_convex_polygon, is_point_in_polygon,
+        },
+        polygon_ops::{
+            gps_to_web_mercator, hash_polygon, is_simple_polygon, polygon_area, polygon_from_gps,
+        },
+        types::{DefaultF, DefaultPoint},
+    },
+};
+
+const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES_LARGE;
+
+type Zone = ([DefaultPoint; MAX_VERTS], usize);
+
+#[derive(Deserialize)]
+pub struct ProveLargeRequest {
+    pub lat: f64,
+    pub lon: f64,
+    /// the map: convex zones as counter-clockwise `[lat, lon]` vertex lists
+    pub zones: Vec<Vec<[f64; 2]>>,
+    /// game-mode hash domain, as for `/prove`
+    #[serde(default)]
+    pub mode_id: u32,
+}
+
+/// Hash the zones into `MAX_HASHES` map slots and prove the point against
+/// the first zone holding it (or zone 0, proving `false`).
+fn prove_large_zones<const MAX_HASHES: usize>(
+    pk: &ProvingKey<Bn254>,
+    cfg: &PoseidonConfig<Fr>,
+    point: DefaultPoint,
+    zones: &[Zone],
+    mode_id: u32,
+) -> Result<(Proof<Bn254>, Vec<Fr>), String> {
+    type F = DefaultF;
+    const PREC: u32 = CIRCUIT_PRECISION;
+
+    if zones.len() > MAX_HASHES {
+        return Err(format!(
+            "at most {MAX_HASHES} zones supported, got {}",
+            zones.len()
+        ));
+    }
+
+    /* 1. map hashes ------------------------------------------------ */
+    let mut pub_hash_arr = [F::zero(); MAX_HASHES];
+    for (slot, (poly, n)) in pub_hash_arr.iter_mut().zip(zones) {
+        *slot = hash_polygon::<F, PREC, MAX_VERTS>(poly, *n, mode_id, cfg);
+    }
+
+    /* 2. native check --------------------------------------------- */
+    let inside = |(poly, n): &&Zone| {
+        is_point_in_polygon::<F, PREC, MAX_VERTS>(&point, poly, *n, BoundaryBehavior::CountAsInside)
+    };
+    let holding = zones.iter().find(inside);
+    // every zone is in the map, so the flag is just "inside one of them"
+    let final_flag = holding.is_some();
+    let (poly, n) = holding.unwrap_or(&zones[0]);
+
+    /* 3. Groth16 proof -------------------------------------------- */
+    let circuit = PointInMapCircuit::<F, PREC, MAX_VERTS, MAX_HASHES>::builder()
+        .point(point)
+        .polygon(*poly, *n as u64)
+        .flag(final_flag)
+        .map_hashes(pub_hash_arr)
+        .mode_id(mode_id)
+        .poseidon(cfg.clone())
+        .build()
+        .map_err(|e| e.to_string())?;
+    // fresh blinding per proof, so proofs for the same point are unlinkable
+    let proof = Groth16::<Bn254>::prove(pk, circuit, &mut OsRng)
+        .map_err(|e| format!("proof generation failed: {e}"))?;
+
+    /* 4. public inputs -------------------------------------------- */
+    let mut public_inputs = Vec::<F>::with_capacity(1 + MAX_HASHES);
+    public_inputs.push(if final_flag { F::one() } else { F::zero() });
+    public_inputs.extend_from_slice(&pub_hash_arr);
+
+    Ok((proof, public_inputs))
+}
+
+#[post("/prove-large")]
+pub async fn prove_large(
+    body: web::Json<ProveLargeRequest>,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let fail = |msg: String| Ok(HttpResponse::Ok().json(ProveResponse::failed(msg)));
+
+    let Some(pk) = app_state.large_pk.clone() else {
+        return Err(ErrorNotFound(
+            "large-polygon circuit disabled; start the server with --large-polygon",
+        ));
+    };
+
+    /* 0. inputs ---------------------------------------------------- */
+    let in_range =
+        |lat: f64, lon: f64| (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon);
+    if !in_range(body.lat, body.lon) {
+        return fail(format!(
+            "coordinates out of range: lat={}, lon={}",
+            body.lat, body.lon
+        ));
+    }
+    if body.zones.is_empty() {
+        return fail("no zones given".into());
+    }
+
+    let mut zones = Vec::with_capacity(body.zones.len());
+    for (i, vertices) in body.zones.iter().enumerate() {
+        if !(3..=MAX_VERTS).contains(&vertices.len()) {
+            return fail(format!(
+                "zone {i} has {} vertices, expected 3..={MAX_VERTS}",
+                vertices.len()
+            ));
+        }
+        if let Some([lat, lon]) = vertices.iter().find(|[lat, lon]| !in_range(*lat, *lon)) {
+            return fail(format!(
+                "zone {i} vertex out of range: lat={lat}, lon={lon}"
+            ));
+        }
+        let (poly, n) = polygon_from_gps::<DefaultF, CIRCUIT_PRECISION, MAX_VERTS>(vertices);
+        if !is_simple_polygon(&poly, n) || polygon_area(&poly, n) <= 0.0 {
+            return fail(format!(
+                "zone {i} is not a simple counter-clockwise polygon"
+            ));
+        }
+        if !is_convex_polygon(&poly, n) {
+            return fail(format!("zone {i} is not convex"));
+        }
+        zones.push((poly, n));
+    }
+
+    let (x, y) = gps_to_web_mercator(body.lon, body.lat);
+    let point = DefaultPoint::from_f64(x, y);
+
+    /* 1-4. hashes → native check → Groth16 proof ---------------- */
+    let cfg = app_state.poseidon_config.clone();
+    let (max_hashes, mode_id) = (app_state.max_polygon_hashes, body.mode_id);
+    let in_flight = app_state.in_flight_proofs.clone();
+    let proved = web::block(move || {
+        // a shutdown drain waits for this proof
+        let _in_flight = InFlightGuard::enter(&in_flight);
+        match_circuit_size!(default_map_slots: max_hashes,
+            N => prove_large_zones::<N>(&pk, &cfg, point, &zones, mode_id),
+            other => Err(format!("no circuit for {other} map hashes")),
+        )
+    })
+    .await?;
+    let (proof, public_inputs) = match proved {
+        Ok(pr) => pr,
+        Err(e) => return fail(e),
+    };
+
+    /* 5. respond --------------------------------------------------- */
+    Ok(HttpResponse::Ok().json(ProveResponse::proved(&proof, &public_inputs)))
+}
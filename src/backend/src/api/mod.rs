@@ -1,14 +1,64 @@
 use actix_web::{HttpResponse, Result, http::Method, web};
 
+/// `match` a circuit size known at runtime onto the const generic the
+/// handlers need: `$body` runs with `$N` bound to the size, `$other`
+/// catches sizes without a circuit. The reduced circuits behind
+/// `AppState::new_test` get their arm here, not in every handler.
+///
+/// - `map_slots:` every size in `keys::SUPPORTED_HASH_COUNTS`
+/// - `default_map_slots:` only `CIRCUIT_MAX_POLYGON_HASHES`
+/// - `zones:` `NON_MEMBERSHIP_MAX_ZONES`
+macro_rules! match_circuit_size {
+    (map_slots: $n:expr, $N:ident => $body:expr, $other:pat => $fallback:expr $(,)?) => {
+        match_circuit_size!(@arms $n, $N => $body, $other => $fallback;
+            64, 256, 512, $crate::zk::circuit::CIRCUIT_MAX_POLYGON_HASHES, 4096;
+            $crate::state::TEST_MAX_POLYGON_HASHES)
+    };
+    (default_map_slots: $n:expr, $N:ident => $body:expr, $other:pat => $fallback:expr $(,)?) => {
+        match_circuit_size!(@arms $n, $N => $body, $other => $fallback;
+            $crate::zk::circuit::CIRCUIT_MAX_POLYGON_HASHES;
+            $crate::state::TEST_MAX_POLYGON_HASHES)
+    };
+    (zones: $n:expr, $N:ident => $body:expr, $other:pat => $fallback:expr $(,)?) => {
+        match_circuit_size!(@arms $n, $N => $body, $other => $fallback;
+            $crate::zk::non_membership::NON_MEMBERSHIP_MAX_ZONES;
+            $crate::state::TEST_NON_MEMBERSHIP_MAX_ZONES)
+    };
+    (@arms $n:expr, $N:ident => $body:expr, $other:pat => $fallback:expr;
+        $($size:expr),+; $test_size:expr) => {
+        match $n {
+            $(n if n == $size => {
+                const $N: usize = $size;
+                $body
+            })+
+            #[cfg(any(test, feature = "test-utils"))]
+            n if n == $test_size => {
+                const $N: usize = $test_size;
+                $body
+            }
+            $other => $fallback,
+        }
+    };
+}
+
+pub mod admin;
+pub mod cbor;
 pub mod healthz;
+pub mod large;
+pub mod outside;
 pub mod prove;
+pub mod prove_form;
+pub mod prove_witness;
+pub mod schema;
+pub mod sector;
+pub mod types;
 pub mod verify; // <── add
 
 async fn options_handler() -> Result<HttpResponse> {
     Ok(HttpResponse::Ok()
         .insert_header(("Access-Control-Allow-Origin", "*"))
         .insert_header(("Access-Control-Allow-Methods", "GET, POST, OPTIONS"))
-        .insert_header(("Access-Control-Allow-Headers", "Content-Type"))
+        .insert_header(("Access-Control-Allow-Headers", "Content-Type, X-Request-ID"))
         .finish())
 }
 
@@ -16,10 +66,56 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("")
             .service(prove::prove)
+            .service(prove::supported_resolutions)
+            .service(prove_form::prove_form)
+            .service(prove_witness::prove_witness)
             .service(verify::verify)
+            .service(verify::verify_with_key)
+            .service(schema::public_inputs_schema)
+            .service(schema::response_schema)
+            .service(sector::prove_sector)
+            .service(outside::prove_outside)
+            .service(large::prove_large)
+            .service(admin::load_arena_geojson)
+            .service(admin::arena)
+            .route("/", web::get().to(healthz::index))
             .route("/healthz", web::get().to(healthz::healthz)) // <── health check
             .route("/prove", web::method(Method::OPTIONS).to(options_handler))
+            .route(
+                "/prove-form",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route(
+                "/prove-witness",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
             .route("/verify", web::method(Method::OPTIONS).to(options_handler))
-            .route("/healthz", web::method(Method::OPTIONS).to(options_handler)),
+            .route(
+                "/verify-with-key",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route("/healthz", web::method(Method::OPTIONS).to(options_handler))
+            .route("/", web::method(Method::OPTIONS).to(options_handler))
+            .route(
+                "/prove-sector",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route(
+                "/prove-outside",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route(
+                "/prove-large",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route(
+                "/public-inputs-schema",
+                web::method(Method::OPTIONS).to(options_handler),
+            )
+            .route("/schema", web::method(Method::OPTIONS).to(options_handler))
+            .route(
+                "/supported-resolutions",
+                web::method(Method::OPTIONS).to(options_handler),
+            ),
     );
 }
@@ -0,0 +1,217 @@
+//! POST /prove-outside – prove a private location is in none of a set of
+//! forbidden H3 zones.
+
+use actix_web::{HttpResponse, Responder, error::ErrorBadRequest, post, web};
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::{snark::SNARK, sponge::poseidon::PoseidonConfig};
+use ark_ff::Zero;
+use ark_groth16::{Groth16, Proof, ProvingKey};
+use ark_std::rand::rngs::OsRng;
+use h3o::CellIndex;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+    api::{prove::parse_cell, types::ProveResponse},
+    shutdown::InFlightGuard,
+    state::AppState,
+    zk::{
+        circuit::{BoundaryBehavior, is_point_in_polygon},
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        non_membership::{
+            NonMembershipCircuit, is_point_outside_all_polygons, non_membership_public_inputs,
+        },
+        polygon_ops::{
+            crosses_antimeridian, hash_polygon, polygon_from_h3_cell, unwrap_antimeridian_lon,
+        },
+        types::{DefaultF, DefaultPoint},
+    },
+};
+
+#[derive(Deserialize)]
+pub struct ProveOutsideRequest {
+    pub lat: f64,
+    pub lon: f64,
+    /// forbidden H3 cells, at most the circuit's zone slots
+    pub h3_zones: Vec<String>,
+    /// game-mode hash domain, as for `/prove`
+    #[serde(default)]
+    pub mode_id: u32,
+}
+
+/// The player point in the zones' frame: past +180° when a zone crosses the
+/// antimeridian, as `/prove` shifts it for its cell. The circuit has one
+/// point for every zone, so a zone that holds the player only in the other
+/// frame cannot be proven against.
+fn point_for_zones(lat: f64, lon: f64, zones: &[CellIndex]) -> Result<DefaultPoint, String> {
+    let raw = DefaultPoint::from_lat_lon(lat, lon);
+    let unwrapped = DefaultPoint::from_lat_lon(lat, unwrap_antimeridian_lon(lon));
+    if !zones.iter().any(|&cell| crosses_antimeridian(cell)) {
+        return Ok(raw);
+    }
+
+    for &cell in zones {
+        let (poly, n) =
+            polygon_from_h3_cell::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(cell);
+        let own = if crosses_antimeridian(cell) {
+            &unwrapped
+        } else {
+            &raw
+        };
+        let inside =
+            |p: &DefaultPoint| is_point_in_polygon(p, &poly, n, BoundaryBehavior::CountAsInside);
+        if inside(own) != inside(&unwrapped) {
+            return Err(format!(
+                "zone {cell} and a zone across ±180° need the player in different frames"
+            ));
+        }
+    }
+    Ok(unwrapped)
+}
+
+/// Hash the zones into `MAX_ZONES` slots, prove the native result and return
+/// the proof with its public inputs.
+fn prove_outside_zones<const MAX_ZONES: usize>(
+    pk: &ProvingKey<Bn254>,
+    cfg: &PoseidonConfig<Fr>,
+    point: DefaultPoint,
+    zones: &[CellIndex],
+    mode_id: u32,
+) -> Result<(Proof<Bn254>, Vec<Fr>), String> {
+    type F = DefaultF;
+    const PREC: u32 = CIRCUIT_PRECISION;
+    const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES;
+
+    if zones.len() > MAX_ZONES {
+        return Err(format!(
+            "at most {MAX_ZONES} zones supported, got {}",
+            zones.len()
+        ));
+    }
+
+    /* 1. zone polygons + hashes ----------------------------------- */
+    let zero_pt = DefaultPoint::from_f64(0.0, 0.0);
+    let mut polygons = [[zero_pt; MAX_VERTS]; MAX_ZONES];
+    let mut num_vertices = [0usize; MAX_ZONES];
+    let mut hashes = [F::zero(); MAX_ZONES];
+    for (i, &cell) in zones.iter().enumerate() {
+        let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX_VERTS>(cell);
+        hashes[i] = hash_polygon::<F, PREC, MAX_VERTS>(&poly, n, mode_id, cfg);
+        polygons[i] = poly;
+        num_vertices[i] = n;
+    }
+
+    /* 2. native check --------------------------------------------- */
+    let outside = is_point_outside_all_polygons::<F, PREC, MAX_VERTS, MAX_ZONES>(
+        &point,
+        &polygons,
+        &num_vertices,
+        zones.len(),
+    );
+
+    /* 3. Groth16 proof -------------------------------------------- */
+    let circuit = NonMembershipCircuit::<F, PREC, MAX_VERTS, MAX_ZONES, MAX_ZONES>::new(
+        point,
+        polygons,
+        num_vertices.map(|n| n as u64),
+        zones.len() as u64,
+        outside,
+        hashes,
+        mode_id,
+        cfg.clone(),
+    );
+    // fresh blinding per proof, so proofs for the same point are unlinkable
+    let proof = Groth16::<Bn254>::prove(pk, circuit, &mut OsRng)
+        .map_err(|e| format!("proof generation failed: {e}"))?;
+
+    Ok((proof, non_membership_public_inputs(outside, &hashes)))
+}
+
+#[post("/prove-outside")]
+pub async fn prove_outside(
+    body: web::Json<ProveOutsideRequest>,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let fail = |msg: String| Ok(HttpResponse::Ok().json(ProveResponse::failed(msg)));
+
+    /* 0. inputs ---------------------------------------------------- */
+    if !(-90.0..=90.0).contains(&body.lat) || !(-180.0..=180.0).contains(&body.lon) {
+        return fail(format!(
+            "coordinates out of range: lat={}, lon={}",
+            body.lat, body.lon
+        ));
+    }
+    let zones = body
+        .h3_zones
+        .iter()
+        .map(|hex| parse_cell(hex))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ErrorBadRequest)?;
+
+    let point = match point_for_zones(body.lat, body.lon, &zones) {
+        Ok(point) => point,
+        Err(e) => return fail(e),
+    };
+
+    /* 1-3. circuit → Groth16 proof → public inputs ------------- */
+    let pk = app_state.non_membership_pk.clone();
+    let cfg = app_state.poseidon_config.clone();
+    let (max_zones, mode_id) = (app_state.non_membership_max_zones, body.mode_id);
+    let in_flight = app_state.in_flight_proofs.clone();
+    let proved = web::block(move || {
+        // a shutdown drain waits for this proof
+        let _in_flight = InFlightGuard::enter(&in_flight);
+        match_circuit_size!(zones: max_zones,
+            N => prove_outside_zones::<N>(&pk, &cfg, point, &zones, mode_id),
+            other => Err(format!("no circuit for {other} zones")),
+        )
+    })
+    .await?;
+    let (proof, public_inputs) = match proved {
+        Ok(pr) => pr,
+        Err(e) => return fail(e),
+    };
+
+    /* 4. respond --------------------------------------------------- */
+    Ok(HttpResponse::Ok().json(ProveResponse::proved(&proof, &public_inputs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use h3o::{LatLng, Resolution};
+
+    #[test]
+    fn player_across_the_antimeridian_is_inside_a_crossing_zone() {
+        // Fiji: the cell spans ±180°, the player is ~100 m west of it
+        let zone = LatLng::new(-17.0, 180.0).unwrap().to_cell(Resolution::Five);
+        assert!(crosses_antimeridian(zone));
+        let (lat, lon) = (-17.0, -179.999);
+        assert_eq!(
+            LatLng::new(lat, lon).unwrap().to_cell(Resolution::Five),
+            zone
+        );
+
+        let (poly, n) =
+            polygon_from_h3_cell::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(zone);
+        let outside = |point: &DefaultPoint| {
+            is_point_outside_all_polygons::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 1>(
+                point,
+                &[poly],
+                &[n],
+                1,
+            )
+        };
+        // unshifted, the player lands a world away from the zone
+        assert!(outside(&DefaultPoint::from_lat_lon(lat, lon)));
+        assert!(!outside(&point_for_zones(lat, lon, &[zone]).unwrap()));
+
+        // a zone on the unshifted side that holds the player cannot share the frame
+        let west = LatLng::new(-17.0, -179.7)
+            .unwrap()
+            .to_cell(Resolution::Five);
+        assert!(!crosses_antimeridian(west));
+        assert!(point_for_zones(-17.0, -179.7, &[zone, west]).is_err());
+    }
+}
@@ -1,7 +1,12 @@
-use actix_web::{HttpResponse, Responder, error::ErrorBadRequest, post, web};
+use actix_web::{
+    HttpRequest, HttpResponse, Responder,
+    error::{ErrorBadGateway, ErrorBadRequest, ErrorForbidden},
+    get, post, web,
+};
 use ark_bn254::{Bn254, Fr};
-use ark_crypto_primitives::{snark::SNARK, sponge::poseidon::PoseidonConfig};
-use ark_groth16::Groth16;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::Proof;
 use ark_serialize::CanonicalSerialize;
 use ark_std::{
     One, Zero,
@@ -10,121 +15,254 @@ use ark_std::{
 use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
 use h3o::{CellIndex, Resolution};
 use serde::Deserialize;
-use std::{str::FromStr, sync::Arc};
+use sha2::{Digest, Sha256};
+use std::{
+    str::FromStr,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+    },
+    time::Instant,
+};
 
 use crate::{
-    state::AppState,
+    api::{
+        cbor::CborJson,
+        types::{ProveDebug, ProveResponse},
+    },
+    audit::AuditRecord,
+    prover::{BoxedCircuit, ProverBackend},
+    shutdown::InFlightGuard,
+    state::{AppState, CellBoundary, PrecomputedMap},
     zk::{
         circuit::{
-            CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, PointInMapCircuit,
-            hash_polygon, is_point_in_polygon,
+            BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, CircuitBuildError,
+            CircuitDescription, PointInMapCircuit, is_point_in_polygon,
+        },
+        map::{MapLoadError, load_map_from_gateway},
+        polygon_ops::{
+            crosses_antimeridian, hash_polygon, polygon_from_h3_cell, unwrap_antimeridian_lon,
         },
-        point_2d::Point2DDec,
+        proof_transcript::bind_proof_to_nonce,
+        public_inputs::{PublicInput, build_public_inputs, index_of},
+        types::{DefaultF, DefaultPoint},
     },
 };
 
 // ───────────────────────── helpers ──────────────────────────
 
-/// EPSG-3857 Web-Mercator projection.
-fn gps_to_web_mercator(lon_deg: f64, lat_deg: f64) -> (f64, f64) {
-    const R: f64 = 6_378_137.0;
-    let x = R * lon_deg.to_radians();
-    let y = R * ((90.0 + lat_deg).to_radians() / 2.0).tan().ln();
-    (x, y)
+#[derive(Debug, PartialEq, Eq)]
+pub enum MapHashError {
+    InvalidCellIndex(String),
 }
 
-/// Build an H3 cell boundary padded to `MAX_VERTS`.
-fn current_h3_polygon<const MAX: usize, const PREC: u32>(
-    lon: f64,
-    lat: f64,
-    res: Resolution,
-) -> ([Point2DDec<Fr, PREC>; MAX], usize) {
-    let cell = h3o::LatLng::new(lat, lon).unwrap().to_cell(res);
-    let boundary = cell.boundary();
-    let n = boundary.len().min(MAX);
+impl std::fmt::Display for MapHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCellIndex(cell) => write!(f, "invalid H3 cell index: {cell}"),
+        }
+    }
+}
 
-    let mut poly = [Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0); MAX];
-    for (i, ll) in boundary.into_iter().take(n).enumerate() {
-        let (x, y) = gps_to_web_mercator(ll.lng(), ll.lat());
-        poly[i] = Point2DDec::from_f64(x, y);
+impl std::error::Error for MapHashError {}
+
+/// Why [`build_circuit_from_request`] refused a request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProveRequestError {
+    InvalidCoordinates {
+        lat: f64,
+        lon: f64,
+    },
+    InvalidResolution(u8),
+    InvalidCellIndex(String),
+    /// a map cell is at another resolution than the request's
+    MapResolutionMismatch {
+        expected: u8,
+        found: u8,
+        cell: String,
+    },
+    Circuit(CircuitBuildError),
+}
+
+impl std::fmt::Display for ProveRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCoordinates { lat, lon } => {
+                write!(f, "coordinates out of range: lat={lat}, lon={lon}")
+            }
+            Self::InvalidResolution(res) => write!(f, "invalid resolution: {res}"),
+            Self::InvalidCellIndex(cell) => write!(f, "invalid H3 cell index: {cell}"),
+            Self::MapResolutionMismatch {
+                expected,
+                found,
+                cell,
+            } => write!(
+                f,
+                "map cell {cell} has resolution {found}, expected resolution {expected}"
+            ),
+            Self::Circuit(e) => e.fmt(f),
+        }
     }
-    (poly, n)
 }
 
-/// Hash a cell boundary with Poseidon.
-fn hash_cell_boundary<const MAX: usize, const PREC: u32>(
-    poly: &[Point2DDec<Fr, PREC>; MAX],
-    n: usize,
-    cfg: &PoseidonConfig<Fr>,
-) -> Fr {
-    hash_polygon::<Fr, PREC, MAX>(poly, n, cfg)
+impl std::error::Error for ProveRequestError {}
+
+impl From<CircuitBuildError> for ProveRequestError {
+    fn from(e: CircuitBuildError) -> Self {
+        Self::Circuit(e)
+    }
 }
 
-/// Hash every H3 cell in the map list.
+impl From<MapHashError> for ProveRequestError {
+    fn from(e: MapHashError) -> Self {
+        match e {
+            MapHashError::InvalidCellIndex(cell) => Self::InvalidCellIndex(cell),
+        }
+    }
+}
+
+pub(crate) fn parse_cell(hex: &str) -> Result<CellIndex, MapHashError> {
+    CellIndex::from_str(hex).map_err(|_| MapHashError::InvalidCellIndex(hex.to_owned()))
+}
+
+/// Hash every H3 cell in the map list, failing on the first malformed index.
 fn hash_map_cells<const MAX: usize, const PREC: u32>(
     h3_cells: &[String],
+    mode_id: u32,
     cfg: &PoseidonConfig<Fr>,
-) -> Vec<Fr> {
+) -> Result<Vec<Fr>, MapHashError> {
     h3_cells
         .iter()
-        .filter_map(|hex| CellIndex::from_str(hex).ok())
-        .map(|cell| {
-            let boundary = cell.boundary();
-            let n = boundary.len().min(MAX);
-            let mut poly = [Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0); MAX];
-            for (i, ll) in boundary.into_iter().take(n).enumerate() {
-                let (x, y) = gps_to_web_mercator(ll.lng(), ll.lat());
-                poly[i] = Point2DDec::from_f64(x, y);
-            }
-            hash_cell_boundary::<MAX, PREC>(&poly, n, cfg)
+        .map(|hex| {
+            let (poly, n) = polygon_from_h3_cell::<Fr, PREC, MAX>(parse_cell(hex)?);
+            Ok(hash_polygon::<Fr, PREC, MAX>(&poly, n, mode_id, cfg))
         })
         .collect()
 }
 
-fn to_b64<T: CanonicalSerialize>(p: &T) -> String {
+pub const REQUEST_ID_HEADER: &str = "X-Request-ID";
+
+/// The client's `X-Request-ID`, or a fresh UUID v4 when absent.
+pub(crate) fn request_id(req: &HttpRequest) -> String {
+    req.headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+}
+
+pub(crate) fn to_b64<T: CanonicalSerialize>(p: &T) -> String {
     let mut buf = Vec::new();
     p.serialize_uncompressed(&mut buf).unwrap();
     B64.encode(buf)
 }
 
-// ───────────────────────── request body ─────────────────────
-#[derive(Deserialize)]
-pub struct ProveRequest {
-    pub lat: f64,
-    pub lon: f64,
-    pub resolution: u8,
-    pub h3_map: Vec<String>,
+/// Error message plus, for `debug` requests whose circuit was built, its
+/// native description.
+type ProveFailure = (String, Option<CircuitDescription>);
+
+enum ProveError {
+    /// bad request input, caught before any circuit exists
+    Rejected(ProveRequestError),
+    /// `ipfs_map_cid` could not be resolved
+    MapLoad(MapLoadError),
+    Failed(ProveFailure),
 }
 
-// ───────────────────────── handler ──────────────────────────
-#[post("/prove")]
-pub async fn prove(
-    body: web::Json<ProveRequest>,
-    app_state: web::Data<Arc<AppState>>,
-) -> Result<impl Responder, actix_web::Error> {
-    type F = Fr;
+/// Everything `/prove` does before Groth16: project the point, hash its cell
+/// and the map, run the native checks and build the circuit for
+/// `MAX_HASHES` map slots. Returns the circuit with the public inputs it
+/// will be verified against.
+pub fn build_circuit_from_request<const MAX_HASHES: usize>(
+    lat: f64,
+    lon: f64,
+    resolution: u8,
+    h3_map: &[String],
+    mode_id: u32,
+    cfg: &PoseidonConfig<Fr>,
+) -> Result<
+    (
+        PointInMapCircuit<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>,
+        Vec<Fr>,
+    ),
+    ProveRequestError,
+> {
+    build_circuit::<MAX_HASHES>(
+        lat,
+        lon,
+        resolution,
+        h3_map,
+        mode_id,
+        cfg,
+        polygon_from_h3_cell::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>,
+        || hash_map_cells::<CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION>(h3_map, mode_id, cfg),
+    )
+}
+
+// `cell_boundary` projects the point's cell; `map_hashes` hashes `h3_map`
+// and runs once the coordinates and resolutions have been checked
+#[allow(clippy::too_many_arguments)]
+fn build_circuit<const MAX_HASHES: usize>(
+    lat: f64,
+    lon: f64,
+    resolution: u8,
+    h3_map: &[String],
+    mode_id: u32,
+    cfg: &PoseidonConfig<Fr>,
+    cell_boundary: impl FnOnce(CellIndex) -> CellBoundary,
+    map_hashes: impl FnOnce() -> Result<Vec<Fr>, MapHashError>,
+) -> Result<
+    (
+        PointInMapCircuit<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>,
+        Vec<Fr>,
+    ),
+    ProveRequestError,
+> {
+    type F = DefaultF;
     const PREC: u32 = CIRCUIT_PRECISION;
     const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES;
-    const MAX_HASHES: usize = CIRCUIT_MAX_POLYGON_HASHES;
 
-    let cfg = &app_state.poseidon_config;
-    let pk = &app_state.pk;
+    /* 0. coordinates + resolution --------------------------------- */
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return Err(ProveRequestError::InvalidCoordinates { lat, lon });
+    }
 
-    /* 0. resolution ------------------------------------------------ */
-    let res =
-        Resolution::try_from(body.resolution).map_err(|_| ErrorBadRequest("invalid resolution"))?;
+    let res = Resolution::try_from(resolution)
+        .map_err(|_| ProveRequestError::InvalidResolution(resolution))?;
 
     /* 1. current cell polygon + hash ------------------------------ */
-    let (poly, n) = current_h3_polygon::<MAX_VERTS, PREC>(body.lon, body.lat, res);
-    let cell_hash = hash_cell_boundary::<MAX_VERTS, PREC>(&poly, n, cfg);
+    let cell = h3o::LatLng::new(lat, lon).unwrap().to_cell(res);
+    let (poly, n) = cell_boundary(cell);
+    let cell_hash = hash_polygon::<F, PREC, MAX_VERTS>(&poly, n, mode_id, cfg);
 
     /* 2. map hashes ------------------------------------------------ */
-    let map_hashes = hash_map_cells::<MAX_VERTS, PREC>(&body.h3_map, cfg);
+    // a cell at another resolution could never match the point's cell hash
+    for hex in h3_map {
+        let found = parse_cell(hex)?.resolution();
+        if found != res {
+            return Err(ProveRequestError::MapResolutionMismatch {
+                expected: resolution,
+                found: u8::from(found),
+                cell: hex.clone(),
+            });
+        }
+    }
+    let map_hashes = map_hashes()?;
 
     /* 3. native checks -------------------------------------------- */
-    let (x, y) = gps_to_web_mercator(body.lon, body.lat);
-    let inside_poly =
-        is_point_in_polygon::<F, PREC, MAX_VERTS>(&Point2DDec::from_f64(x, y), &poly, n);
+    // projected on the same side of ±180° as the cell polygon
+    let lon = if crosses_antimeridian(cell) {
+        unwrap_antimeridian_lon(lon)
+    } else {
+        lon
+    };
+    let point = DefaultPoint::from_lat_lon(lat, lon);
+    let inside_poly = is_point_in_polygon::<F, PREC, MAX_VERTS>(
+        &point,
+        &poly,
+        n,
+        BoundaryBehavior::CountAsInside,
+    );
     let hash_match = map_hashes.iter().any(|h| h == &cell_hash);
     let final_flag = inside_poly && hash_match;
 
@@ -134,70 +272,482 @@ pub async fn prove(
         pub_hash_arr[i] = *h;
     }
 
-    // we need `poly` and `pub_hash_arr` twice, so keep copies
-    // let poly_copy = poly; // Copy types, cheap
+    // we need `pub_hash_arr` twice, so keep a copy
     let pub_hash_copy = pub_hash_arr;
 
-    // --- debug: count constraints ---------------------------------
-    // let cs_dbg = ConstraintSystem::<F>::new_ref();
-    // PointInMapCircuit::<F, PREC, MAX_VERTS, MAX_HASHES>::new(
-    //     Point2DDec::from_f64(x, y),
-    //     poly_copy,
-    //     n as u64,
-    //     final_flag,
-    //     pub_hash_copy,
-    //     cfg.clone(),
-    // )
-    // .generate_constraints(cs_dbg.clone())
-    // .unwrap();
-    // let num_constraints = cs_dbg.num_constraints(); // --- debug
-
     // main proving circuit (moves original arrays)
-    let circuit = PointInMapCircuit::<F, PREC, MAX_VERTS, MAX_HASHES>::new(
-        Point2DDec::from_f64(x, y),
-        poly,
-        n as u64,
-        final_flag,
-        pub_hash_arr,
-        cfg.clone(),
+    let circuit = PointInMapCircuit::<F, PREC, MAX_VERTS, MAX_HASHES>::builder()
+        .point(point)
+        .polygon(poly, n as u64)
+        .flag(final_flag)
+        .map_hashes(pub_hash_arr)
+        .mode_id(mode_id)
+        .poseidon(cfg.clone())
+        .build()?;
+
+    /* 5. public inputs ------------------------------------------- */
+    Ok((circuit, build_public_inputs(final_flag, &pub_hash_copy)))
+}
+
+/// `(mode_id, h3_map)` fingerprint keying [`PrecomputedMap`].
+fn map_source_hash(h3_map: &[String], mode_id: u32) -> Fr {
+    let json = serde_json::to_vec(&(mode_id, h3_map)).expect("serialise h3_map");
+    Fr::from_le_bytes_mod_order(&Sha256::digest(json))
+}
+
+/// `hash_map_cells`, served from `cache` while the same map keeps arriving.
+fn cached_map_hashes(
+    cache: &RwLock<Option<PrecomputedMap>>,
+    hits: &AtomicU64,
+    h3_map: &[String],
+    mode_id: u32,
+    cfg: &PoseidonConfig<Fr>,
+) -> Result<Vec<Fr>, MapHashError> {
+    const PREC: u32 = CIRCUIT_PRECISION;
+    const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES;
+
+    let source_hash = map_source_hash(h3_map, mode_id);
+    if let Some(map) = cache.read().unwrap().as_ref() {
+        if map.source_hash == source_hash {
+            hits.fetch_add(1, AtomicOrdering::Relaxed);
+            return h3_map
+                .iter()
+                .map(|hex| Ok(map.cell_hashes[&parse_cell(hex)?]))
+                .collect();
+        }
+    }
+
+    let hashes = hash_map_cells::<MAX_VERTS, PREC>(h3_map, mode_id, cfg)?;
+    let cell_hashes = h3_map
+        .iter()
+        .zip(&hashes)
+        .map(|(hex, h)| Ok((parse_cell(hex)?, *h)))
+        .collect::<Result<_, MapHashError>>()?;
+    *cache.write().unwrap() = Some(PrecomputedMap {
+        cell_hashes,
+        source_hash,
+    });
+    Ok(hashes)
+}
+
+/// Native breakdown of the result flag a circuit will prove.
+fn prove_debug<const MAX_HASHES: usize>(
+    circuit: &PointInMapCircuit<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>,
+) -> ProveDebug {
+    let n = circuit.private_num_vertices as usize;
+    let cell_hash = hash_polygon::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(
+        &circuit.private_polygon_vertices,
+        n,
+        circuit.mode_id,
+        &circuit.poseidon_config,
     );
+    let matching_hash_index = circuit
+        .public_polygon_hashes
+        .iter()
+        .position(|h| *h == cell_hash);
 
-    /* 5. Groth16 proof ------------------------------------------- */
+    ProveDebug {
+        inside_polygon: circuit.describe().inside_polygon_native,
+        hash_found_in_map: matching_hash_index.is_some(),
+        matching_hash_index,
+        cell_hash_hex: cell_hash.into_bigint().to_bytes_be().iter().fold(
+            String::from("0x"),
+            |mut hex, b| {
+                hex.push_str(&format!("{b:02x}"));
+                hex
+            },
+        ),
+    }
+}
+
+/// Proof, public inputs and the optional [`ProveDebug`] breakdown.
+type Proved = (Proof<Bn254>, Vec<Fr>, Option<ProveDebug>);
+
+/// Build the `MAX_HASHES` circuit for a request and prove it with `prover`,
+/// with the [`ProveDebug`] breakdown when `debug` is set.
+fn prove_request<const MAX_HASHES: usize>(
+    state: &AppState,
+    prover: &dyn ProverBackend,
+    body: &ProveRequest,
+    debug: bool,
+) -> Result<Proved, ProveError> {
+    let cfg = &state.poseidon_config;
+    let (circuit, public_inputs) = build_circuit::<MAX_HASHES>(
+        body.lat,
+        body.lon,
+        body.resolution,
+        &body.h3_map,
+        body.mode_id,
+        cfg,
+        |cell| state.cell_boundary_cache.get(cell),
+        || {
+            cached_map_hashes(
+                &state.precomputed_map,
+                &state.map_cache_hits,
+                &body.h3_map,
+                body.mode_id,
+                cfg,
+            )
+        },
+    )
+    .map_err(ProveError::Rejected)?;
+
+    let debug = debug.then(|| prove_debug(&circuit));
     let mut rng: StdRng = SeedableRng::seed_from_u64(0);
-    let proof = match Groth16::<Bn254>::prove(pk, circuit, &mut rng) {
+    // the description reveals whether the point is inside, like `debug` does
+    let description = debug.is_some().then(|| circuit.describe());
+    let proof = prover
+        .prove(BoxedCircuit::new(circuit), &mut rng)
+        .map_err(|e| ProveError::Failed((e.to_string(), description)))?;
+
+    Ok((proof, public_inputs, debug))
+}
+
+// ───────────────────────── request body ─────────────────────
+#[derive(Deserialize)]
+pub struct ProveRequest {
+    pub lat: f64,
+    pub lon: f64,
+    pub resolution: u8,
+    /// may be omitted when `ipfs_map_cid` is given, or for the server's
+    /// `H3_MAP_FILE` map
+    #[serde(default)]
+    pub h3_map: Vec<String>,
+    /// load the map from IPFS instead of `h3_map`
+    #[serde(default)]
+    pub ipfs_map_cid: Option<String>,
+    /// game-mode hash domain; 0 for requests predating game modes
+    #[serde(default)]
+    pub mode_id: u32,
+    /// game round or session the proof is for; its UTF-8 bytes key the
+    /// response's `binding_tag`, which labels the proof but does not bind it
+    #[serde(default)]
+    pub nonce: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ProveQuery {
+    /// add the [`ProveDebug`] fields; needs `ALLOW_DEBUG_ENDPOINT`
+    #[serde(default)]
+    pub debug: bool,
+}
+
+// ───────────────────────── handler ──────────────────────────
+/// Body as JSON, or as CBOR under `Content-Type: application/cbor`.
+#[post("/prove")]
+pub async fn prove(
+    req: HttpRequest,
+    query: web::Query<ProveQuery>,
+    body: CborJson<ProveRequest>,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let debug = query.debug;
+    if debug && !app_state.allow_debug {
+        return Err(ErrorForbidden("debug output is disabled"));
+    }
+
+    prove_and_respond(
+        request_id(&req),
+        app_state.get_ref().clone(),
+        body.into_inner(),
+        debug,
+    )
+    .await
+}
+
+/// H3 resolutions `/prove` accepts (`SUPPORTED_RESOLUTIONS`).
+#[get("/supported-resolutions")]
+pub async fn supported_resolutions(app_state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let resolutions: Vec<u8> = app_state
+        .supported_resolutions
+        .iter()
+        .map(|&res| u8::from(res))
+        .collect();
+    HttpResponse::Ok().json(serde_json::json!({ "resolutions": resolutions }))
+}
+
+/// Everything `/prove` does once the request is parsed; shared with
+/// `/prove-form`.
+pub(crate) async fn prove_and_respond(
+    request_id: String,
+    state: Arc<AppState>,
+    mut body: ProveRequest,
+    debug: bool,
+) -> Result<HttpResponse, actix_web::Error> {
+    // a shutdown drain waits for this request to answer
+    let _in_flight = InFlightGuard::enter(&state.in_flight_proofs);
+    let (lat, lon, resolution) = (body.lat, body.lon, body.resolution);
+    let nonce = body.nonce.clone();
+    // resolutions h3o rejects outright are reported by `build_circuit`
+    if Resolution::try_from(resolution).is_ok_and(|res| !state.supported_resolutions.contains(&res))
+    {
+        return Err(ErrorBadRequest(format!(
+            "resolution {resolution} is not supported, see /supported-resolutions"
+        )));
+    }
+
+    /* circuit → Groth16 proof → public inputs ------------------- */
+    // hashing the map and proving are CPU-bound; keep them off the async workers
+    let started = Instant::now();
+    let (h3_map_count, key_version, proved) = web::block(move || {
+        if let Some(cid) = &body.ipfs_map_cid {
+            match load_map_from_gateway(&state.ipfs_gateway_url, cid, &state.ipfs_map_cache) {
+                Ok(map) => body.h3_map = map,
+                Err(e) => return (0, None, Err(ProveError::MapLoad(e))),
+            }
+        } else if body.h3_map.is_empty() {
+            body.h3_map = state.default_h3_map.clone();
+        }
+        // the smallest circuit the map fits; see `AppState::keys_for_map`
+        let (n_hashes, prover, pvk) = state.keys_for_map(body.h3_map.len());
+        let key_version = state.key_version_of(pvk);
+        let prover = prover.as_ref();
+        let proved = match_circuit_size!(map_slots: n_hashes,
+            N => prove_request::<N>(&state, prover, &body, debug),
+            other => Err(ProveError::Failed((
+                format!("no circuit for {other} map hashes"),
+                None,
+            ))),
+        );
+        (body.h3_map.len(), Some(key_version), proved)
+    })
+    .await?;
+
+    let audit = AuditRecord::new(
+        &request_id,
+        lat,
+        lon,
+        resolution,
+        h3_map_count,
+        None,
+        started.elapsed(),
+    );
+    let respond = |body: ProveResponse, result_flag: Option<bool>| {
+        let mut resp = HttpResponse::Ok()
+            .insert_header((REQUEST_ID_HEADER, request_id.as_str()))
+            .json(ProveResponse {
+                key_version: key_version.clone(),
+                ..body.with_request_id(&request_id)
+            });
+        resp.extensions_mut().insert(AuditRecord {
+            result_flag,
+            ..audit.clone()
+        });
+        resp
+    };
+    let (proof, public_inputs, debug) = match proved {
         Ok(pr) => pr,
-        Err(e) => {
-            return Ok(HttpResponse::Ok().json(serde_json::json!({
-                "ok": false,
-                "err_msg": format!("proof generation failed: {e}")
-            })));
+        Err(ProveError::Rejected(
+            e @ (ProveRequestError::InvalidResolution(_)
+            | ProveRequestError::InvalidCellIndex(_)
+            | ProveRequestError::MapResolutionMismatch { .. }),
+        )) => return Err(ErrorBadRequest(e)),
+        Err(ProveError::Rejected(e)) => {
+            return Ok(respond(ProveResponse::failed(e.to_string()), None));
+        }
+        Err(ProveError::MapLoad(e @ MapLoadError::InvalidCid(_))) => {
+            return Err(ErrorBadRequest(e));
+        }
+        Err(ProveError::MapLoad(e)) => return Err(ErrorBadGateway(e)),
+        Err(ProveError::Failed((e, circuit))) => {
+            match &circuit {
+                Some(desc) => println!("[{request_id}] proof generation failed: {e} ({desc})"),
+                None => println!("[{request_id}] proof generation failed: {e}"),
+            }
+            return Ok(respond(
+                ProveResponse {
+                    circuit,
+                    ..ProveResponse::failed(format!("proof generation failed: {e}"))
+                },
+                None,
+            ));
         }
     };
 
-    /* 6. public inputs ------------------------------------------- */
-    let mut public_inputs = Vec::<F>::new();
-    public_inputs.push(if final_flag { F::one() } else { F::zero() });
-    public_inputs.extend_from_slice(&pub_hash_copy);
-
     /* 7. serialise (uncompressed) → base-64 ----------------------- */
-    let proof_json = serde_json::json!({
-        "a": to_b64(&proof.a),
-        "b": to_b64(&proof.b),
-        "c": to_b64(&proof.c),
-    });
+    let binding_tag =
+        nonce.map(|nonce| B64.encode(bind_proof_to_nonce(&proof, nonce.as_bytes()).binding_tag));
+    Ok(respond(
+        ProveResponse {
+            debug,
+            binding_tag,
+            ..ProveResponse::proved(&proof, &public_inputs)
+        },
+        Some(public_inputs[index_of(PublicInput::ResultFlag)].is_one()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+
+    use crate::{
+        prover::ProverRng, state::TEST_MAX_POLYGON_HASHES, zk::poseidon::default_poseidon_config,
+    };
+
+    const MAX: usize = CIRCUIT_MAX_VERTICES;
+    const PREC: u32 = CIRCUIT_PRECISION;
+
+    fn hash_map(cells: &[&str]) -> Result<Vec<Fr>, MapHashError> {
+        let cells: Vec<String> = cells.iter().map(|c| c.to_string()).collect();
+        hash_map_cells::<MAX, PREC>(&cells, 0, &default_poseidon_config())
+    }
+
+    #[test]
+    fn empty_map_hashes_to_nothing() {
+        assert_eq!(hash_map(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn invalid_cell_is_reported() {
+        for bad in ["not-a-cell", "872830828fffff", "zz2830828ffffff"] {
+            assert_eq!(
+                hash_map(&["872830828ffffff", bad]),
+                Err(MapHashError::InvalidCellIndex(bad.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn valid_map_hashes_every_cell() {
+        let hashes = hash_map(&["872830828ffffff", "87283082effffff"]).unwrap();
+        assert_eq!(hashes.len(), 2);
+        assert_ne!(hashes[0], hashes[1]);
+    }
+
+    // centre of a resolution-7 San Francisco cell and a neighbour
+    const SF_CELL: &str = "872830828ffffff";
+    const NEIGHBOUR: &str = "87283082effffff";
+
+    // `lat: None` uses the centre of `SF_CELL`
+    fn build(
+        lat: Option<f64>,
+        h3_map: &[&str],
+    ) -> Result<(PointInMapCircuit<Fr, PREC, MAX, 4>, Vec<Fr>), ProveRequestError> {
+        let centre = h3o::LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+        let lat = lat.unwrap_or(centre.lat());
+        let map: Vec<String> = h3_map.iter().map(|c| c.to_string()).collect();
+        build_circuit_from_request::<4>(lat, centre.lng(), 7, &map, 0, &default_poseidon_config())
+    }
 
-    let publics_b64: Vec<String> = public_inputs.iter().map(to_b64).collect();
+    #[test]
+    fn request_inside_map_builds_a_true_circuit() {
+        let (circuit, publics) = build(None, &[NEIGHBOUR, SF_CELL]).unwrap();
+        let desc = circuit.describe();
+        assert!(desc.inside_polygon_native && desc.final_flag);
+        assert_eq!(desc.hashes_found, 1);
 
-    /* 8. success JSON -------------------------------------------- */
-    Ok(HttpResponse::Ok().json(serde_json::json!({
-        "ok": true,
+        assert_eq!(publics.len(), 1 + 4);
+        assert_eq!(publics[0], Fr::one());
+        assert_eq!(
+            &publics[1..3],
+            &hash_map(&[NEIGHBOUR, SF_CELL]).unwrap()[..]
+        );
+        assert_eq!(publics[3], Fr::zero());
+    }
+
+    #[test]
+    fn request_outside_map_builds_a_false_circuit() {
+        let (circuit, publics) = build(None, &[NEIGHBOUR]).unwrap();
+        let desc = circuit.describe();
+        assert!(desc.inside_polygon_native);
+        assert_eq!(desc.hashes_found, 0);
+        assert_eq!(publics[0], Fr::zero());
+    }
+
+    #[test]
+    fn built_circuits_are_satisfied_by_their_own_witness() {
+        for map in [&[NEIGHBOUR, SF_CELL][..], &[NEIGHBOUR]] {
+            let (circuit, _) = build(None, map).unwrap();
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            assert!(cs.is_satisfied().unwrap(), "map {map:?}");
+        }
+    }
+
+    #[test]
+    fn bad_request_inputs_are_rejected() {
+        assert!(matches!(
+            build(Some(91.0), &[SF_CELL]),
+            Err(ProveRequestError::InvalidCoordinates { .. })
+        ));
+        assert!(matches!(
+            build(None, &[SF_CELL, "not-a-cell"]),
+            Err(ProveRequestError::InvalidCellIndex(c)) if c == "not-a-cell"
+        ));
+
+        let res9 = CellIndex::from_str(SF_CELL)
+            .unwrap()
+            .center_child(h3o::Resolution::Nine)
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            build(None, &[SF_CELL, &res9]).err(),
+            Some(ProveRequestError::MapResolutionMismatch {
+                expected: 7,
+                found: 9,
+                cell: res9,
+            })
+        );
+    }
+
+    #[test]
+    fn repeated_map_is_served_from_cache() {
+        let cfg = default_poseidon_config();
+        let (cache, hits) = (RwLock::new(None), AtomicU64::new(0));
+        let map = |cells: &[&str]| -> Vec<String> { cells.iter().map(|c| c.to_string()).collect() };
+        let lookup = |cells: &[&str], mode_id| {
+            cached_map_hashes(&cache, &hits, &map(cells), mode_id, &cfg).unwrap()
+        };
 
-        // "inside_polygon": inside_poly,
-        // "hash_found_in_map": hash_match,
-        // "in_map_final": final_flag,
-        // "num_constraints": num_constraints,            // --- debug
+        let first = lookup(&[SF_CELL, NEIGHBOUR], 0);
+        assert_eq!(first, hash_map(&[SF_CELL, NEIGHBOUR]).unwrap());
+        assert_eq!(hits.load(AtomicOrdering::Relaxed), 0);
 
-        "proof": proof_json,
-        "public_inputs": publics_b64
-    })))
+        assert_eq!(lookup(&[SF_CELL, NEIGHBOUR], 0), first);
+        assert_eq!(hits.load(AtomicOrdering::Relaxed), 1);
+
+        // reordered map or another mode replaces the entry
+        assert_eq!(lookup(&[NEIGHBOUR, SF_CELL], 0), vec![first[1], first[0]]);
+        assert_ne!(lookup(&[NEIGHBOUR, SF_CELL], 1), vec![first[1], first[0]]);
+        assert_eq!(hits.load(AtomicOrdering::Relaxed), 1);
+    }
+
+    struct FailingProver;
+
+    impl ProverBackend for FailingProver {
+        fn prove(
+            &self,
+            _circuit: BoxedCircuit,
+            _rng: &mut dyn ProverRng,
+        ) -> Result<Proof<Bn254>, SynthesisError> {
+            Err(SynthesisError::Unsatisfiable)
+        }
+    }
+
+    #[test]
+    fn failed_proofs_describe_the_circuit_only_in_debug() {
+        let state = AppState::new_test();
+        let centre = h3o::LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+        let body = ProveRequest {
+            lat: centre.lat(),
+            lon: centre.lng(),
+            resolution: 7,
+            h3_map: vec![SF_CELL.to_owned()],
+            ipfs_map_cid: None,
+            mode_id: 0,
+            nonce: None,
+        };
+
+        for debug in [false, true] {
+            let failed =
+                prove_request::<TEST_MAX_POLYGON_HASHES>(&state, &FailingProver, &body, debug);
+            let Err(ProveError::Failed((_, description))) = failed else {
+                panic!("the prover fails");
+            };
+            assert_eq!(description.is_some(), debug);
+        }
+    }
 }
@@ -0,0 +1,92 @@
+//! POST /prove-form – `/prove` with the map uploaded as a multipart file, for
+//! maps too large for the JSON body limit.
+//!
+//! Parts, in this order:
+//! - `coordinates`: JSON `{lat, lon, resolution, mode_id?}`, under 1 KiB
+//! - `h3_map`: text with one H3 cell index per line, under 1 MiB
+
+use actix_multipart::{Field, Multipart};
+use actix_web::{
+    HttpRequest, Responder,
+    error::{ErrorBadRequest, ErrorPayloadTooLarge},
+    post, web,
+};
+use futures_util::TryStreamExt as _;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+    api::prove::{ProveRequest, prove_and_respond, request_id},
+    state::AppState,
+};
+
+const COORDINATES_LIMIT: usize = 1024;
+const H3_MAP_LIMIT: usize = 1024 * 1024;
+
+#[derive(Deserialize)]
+struct FormCoordinates {
+    lat: f64,
+    lon: f64,
+    resolution: u8,
+    #[serde(default)]
+    mode_id: u32,
+}
+
+/// The next part, which must be called `name`, read up to `limit` bytes.
+async fn read_part(
+    form: &mut Multipart,
+    name: &str,
+    limit: usize,
+) -> Result<web::Bytes, actix_web::Error> {
+    let mut field: Field = form
+        .try_next()
+        .await?
+        .ok_or_else(|| ErrorBadRequest(format!("missing part `{name}`")))?;
+    if field.name() != Some(name) {
+        return Err(ErrorBadRequest(format!(
+            "expected part `{name}`, got {:?}",
+            field.name()
+        )));
+    }
+    match field.bytes(limit).await {
+        Ok(bytes) => Ok(bytes?),
+        Err(_) => Err(ErrorPayloadTooLarge(format!(
+            "part `{name}` exceeds {limit} bytes"
+        ))),
+    }
+}
+
+#[post("/prove-form")]
+pub async fn prove_form(
+    req: HttpRequest,
+    mut form: Multipart,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let coordinates = read_part(&mut form, "coordinates", COORDINATES_LIMIT).await?;
+    let coordinates: FormCoordinates = serde_json::from_slice(&coordinates)
+        .map_err(|e| ErrorBadRequest(format!("invalid `coordinates`: {e}")))?;
+
+    let h3_map = read_part(&mut form, "h3_map", H3_MAP_LIMIT).await?;
+    let h3_map = std::str::from_utf8(&h3_map)
+        .map_err(|_| ErrorBadRequest("`h3_map` is not UTF-8 text"))?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect();
+
+    if form.try_next().await?.is_some() {
+        return Err(ErrorBadRequest("unexpected part after `h3_map`"));
+    }
+
+    let body = ProveRequest {
+        lat: coordinates.lat,
+        lon: coordinates.lon,
+        resolution: coordinates.resolution,
+        h3_map,
+        ipfs_map_cid: None,
+        mode_id: coordinates.mode_id,
+        nonce: None,
+    };
+    prove_and_respond(request_id(&req), app_state.get_ref().clone(), body, false).await
+}
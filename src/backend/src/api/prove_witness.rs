@@ -0,0 +1,91 @@
+//! POST /prove-witness – prove a `zk::witness::CircuitWitness` built by the
+//! client, skipping the GPS and H3 steps of `/prove`.
+
+use actix_web::{HttpResponse, Responder, error::ErrorBadRequest, post, web};
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::Proof;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_std::rand::rngs::OsRng;
+use std::sync::Arc;
+
+use crate::{
+    api::types::ProveResponse,
+    prover::{BoxedCircuit, ProverBackend},
+    shutdown::InFlightGuard,
+    state::AppState,
+    zk::{
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        public_inputs::build_public_inputs,
+        witness::CircuitWitness,
+    },
+};
+
+fn prove_witness_with<const MAX_HASHES: usize>(
+    state: &AppState,
+    prover: &dyn ProverBackend,
+    witness: &CircuitWitness,
+) -> Result<(Proof<Bn254>, Vec<Fr>), String> {
+    let build = || {
+        witness
+            .into_circuit::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>(
+                &state.poseidon_config,
+            )
+            .map_err(|e| e.to_string())
+    };
+    let circuit = build()?;
+
+    // Groth16 "proves" an unsatisfied witness too, just to something that
+    // never verifies; the native recomputation alone does not rule it out
+    let description = circuit.describe();
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    build()?
+        .generate_constraints(cs.clone())
+        .map_err(|e| format!("constraint synthesis failed: {e}"))?;
+    let satisfied = cs
+        .is_satisfied()
+        .map_err(|e| format!("constraint check failed: {e}"))?;
+    if description.final_flag != witness.is_in_map || !satisfied {
+        return Err(format!(
+            "witness does not satisfy the circuit: {description}"
+        ));
+    }
+
+    let public_inputs =
+        build_public_inputs(circuit.public_is_in_map, &circuit.public_polygon_hashes);
+    // fresh blinding per proof: the client chose the witness, so a fixed
+    // seed would make resubmitting it give the very same proof
+    let proof = prover
+        .prove(BoxedCircuit::new(circuit), &mut OsRng)
+        .map_err(|e| format!("proof generation failed: {e}"))?;
+    Ok((proof, public_inputs))
+}
+
+/// Proven with the keys `/prove` would pick for `polygon_hashes.len()`
+/// map cells; `/verify` takes the result as is.
+#[post("/prove-witness")]
+pub async fn prove_witness(
+    body: String,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let witness = CircuitWitness::from_json(&body).map_err(ErrorBadRequest)?;
+
+    let state = app_state.get_ref().clone();
+    let proved = web::block(move || {
+        // a shutdown drain waits for this proof
+        let _in_flight = InFlightGuard::enter(&state.in_flight_proofs);
+        let (n_hashes, prover, _) = state.keys_for_map(witness.polygon_hashes.len());
+        let prover = prover.as_ref();
+        match_circuit_size!(map_slots: n_hashes,
+            N => prove_witness_with::<N>(&state, prover, &witness),
+            other => Err(format!("no circuit for {other} map hashes")),
+        )
+    })
+    .await?;
+
+    Ok(match proved {
+        Ok((proof, public_inputs)) => {
+            HttpResponse::Ok().json(ProveResponse::proved(&proof, &public_inputs))
+        }
+        Err(e) => HttpResponse::Ok().json(ProveResponse::failed(e)),
+    })
+}
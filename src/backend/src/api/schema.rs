@@ -0,0 +1,24 @@
+use actix_web::{HttpResponse, Result, get, web};
+use schemars::schema_for;
+use std::sync::Arc;
+
+use crate::{
+    api::types::{ProveResponse, VerifyResponse},
+    state::AppState,
+};
+
+#[get("/public-inputs-schema")]
+pub async fn public_inputs_schema(app_state: web::Data<Arc<AppState>>) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "schema": app_state.public_inputs_schema,
+    })))
+}
+
+/// JSON schemas of the prove and verify response bodies.
+#[get("/schema")]
+pub async fn response_schema() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "prove_response": schema_for!(ProveResponse),
+        "verify_response": schema_for!(VerifyResponse),
+    })))
+}
@@ -0,0 +1,96 @@
+//! POST /prove-sector – prove a private location lies in a pie-slice zone.
+
+use actix_web::{HttpResponse, Responder, post, web};
+use ark_bn254::Bn254;
+use ark_crypto_primitives::snark::SNARK;
+use ark_groth16::Groth16;
+use ark_std::rand::rngs::OsRng;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+    api::types::ProveResponse,
+    shutdown::InFlightGuard,
+    state::AppState,
+    zk::{
+        polygon_ops::gps_to_web_mercator,
+        sector::{SectorCircuit, is_in_sector, sector_public_inputs},
+        types::{DefaultDec, DefaultPoint},
+    },
+};
+
+#[derive(Deserialize)]
+pub struct ProveSectorRequest {
+    pub lat: f64,
+    pub lon: f64,
+    pub center_lat: f64,
+    pub center_lon: f64,
+    /// degrees clockwise from north; `start > end` wraps through north
+    pub start_bearing: f64,
+    pub end_bearing: f64,
+    /// Web-Mercator metres
+    pub max_radius: f64,
+}
+
+#[post("/prove-sector")]
+pub async fn prove_sector(
+    body: web::Json<ProveSectorRequest>,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    let fail = |msg: String| Ok(HttpResponse::Ok().json(ProveResponse::failed(msg)));
+
+    /* 0. input ranges --------------------------------------------- */
+    for (lat, lon) in [(body.lat, body.lon), (body.center_lat, body.center_lon)] {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return fail(format!("coordinates out of range: lat={lat}, lon={lon}"));
+        }
+    }
+    for b in [body.start_bearing, body.end_bearing] {
+        if !(0.0..360.0).contains(&b) {
+            return fail(format!("bearing out of range [0, 360): {b}"));
+        }
+    }
+    if body.max_radius.is_nan() || body.max_radius < 0.0 {
+        return fail(format!("invalid max_radius: {}", body.max_radius));
+    }
+
+    /* 1. project + native check ----------------------------------- */
+    let (x, y) = gps_to_web_mercator(body.lon, body.lat);
+    let (cx, cy) = gps_to_web_mercator(body.center_lon, body.center_lat);
+    let point = DefaultPoint::from_f64(x, y);
+    let center = DefaultPoint::from_f64(cx, cy);
+    // bearings are public inputs as sent; refuse ones the circuit would round
+    let (start, end) = match (
+        DefaultDec::try_from_f64(body.start_bearing),
+        DefaultDec::try_from_f64(body.end_bearing),
+    ) {
+        (Ok(start), Ok(end)) => (start, end),
+        (Err(e), _) | (_, Err(e)) => return fail(format!("invalid bearing: {e}")),
+    };
+    let max_r2 = DefaultDec::from_f64(body.max_radius * body.max_radius);
+
+    if !is_in_sector(&point, &center, start, end, max_r2) {
+        return fail("point is not inside the sector".into());
+    }
+
+    /* 2. Groth16 proof -------------------------------------------- */
+    let circuit = SectorCircuit::new(point, center, start, end, max_r2);
+    let pk = app_state.sector_pk.clone();
+    let in_flight = app_state.in_flight_proofs.clone();
+    let proved = web::block(move || {
+        // a shutdown drain waits for this proof
+        let _in_flight = InFlightGuard::enter(&in_flight);
+        // fresh blinding per proof; a fixed seed makes equal inputs give
+        // equal, linkable proofs
+        Groth16::<Bn254>::prove(&pk, circuit, &mut OsRng)
+    })
+    .await?;
+    let proof = match proved {
+        Ok(p) => p,
+        Err(e) => return fail(format!("proof generation failed: {e}")),
+    };
+
+    /* 3. respond --------------------------------------------------- */
+    let public_inputs = sector_public_inputs(&center, start, end, max_r2);
+    Ok(HttpResponse::Ok().json(ProveResponse::proved(&proof, &public_inputs)))
+}
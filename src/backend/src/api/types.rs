@@ -0,0 +1,121 @@
+//! Typed JSON bodies returned by the prove and verify handlers.
+//!
+//! `None` fields are left out of the JSON, so clients still see the same
+//! shapes as before; `GET /schema` publishes the schemas derived from here.
+
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::Proof;
+use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::{
+    api::prove::to_b64,
+    zk::{circuit::CircuitDescription, proof_encoding::serialize_proof_versioned},
+};
+
+/// Uncompressed, base-64 Groth16 proof points.
+#[derive(Serialize, JsonSchema)]
+pub struct ProofFields {
+    pub a: String,
+    pub b: String,
+    pub c: String,
+}
+
+impl From<&Proof<Bn254>> for ProofFields {
+    fn from(proof: &Proof<Bn254>) -> Self {
+        Self {
+            a: to_b64(&proof.a),
+            b: to_b64(&proof.b),
+            c: to_b64(&proof.c),
+        }
+    }
+}
+
+/// Why `/prove` produced its result flag; only with `?debug=true`.
+#[derive(Serialize, JsonSchema)]
+pub struct ProveDebug {
+    pub inside_polygon: bool,
+    pub hash_found_in_map: bool,
+    /// first map slot holding the point's cell hash
+    pub matching_hash_index: Option<usize>,
+    /// big-endian hex of the point's cell hash
+    pub cell_hash_hex: String,
+}
+
+/// Body of `/prove`, `/prove-sector` and `/prove-outside`.
+#[derive(Default, Serialize, JsonSchema)]
+pub struct ProveResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<ProofFields>,
+    /// the same proof as base-64 `ZK01` bytes (`zk::proof_encoding`);
+    /// `/verify` takes either form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_versioned: Option<String>,
+    /// base-64 field elements, in circuit order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_inputs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub err_msg: Option<String>,
+    /// native re-run of a circuit that failed to prove (`/prove?debug=true` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuit: Option<CircuitDescription>,
+    /// echoed `X-Request-ID` (`/prove` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// version of the keys behind `/verify` (`/prove` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_version: Option<String>,
+    /// base-64 `zk::proof_transcript` tag of the proof under the request's
+    /// `nonce` (`/prove` only); anyone with the proof can compute it, so it
+    /// labels the round rather than binding the proof to it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding_tag: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<ProveDebug>,
+}
+
+impl ProveResponse {
+    pub fn proved(proof: &Proof<Bn254>, public_inputs: &[Fr]) -> Self {
+        Self {
+            ok: true,
+            proof: Some(proof.into()),
+            proof_versioned: Some(B64.encode(serialize_proof_versioned(proof))),
+            public_inputs: Some(public_inputs.iter().map(to_b64).collect()),
+            ..Self::default()
+        }
+    }
+
+    pub fn failed(err_msg: impl Into<String>) -> Self {
+        Self {
+            err_msg: Some(err_msg.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_request_id(self, request_id: &str) -> Self {
+        Self {
+            request_id: Some(request_id.to_owned()),
+            ..self
+        }
+    }
+}
+
+/// Decoded public inputs of a checked `/verify` proof.
+#[derive(Serialize, JsonSchema)]
+pub struct VerifiedPublicInputs {
+    pub result_flag: bool,
+    /// non-zero map hashes, as decimal field elements
+    pub cell_hashes: Vec<String>,
+}
+
+/// Body of `/verify` and `/verify-with-key`.
+#[derive(Serialize, JsonSchema)]
+pub struct VerifyResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub err_msg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_inputs: Option<VerifiedPublicInputs>,
+}
@@ -1,86 +1,239 @@
 //! POST /verify – check a Groth16 proof and its public inputs.
+//! POST /verify-with-key – same, against a caller-supplied VK (multi-arena).
 
-use actix_web::{HttpResponse, Responder, error::ErrorBadRequest, post, web};
+use actix_web::{
+    HttpResponse, Responder,
+    error::{ErrorBadRequest, ErrorConflict, ErrorServiceUnavailable},
+    post,
+    rt::time::{sleep, timeout},
+    web,
+};
 
 use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
-use ark_crypto_primitives::snark::SNARK;
-use ark_groth16::{Groth16, Proof};
-use ark_serialize::CanonicalDeserialize;
+use ark_groth16::{PreparedVerifyingKey, Proof, VerifyingKey};
 
 use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
 use serde::Deserialize;
-use std::{io::Cursor, sync::Arc};
+use sha2::{Digest, Sha256};
+use std::{future::Future, sync::Arc};
 
-use crate::state::AppState;
+use crate::{
+    api::types::{VerifiedPublicInputs, VerifyResponse},
+    keys::prepare_verifying_key_cached,
+    state::AppState,
+    zk::{
+        deserialize::{deserialize_b64_uncompressed, deserialize_uncompressed},
+        proof_encoding::deserialize_proof_versioned,
+        public_inputs::parse_public_inputs,
+        verify::verify_proof,
+    },
+};
 
 /* ------------ request formats ------------------------------------------------ */
 
 #[derive(Deserialize)]
-struct ProofBase64 {
+struct ProofPoints {
     a: String,
     b: String,
     c: String,
 }
 
+/// `proof` or `proof_versioned` from `/prove`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProofBase64 {
+    Points(ProofPoints),
+    Versioned(String),
+}
+
 #[derive(Deserialize)]
 struct VerifyRequest {
     proof: ProofBase64,
     public_inputs: Vec<String>,
+    /// `key_version` from `/prove`; 409 if the server's keys have changed
+    #[serde(default)]
+    expected_key_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct VerifyWithKeyRequest {
+    proof: ProofBase64,
+    public_inputs: Vec<String>,
+    /// base-64 of the uncompressed `VerifyingKey<Bn254>`
+    vk_b64: String,
+}
+
+/* ------------ decoding ------------------------------------------------------- */
+
+fn decode_proof(p: &ProofBase64) -> Result<Proof<Bn254>, actix_web::Error> {
+    let p = match p {
+        ProofBase64::Points(p) => p,
+        ProofBase64::Versioned(s) => {
+            let bytes = B64
+                .decode(s)
+                .map_err(|_| ErrorBadRequest("base64 decode (proof) failed"))?;
+            return deserialize_proof_versioned(&bytes).map_err(ErrorBadRequest);
+        }
+    };
+
+    Ok(Proof::<Bn254> {
+        a: deserialize_b64_uncompressed::<G1Affine>("G1", &p.a).map_err(ErrorBadRequest)?,
+        b: deserialize_b64_uncompressed::<G2Affine>("G2", &p.b).map_err(ErrorBadRequest)?,
+        c: deserialize_b64_uncompressed::<G1Affine>("G1", &p.c).map_err(ErrorBadRequest)?,
+    })
+}
+
+fn decode_publics(inputs: &[String]) -> Result<Vec<Fr>, actix_web::Error> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| {
+            deserialize_b64_uncompressed::<Fr>(&format!("pi #{idx}"), s).map_err(ErrorBadRequest)
+        })
+        .collect()
 }
 
-/* ------------ handler -------------------------------------------------------- */
+/// Look the VK up by SHA-256 of its bytes, deserialising it on first use.
+fn cached_vk(
+    app_state: &AppState,
+    vk_b64: &str,
+) -> Result<Arc<PreparedVerifyingKey<Bn254>>, actix_web::Error> {
+    let bytes = B64
+        .decode(vk_b64)
+        .map_err(|_| ErrorBadRequest("base64 decode (vk) failed"))?;
+    let digest: String = Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+
+    if let Some(pvk) = app_state.vk_cache.get(&digest) {
+        return Ok(pvk);
+    }
+
+    let vk =
+        deserialize_uncompressed::<VerifyingKey<Bn254>>("vk", &bytes).map_err(ErrorBadRequest)?;
+    let pvk = prepare_verifying_key_cached(&app_state.prepared_vk_cache, &vk);
+    app_state.vk_cache.insert(digest, pvk.clone());
+    Ok(pvk)
+}
+
+/* ------------ verify + respond ----------------------------------------------- */
+
+/// Runs a verify handler so its response time says nothing about the input.
+///
+/// A malformed proof or a public-input count that doesn't match the key
+/// fails in microseconds, while a real pairing check takes milliseconds;
+/// every answer is held back to at least `verify_min_latency` so callers
+/// can't probe input lengths by timing. Handlers past `verify_timeout` get
+/// `503` (their blocking verification still runs to completion).
+async fn with_verify_timing(
+    app_state: &AppState,
+    handler: impl Future<Output = Result<HttpResponse, actix_web::Error>>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let floor = sleep(app_state.verify_min_latency);
+    let resp = match timeout(app_state.verify_timeout, handler).await {
+        Ok(resp) => resp,
+        Err(_) => Err(ErrorServiceUnavailable("verification timed out")),
+    };
+    floor.await;
+    resp
+}
+
+/// [`verify_response`] off the async workers, so it can be timed out.
+async fn verify_and_respond(
+    pvk: Arc<PreparedVerifyingKey<Bn254>>,
+    public_inputs: Vec<Fr>,
+    proof: Proof<Bn254>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let resp = web::block(move || verify_response(&pvk, &public_inputs, &proof)).await?;
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+fn verify_response(
+    pvk: &PreparedVerifyingKey<Bn254>,
+    public_inputs: &[Fr],
+    proof: &Proof<Bn254>,
+) -> VerifyResponse {
+    let ok = match verify_proof(proof, public_inputs, pvk) {
+        Ok(b) => b,
+        Err(e) => {
+            return VerifyResponse {
+                ok: false,
+                err_msg: Some(format!("verification error: {e}")),
+                public_inputs: None,
+            };
+        }
+    };
+
+    let decoded = match parse_public_inputs(public_inputs) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            return VerifyResponse {
+                ok: false,
+                err_msg: Some(format!("malformed public inputs: {e}")),
+                public_inputs: None,
+            };
+        }
+    };
+    let cell_hashes: Vec<String> = decoded.active_cell_hashes().map(Fr::to_string).collect();
+
+    VerifyResponse {
+        ok,
+        err_msg: None,
+        public_inputs: Some(VerifiedPublicInputs {
+            result_flag: decoded.result_flag,
+            cell_hashes,
+        }),
+    }
+}
+
+/* ------------ handlers ------------------------------------------------------- */
 #[post("/verify")]
 pub async fn verify(
     body: web::Json<VerifyRequest>,
     app_state: web::Data<Arc<AppState>>,
 ) -> Result<impl Responder, actix_web::Error> {
-    /* ---- 1. decode & deserialise proof ------------------------------------ */
-    let decode_g1 = |s: &str| -> Result<G1Affine, actix_web::Error> {
-        let bytes = B64
-            .decode(s)
-            .map_err(|_| ErrorBadRequest("base64 decode (G1) failed"))?;
-        G1Affine::deserialize_uncompressed(&mut Cursor::new(bytes))
-            .map_err(|_| ErrorBadRequest("G1 deserialise failed"))
-    };
+    with_verify_timing(&app_state, verify_request(&body, &app_state)).await
+}
 
-    let decode_g2 = |s: &str| -> Result<G2Affine, actix_web::Error> {
-        let bytes = B64
-            .decode(s)
-            .map_err(|_| ErrorBadRequest("base64 decode (G2) failed"))?;
-        G2Affine::deserialize_uncompressed(&mut Cursor::new(bytes))
-            .map_err(|_| ErrorBadRequest("G2 deserialise failed"))
-    };
+async fn verify_request(
+    body: &VerifyRequest,
+    app_state: &AppState,
+) -> Result<HttpResponse, actix_web::Error> {
+    let public_inputs = decode_publics(&body.public_inputs)?;
+    // one result flag, then the map slots that pick the circuit
+    let pvk = app_state
+        .pvk_for_hash_count(public_inputs.len().saturating_sub(1))
+        .unwrap_or(&app_state.pvk)
+        .clone();
+    if let Some(expected) = &body.expected_key_version {
+        let actual = app_state.key_version_of(&pvk);
+        if *expected != actual {
+            return Err(ErrorConflict(format!(
+                "key version mismatch: proof made with {expected}, server has {actual}"
+            )));
+        }
+    }
+    let proof = decode_proof(&body.proof)?;
 
-    let proof = Proof::<Bn254> {
-        a: decode_g1(&body.proof.a)?,
-        b: decode_g2(&body.proof.b)?,
-        c: decode_g1(&body.proof.c)?,
-    };
+    verify_and_respond(pvk, public_inputs, proof).await
+}
 
-    /* ---- 2. decode & deserialise public inputs --------------------------- */
-    let mut public_inputs = Vec::<Fr>::with_capacity(body.public_inputs.len());
+#[post("/verify-with-key")]
+pub async fn verify_with_key(
+    body: web::Json<VerifyWithKeyRequest>,
+    app_state: web::Data<Arc<AppState>>,
+) -> Result<impl Responder, actix_web::Error> {
+    with_verify_timing(&app_state, verify_with_key_request(&body, &app_state)).await
+}
 
-    for (idx, s) in body.public_inputs.iter().enumerate() {
-        let bytes = B64
-            .decode(s)
-            .map_err(|_| ErrorBadRequest(format!("base64 decode (pi #{idx}) failed")))?;
-        let f = Fr::deserialize_uncompressed(&mut Cursor::new(bytes))
-            .map_err(|_| ErrorBadRequest(format!("field deserialise (pi #{idx}) failed")))?;
-        public_inputs.push(f);
-    }
+async fn verify_with_key_request(
+    body: &VerifyWithKeyRequest,
+    app_state: &AppState,
+) -> Result<HttpResponse, actix_web::Error> {
+    let proof = decode_proof(&body.proof)?;
+    let public_inputs = decode_publics(&body.public_inputs)?;
+    let pvk = cached_vk(app_state, &body.vk_b64)?;
 
-    /* ---- 3. verify ------------------------------------------------------- */
-    let ok =
-        match Groth16::<Bn254>::verify_with_processed_vk(&app_state.pvk, &public_inputs, &proof) {
-            Ok(b) => b,
-            Err(e) => {
-                return Ok(HttpResponse::Ok().json(serde_json::json!({
-                    "ok": false,
-                    "err_msg": format!("verification error: {e}")
-                })));
-            }
-        };
-
-    /* ---- 4. respond ------------------------------------------------------ */
-    Ok(HttpResponse::Ok().json(serde_json::json!({ "ok": ok })))
+    verify_and_respond(pvk, public_inputs, proof).await
 }
@@ -0,0 +1,220 @@
+//! Audit trail of `/prove` requests, one JSON line per proof attempt.
+//!
+//! Handlers attach an [`AuditRecord`] to their response; [`RequestAuditLogger`]
+//! appends it to the [`AuditLog`] once the response is built. Coordinates are
+//! rounded to 3 decimal places (~100 m) before they are stored, and proofs
+//! and keys are never logged.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    future::{Ready, ready},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use actix_web::{
+    Error,
+    dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready},
+};
+use futures_util::future::LocalBoxFuture;
+use serde::Serialize;
+
+/// Rotation threshold when `AUDIT_LOG_ROTATE_BYTES` is unset.
+pub const DEFAULT_AUDIT_LOG_ROTATE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// One `/prove` attempt. Failed attempts have no `result_flag`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AuditRecord {
+    pub request_id: String,
+    pub timestamp_ms: u64,
+    pub lat_rounded_3dp: f64,
+    pub lon_rounded_3dp: f64,
+    pub resolution: u8,
+    pub h3_map_count: usize,
+    pub result_flag: Option<bool>,
+    pub proof_generation_ms: u64,
+}
+
+impl AuditRecord {
+    /// Stamped with the current time; `lat` and `lon` are rounded here.
+    pub fn new(
+        request_id: &str,
+        lat: f64,
+        lon: f64,
+        resolution: u8,
+        h3_map_count: usize,
+        result_flag: Option<bool>,
+        proof_generation: Duration,
+    ) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        Self {
+            request_id: request_id.to_owned(),
+            timestamp_ms,
+            lat_rounded_3dp: round_3dp(lat),
+            lon_rounded_3dp: round_3dp(lon),
+            resolution,
+            h3_map_count,
+            result_flag,
+            proof_generation_ms: proof_generation.as_millis() as u64,
+        }
+    }
+}
+
+fn round_3dp(x: f64) -> f64 {
+    (x * 1000.0).round() / 1000.0
+}
+
+/// JSON-lines file, moved to `<path>.1` (replacing the previous one) once
+/// it reaches `rotate_bytes`.
+pub struct AuditLog {
+    path: PathBuf,
+    rotate_bytes: u64,
+    file: Mutex<File>,
+}
+
+impl AuditLog {
+    pub fn open(path: impl AsRef<Path>, rotate_bytes: u64) -> io::Result<Self> {
+        let path = path.as_ref().to_owned();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            rotate_bytes,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn append(&self, record: &AuditRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().unwrap();
+        if file.metadata()?.len() + line.len() as u64 > self.rotate_bytes {
+            let mut rotated = self.path.clone().into_os_string();
+            rotated.push(".1");
+            fs::rename(&self.path, rotated)?;
+            *file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+        }
+        file.write_all(&line)
+    }
+
+    /// Push everything appended so far to disk; called on shutdown.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut file = self.file.lock().unwrap();
+        file.flush()?;
+        file.sync_all()
+    }
+}
+
+/// Middleware writing each response's [`AuditRecord`] to the log; passes
+/// everything through untouched when there is no log.
+#[derive(Clone)]
+pub struct RequestAuditLogger {
+    log: Option<Arc<AuditLog>>,
+}
+
+impl RequestAuditLogger {
+    pub fn new(log: Option<Arc<AuditLog>>) -> Self {
+        Self { log }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestAuditLogger
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestAuditLoggerMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestAuditLoggerMiddleware {
+            service: Rc::new(service),
+            log: self.log.clone(),
+        }))
+    }
+}
+
+pub struct RequestAuditLoggerMiddleware<S> {
+    service: Rc<S>,
+    log: Option<Arc<AuditLog>>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestAuditLoggerMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let log = self.log.clone();
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let res = fut.await?;
+            if let Some(log) = log {
+                if let Some(record) = res.response().extensions().get::<AuditRecord>() {
+                    // a full disk must not fail the request
+                    if let Err(e) = log.append(record) {
+                        println!("[{}] audit log write failed: {e}", record.request_id);
+                    }
+                }
+            }
+            Ok(res)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_round_and_rotate() {
+        let record = AuditRecord::new(
+            "req-1",
+            37.774_929,
+            -122.419_416,
+            7,
+            3,
+            Some(true),
+            Duration::from_millis(412),
+        );
+        assert_eq!(
+            (record.lat_rounded_3dp, record.lon_rounded_3dp),
+            (37.775, -122.419)
+        );
+        assert_eq!(record.proof_generation_ms, 412);
+
+        let dir = std::env::temp_dir().join(format!("audit-{}", uuid::Uuid::new_v4()));
+        fs::create_dir(&dir).unwrap();
+        let path = dir.join("audit.log");
+        let line_len = serde_json::to_vec(&record).unwrap().len() as u64 + 1;
+
+        // room for two lines; the third rotates them out
+        let log = AuditLog::open(&path, 2 * line_len).unwrap();
+        for _ in 0..3 {
+            log.append(&record).unwrap();
+        }
+        let lines = |p: &Path| fs::read_to_string(p).unwrap().lines().count();
+        assert_eq!(lines(&path), 1);
+        assert_eq!(lines(&dir.join("audit.log.1")), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+//! A concurrent map capped at a fixed number of entries, for the caches
+//! `AppState` keeps between requests.
+
+use std::{
+    hash::Hash,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use dashmap::DashMap;
+
+/// A `DashMap` capped at `capacity` entries, least recently used evicted
+/// first.
+///
+/// Each entry carries the tick of its last use; once full, the oldest tenth
+/// goes in one pass so inserts don't each scan the map.
+pub struct LruCache<K, V> {
+    entries: DashMap<K, (V, u64)>,
+    clock: AtomicU64,
+    capacity: usize,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::with_capacity(capacity),
+            clock: AtomicU64::new(0),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// The value for `key`, marked as just used.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let tick = self.clock.fetch_add(1, Ordering::Relaxed);
+        self.entries.get_mut(key).map(|mut entry| {
+            entry.1 = tick;
+            entry.0.clone()
+        })
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let tick = self.clock.fetch_add(1, Ordering::Relaxed);
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            self.evict_oldest();
+        }
+        self.entries.insert(key, (value, tick));
+    }
+
+    /// The value for `key`, computed with `make` and inserted on a miss.
+    pub fn get_or_insert_with(&self, key: K, make: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = make();
+        self.insert(key, value.clone());
+        value
+    }
+
+    // drop the least recently used tenth (at least one entry)
+    fn evict_oldest(&self) {
+        let mut ticks: Vec<u64> = self.entries.iter().map(|e| e.value().1).collect();
+        if ticks.is_empty() {
+            return;
+        }
+        let k = (ticks.len() / 10).max(1) - 1;
+        let (_, &mut cutoff, _) = ticks.select_nth_unstable(k);
+        self.entries.retain(|_, (_, tick)| *tick > cutoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru_cache_stays_within_capacity() {
+        let cache = LruCache::new(3);
+        for i in 0..10 {
+            cache.insert(i, i * 2);
+        }
+        assert!(cache.len() <= 3);
+        assert_eq!(cache.get(&9), Some(18));
+
+        // overwriting a key never evicts another
+        let cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.insert("b", 3);
+        assert_eq!((cache.get(&"a"), cache.get(&"b")), (Some(1), Some(3)));
+        assert_eq!(cache.get_or_insert_with("a", || unreachable!()), 1);
+    }
+}
@@ -0,0 +1,210 @@
+//! Runtime configuration read from the environment at startup.
+
+use h3o::Resolution;
+use std::{
+    env,
+    io::{Error, ErrorKind, Result},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{
+    audit::DEFAULT_AUDIT_LOG_ROTATE_BYTES,
+    keys::SUPPORTED_HASH_COUNTS,
+    zk::poseidon::{
+        POSEIDON_ALPHA, POSEIDON_FULL_ROUNDS, POSEIDON_PARTIAL_ROUNDS, validate_poseidon_alpha,
+        validate_poseidon_rounds,
+    },
+};
+
+/// A connection that has not sent its request head by then is dropped.
+pub const CLIENT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time a client gets to close its side once the server has closed.
+pub const CLIENT_DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub const DEFAULT_MAX_CONNECTIONS: usize = 25_000;
+pub const DEFAULT_KEEP_ALIVE_SECS: u64 = 5;
+/// `VERIFY_MIN_LATENCY_MS` when unset.
+pub const DEFAULT_VERIFY_MIN_LATENCY_MS: u64 = 50;
+/// `VERIFY_TIMEOUT_MS` when unset.
+pub const DEFAULT_VERIFY_TIMEOUT_MS: u64 = 5_000;
+/// Gateway used when `IPFS_GATEWAY_URL` is unset.
+pub const DEFAULT_IPFS_GATEWAY_URL: &str = "https://ipfs.io/ipfs";
+
+#[derive(Clone)]
+pub struct Config {
+    /// `ENABLE_GZIP=true` – compress responses for clients sending `Accept-Encoding`.
+    pub enable_gzip: bool,
+    /// `ALLOW_DEBUG_ENDPOINT=true` – honour `/prove?debug=true`; keep off in production.
+    pub allow_debug_endpoint: bool,
+    /// `ALLOW_ADMIN_ENDPOINTS=true` – serve `/admin/*`; they are unauthenticated.
+    pub allow_admin_endpoints: bool,
+    /// `POSEIDON_RATE` – field elements absorbed per permutation.
+    pub poseidon_rate: usize,
+    /// `POSEIDON_CAPACITY` – sponge capacity; width is `rate + capacity`.
+    pub poseidon_capacity: usize,
+    /// `POSEIDON_FULL_ROUNDS` – even, at least `POSEIDON_MIN_FULL_ROUNDS`.
+    pub poseidon_full_rounds: usize,
+    /// `POSEIDON_PARTIAL_ROUNDS` – at least `min_partial_rounds` for the alpha.
+    pub poseidon_partial_rounds: usize,
+    /// `POSEIDON_ALPHA` – S-box exponent, coprime to `r - 1` (17, or 5 with
+    /// at least 57 partial rounds).
+    pub poseidon_alpha: u64,
+    /// `AUDIT_LOG_FILE` – JSON-lines audit trail of `/prove`; off when unset.
+    pub audit_log_file: Option<PathBuf>,
+    /// `AUDIT_LOG_ROTATE_BYTES` – size at which the audit log is rotated.
+    pub audit_log_rotate_bytes: u64,
+    /// `MAP_HASH_COUNTS` – comma-separated map sizes to load keys for, from
+    /// `SUPPORTED_HASH_COUNTS`; `/prove` uses the smallest a map fits.
+    pub map_hash_counts: Vec<usize>,
+    /// `SUPPORTED_RESOLUTIONS` – comma-separated H3 resolutions `/prove`
+    /// accepts; every resolution when unset.
+    pub supported_resolutions: Vec<Resolution>,
+    /// `VERIFY_MIN_LATENCY_MS` – `/verify` never answers sooner than this.
+    pub verify_min_latency_ms: u64,
+    /// `VERIFY_TIMEOUT_MS` – `/verify` gives up with `503` after this.
+    pub verify_timeout_ms: u64,
+    /// `MAX_CONNECTIONS` – concurrent connections per worker.
+    pub max_connections: usize,
+    /// `KEEP_ALIVE_SECS` – idle keep-alive connections are closed after this.
+    pub keep_alive_secs: u64,
+    /// `IPFS_GATEWAY_URL` – gateway `/prove` fetches `ipfs_map_cid` maps from.
+    pub ipfs_gateway_url: String,
+    /// `H3_MAP_FILE` – map `/prove` uses when a request sends neither
+    /// `h3_map` nor `ipfs_map_cid`; JSON array or JSON Lines.
+    pub h3_map_file: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        let config = Self {
+            enable_gzip: env_flag("ENABLE_GZIP", false),
+            allow_debug_endpoint: env_flag("ALLOW_DEBUG_ENDPOINT", false),
+            allow_admin_endpoints: env_flag("ALLOW_ADMIN_ENDPOINTS", false),
+            poseidon_rate: env_usize("POSEIDON_RATE", 2)?,
+            poseidon_capacity: env_usize("POSEIDON_CAPACITY", 1)?,
+            poseidon_full_rounds: env_usize("POSEIDON_FULL_ROUNDS", POSEIDON_FULL_ROUNDS)?,
+            poseidon_partial_rounds: env_usize("POSEIDON_PARTIAL_ROUNDS", POSEIDON_PARTIAL_ROUNDS)?,
+            poseidon_alpha: env_usize("POSEIDON_ALPHA", POSEIDON_ALPHA as usize)? as u64,
+            audit_log_file: env::var_os("AUDIT_LOG_FILE").map(PathBuf::from),
+            audit_log_rotate_bytes: env_usize(
+                "AUDIT_LOG_ROTATE_BYTES",
+                DEFAULT_AUDIT_LOG_ROTATE_BYTES as usize,
+            )? as u64,
+            map_hash_counts: env_usize_list("MAP_HASH_COUNTS")?,
+            supported_resolutions: env_resolutions("SUPPORTED_RESOLUTIONS")?,
+            verify_min_latency_ms: env_usize(
+                "VERIFY_MIN_LATENCY_MS",
+                DEFAULT_VERIFY_MIN_LATENCY_MS as usize,
+            )? as u64,
+            verify_timeout_ms: env_usize("VERIFY_TIMEOUT_MS", DEFAULT_VERIFY_TIMEOUT_MS as usize)?
+                as u64,
+            max_connections: env_usize("MAX_CONNECTIONS", DEFAULT_MAX_CONNECTIONS)?,
+            keep_alive_secs: env_usize("KEEP_ALIVE_SECS", DEFAULT_KEEP_ALIVE_SECS as usize)? as u64,
+            ipfs_gateway_url: env::var("IPFS_GATEWAY_URL")
+                .unwrap_or_else(|_| DEFAULT_IPFS_GATEWAY_URL.to_owned()),
+            h3_map_file: env::var_os("H3_MAP_FILE").map(PathBuf::from),
+        };
+
+        if config.poseidon_rate == 0 || config.poseidon_capacity == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "POSEIDON_RATE and POSEIDON_CAPACITY must be at least 1",
+            ));
+        }
+        if config.max_connections == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "MAX_CONNECTIONS must be at least 1",
+            ));
+        }
+        if config.verify_timeout_ms < config.verify_min_latency_ms {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "VERIFY_TIMEOUT_MS must be at least VERIFY_MIN_LATENCY_MS",
+            ));
+        }
+        validate_poseidon_alpha(config.poseidon_alpha)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+        validate_poseidon_rounds(
+            config.poseidon_full_rounds,
+            config.poseidon_partial_rounds,
+            config.poseidon_alpha,
+        )
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+        if let Some(n) = config
+            .map_hash_counts
+            .iter()
+            .find(|n| !SUPPORTED_HASH_COUNTS.contains(n))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "MAP_HASH_COUNTS: no circuit for {n} map hashes, pick from {SUPPORTED_HASH_COUNTS:?}"
+                ),
+            ));
+        }
+        Ok(config)
+    }
+}
+
+fn env_flag(name: &str, default: bool) -> bool {
+    match env::var(name) {
+        Ok(v) => matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"),
+        Err(_) => default,
+    }
+}
+
+fn env_usize(name: &str, default: usize) -> Result<usize> {
+    match env::var(name) {
+        Ok(v) => v.trim().parse().map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{name} must be a positive integer, got {v:?}"),
+            )
+        }),
+        Err(_) => Ok(default),
+    }
+}
+
+fn env_usize_list(name: &str) -> Result<Vec<usize>> {
+    let Ok(v) = env::var(name) else {
+        return Ok(Vec::new());
+    };
+    v.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse().map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("{name} must be a comma-separated list of integers, got {v:?}"),
+                )
+            })
+        })
+        .collect()
+}
+
+fn env_resolutions(name: &str) -> Result<Vec<Resolution>> {
+    let list = env_usize_list(name)?;
+    if list.is_empty() {
+        return Ok(Resolution::range(Resolution::Zero, Resolution::Fifteen).collect());
+    }
+    let mut resolutions = list
+        .into_iter()
+        .map(|n| {
+            u8::try_from(n)
+                .ok()
+                .and_then(|n| Resolution::try_from(n).ok())
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{name}: {n} is not an H3 resolution (0-15)"),
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    resolutions.sort();
+    resolutions.dedup();
+    Ok(resolutions)
+}
@@ -1,75 +1,638 @@
 // ───────────── parameters on disk ────────────────────────────
-use std::{fs, path::Path};
+use std::{fs, path::Path, sync::Arc};
 
 use ark_bn254::{Bn254, Fr};
 use ark_crypto_primitives::{snark::SNARK, sponge::poseidon::PoseidonConfig};
 use ark_ff::Zero;
-use ark_groth16::{Groth16, PreparedVerifyingKey, ProvingKey, prepare_verifying_key};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_groth16::{Groth16, PreparedVerifyingKey, ProvingKey, VerifyingKey, prepare_verifying_key};
+use ark_relations::r1cs::{ConstraintSynthesizer, SynthesisError};
+use ark_serialize::CanonicalSerialize;
 use ark_std::rand::{SeedableRng, rngs::StdRng};
+use sha2::{Digest, Sha256};
 
-use crate::zk::{circuit::PointInMapCircuit, point_2d::Point2DDec};
+use crate::{
+    state::PreparedVkCache,
+    zk::{
+        circuit::{CIRCUIT_MAX_VERTICES_LARGE, PointInMapCircuit},
+        deserialize::{DeserializeError, deserialize_uncompressed},
+        fixed_point_decimal::Dec,
+        non_membership::{NonMembershipCircuit, non_membership_public_inputs},
+        point_2d::Point2DDec,
+        public_inputs::build_public_inputs,
+        sector::{SectorCircuit, sector_public_inputs},
+    },
+};
 
 const PARAM_DIR: &str = "./params";
 const PK_PATH: &str = "./params/proving_key.bin";
 const VK_PATH: &str = "./params/verifying_key.bin";
+const LARGE_PK_PATH: &str = "./params/proving_key_large.bin";
+const LARGE_VK_PATH: &str = "./params/verifying_key_large.bin";
+const SECTOR_PK_PATH: &str = "./params/sector_proving_key.bin";
+const SECTOR_VK_PATH: &str = "./params/sector_verifying_key.bin";
+const NON_MEMBERSHIP_PK_PATH: &str = "./params/non_membership_proving_key.bin";
+const NON_MEMBERSHIP_VK_PATH: &str = "./params/non_membership_verifying_key.bin";
 
-fn read_keys_from_disk() -> Option<(ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>)> {
-    if !(Path::new(PK_PATH).exists() && Path::new(VK_PATH).exists()) {
+fn read_keys_from_disk(
+    pk_path: &str,
+    vk_path: &str,
+) -> Option<(ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>)> {
+    if !(Path::new(pk_path).exists() && Path::new(vk_path).exists()) {
         return None;
     }
 
-    println!("🗝️  Loading keys from {PARAM_DIR} …");
+    println!("🗝️  Loading keys from {pk_path} …");
 
-    let pk_bytes = fs::read(PK_PATH).ok()?;
-    let vk_bytes = fs::read(VK_PATH).ok()?;
+    let pk_bytes = fs::read(pk_path).ok()?;
+    let vk_bytes = fs::read(vk_path).ok()?;
+    println!(
+        "🗝️  on disk: {}",
+        KeySizes {
+            pk_bytes: pk_bytes.len(),
+            vk_bytes: vk_bytes.len(),
+        }
+    );
 
-    let pk = ProvingKey::<Bn254>::deserialize_uncompressed(&*pk_bytes).ok()?;
-    let vk = ark_groth16::VerifyingKey::<Bn254>::deserialize_uncompressed(&*vk_bytes).ok()?;
+    let (pk, vk) = match decode_keys(&pk_bytes, &vk_bytes) {
+        Ok(keys) => keys,
+        Err(e) => {
+            println!("🗝️  {e}; generating new keys");
+            return None;
+        }
+    };
+    report_key_sizes(&pk, &vk);
 
     Some((pk, prepare_verifying_key(&vk)))
 }
 
-fn write_keys_to_disk(pk: &ProvingKey<Bn254>, vk: &ark_groth16::VerifyingKey<Bn254>) {
+fn decode_keys(
+    pk_bytes: &[u8],
+    vk_bytes: &[u8],
+) -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), DeserializeError> {
+    Ok((
+        deserialize_uncompressed("proving key", pk_bytes)?,
+        deserialize_uncompressed("verifying key", vk_bytes)?,
+    ))
+}
+
+fn write_keys_to_disk(
+    pk: &ProvingKey<Bn254>,
+    vk: &ark_groth16::VerifyingKey<Bn254>,
+    pk_path: &str,
+    vk_path: &str,
+) {
     fs::create_dir_all(PARAM_DIR).expect("create param dir");
 
     let mut buf = Vec::new();
     pk.serialize_uncompressed(&mut buf).unwrap();
-    fs::write(PK_PATH, &buf).expect("write pk");
+    fs::write(pk_path, &buf).expect("write pk");
 
     buf.clear();
     vk.serialize_uncompressed(&mut buf).unwrap();
-    fs::write(VK_PATH, &buf).expect("write vk");
+    fs::write(vk_path, &buf).expect("write vk");
 
-    println!("🗝️  Groth16 keys written to {PARAM_DIR}");
+    println!("🗝️  Groth16 keys written to {pk_path} / {vk_path}");
+    report_key_sizes(pk, vk);
 }
 
-// ───────────── load-or-generate helper  ───────────────────────
-pub fn load_or_gen_keys<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
-    poseidon_cfg: &PoseidonConfig<Fr>,
-) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
-    if let Some(keys) = read_keys_from_disk() {
-        return keys;
+/// Proving keys larger than this get a warning at startup.
+pub const PK_SIZE_WARN_BYTES: usize = 500 * 1024 * 1024;
+
+/// Uncompressed sizes of a key pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeySizes {
+    pub pk_bytes: usize,
+    pub vk_bytes: usize,
+}
+
+impl KeySizes {
+    pub fn of(pk: &ProvingKey<Bn254>, vk: &VerifyingKey<Bn254>) -> Self {
+        Self {
+            pk_bytes: pk.uncompressed_size(),
+            vk_bytes: vk.uncompressed_size(),
+        }
+    }
+
+    pub fn pk_oversized(&self) -> bool {
+        self.pk_bytes > PK_SIZE_WARN_BYTES
     }
+}
+
+impl std::fmt::Display for KeySizes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "pk size: {:.1} MB, vk size: {:.1} KB, pk/vk ratio: {:.0}",
+            self.pk_bytes as f64 / (1024.0 * 1024.0),
+            self.vk_bytes as f64 / 1024.0,
+            self.pk_bytes as f64 / self.vk_bytes.max(1) as f64
+        )
+    }
+}
 
-    println!("🗝️  Keys not found – running circuit-specific setup (this is one-off).");
+/// Print the sizes of a loaded key pair, warning when the proving key is
+/// over [`PK_SIZE_WARN_BYTES`]: it is held in memory for every proof.
+pub fn report_key_sizes(pk: &ProvingKey<Bn254>, vk: &VerifyingKey<Bn254>) -> KeySizes {
+    let sizes = KeySizes::of(pk, vk);
+    println!("🗝️  in memory: {sizes}");
+    if sizes.pk_oversized() {
+        println!(
+            "⚠️  proving key exceeds {} MB; reduce CIRCUIT_MAX_POLYGON_HASHES or serve \
+             smaller maps from MAP_HASH_COUNTS circuits",
+            PK_SIZE_WARN_BYTES / (1024 * 1024)
+        );
+    }
+    sizes
+}
+
+/// First 16 hex digits of the SHA-256 of the uncompressed VK; changes
+/// whenever the keys are rotated.
+pub fn key_version(vk: &ark_groth16::VerifyingKey<Bn254>) -> String {
+    let mut buf = Vec::new();
+    vk.serialize_uncompressed(&mut buf).unwrap();
+    Sha256::digest(&buf)
+        .iter()
+        .take(8)
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// [`prepare_verifying_key`] once per distinct VK in `cache`: its pairing is
+/// the slow part, and later calls with the same key bytes share the first
+/// result until the cache evicts it.
+///
+/// The prepared key holds the full pairing `e(alpha_g1, beta_g2)` and the
+/// Miller-loop coefficients of `-gamma_g2` and `-delta_g2`, so each
+/// `verify_with_processed_vk` runs three Miller loops and one final
+/// exponentiation; there is no fourth pairing left to cache.
+pub fn prepare_verifying_key_cached(
+    cache: &PreparedVkCache,
+    vk: &VerifyingKey<Bn254>,
+) -> Arc<PreparedVerifyingKey<Bn254>> {
+    let mut buf = Vec::new();
+    vk.serialize_uncompressed(&mut buf).unwrap();
+    let digest: [u8; 32] = Sha256::digest(&buf).into();
+
+    cache.get_or_insert_with(digest, || Arc::new(prepare_verifying_key(vk)))
+}
+
+// ───────────── circuit-specific setup  ────────────────────────
+pub fn setup_keys<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+    seed: u64,
+) -> (ProvingKey<Bn254>, ark_groth16::VerifyingKey<Bn254>) {
+    let circuit = dummy_circuit::<PREC, MAX_VERTS, MAX_HASHES>(poseidon_cfg);
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).expect("setup failed")
+}
 
-    // ---- dummy circuit identical to the one used previously ----
+// all-zero point and polygon, no vertices, no map hashes; satisfied with
+// the flag false
+fn dummy_circuit<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> PointInMapCircuit<Fr, PREC, MAX_VERTS, MAX_HASHES> {
     let zero_pt = Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0);
     let zero_poly = core::array::from_fn(|_| zero_pt);
-    let circuit = PointInMapCircuit::<Fr, PREC, MAX_VERTS, MAX_HASHES>::new(
+    PointInMapCircuit::new(
         zero_pt,
         zero_poly,
         0,
         false,
         [Fr::zero(); MAX_HASHES],
+        0,
         poseidon_cfg.clone(),
-    );
+        false,
+    )
+}
+
+#[derive(Debug)]
+pub enum KeyIntegrityError {
+    /// the dummy circuit could not be proven with the PK
+    Prove(SynthesisError),
+    Verify(SynthesisError),
+    /// the VK rejects a proof made with the PK: they come from different setups
+    Mismatch,
+}
+
+impl std::fmt::Display for KeyIntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyIntegrityError::Prove(e) => write!(f, "dummy proof failed: {e}"),
+            KeyIntegrityError::Verify(e) => write!(f, "dummy verification failed: {e}"),
+            KeyIntegrityError::Mismatch => {
+                write!(f, "verifying key rejects a proof from the proving key")
+            }
+        }
+    }
+}
 
+impl std::error::Error for KeyIntegrityError {}
+
+// prove the satisfied `circuit` with `pk`, then verify against `pvk`
+fn check_key_pair<C: ConstraintSynthesizer<Fr>>(
+    pk: &ProvingKey<Bn254>,
+    pvk: &PreparedVerifyingKey<Bn254>,
+    circuit: C,
+    public_inputs: &[Fr],
+) -> Result<(), KeyIntegrityError> {
     let mut rng: StdRng = SeedableRng::seed_from_u64(0);
-    let (pk, vk) =
-        Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).expect("setup failed");
+    let proof = Groth16::<Bn254>::prove(pk, circuit, &mut rng).map_err(KeyIntegrityError::Prove)?;
+
+    match Groth16::<Bn254>::verify_with_processed_vk(pvk, public_inputs, &proof) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(KeyIntegrityError::Mismatch),
+        Err(e) => Err(KeyIntegrityError::Verify(e)),
+    }
+}
 
-    write_keys_to_disk(&pk, &vk);
+/// Check that `pk` and `pvk` come from the same setup by proving the
+/// [`setup_keys`] dummy circuit and verifying it. Costs one full proof.
+pub fn verify_key_integrity<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+    pk: &ProvingKey<Bn254>,
+    pvk: &PreparedVerifyingKey<Bn254>,
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> Result<(), KeyIntegrityError> {
+    check_key_pair(
+        pk,
+        pvk,
+        dummy_circuit::<PREC, MAX_VERTS, MAX_HASHES>(poseidon_cfg),
+        &build_public_inputs(false, &[Fr::zero(); MAX_HASHES]),
+    )
+}
+
+/// [`verify_key_integrity`] for a map size picked at runtime.
+///
+/// Panics unless `n_hashes` is one of [`SUPPORTED_HASH_COUNTS`].
+pub fn verify_key_integrity_for_hash_count<const PREC: u32, const MAX_VERTS: usize>(
+    n_hashes: usize,
+    pk: &ProvingKey<Bn254>,
+    pvk: &PreparedVerifyingKey<Bn254>,
+    cfg: &PoseidonConfig<Fr>,
+) -> Result<(), KeyIntegrityError> {
+    match n_hashes {
+        64 => verify_key_integrity::<PREC, MAX_VERTS, 64>(pk, pvk, cfg),
+        256 => verify_key_integrity::<PREC, MAX_VERTS, 256>(pk, pvk, cfg),
+        512 => verify_key_integrity::<PREC, MAX_VERTS, 512>(pk, pvk, cfg),
+        1024 => verify_key_integrity::<PREC, MAX_VERTS, 1024>(pk, pvk, cfg),
+        4096 => verify_key_integrity::<PREC, MAX_VERTS, 4096>(pk, pvk, cfg),
+        other => panic!("no circuit for {other} map hashes"),
+    }
+}
+
+// keys from disk that pass `check`; anything else is deleted and replaced
+// by `setup`
+fn load_checked_or_setup(
+    pk_path: &str,
+    vk_path: &str,
+    check: impl FnOnce(
+        &ProvingKey<Bn254>,
+        &PreparedVerifyingKey<Bn254>,
+    ) -> Result<(), KeyIntegrityError>,
+    setup: impl FnOnce() -> (ProvingKey<Bn254>, VerifyingKey<Bn254>),
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    if let Some((pk, pvk)) = read_keys_from_disk(pk_path, vk_path) {
+        match check(&pk, &pvk) {
+            Ok(()) => return (pk, pvk),
+            Err(e) => {
+                println!("⚠️  {pk_path} / {vk_path} do not match ({e}); deleting them");
+                let _ = fs::remove_file(pk_path);
+                let _ = fs::remove_file(vk_path);
+            }
+        }
+    }
+
+    println!("🗝️  No keys at {pk_path} – running circuit-specific setup (this is one-off).");
+
+    let (pk, vk) = setup();
+
+    write_keys_to_disk(&pk, &vk, pk_path, vk_path);
     (pk, prepare_verifying_key(&vk))
 }
+
+/// Map sizes `/prove` can have keys for, besides `CIRCUIT_MAX_POLYGON_HASHES`.
+pub const SUPPORTED_HASH_COUNTS: [usize; 5] = [64, 256, 512, 1024, 4096];
+
+/// [`setup_keys`] for a map size picked at runtime.
+///
+/// Panics unless `n_hashes` is one of [`SUPPORTED_HASH_COUNTS`].
+pub fn generate_keys_for_hash_count<const PREC: u32, const MAX_VERTS: usize>(
+    n_hashes: usize,
+    cfg: &PoseidonConfig<Fr>,
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    let (pk, vk) = match n_hashes {
+        64 => setup_keys::<PREC, MAX_VERTS, 64>(cfg, 0),
+        256 => setup_keys::<PREC, MAX_VERTS, 256>(cfg, 0),
+        512 => setup_keys::<PREC, MAX_VERTS, 512>(cfg, 0),
+        1024 => setup_keys::<PREC, MAX_VERTS, 1024>(cfg, 0),
+        4096 => setup_keys::<PREC, MAX_VERTS, 4096>(cfg, 0),
+        other => panic!("no circuit for {other} map hashes"),
+    };
+    (pk, prepare_verifying_key(&vk))
+}
+
+// ───────────── load-or-generate helper  ───────────────────────
+pub fn load_or_gen_keys<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    load_checked_or_setup(
+        PK_PATH,
+        VK_PATH,
+        |pk, pvk| verify_key_integrity::<PREC, MAX_VERTS, MAX_HASHES>(pk, pvk, poseidon_cfg),
+        || setup_keys::<PREC, MAX_VERTS, MAX_HASHES>(poseidon_cfg, 0),
+    )
+}
+
+/// [`generate_keys_for_hash_count`], kept in `params/proving_key_{n}.bin`
+/// and `params/verifying_key_{n}.bin`.
+pub fn load_or_gen_keys_for_hash_count<const PREC: u32, const MAX_VERTS: usize>(
+    n_hashes: usize,
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    let pk_path = format!("{PARAM_DIR}/proving_key_{n_hashes}.bin");
+    let vk_path = format!("{PARAM_DIR}/verifying_key_{n_hashes}.bin");
+    load_checked_or_setup(
+        &pk_path,
+        &vk_path,
+        |pk, pvk| {
+            verify_key_integrity_for_hash_count::<PREC, MAX_VERTS>(n_hashes, pk, pvk, poseidon_cfg)
+        },
+        || {
+            let (pk, pvk) = generate_keys_for_hash_count::<PREC, MAX_VERTS>(n_hashes, poseidon_cfg);
+            (pk, pvk.vk)
+        },
+    )
+}
+
+// ───────────── large-polygon circuit (`/prove-large`) ──────────
+pub fn load_or_gen_large_keys<const PREC: u32, const MAX_HASHES: usize>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    load_checked_or_setup(
+        LARGE_PK_PATH,
+        LARGE_VK_PATH,
+        |pk, pvk| {
+            verify_key_integrity::<PREC, CIRCUIT_MAX_VERTICES_LARGE, MAX_HASHES>(
+                pk,
+                pvk,
+                poseidon_cfg,
+            )
+        },
+        || setup_keys::<PREC, CIRCUIT_MAX_VERTICES_LARGE, MAX_HASHES>(poseidon_cfg, 0),
+    )
+}
+
+// ───────────── sector circuit (`/prove-sector`) ────────────────
+pub fn setup_sector_keys<const PREC: u32>(
+    seed: u64,
+) -> (ProvingKey<Bn254>, ark_groth16::VerifyingKey<Bn254>) {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    Groth16::<Bn254>::circuit_specific_setup(dummy_sector_circuit::<PREC>(), &mut rng)
+        .expect("sector setup failed")
+}
+
+// the point at the centre of a zero-radius, zero-width sector; satisfied
+fn dummy_sector_circuit<const PREC: u32>() -> SectorCircuit<Fr, PREC> {
+    let zero_pt = Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0);
+    let zero = Dec::from_f64(0.0);
+    SectorCircuit::<Fr, PREC>::new(zero_pt, zero_pt, zero, zero, zero)
+}
+
+/// [`verify_key_integrity`] for the sector circuit.
+pub fn verify_sector_key_integrity<const PREC: u32>(
+    pk: &ProvingKey<Bn254>,
+    pvk: &PreparedVerifyingKey<Bn254>,
+) -> Result<(), KeyIntegrityError> {
+    let zero_pt = Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0);
+    let zero = Dec::from_f64(0.0);
+    check_key_pair(
+        pk,
+        pvk,
+        dummy_sector_circuit::<PREC>(),
+        &sector_public_inputs(&zero_pt, zero, zero, zero),
+    )
+}
+
+pub fn load_or_gen_sector_keys<const PREC: u32>() -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>)
+{
+    load_checked_or_setup(
+        SECTOR_PK_PATH,
+        SECTOR_VK_PATH,
+        verify_sector_key_integrity::<PREC>,
+        || setup_sector_keys::<PREC>(0),
+    )
+}
+
+// ───────────── non-membership circuit (`/prove-outside`) ──────
+pub fn setup_non_membership_keys<
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_ZONES: usize,
+>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+    seed: u64,
+) -> (ProvingKey<Bn254>, ark_groth16::VerifyingKey<Bn254>) {
+    let circuit = dummy_non_membership_circuit::<PREC, MAX_VERTS, MAX_ZONES>(poseidon_cfg);
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
+        .expect("non-membership setup failed")
+}
+
+// no active zones, so the point is outside all of them; satisfied
+fn dummy_non_membership_circuit<const PREC: u32, const MAX_VERTS: usize, const MAX_ZONES: usize>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> NonMembershipCircuit<Fr, PREC, MAX_VERTS, MAX_ZONES, MAX_ZONES> {
+    let zero_pt = Point2DDec::<Fr, PREC>::from_f64(0.0, 0.0);
+    NonMembershipCircuit::new(
+        zero_pt,
+        [[zero_pt; MAX_VERTS]; MAX_ZONES],
+        [0; MAX_ZONES],
+        0,
+        true,
+        [Fr::zero(); MAX_ZONES],
+        0,
+        poseidon_cfg.clone(),
+    )
+}
+
+/// [`verify_key_integrity`] for the non-membership circuit.
+pub fn verify_non_membership_key_integrity<
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_ZONES: usize,
+>(
+    pk: &ProvingKey<Bn254>,
+    pvk: &PreparedVerifyingKey<Bn254>,
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> Result<(), KeyIntegrityError> {
+    check_key_pair(
+        pk,
+        pvk,
+        dummy_non_membership_circuit::<PREC, MAX_VERTS, MAX_ZONES>(poseidon_cfg),
+        &non_membership_public_inputs(true, &[Fr::zero(); MAX_ZONES]),
+    )
+}
+
+pub fn load_or_gen_non_membership_keys<
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_ZONES: usize,
+>(
+    poseidon_cfg: &PoseidonConfig<Fr>,
+) -> (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>) {
+    load_checked_or_setup(
+        NON_MEMBERSHIP_PK_PATH,
+        NON_MEMBERSHIP_VK_PATH,
+        |pk, pvk| {
+            verify_non_membership_key_integrity::<PREC, MAX_VERTS, MAX_ZONES>(pk, pvk, poseidon_cfg)
+        },
+        || setup_non_membership_keys::<PREC, MAX_VERTS, MAX_ZONES>(poseidon_cfg, 0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::{G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+    use ark_serialize::CanonicalDeserialize;
+
+    #[test]
+    fn identical_vk_bytes_share_one_prepared_key() {
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 2],
+        };
+        let mut bytes = Vec::new();
+        vk.serialize_uncompressed(&mut bytes).unwrap();
+        let copy = VerifyingKey::<Bn254>::deserialize_uncompressed(&*bytes).unwrap();
+
+        let cache = PreparedVkCache::new(1);
+        let first = prepare_verifying_key_cached(&cache, &vk);
+        let second = prepare_verifying_key_cached(&cache, &copy);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(*first, prepare_verifying_key(&vk));
+
+        let other = VerifyingKey {
+            gamma_abc_g1: vec![G1Affine::generator(); 3],
+            ..vk
+        };
+        assert!(!Arc::ptr_eq(
+            &first,
+            &prepare_verifying_key_cached(&cache, &other)
+        ));
+        // capacity 1: `other` pushed `vk` out
+        assert_eq!(cache.len(), 1);
+        assert!(!Arc::ptr_eq(
+            &first,
+            &prepare_verifying_key_cached(&cache, &vk)
+        ));
+    }
+
+    #[test]
+    fn key_integrity_catches_keys_from_another_setup() {
+        use crate::zk::{
+            circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+            poseidon::default_poseidon_config,
+        };
+
+        let cfg = default_poseidon_config();
+        let (pk, vk) = setup_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(&cfg, 1);
+        let (_, other_vk) = setup_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(&cfg, 2);
+
+        verify_key_integrity::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(
+            &pk,
+            &prepare_verifying_key(&vk),
+            &cfg,
+        )
+        .unwrap();
+        assert!(matches!(
+            verify_key_integrity::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(
+                &pk,
+                &prepare_verifying_key(&other_vk),
+                &cfg,
+            ),
+            Err(KeyIntegrityError::Mismatch)
+        ));
+    }
+
+    #[test]
+    fn sector_and_non_membership_keys_are_checked_too() {
+        use crate::zk::{
+            circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+            poseidon::default_poseidon_config,
+        };
+
+        let (pk, vk) = setup_sector_keys::<CIRCUIT_PRECISION>(1);
+        let (_, other_vk) = setup_sector_keys::<CIRCUIT_PRECISION>(2);
+        verify_sector_key_integrity::<CIRCUIT_PRECISION>(&pk, &prepare_verifying_key(&vk)).unwrap();
+        assert!(matches!(
+            verify_sector_key_integrity::<CIRCUIT_PRECISION>(
+                &pk,
+                &prepare_verifying_key(&other_vk)
+            ),
+            Err(KeyIntegrityError::Mismatch)
+        ));
+
+        let cfg = default_poseidon_config();
+        let setup = |seed| {
+            setup_non_membership_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(&cfg, seed)
+        };
+        let check = |pk: &ProvingKey<Bn254>, vk: &VerifyingKey<Bn254>| {
+            verify_non_membership_key_integrity::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(
+                pk,
+                &prepare_verifying_key(vk),
+                &cfg,
+            )
+        };
+        let ((pk, vk), (_, other_vk)) = (setup(1), setup(2));
+        check(&pk, &vk).unwrap();
+        assert!(matches!(
+            check(&pk, &other_vk),
+            Err(KeyIntegrityError::Mismatch)
+        ));
+    }
+
+    #[test]
+    fn key_sizes_report_in_mb_and_kb() {
+        let sizes = KeySizes {
+            pk_bytes: 3 * 1024 * 1024,
+            vk_bytes: 2 * 1024,
+        };
+        assert_eq!(
+            sizes.to_string(),
+            "pk size: 3.0 MB, vk size: 2.0 KB, pk/vk ratio: 1536"
+        );
+        assert!(!sizes.pk_oversized());
+        assert!(
+            KeySizes {
+                pk_bytes: PK_SIZE_WARN_BYTES + 1,
+                ..sizes
+            }
+            .pk_oversized()
+        );
+    }
+
+    #[test]
+    fn prepared_key_already_holds_alpha_beta_pairing() {
+        use ark_ec::pairing::Pairing;
+
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: (G1Affine::generator() * Fr::from(3u64)).into(),
+            beta_g2: (G2Affine::generator() * Fr::from(5u64)).into(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 2],
+        };
+        let pvk = prepare_verifying_key(&vk);
+        assert_eq!(
+            pvk.alpha_g1_beta_g2,
+            Bn254::pairing(vk.alpha_g1, vk.beta_g2).0
+        );
+    }
+}
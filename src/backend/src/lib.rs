@@ -0,0 +1,9 @@
+pub mod api;
+pub mod audit;
+pub mod cache;
+pub mod config;
+pub mod keys;
+pub mod prover;
+pub mod shutdown;
+pub mod state;
+pub mod zk;
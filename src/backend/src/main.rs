@@ -1,53 +1,184 @@
-use actix_web::{App, HttpServer, middleware::DefaultHeaders};
+use actix_web::{
+    App, HttpServer,
+    middleware::{Compress, Condition, DefaultHeaders, NormalizePath},
+};
 use ark_bn254::Fr;
-use ark_crypto_primitives::sponge::poseidon::{PoseidonConfig, find_poseidon_ark_and_mds};
-use ark_ff::PrimeField;
-
-use crate::{
-    keys::load_or_gen_keys,
-    zk::circuit::{CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use std::{
+    io::{Error, ErrorKind},
+    sync::{Arc, atomic::Ordering},
+    time::Duration,
 };
 
-mod api;
-mod keys;
-mod state;
-mod zk;
+use backend::{
+    api::{
+        self,
+        prove::{ProveRequest, build_circuit_from_request},
+    },
+    audit::{AuditLog, RequestAuditLogger},
+    config::{CLIENT_DISCONNECT_TIMEOUT, CLIENT_REQUEST_TIMEOUT, Config},
+    keys::{
+        load_or_gen_keys, load_or_gen_keys_for_hash_count, load_or_gen_large_keys,
+        load_or_gen_non_membership_keys, load_or_gen_sector_keys,
+    },
+    prover,
+    shutdown::{DRAIN_TIMEOUT, drain_on_shutdown_signal},
+    state,
+    zk::{
+        circuit::{CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        non_membership::NON_MEMBERSHIP_MAX_ZONES,
+        poseidon::poseidon_config_with_alpha,
+    },
+};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(Fr::MODULUS_BIT_SIZE as u64, 3, 8, 31, 0);
-    let poseidon_config = PoseidonConfig {
-        full_rounds: 8,
-        partial_rounds: 31,
-        alpha: 17,
-        ark,
-        mds,
-        rate: 2,
-        capacity: 1,
-    };
-
-    let (pk, pvk) =
+    let config = Config::from_env()?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    // `--large-polygon` also serves `/prove-large` (12-vertex zones)
+    let large_polygon = args.iter().any(|arg| arg == "--large-polygon");
+
+    let poseidon_config = poseidon_config_with_alpha(
+        config.poseidon_rate,
+        config.poseidon_capacity,
+        config.poseidon_full_rounds,
+        config.poseidon_partial_rounds,
+        config.poseidon_alpha,
+    );
+
+    // `--dry-run <request.json>` (alias `--count-constraints`): check the
+    // circuit for a `/prove` body and exit, before any keys are loaded
+    if let Some(i) = args
+        .iter()
+        .position(|arg| arg == "--dry-run" || arg == "--count-constraints")
+    {
+        return dry_run(args.get(i + 1), &poseidon_config);
+    }
+
+    let keys =
         load_or_gen_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, CIRCUIT_MAX_POLYGON_HASHES>(
             &poseidon_config,
         );
 
-    let shared = state::AppState::init(pk, pvk, poseidon_config).expect("init state");
+    // the default circuit already covers `CIRCUIT_MAX_POLYGON_HASHES`
+    let hash_count_keys = config
+        .map_hash_counts
+        .iter()
+        .filter(|&&n| n != CIRCUIT_MAX_POLYGON_HASHES)
+        .map(|&n| {
+            let keys = load_or_gen_keys_for_hash_count::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(
+                n,
+                &poseidon_config,
+            );
+            (n, keys)
+        })
+        .collect();
+
+    // the server keeps only the proving keys of these circuits; the loaders
+    // check each VK against its key and leave it in `params/` for clients
+    let (sector_pk, _) = load_or_gen_sector_keys::<CIRCUIT_PRECISION>();
+
+    let (non_membership_pk, _) = load_or_gen_non_membership_keys::<
+        CIRCUIT_PRECISION,
+        CIRCUIT_MAX_VERTICES,
+        NON_MEMBERSHIP_MAX_ZONES,
+    >(&poseidon_config);
+
+    let large_pk = large_polygon.then(|| {
+        load_or_gen_large_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_POLYGON_HASHES>(&poseidon_config).0
+    });
+
+    let shared = state::AppState::init(
+        keys,
+        hash_count_keys,
+        sector_pk,
+        non_membership_pk,
+        large_pk,
+        poseidon_config,
+        &config,
+    )
+    .expect("init state");
+
+    let audit_log = config
+        .audit_log_file
+        .as_ref()
+        .map(|path| AuditLog::open(path, config.audit_log_rotate_bytes).map(Arc::new))
+        .transpose()?;
 
     ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-    println!("Starting server at 8080");
-    HttpServer::new(move || {
+    println!("Starting server at 8080 (gzip: {})", config.enable_gzip);
+    let app_state = shared.clone();
+    let app_audit_log = audit_log.clone();
+    let server = HttpServer::new(move || {
         App::new()
-            .app_data(shared.clone())
+            .app_data(app_state.clone())
+            .wrap(RequestAuditLogger::new(app_audit_log.clone()))
+            .wrap(Condition::new(config.enable_gzip, Compress::default()))
             .wrap(
                 DefaultHeaders::new()
                     .add(("Access-Control-Allow-Origin", "*"))
                     .add(("Access-Control-Allow-Methods", "GET, POST, OPTIONS"))
-                    .add(("Access-Control-Allow-Headers", "Content-Type")),
+                    .add(("Access-Control-Allow-Headers", "Content-Type, X-Request-ID"))
+                    .add(("Access-Control-Expose-Headers", "X-Request-ID")),
             )
+            // `/prove/` routes like `/prove`, CORS preflights included
+            .wrap(NormalizePath::trim())
             .configure(api::config)
     })
+    // stalled and idle clients must not hold file descriptors forever
+    .client_request_timeout(CLIENT_REQUEST_TIMEOUT)
+    .client_disconnect_timeout(CLIENT_DISCONNECT_TIMEOUT)
+    .keep_alive(Duration::from_secs(config.keep_alive_secs))
+    .max_connections(config.max_connections)
     .bind(("0.0.0.0", 8080))?
-    .run()
-    .await
+    // SIGTERM and SIGINT are handled by `drain_on_shutdown_signal`, which
+    // waits for in-flight proofs
+    .disable_signals()
+    .run();
+    drain_on_shutdown_signal(&server, shared.in_flight_proofs.clone(), DRAIN_TIMEOUT)?;
+    server.await?;
+
+    if let Some(log) = &audit_log {
+        log.flush()?;
+    }
+    println!(
+        "Stopped; map cache hits: {}, cell boundary cache hits/misses: {}/{}",
+        shared.map_cache_hits.load(Ordering::Relaxed),
+        shared.cell_boundary_cache.hits.load(Ordering::Relaxed),
+        shared.cell_boundary_cache.misses.load(Ordering::Relaxed),
+    );
+    Ok(())
+}
+
+/// Print `{"satisfied", "num_constraints", "num_variables"}` for the default
+/// circuit built from the `/prove` body in `request_path`.
+fn dry_run(request_path: Option<&String>, cfg: &PoseidonConfig<Fr>) -> std::io::Result<()> {
+    let path = request_path.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "--dry-run needs the path of a /prove request body",
+        )
+    })?;
+    let body: ProveRequest = serde_json::from_slice(&std::fs::read(path)?)?;
+    if body.h3_map.is_empty() && body.ipfs_map_cid.is_some() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "--dry-run needs h3_map inline, not ipfs_map_cid",
+        ));
+    }
+
+    let (circuit, _) = build_circuit_from_request::<CIRCUIT_MAX_POLYGON_HASHES>(
+        body.lat,
+        body.lon,
+        body.resolution,
+        &body.h3_map,
+        body.mode_id,
+        cfg,
+    )
+    .map_err(|e| Error::new(ErrorKind::InvalidInput, e.to_string()))?;
+    let report = prover::dry_run(circuit).map_err(Error::other)?;
+
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
 }
@@ -0,0 +1,199 @@
+//! Groth16 proving behind a trait, so handler tests can swap the prover
+//! for [`MockProverBackend`] instead of spending seconds per proof.
+
+use std::sync::Arc;
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_groth16::{Groth16, Proof, ProvingKey};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use ark_std::rand::{CryptoRng, RngCore};
+use serde::Serialize;
+
+/// Randomness Groth16 accepts, usable as `&mut dyn ProverRng`.
+pub trait ProverRng: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng> ProverRng for R {}
+
+type Synthesize = Box<dyn FnOnce(ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> + Send>;
+
+/// Any circuit over `Fr`, boxed so [`ProverBackend`] stays object safe
+/// while `/prove` builds circuits for different map sizes.
+pub struct BoxedCircuit(Synthesize);
+
+impl BoxedCircuit {
+    pub fn new<C: ConstraintSynthesizer<Fr> + Send + 'static>(circuit: C) -> Self {
+        Self(Box::new(move |cs| circuit.generate_constraints(cs)))
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for BoxedCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        (self.0)(cs)
+    }
+}
+
+pub trait ProverBackend: Send + Sync {
+    fn prove(
+        &self,
+        circuit: BoxedCircuit,
+        rng: &mut dyn ProverRng,
+    ) -> Result<Proof<Bn254>, SynthesisError>;
+}
+
+/// Real proofs under `pk`.
+pub struct Groth16ProverBackend {
+    pk: Arc<ProvingKey<Bn254>>,
+}
+
+impl Groth16ProverBackend {
+    pub fn new(pk: Arc<ProvingKey<Bn254>>) -> Self {
+        Self { pk }
+    }
+
+    /// The key [`ProverBackend::prove`] proves under.
+    pub fn proving_key(&self) -> &Arc<ProvingKey<Bn254>> {
+        &self.pk
+    }
+}
+
+impl ProverBackend for Groth16ProverBackend {
+    fn prove(
+        &self,
+        circuit: BoxedCircuit,
+        mut rng: &mut dyn ProverRng,
+    ) -> Result<Proof<Bn254>, SynthesisError> {
+        Groth16::<Bn254>::prove(&self.pk, circuit, &mut rng)
+    }
+}
+
+/// Size and satisfiability of a circuit, from [`dry_run`].
+#[derive(Debug, Serialize)]
+pub struct DryRunReport {
+    pub satisfied: bool,
+    pub num_constraints: usize,
+    /// instance (including the constant one) plus witness variables
+    pub num_variables: usize,
+}
+
+/// Synthesise `circuit` and check its witness without proving: seconds
+/// where a Groth16 proof of a large circuit takes minutes.
+pub fn dry_run<C: ConstraintSynthesizer<Fr>>(circuit: C) -> Result<DryRunReport, SynthesisError> {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit.generate_constraints(cs.clone())?;
+    Ok(DryRunReport {
+        satisfied: cs.is_satisfied()?,
+        num_constraints: cs.num_constraints(),
+        num_variables: cs.num_instance_variables() + cs.num_witness_variables(),
+    })
+}
+
+/// Proof of the `TEST_MAX_POLYGON_HASHES` circuit for the centre of H3
+/// cell `872830828ffffff` (resolution 7) in a one-cell map, made with the
+/// `AppState::new_test` keys of its day; base-64 of the uncompressed proof.
+/// Circuit changes rotate those keys, so nothing relies on it verifying.
+#[cfg(any(test, feature = "test-utils"))]
+pub const MOCK_PROOF_B64: &str = concat!(
+    "hriLIBOjZ6mf2a7pevkNCyDrOOpsXb4VJ8YkvKI2TS04Tuhz1FF74m6VGFSw3B6zdI1NH+S0USJZYYtIQ1u4pa",
+    "gY9to5CrZz+HoF2AyZIFnyu3f2FgpihyHp2MN4EhseAiIGNuWtMGZWkJvCb4Xzir4Wno/Y5nbEnBe6nwZt6y5w",
+    "umfaTfa4II/USpVdCVoAkNvIKpy3ZUupMMyVx/BtLaNKtnB/sAfcLIsi+9lu3GId6KJe7K3eYlVYy3fvefCrcv",
+    "k8OqLPT3VuVq3aD3vdXrfbkMrox6ah68+e89VcPwY61LIOx71ttazG6sdT0frgnKuTFW+4pv5WWuv3DIc9Bw==",
+);
+
+/// Skips proving entirely and answers every circuit with
+/// [`MOCK_PROOF_B64`]. The proof does not verify, so use it for handler
+/// logic, not for `/verify` round trips.
+#[cfg(any(test, feature = "test-utils"))]
+pub struct MockProverBackend {
+    proof: Proof<Bn254>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl Default for MockProverBackend {
+    fn default() -> Self {
+        use ark_serialize::CanonicalDeserialize;
+        use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
+
+        let bytes = B64.decode(MOCK_PROOF_B64).expect("mock proof is base-64");
+        Self {
+            proof: Proof::deserialize_uncompressed(&bytes[..]).expect("mock proof decodes"),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl ProverBackend for MockProverBackend {
+    fn prove(
+        &self,
+        _circuit: BoxedCircuit,
+        _rng: &mut dyn ProverRng,
+    ) -> Result<Proof<Bn254>, SynthesisError> {
+        Ok(self.proof.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+    use ark_groth16::prepare_verifying_key;
+
+    use crate::{
+        api::prove::{build_circuit_from_request, to_b64},
+        keys::setup_keys,
+        zk::{
+            circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+            poseidon::default_poseidon_config,
+        },
+    };
+
+    #[test]
+    fn mock_answers_any_circuit_with_the_fixture() {
+        let (circuit, _) =
+            build_circuit_from_request::<2>(37.77, -122.42, 7, &[], 0, &default_poseidon_config())
+                .unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let proof = MockProverBackend::default()
+            .prove(BoxedCircuit::new(circuit), &mut rng)
+            .unwrap();
+        assert_eq!(to_b64(&proof), MOCK_PROOF_B64);
+    }
+
+    #[test]
+    fn concurrent_proofs_share_one_proving_key() {
+        let cfg = default_poseidon_config();
+        let (pk, vk) = setup_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, 2>(&cfg, 1);
+        let pvk = prepare_verifying_key(&vk);
+        let pk = Arc::new(pk);
+        let backend = Arc::new(Groth16ProverBackend::new(pk.clone()));
+
+        let handles: Vec<_> = (0..3)
+            .map(|seed| {
+                let (backend, cfg) = (backend.clone(), cfg.clone());
+                std::thread::spawn(move || {
+                    let (circuit, publics) =
+                        build_circuit_from_request::<2>(37.77, -122.42, 7, &[], 0, &cfg).unwrap();
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let proof = backend.prove(BoxedCircuit::new(circuit), &mut rng).unwrap();
+                    (proof, publics, backend.proving_key().clone())
+                })
+            })
+            .collect();
+        for handle in handles {
+            let (proof, publics, used) = handle.join().unwrap();
+            assert!(
+                Arc::ptr_eq(&used, &pk),
+                "worker proved under a copy of the key"
+            );
+            assert!(Groth16::<Bn254>::verify_with_processed_vk(&pvk, &publics, &proof).unwrap());
+        }
+
+        // the workers' handles are gone: only `pk` and the backend held it
+        drop(backend);
+        assert_eq!(Arc::strong_count(&pk), 1);
+    }
+}
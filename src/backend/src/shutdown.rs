@@ -0,0 +1,109 @@
+//! Graceful shutdown on `SIGTERM` or `SIGINT` (Ctrl-C).
+//!
+//! The server stops accepting connections, waits for the proofs already
+//! being generated (counted by [`InFlightGuard`]) and only then stops its
+//! workers, so a deploy never cuts a client off mid-proof.
+
+use std::{
+    io,
+    pin::pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use actix_web::{dev::Server, rt};
+use futures_util::future::{Either, select};
+use tokio::signal::unix::{SignalKind, signal};
+
+/// How often the drain checks the in-flight counter.
+pub const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Longest the drain waits for in-flight proofs before stopping anyway.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Counts one request in the in-flight counter for as long as it lives,
+/// including when the handler returns early with an error.
+pub struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    pub fn enter(in_flight: &Arc<AtomicUsize>) -> Self {
+        in_flight.fetch_add(1, Ordering::SeqCst);
+        Self(in_flight.clone())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Wait until `in_flight` reaches zero, checking every
+/// [`DRAIN_POLL_INTERVAL`]; `false` if `timeout` expired first.
+pub async fn wait_for_drain(in_flight: &AtomicUsize, timeout: Duration) -> bool {
+    let started = Instant::now();
+    while in_flight.load(Ordering::SeqCst) > 0 {
+        if started.elapsed() >= timeout {
+            return false;
+        }
+        rt::time::sleep(DRAIN_POLL_INTERVAL).await;
+    }
+    true
+}
+
+/// Stop `server` gracefully once the process gets `SIGTERM` or `SIGINT`:
+/// pause accepting, drain `in_flight` for at most `timeout`, then stop.
+///
+/// The handlers are installed before this returns, so build the server with
+/// `disable_signals()` and call it from inside the runtime before awaiting
+/// `server`.
+pub fn drain_on_shutdown_signal(
+    server: &Server,
+    in_flight: Arc<AtomicUsize>,
+    timeout: Duration,
+) -> io::Result<()> {
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    let handle = server.handle();
+    rt::spawn(async move {
+        let name = match select(pin!(sigterm.recv()), pin!(sigint.recv())).await {
+            Either::Left((Some(()), _)) => "SIGTERM",
+            Either::Right((Some(()), _)) => "SIGINT",
+            _ => return,
+        };
+        println!("{name}: no longer accepting connections, draining in-flight proofs");
+        handle.pause().await;
+        if !wait_for_drain(&in_flight, timeout).await {
+            println!(
+                "{name}: {} proofs still running after {timeout:?}, stopping anyway",
+                in_flight.load(Ordering::SeqCst)
+            );
+        }
+        handle.stop(true).await;
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn drain_waits_for_guards_and_gives_up_on_timeout() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        assert!(wait_for_drain(&in_flight, Duration::ZERO).await);
+
+        let guard = InFlightGuard::enter(&in_flight);
+        assert_eq!(in_flight.load(Ordering::SeqCst), 1);
+        assert!(!wait_for_drain(&in_flight, DRAIN_POLL_INTERVAL).await);
+
+        rt::spawn(async move {
+            rt::time::sleep(DRAIN_POLL_INTERVAL).await;
+            drop(guard);
+        });
+        assert!(wait_for_drain(&in_flight, DRAIN_TIMEOUT).await);
+    }
+}
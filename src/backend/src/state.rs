@@ -1,26 +1,530 @@
-use std::{io::Result, sync::Arc};
+use std::{
+    collections::HashMap,
+    io::{Error, ErrorKind, Result},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
 use actix_web::web::Data;
 use ark_bn254::{Bn254, Fr};
 use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
 use ark_groth16::{PreparedVerifyingKey, ProvingKey};
+use h3o::{CellIndex, Resolution};
 
+use crate::{
+    cache::LruCache,
+    config::{
+        Config, DEFAULT_IPFS_GATEWAY_URL, DEFAULT_VERIFY_MIN_LATENCY_MS, DEFAULT_VERIFY_TIMEOUT_MS,
+    },
+    keys::key_version,
+    prover::{Groth16ProverBackend, ProverBackend},
+    zk::{
+        circuit::{CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        geo_import::Arena,
+        map::{IpfsMapCache, load_h3_map_from_file},
+        non_membership::NON_MEMBERSHIP_MAX_ZONES,
+        polygon_ops::polygon_from_h3_cell,
+        poseidon::validate_poseidon_config,
+        public_inputs::{PublicInputField, public_inputs_schema},
+        types::{DefaultF, DefaultPoint},
+    },
+};
+
+/// Prepared VKs by SHA-256 of their uncompressed bytes.
+pub type PreparedVkCache = LruCache<[u8; 32], Arc<PreparedVerifyingKey<Bn254>>>;
+
+/// Proving and verifying keys of one circuit, as `keys` loads them.
+pub type KeyPair = (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>);
+
+/// Map slots with the prover and VK of the circuit that has them.
+pub type HashCountKeys = (
+    usize,
+    Arc<dyn ProverBackend>,
+    Arc<PreparedVerifyingKey<Bn254>>,
+);
+
+/// Arena zones at the `/prove` circuit's precision and vertex count.
+pub type DefaultArena = Arena<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>;
+
+/// Map slots of the reduced circuit behind [`AppState::new_test`].
+#[cfg(any(test, feature = "test-utils"))]
+pub const TEST_MAX_POLYGON_HASHES: usize = 4;
+
+/// Zone slots of the reduced non-membership circuit behind [`AppState::new_test`].
+#[cfg(any(test, feature = "test-utils"))]
+pub const TEST_NON_MEMBERSHIP_MAX_ZONES: usize = 2;
+
+/// Hashes of the last `h3_map` seen by `/prove`, reused while clients keep
+/// sending the same map.
+pub struct PrecomputedMap {
+    pub cell_hashes: HashMap<CellIndex, Fr>,
+    /// SHA-256 of the `(mode_id, h3_map)` JSON, reduced into `Fr`
+    pub source_hash: Fr,
+}
+
+/// Entries [`CellBoundaryCache`] holds before evicting.
+pub const CELL_BOUNDARY_CACHE_CAPACITY: usize = 10_000;
+
+/// VKs [`AppState::vk_cache`] holds before evicting.
+pub const VK_CACHE_CAPACITY: usize = 64;
+
+/// Prepared VKs [`AppState::prepared_vk_cache`] holds before evicting.
+pub const PREPARED_VK_CACHE_CAPACITY: usize = 64;
+
+/// Maps [`AppState::ipfs_map_cache`] holds before evicting.
+pub const IPFS_MAP_CACHE_CAPACITY: usize = 256;
+
+/// A cell boundary as `polygon_from_h3_cell` returns it: Web Mercator
+/// vertices padded to `CIRCUIT_MAX_VERTICES`, and the real vertex count.
+pub type CellBoundary = ([DefaultPoint; CIRCUIT_MAX_VERTICES], usize);
+
+/// Projected H3 cell boundaries, least recently used evicted first.
+///
+/// Boundaries never change, but resolution 7 alone has ~842k cells, so the
+/// cache is capped.
+pub struct CellBoundaryCache {
+    entries: LruCache<CellIndex, CellBoundary>,
+    pub hits: AtomicU64,
+    pub misses: AtomicU64,
+}
+
+impl CellBoundaryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(capacity),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The boundary of `cell`, projected on a miss.
+    pub fn get(&self, cell: CellIndex) -> CellBoundary {
+        if let Some(boundary) = self.entries.get(&cell) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return boundary;
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let boundary =
+            polygon_from_h3_cell::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(cell);
+        self.entries.insert(cell, boundary);
+        boundary
+    }
+}
+
+/// Shared by every worker behind one `Arc`.
+///
+/// Only the caches are written after startup: the [`LruCache`]s lock per
+/// shard, and `precomputed_map` is read far more often than replaced.
+/// Keys and the Poseidon config are read-only; they sit in their own `Arc`
+/// so handlers can move them into `web::block` for proving.
 pub struct AppState {
-    pub pk: ProvingKey<Bn254>,
-    pub pvk: PreparedVerifyingKey<Bn254>,
-    pub poseidon_config: PoseidonConfig<Fr>,
+    pub pk: Arc<ProvingKey<Bn254>>,
+    /// proves `/prove` circuits under `pk`
+    pub prover: Arc<dyn ProverBackend>,
+    pub pvk: Arc<PreparedVerifyingKey<Bn254>>,
+    /// [`key_version`] of `pvk`, reported so clients notice key rotation;
+    /// see [`Self::key_version_of`] for the `hash_count_keys`
+    pub key_version: String,
+    pub poseidon_config: Arc<PoseidonConfig<Fr>>,
+
+    pub max_vertices: usize,
+    pub max_polygon_hashes: usize,
+    /// `(map slots, prover, VK)` for the `MAP_HASH_COUNTS` circuits, by
+    /// ascending size; see [`Self::keys_for_map`]
+    pub hash_count_keys: Vec<HashCountKeys>,
+    /// `/prove?debug=true` is refused unless set (`ALLOW_DEBUG_ENDPOINT`)
+    pub allow_debug: bool,
+    /// `/admin/*` answers `403` unless set (`ALLOW_ADMIN_ENDPOINTS`)
+    pub allow_admin: bool,
+    /// H3 resolutions `/prove` accepts, ascending (`SUPPORTED_RESOLUTIONS`)
+    pub supported_resolutions: Vec<Resolution>,
+    /// `/verify` answers no sooner than this (`VERIFY_MIN_LATENCY_MS`)
+    pub verify_min_latency: Duration,
+    /// `/verify` gives up after this (`VERIFY_TIMEOUT_MS`)
+    pub verify_timeout: Duration,
+    pub public_inputs_schema: Vec<PublicInputField>,
+
+    /// proving key for `SectorCircuit` (`/prove-sector`); clients verify
+    /// with `params/sector_verifying_key.bin`
+    pub sector_pk: Arc<ProvingKey<Bn254>>,
+
+    /// proving key for `NonMembershipCircuit` (`/prove-outside`); clients
+    /// verify with `params/non_membership_verifying_key.bin`
+    pub non_membership_pk: Arc<ProvingKey<Bn254>>,
+    pub non_membership_max_zones: usize,
+
+    /// proving key for the `CIRCUIT_MAX_VERTICES_LARGE` circuit
+    /// (`/prove-large`); `None` unless the server runs with `--large-polygon`
+    pub large_pk: Option<Arc<ProvingKey<Bn254>>>,
+
+    /// VKs seen by `/verify-with-key`, keyed by hex SHA-256 of their bytes.
+    pub vk_cache: Arc<LruCache<String, Arc<PreparedVerifyingKey<Bn254>>>>,
+    /// see [`prepare_verifying_key_cached`]
+    ///
+    /// [`prepare_verifying_key_cached`]: crate::keys::prepare_verifying_key_cached
+    pub prepared_vk_cache: Arc<PreparedVkCache>,
+    /// gateway `/prove` fetches `ipfs_map_cid` from (`IPFS_GATEWAY_URL`)
+    pub ipfs_gateway_url: String,
+    /// maps fetched for `ipfs_map_cid`, by CID
+    pub ipfs_map_cache: Arc<IpfsMapCache>,
+    /// map `/prove` falls back to when a request sends none (`H3_MAP_FILE`);
+    /// empty when unset
+    pub default_h3_map: Vec<String>,
+
+    pub precomputed_map: Arc<RwLock<Option<PrecomputedMap>>>,
+    /// zones from the last `/admin/load-arena-geojson`, listed by `/admin/arena`
+    pub arena: Arc<RwLock<DefaultArena>>,
+    /// `/prove` calls served from `precomputed_map`
+    pub map_cache_hits: AtomicU64,
+    /// boundaries of the cells `/prove` requests land in
+    pub cell_boundary_cache: Arc<CellBoundaryCache>,
+    /// proofs being generated by any `/prove*` endpoint; drained on shutdown
+    pub in_flight_proofs: Arc<AtomicUsize>,
+}
+
+fn hash_count_keys_from(keys: Vec<(usize, KeyPair)>) -> Vec<HashCountKeys> {
+    let mut keys: Vec<HashCountKeys> = keys
+        .into_iter()
+        .map(|(n, (pk, pvk))| {
+            let prover: Arc<dyn ProverBackend> = Arc::new(Groth16ProverBackend::new(Arc::new(pk)));
+            (n, prover, Arc::new(pvk))
+        })
+        .collect();
+    keys.sort_by_key(|(n, ..)| *n);
+    keys
 }
 
 impl AppState {
+    /// Map slots, prover and VK for a map of `len` cells: the smallest
+    /// circuit it fits, or the default one when none is big enough.
+    pub fn keys_for_map(
+        &self,
+        len: usize,
+    ) -> (
+        usize,
+        &Arc<dyn ProverBackend>,
+        &Arc<PreparedVerifyingKey<Bn254>>,
+    ) {
+        let default = (self.max_polygon_hashes, &self.prover, &self.pvk);
+        self.hash_count_keys
+            .iter()
+            .map(|(n, prover, pvk)| (*n, prover, pvk))
+            .chain([default])
+            .filter(|(n, ..)| *n >= len)
+            .min_by_key(|(n, ..)| *n)
+            .unwrap_or(default)
+    }
+
+    /// [`key_version`] of `pvk`, which is one of this state's VKs.
+    pub fn key_version_of(&self, pvk: &PreparedVerifyingKey<Bn254>) -> String {
+        if std::ptr::eq(pvk, self.pvk.as_ref()) {
+            return self.key_version.clone();
+        }
+        key_version(&pvk.vk)
+    }
+
+    /// VK of the circuit with `n_hashes` map slots, if the server has one.
+    pub fn pvk_for_hash_count(&self, n_hashes: usize) -> Option<&Arc<PreparedVerifyingKey<Bn254>>> {
+        if n_hashes == self.max_polygon_hashes {
+            return Some(&self.pvk);
+        }
+        self.hash_count_keys
+            .iter()
+            .find(|(n, ..)| *n == n_hashes)
+            .map(|(_, _, pvk)| pvk)
+    }
+
+    fn build(
+        (pk, pvk): (ProvingKey<Bn254>, PreparedVerifyingKey<Bn254>),
+        sector_pk: ProvingKey<Bn254>,
+        non_membership_pk: ProvingKey<Bn254>,
+        poseidon_config: PoseidonConfig<Fr>,
+        max_vertices: usize,
+        max_polygon_hashes: usize,
+        non_membership_max_zones: usize,
+    ) -> Self {
+        let pk = Arc::new(pk);
+        Self {
+            key_version: key_version(&pvk.vk),
+            prover: Arc::new(Groth16ProverBackend::new(pk.clone())),
+            pk,
+            pvk: Arc::new(pvk),
+            poseidon_config: Arc::new(poseidon_config),
+            max_vertices,
+            max_polygon_hashes,
+            hash_count_keys: Vec::new(),
+            allow_debug: false,
+            allow_admin: false,
+            supported_resolutions: Resolution::range(Resolution::Zero, Resolution::Fifteen)
+                .collect(),
+            verify_min_latency: Duration::from_millis(DEFAULT_VERIFY_MIN_LATENCY_MS),
+            verify_timeout: Duration::from_millis(DEFAULT_VERIFY_TIMEOUT_MS),
+            public_inputs_schema: public_inputs_schema(max_polygon_hashes),
+            sector_pk: Arc::new(sector_pk),
+            non_membership_pk: Arc::new(non_membership_pk),
+            non_membership_max_zones,
+            large_pk: None,
+            vk_cache: Arc::new(LruCache::new(VK_CACHE_CAPACITY)),
+            prepared_vk_cache: Arc::new(LruCache::new(PREPARED_VK_CACHE_CAPACITY)),
+            ipfs_gateway_url: DEFAULT_IPFS_GATEWAY_URL.to_owned(),
+            ipfs_map_cache: Arc::new(LruCache::new(IPFS_MAP_CACHE_CAPACITY)),
+            default_h3_map: Vec::new(),
+            precomputed_map: Arc::new(RwLock::new(None)),
+            arena: Arc::new(RwLock::new(DefaultArena::new())),
+            map_cache_hits: AtomicU64::new(0),
+            cell_boundary_cache: Arc::new(CellBoundaryCache::new(CELL_BOUNDARY_CACHE_CAPACITY)),
+            in_flight_proofs: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// State for the served circuits, with the settings of `config`; reads
+    /// its `H3_MAP_FILE`.
     pub fn init(
-        pk: ProvingKey<Bn254>,
-        pvk: PreparedVerifyingKey<Bn254>,
+        keys: KeyPair,
+        hash_count_keys: Vec<(usize, KeyPair)>,
+        sector_pk: ProvingKey<Bn254>,
+        non_membership_pk: ProvingKey<Bn254>,
+        large_pk: Option<ProvingKey<Bn254>>,
         poseidon_config: PoseidonConfig<Fr>,
+        config: &Config,
     ) -> Result<Data<Arc<Self>>> {
+        validate_poseidon_config(&poseidon_config)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+        let default_h3_map = config
+            .h3_map_file
+            .as_deref()
+            .map(load_h3_map_from_file)
+            .transpose()
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("H3_MAP_FILE: {e}")))?
+            .unwrap_or_default();
+
         Ok(Data::new(Arc::new(Self {
-            pk,
-            pvk,
-            poseidon_config,
+            allow_debug: config.allow_debug_endpoint,
+            allow_admin: config.allow_admin_endpoints,
+            supported_resolutions: config.supported_resolutions.clone(),
+            verify_min_latency: Duration::from_millis(config.verify_min_latency_ms),
+            verify_timeout: Duration::from_millis(config.verify_timeout_ms),
+            ipfs_gateway_url: config.ipfs_gateway_url.clone(),
+            default_h3_map,
+            hash_count_keys: hash_count_keys_from(hash_count_keys),
+            large_pk: large_pk.map(Arc::new),
+            ..Self::build(
+                keys,
+                sector_pk,
+                non_membership_pk,
+                poseidon_config,
+                CIRCUIT_MAX_VERTICES,
+                CIRCUIT_MAX_POLYGON_HASHES,
+                NON_MEMBERSHIP_MAX_ZONES,
+            )
         })))
     }
+
+    /// State backed by a small circuit (`TEST_MAX_POLYGON_HASHES` map slots)
+    /// whose keys are generated once with `seed_from_u64(1)` and shared by
+    /// every caller in the process.
+    ///
+    /// Vertices stay at `CIRCUIT_MAX_VERTICES`: a triangle circuit would set
+    /// up faster, but no H3 cell fits in one, so `/prove` could never succeed.
+    /// `/prove-outside` gets `TEST_NON_MEMBERSHIP_MAX_ZONES` zone slots,
+    /// `/prove-large` is enabled with the same map slots, maps of up to
+    /// `SUPPORTED_HASH_COUNTS[0]` cells get that circuit, and
+    /// `/prove?debug=true` is allowed.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_test() -> Data<Arc<Self>> {
+        use std::sync::OnceLock;
+
+        use ark_groth16::prepare_verifying_key;
+
+        use crate::{
+            keys::{
+                SUPPORTED_HASH_COUNTS, generate_keys_for_hash_count, setup_keys,
+                setup_non_membership_keys, setup_sector_keys,
+            },
+            zk::{circuit::CIRCUIT_MAX_VERTICES_LARGE, poseidon::default_poseidon_config},
+        };
+
+        static STATE: OnceLock<Data<Arc<AppState>>> = OnceLock::new();
+
+        STATE
+            .get_or_init(|| {
+                let cfg = default_poseidon_config();
+                let (pk, vk) =
+                    setup_keys::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, TEST_MAX_POLYGON_HASHES>(
+                        &cfg, 1,
+                    );
+
+                let (sector_pk, _) = setup_sector_keys::<CIRCUIT_PRECISION>(1);
+                let (nm_pk, _) = setup_non_membership_keys::<
+                    CIRCUIT_PRECISION,
+                    CIRCUIT_MAX_VERTICES,
+                    TEST_NON_MEMBERSHIP_MAX_ZONES,
+                >(&cfg, 1);
+                let (large_pk, _) = setup_keys::<
+                    CIRCUIT_PRECISION,
+                    CIRCUIT_MAX_VERTICES_LARGE,
+                    TEST_MAX_POLYGON_HASHES,
+                >(&cfg, 1);
+
+                let n = SUPPORTED_HASH_COUNTS[0];
+                let extra_keys = generate_keys_for_hash_count::<
+                    CIRCUIT_PRECISION,
+                    CIRCUIT_MAX_VERTICES,
+                >(n, &cfg);
+
+                Data::new(Arc::new(Self {
+                    allow_debug: true,
+                    allow_admin: true,
+                    hash_count_keys: hash_count_keys_from(vec![(n, extra_keys)]),
+                    large_pk: Some(Arc::new(large_pk)),
+                    ..Self::build(
+                        (pk, prepare_verifying_key(&vk)),
+                        sector_pk,
+                        nm_pk,
+                        cfg,
+                        CIRCUIT_MAX_VERTICES,
+                        TEST_MAX_POLYGON_HASHES,
+                        TEST_NON_MEMBERSHIP_MAX_ZONES,
+                    )
+                }))
+            })
+            .clone()
+    }
+
+    /// [`Self::new_test`] with `/prove` answered by [`MockProverBackend`]:
+    /// same keys and settings, fresh caches.
+    ///
+    /// [`MockProverBackend`]: crate::prover::MockProverBackend
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_test_mock_prover() -> Data<Arc<Self>> {
+        use crate::prover::MockProverBackend;
+
+        Data::new(Arc::new(Self {
+            prover: Arc::new(MockProverBackend::default()),
+            ..Self::new_test().test_copy()
+        }))
+    }
+
+    /// [`Self::new_test`] accepting only `resolutions` on `/prove`.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_test_with_resolutions(resolutions: &[Resolution]) -> Data<Arc<Self>> {
+        Data::new(Arc::new(Self {
+            supported_resolutions: resolutions.to_vec(),
+            ..Self::new_test().test_copy()
+        }))
+    }
+
+    /// [`Self::new_test`] fetching `ipfs_map_cid` from `gateway`.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_test_with_ipfs_gateway(gateway: &str) -> Data<Arc<Self>> {
+        Data::new(Arc::new(Self {
+            ipfs_gateway_url: gateway.to_owned(),
+            ..Self::new_test().test_copy()
+        }))
+    }
+
+    /// [`Self::new_test`] falling back to `map` when `/prove` is sent none.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn new_test_with_default_h3_map(map: Vec<String>) -> Data<Arc<Self>> {
+        Data::new(Arc::new(Self {
+            default_h3_map: map,
+            ..Self::new_test().test_copy()
+        }))
+    }
+
+    // the shared test keys and settings with fresh caches
+    #[cfg(any(test, feature = "test-utils"))]
+    fn test_copy(&self) -> Self {
+        Self {
+            pk: self.pk.clone(),
+            prover: self.prover.clone(),
+            pvk: self.pvk.clone(),
+            key_version: self.key_version.clone(),
+            poseidon_config: self.poseidon_config.clone(),
+            max_vertices: self.max_vertices,
+            max_polygon_hashes: self.max_polygon_hashes,
+            hash_count_keys: self.hash_count_keys.clone(),
+            allow_debug: self.allow_debug,
+            allow_admin: self.allow_admin,
+            supported_resolutions: self.supported_resolutions.clone(),
+            verify_min_latency: self.verify_min_latency,
+            verify_timeout: self.verify_timeout,
+            public_inputs_schema: self.public_inputs_schema.clone(),
+            sector_pk: self.sector_pk.clone(),
+            non_membership_pk: self.non_membership_pk.clone(),
+            non_membership_max_zones: self.non_membership_max_zones,
+            large_pk: self.large_pk.clone(),
+            vk_cache: Arc::new(LruCache::new(VK_CACHE_CAPACITY)),
+            prepared_vk_cache: Arc::new(LruCache::new(PREPARED_VK_CACHE_CAPACITY)),
+            ipfs_gateway_url: self.ipfs_gateway_url.clone(),
+            ipfs_map_cache: Arc::new(LruCache::new(IPFS_MAP_CACHE_CAPACITY)),
+            default_h3_map: self.default_h3_map.clone(),
+            precomputed_map: Arc::new(RwLock::new(None)),
+            arena: Arc::new(RwLock::new(DefaultArena::new())),
+            map_cache_hits: AtomicU64::new(0),
+            cell_boundary_cache: Arc::new(CellBoundaryCache::new(CELL_BOUNDARY_CACHE_CAPACITY)),
+            in_flight_proofs: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use h3o::{LatLng, Resolution};
+
+    fn cells(n: usize) -> Vec<CellIndex> {
+        let origin = LatLng::new(13.7563, 100.5018)
+            .unwrap()
+            .to_cell(Resolution::Seven);
+        origin.grid_disk::<Vec<_>>(4).into_iter().take(n).collect()
+    }
+
+    #[test]
+    fn boundary_cache_counts_hits_and_misses() {
+        let cache = CellBoundaryCache::new(8);
+        let cell = cells(1)[0];
+
+        let coords = |(poly, n): CellBoundary| {
+            poly[..n]
+                .iter()
+                .map(|p| (p.x.to_f64(), p.y.to_f64()))
+                .collect::<Vec<_>>()
+        };
+        let fresh = polygon_from_h3_cell::<DefaultF, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(cell);
+        assert_eq!(coords(cache.get(cell)), coords(fresh));
+        assert_eq!(coords(cache.get(cell)), coords(fresh));
+        assert_eq!(cache.misses.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.hits.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn boundary_cache_evicts_least_recently_used() {
+        let cache = CellBoundaryCache::new(4);
+        let cells = cells(5);
+        for &cell in &cells[..4] {
+            cache.get(cell);
+        }
+        // touch cell 0 so cell 1 is now the oldest
+        cache.get(cells[0]);
+        cache.get(cells[4]);
+
+        assert_eq!(cache.len(), 4);
+        assert!(cache.entries.contains_key(&cells[0]));
+        assert!(!cache.entries.contains_key(&cells[1]));
+        assert!(cache.entries.contains_key(&cells[4]));
+    }
 }
@@ -0,0 +1,260 @@
+//! Many `PointInMapCircuit` proofs under one proving key at once, e.g.
+//! every player's location at the end of a tournament round.
+//!
+//! Each proof is independent, so the batch is split across the rayon pool;
+//! within a proof, Groth16's own MSMs stay parallel as well. Every circuit
+//! gets its own RNG seeded from the caller's, so proofs never share
+//! blinding factors.
+//!
+//! Groth16 synthesises a circuit once per proof, but it also rebuilds and
+//! inlines the R1CS matrices every time, although they only depend on
+//! `PREC`, `MAX_VERTICES` and `MAX_HASHES`. [`CachedConstraints`] builds
+//! them once, so later proofs only compute the witness.
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::{Groth16, Proof, ProvingKey};
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, OptimizationGoal, SynthesisError,
+    SynthesisMode,
+};
+use ark_std::rand::{Rng, SeedableRng, rngs::StdRng};
+use rayon::prelude::*;
+
+use crate::zk::circuit::PointInMapCircuit;
+
+/// Prove every circuit under `pk`; results are in the order of `circuits`,
+/// and one failing circuit does not fail the others.
+pub fn prove_batch<const PREC: u32, const MAX_VERTICES: usize, const MAX_HASHES: usize>(
+    circuits: Vec<PointInMapCircuit<Fr, PREC, MAX_VERTICES, MAX_HASHES>>,
+    pk: &ProvingKey<Bn254>,
+    rng: &mut StdRng,
+) -> Vec<Result<Proof<Bn254>, SynthesisError>> {
+    seed_each(circuits, rng)
+        .into_par_iter()
+        .map(|(circuit, mut rng)| Groth16::<Bn254>::prove(pk, circuit, &mut rng))
+        .collect()
+}
+
+// pair every circuit with its own RNG, seeded in order from `rng`
+fn seed_each<C>(circuits: Vec<C>, rng: &mut StdRng) -> Vec<(C, StdRng)> {
+    circuits
+        .into_iter()
+        .map(|circuit| {
+            let rng = StdRng::from_rng(&mut *rng).expect("StdRng seeds from StdRng");
+            (circuit, rng)
+        })
+        .collect()
+}
+
+/// The R1CS matrices of one circuit shape, shared by every proof of a
+/// circuit with the same const parameters.
+pub struct CachedConstraints<F: PrimeField> {
+    matrices: ConstraintMatrices<F>,
+}
+
+impl<F: PrimeField> CachedConstraints<F> {
+    /// Synthesise `template` in setup mode – its witness is never read –
+    /// and keep the inlined matrices.
+    pub fn new<C: ConstraintSynthesizer<F>>(template: C) -> Result<Self, SynthesisError> {
+        let cs = ConstraintSystem::new_ref();
+        // the same goal Groth16 proves under, so the inlined LCs match
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        cs.set_mode(SynthesisMode::Setup);
+        template.generate_constraints(cs.clone())?;
+        cs.finalize();
+        let matrices = cs.to_matrices().ok_or(SynthesisError::MissingCS)?;
+        Ok(Self { matrices })
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.matrices.num_constraints
+    }
+}
+
+impl CachedConstraints<Fr> {
+    /// `Groth16::prove`, but the circuit is only run for its witness and the
+    /// cached matrices stand in for the ones it would build.
+    ///
+    /// A circuit of another shape fails with `SynthesisError::Unsatisfiable`:
+    /// its variable and constraint counts are always compared, and in debug
+    /// builds its full matrices are too. Release builds skip building them,
+    /// which is what the cache is for.
+    pub fn prove<C: ConstraintSynthesizer<Fr>, R: Rng>(
+        &self,
+        pk: &ProvingKey<Bn254>,
+        circuit: C,
+        rng: &mut R,
+    ) -> Result<Proof<Bn254>, SynthesisError> {
+        let check_matrices = cfg!(debug_assertions);
+        let cs = ConstraintSystem::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        cs.set_mode(SynthesisMode::Prove {
+            construct_matrices: check_matrices,
+        });
+        circuit.generate_constraints(cs.clone())?;
+
+        let num_inputs = cs.num_instance_variables();
+        let num_constraints = cs.num_constraints();
+        let shape = (num_inputs, cs.num_witness_variables(), num_constraints);
+        let cached = &self.matrices;
+        if shape
+            != (
+                cached.num_instance_variables,
+                cached.num_witness_variables,
+                cached.num_constraints,
+            )
+        {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        if check_matrices {
+            cs.finalize();
+            if cs.to_matrices().as_ref() != Some(cached) {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+        let full_assignment = {
+            let cs = cs.borrow().ok_or(SynthesisError::MissingCS)?;
+            [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat()
+        };
+
+        // drawn in the order `Groth16::prove` draws them
+        let r = Fr::rand(rng);
+        let s = Fr::rand(rng);
+        Groth16::<Bn254>::create_proof_with_reduction_and_matrices(
+            pk,
+            r,
+            s,
+            &self.matrices,
+            num_inputs,
+            num_constraints,
+            &full_assignment,
+        )
+    }
+}
+
+/// [`prove_batch`] with `use_cached` choosing between plain `Groth16::prove`
+/// and [`CachedConstraints::prove`]; the matrices are built from `template`.
+pub fn prove_batch_cached<const PREC: u32, const MAX_VERTICES: usize, const MAX_HASHES: usize>(
+    template: PointInMapCircuit<Fr, PREC, MAX_VERTICES, MAX_HASHES>,
+    circuits: Vec<PointInMapCircuit<Fr, PREC, MAX_VERTICES, MAX_HASHES>>,
+    pk: &ProvingKey<Bn254>,
+    rng: &mut StdRng,
+    use_cached: bool,
+) -> Result<Vec<Result<Proof<Bn254>, SynthesisError>>, SynthesisError> {
+    if !use_cached {
+        return Ok(prove_batch(circuits, pk, rng));
+    }
+    let cache = CachedConstraints::new(template)?;
+    Ok(seed_each(circuits, rng)
+        .into_par_iter()
+        .map(|(circuit, mut rng)| cache.prove(pk, circuit, &mut rng))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ff::{One, Zero};
+    use ark_groth16::prepare_verifying_key;
+
+    use crate::{
+        keys::setup_keys,
+        zk::{
+            circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+            point_2d::Point2DDec,
+            polygon_ops::hash_polygon,
+            poseidon::default_poseidon_config,
+        },
+    };
+
+    const PREC: u32 = CIRCUIT_PRECISION;
+    const MAX: usize = CIRCUIT_MAX_VERTICES;
+
+    #[test]
+    fn batch_proofs_verify_in_order() {
+        let cfg = default_poseidon_config();
+        let (pk, vk) = setup_keys::<PREC, MAX, 2>(&cfg, 1);
+        let pvk = prepare_verifying_key(&vk);
+
+        // square of side 200 around the origin, padded with vertex 0
+        let mut poly = [Point2DDec::<Fr, PREC>::from_f64(100.0, 100.0); MAX];
+        for (p, (x, y)) in poly.iter_mut().zip([
+            (100.0, 100.0),
+            (-100.0, 100.0),
+            (-100.0, -100.0),
+            (100.0, -100.0),
+        ]) {
+            *p = Point2DDec::from_f64(x, y);
+        }
+        let hashes = [hash_polygon::<Fr, PREC, MAX>(&poly, 4, 0, &cfg), Fr::zero()];
+        let circuit = |x: f64, inside: bool| {
+            PointInMapCircuit::<Fr, PREC, MAX, 2>::new(
+                Point2DDec::from_f64(x, 0.0),
+                poly,
+                4,
+                inside,
+                hashes,
+                0,
+                cfg.clone(),
+                false,
+            )
+        };
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let proofs = prove_batch(
+            vec![
+                circuit(0.0, true),
+                circuit(500.0, false),
+                circuit(50.0, true),
+            ],
+            &pk,
+            &mut rng,
+        );
+        assert_eq!(proofs.len(), 3);
+
+        for (proof, inside) in proofs.iter().zip([true, false, true]) {
+            let flag = if inside { Fr::one() } else { Fr::zero() };
+            let publics = [flag, hashes[0], hashes[1]];
+            let proof = proof.as_ref().unwrap();
+            assert!(Groth16::<Bn254>::verify_with_processed_vk(&pvk, &publics, proof).unwrap());
+        }
+        assert_ne!(proofs[0].as_ref().unwrap(), proofs[2].as_ref().unwrap());
+
+        // the cached matrices give the very proof Groth16::prove gives
+        let plain = prove_batch(vec![circuit(0.0, true)], &pk, &mut StdRng::seed_from_u64(1));
+        let cached = prove_batch_cached(
+            circuit(0.0, true),
+            vec![circuit(0.0, true)],
+            &pk,
+            &mut StdRng::seed_from_u64(1),
+            true,
+        )
+        .unwrap();
+        assert_eq!(plain[0].as_ref().unwrap(), cached[0].as_ref().unwrap());
+
+        // a circuit of another shape fails on its own, without a panic
+        let other_shape = PointInMapCircuit::<Fr, PREC, MAX, 2>::new(
+            Point2DDec::from_f64(0.0, 0.0),
+            poly,
+            4,
+            true,
+            hashes,
+            0,
+            cfg.clone(),
+            true,
+        );
+        let mixed = prove_batch_cached(
+            circuit(0.0, true),
+            vec![other_shape, circuit(0.0, true)],
+            &pk,
+            &mut StdRng::seed_from_u64(1),
+            true,
+        )
+        .unwrap();
+        assert!(matches!(mixed[0], Err(SynthesisError::Unsatisfiable)));
+        assert!(mixed[1].is_ok());
+    }
+}
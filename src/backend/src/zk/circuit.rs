@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, fmt};
 
 use ark_crypto_primitives::sponge::{
     Absorb, CryptographicSponge,
@@ -8,26 +8,50 @@ use ark_crypto_primitives::sponge::{
 use ark_ff::PrimeField;
 use ark_r1cs_std::{
     boolean::Boolean,
+    eq::EqGadget,
     fields::{FieldVar, fp::FpVar},
 };
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use schemars::JsonSchema;
+use serde::Serialize;
 
 use crate::zk::{
     fixed_point_decimal::{Dec, DecVar},
     point_2d::{Point2DDec, Point2DDecVar},
+    polygon_ops::{
+        POLYGON_HASH_PADDING_MODE, POLYGON_HASH_SEPARATOR, PaddingMode, hash_polygon,
+        hash_polygon_with_context,
+    },
 };
 
 pub const CIRCUIT_MAX_VERTICES: usize = 6;
+/// Vertex slots for custom arena zones (`/prove-large`); H3 cells need at most 6.
+pub const CIRCUIT_MAX_VERTICES_LARGE: usize = 12;
 pub const CIRCUIT_PRECISION: u32 = 8;
 pub const CIRCUIT_MAX_POLYGON_HASHES: usize = 1024;
 
-// compare l < r
+/// Whether the circuit constants above describe a usable circuit: room for
+/// a triangle, and map slots that fill a binary Merkle tree.
+pub const fn circuit_params_valid() -> bool {
+    CIRCUIT_MAX_VERTICES >= 3 && CIRCUIT_MAX_POLYGON_HASHES.is_power_of_two()
+}
+
+const _: () = assert!(
+    CIRCUIT_MAX_VERTICES >= 3,
+    "need at least 3 vertices for a polygon"
+);
+const _: () = assert!(
+    CIRCUIT_MAX_POLYGON_HASHES.is_power_of_two(),
+    "MAX_HASHES should be a power of two for Merkle tree compatibility"
+);
+
+// compare l < r; -0 and +0 are equal
 pub fn comp_dec_less_than<F: PrimeField, const PREC: u32>(
     l: &Dec<F, PREC>,
     r: &Dec<F, PREC>,
 ) -> bool {
     if l.neg && !r.neg {
-        true
+        !(l.val.is_zero() && r.val.is_zero())
     } else if !l.neg && r.neg {
         false
     } else if !l.neg && !r.neg {
@@ -37,12 +61,13 @@ pub fn comp_dec_less_than<F: PrimeField, const PREC: u32>(
     }
 }
 
-// compare l < r
+// compare l < r; -0 and +0 are equal
 pub fn comp_dec_less_than_gadget<F: PrimeField, const PREC: u32>(
     l: &DecVar<F, PREC>,
     r: &DecVar<F, PREC>,
 ) -> Result<Boolean<F>, SynthesisError> {
-    let case1 = &l.neg & &!r.neg.clone();
+    let both_zero = l.val.is_zero()? & r.val.is_zero()?;
+    let case1 = &l.neg & &!r.neg.clone() & !both_zero;
 
     let case3_cond = &!l.neg.clone() & &!r.neg.clone();
     let case3_val_lt = l
@@ -59,10 +84,38 @@ pub fn comp_dec_less_than_gadget<F: PrimeField, const PREC: u32>(
     Ok(case1 | case3 | case4)
 }
 
+pub fn comp_dec_less_than_or_equal<F: PrimeField, const PREC: u32>(
+    l: &Dec<F, PREC>,
+    r: &Dec<F, PREC>,
+) -> bool {
+    comp_dec_less_than(l, r) || (l.val == r.val && (l.neg == r.neg || l.val.is_zero()))
+}
+
+// compare l <= r
+pub fn comp_dec_less_than_or_equal_gadget<F: PrimeField, const PREC: u32>(
+    l: &DecVar<F, PREC>,
+    r: &DecVar<F, PREC>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let zero_eq = l.val.is_zero()? & r.val.is_zero()?;
+    Ok(comp_dec_less_than_gadget(l, r)? | l.is_equal(r)? | zero_eq)
+}
+
+/// What a point lying exactly on an edge (cross product `d_j == 0`) counts as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoundaryBehavior {
+    /// an edge is crossed only when `d_j < 0`
+    #[default]
+    CountAsInside,
+    /// an edge is crossed when `d_j <= 0`; a zero-length edge (a vertex
+    /// repeated) has `d_j == 0` for every point and is never crossed
+    CountAsOutside,
+}
+
 pub fn is_point_in_polygon<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
     point: &Point2DDec<F, PREC>,
     polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
     num_vertices: usize,
+    boundary: BoundaryBehavior,
 ) -> bool {
     if num_vertices < 3 {
         return false;
@@ -88,7 +141,14 @@ pub fn is_point_in_polygon<F: PrimeField, const PREC: u32, const MAX_VERTICES: u
         let b = y2_y1.mul_unscaled(px_x1);
         let d_j = a.sub(b);
 
-        if comp_dec_less_than(&d_j, &zero_dec) {
+        let crossed = match boundary {
+            BoundaryBehavior::CountAsInside => comp_dec_less_than(&d_j, &zero_dec),
+            BoundaryBehavior::CountAsOutside => {
+                let degenerate = x2_x1.val.is_zero() && y2_y1.val.is_zero();
+                comp_dec_less_than_or_equal(&d_j, &zero_dec) && !degenerate
+            }
+        };
+        if crossed {
             outside_count += 1;
         }
     }
@@ -100,6 +160,7 @@ pub fn is_point_in_polygon_gadget<F: PrimeField, const PREC: u32, const MAX_VERT
     point: &Point2DDecVar<F, PREC>,
     polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
     num_vertices: &FpVar<F>,
+    boundary: BoundaryBehavior,
 ) -> Result<Boolean<F>, SynthesisError> {
     let zero_f = FpVar::<F>::zero();
     let one_f = FpVar::<F>::constant(F::one());
@@ -116,8 +177,14 @@ pub fn is_point_in_polygon_gadget<F: PrimeField, const PREC: u32, const MAX_VERT
         let i_const = FpVar::<F>::constant(F::from(i as u64));
         let active_i = i_const.is_cmp_unchecked(num_vertices, Ordering::Less, false)?;
 
+        // the last active edge closes back onto vertex 0, not onto padding
         let current = &polygon[i];
+        let wrap = FpVar::<F>::constant(F::from(i as u64 + 1)).is_eq(num_vertices)?;
         let next = &polygon[(i + 1) % MAX_VERTICES];
+        let next = Point2DDecVar {
+            x: DecVar::conditional_select(&wrap, &polygon[0].x, &next.x)?,
+            y: DecVar::conditional_select(&wrap, &polygon[0].y, &next.y)?,
+        };
 
         // d_j = (x2-x1)*(py-y1) − (y2-y1)*(px-x1)
         let x2_x1 = next.x.sub(&current.x)?;
@@ -129,53 +196,232 @@ pub fn is_point_in_polygon_gadget<F: PrimeField, const PREC: u32, const MAX_VERT
         let b = y2_y1.mul_unscaled(&px_x1)?;
         let d_j = a.sub(&b)?;
 
-        let is_outside = comp_dec_less_than_gadget(&d_j, &zero_dec)?;
+        let is_outside = match boundary {
+            BoundaryBehavior::CountAsInside => comp_dec_less_than_gadget(&d_j, &zero_dec)?,
+            BoundaryBehavior::CountAsOutside => {
+                let degenerate = x2_x1.val.is_zero()? & y2_y1.val.is_zero()?;
+                comp_dec_less_than_or_equal_gadget(&d_j, &zero_dec)? & !degenerate
+            }
+        };
         let inc_flag = active_i & is_outside;
 
         let inc_val = Boolean::select(&inc_flag, &one_f, &zero_f)?;
         outside_count = &outside_count + &inc_val;
     }
 
-    let valid_n = num_vertices.is_cmp_unchecked(&three_f, Ordering::Greater, false)?;
+    // n >= 3: `true` asks for the comparison to include equality
+    let valid_n = num_vertices.is_cmp_unchecked(&three_f, Ordering::Greater, true)?;
     let outside_zero = outside_count.is_zero()?;
     Ok(valid_n & outside_zero)
 }
 
-pub fn hash_polygon<F: PrimeField + Absorb, const PREC: u32, const MAX_VERTICES: usize>(
+// `num_vertices` fixed at compile time: the `i < n` masks and the `n >= 3`
+// check fold away, and the edge wrap uses `N` just like the native version.
+// Boundary points count as inside (`BoundaryBehavior::CountAsInside`).
+pub fn is_point_in_polygon_gadget_const_n<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+    const N: usize,
+>(
+    point: &Point2DDecVar<F, PREC>,
+    polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
+) -> Result<Boolean<F>, SynthesisError> {
+    assert!(N <= MAX_VERTICES, "num_vertices out of range");
+    if N < 3 {
+        return Ok(Boolean::FALSE);
+    }
+
+    let zero_dec = DecVar::<F, PREC> {
+        val: FpVar::<F>::zero(),
+        neg: Boolean::constant(false),
+    };
+
+    let mut outside = Vec::with_capacity(N);
+    for i in 0..N {
+        let current = &polygon[i];
+        let next = &polygon[(i + 1) % N];
+
+        // d_j = (x2-x1)*(py-y1) − (y2-y1)*(px-x1)
+        let x2_x1 = next.x.sub(&current.x)?;
+        let py_y1 = point.y.sub(&current.y)?;
+        let y2_y1 = next.y.sub(&current.y)?;
+        let px_x1 = point.x.sub(&current.x)?;
+
+        let a = x2_x1.mul_unscaled(&py_y1)?;
+        let b = y2_y1.mul_unscaled(&px_x1)?;
+        let d_j = a.sub(&b)?;
+
+        outside.push(comp_dec_less_than_gadget(&d_j, &zero_dec)?);
+    }
+
+    Ok(!Boolean::kary_or(&outside)?)
+}
+
+// z component of (b - a) × (c - b): positive for a left turn at `b`
+fn turn<F: PrimeField, const PREC: u32>(
+    a: &Point2DDec<F, PREC>,
+    b: &Point2DDec<F, PREC>,
+    c: &Point2DDec<F, PREC>,
+) -> Dec<F, PREC> {
+    let ab_x = b.x.sub(a.x);
+    let ab_y = b.y.sub(a.y);
+    let bc_x = c.x.sub(b.x);
+    let bc_y = c.y.sub(b.y);
+    ab_x.mul_unscaled(bc_y).sub(ab_y.mul_unscaled(bc_x))
+}
+
+/// Every turn of the first `num_vertices` vertices bends the same way
+/// (collinear vertices allowed). The sign-count test in
+/// [`is_point_in_polygon`] is only correct for such polygons.
+pub fn is_convex_polygon<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
     polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
     num_vertices: usize,
-    cfg: &PoseidonConfig<F>,
-) -> F {
-    assert!(num_vertices <= MAX_VERTICES, "num_vertices out of range");
+) -> bool {
+    if !(3..=MAX_VERTICES).contains(&num_vertices) {
+        return false;
+    }
 
-    let mut sponge = PoseidonSponge::<F>::new(cfg);
+    let zero_dec = Dec::<F, PREC>::zero();
+    let (mut left, mut right) = (0, 0);
+    for i in 0..num_vertices {
+        let t = turn(
+            &polygon[i],
+            &polygon[(i + 1) % num_vertices],
+            &polygon[(i + 2) % num_vertices],
+        );
+        if comp_dec_less_than(&zero_dec, &t) {
+            left += 1;
+        } else if comp_dec_less_than(&t, &zero_dec) {
+            right += 1;
+        }
+    }
 
-    sponge.absorb(&F::from(num_vertices as u64));
+    // all collinear is a segment, not a polygon
+    (left == 0) != (right == 0)
+}
 
-    for i in 0..MAX_VERTICES {
-        if i < num_vertices {
-            let v = &polygon[i];
+/// In-circuit [`is_convex_polygon`]: counts left and right turns over the
+/// active vertices (wrapping at `num_vertices`) and is true when exactly one
+/// count is non-zero.
+pub fn is_convex_polygon_gadget<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
+    num_vertices: &FpVar<F>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let zero_f = FpVar::<F>::zero();
+    let one_f = FpVar::<F>::constant(F::one());
+    let three_f = FpVar::<F>::constant(F::from(3u64));
+    let zero_dec = DecVar::<F, PREC>::zero();
 
-            sponge.absorb(&v.x.val);
-            sponge.absorb(&if v.x.neg { F::one() } else { F::zero() });
+    let select = |cond: &Boolean<F>, t: &Point2DDecVar<F, PREC>, f: &Point2DDecVar<F, PREC>| {
+        Ok::<_, SynthesisError>(Point2DDecVar {
+            x: DecVar::conditional_select(cond, &t.x, &f.x)?,
+            y: DecVar::conditional_select(cond, &t.y, &f.y)?,
+        })
+    };
 
-            sponge.absorb(&v.y.val);
-            sponge.absorb(&if v.y.neg { F::one() } else { F::zero() });
-        } else {
-            sponge.absorb(&F::zero()); // x_val
-            sponge.absorb(&F::zero()); // x_sign
-            sponge.absorb(&F::zero()); // y_val
-            sponge.absorb(&F::zero()); // y_sign
-        }
+    let mut left = zero_f.clone();
+    let mut right = zero_f.clone();
+    for i in 0..MAX_VERTICES {
+        let i_const = FpVar::<F>::constant(F::from(i as u64));
+        let active_i = i_const.is_cmp_unchecked(num_vertices, Ordering::Less, false)?;
+
+        // vertices i + 1 and i + 2, wrapped at `num_vertices`
+        let wrap_1 = FpVar::<F>::constant(F::from(i as u64 + 1)).is_eq(num_vertices)?;
+        let wrap_2 = FpVar::<F>::constant(F::from(i as u64 + 2)).is_eq(num_vertices)?;
+        let next = select(&wrap_1, &polygon[0], &polygon[(i + 1) % MAX_VERTICES])?;
+        let after = select(&wrap_2, &polygon[0], &polygon[(i + 2) % MAX_VERTICES])?;
+        let after = select(&wrap_1, &polygon[1 % MAX_VERTICES], &after)?;
+
+        // (next - current) × (after - next)
+        let ab_x = next.x.sub(&polygon[i].x)?;
+        let ab_y = next.y.sub(&polygon[i].y)?;
+        let bc_x = after.x.sub(&next.x)?;
+        let bc_y = after.y.sub(&next.y)?;
+        let t = ab_x.mul_unscaled(&bc_y)?.sub(&ab_y.mul_unscaled(&bc_x)?)?;
+
+        let is_left = &active_i & &comp_dec_less_than_gadget(&zero_dec, &t)?;
+        let is_right = &active_i & &comp_dec_less_than_gadget(&t, &zero_dec)?;
+        left = &left + &Boolean::select(&is_left, &one_f, &zero_f)?;
+        right = &right + &Boolean::select(&is_right, &one_f, &zero_f)?;
     }
 
-    sponge.squeeze_field_elements(1)[0]
+    let valid_n = num_vertices.is_cmp_unchecked(&three_f, Ordering::Greater, true)?;
+    let one_way = (&left * &right).is_zero()?;
+    let turns = (&left + &right).is_zero()?;
+    Ok(valid_n & one_way & !turns)
 }
 
 pub fn hash_polygon_gadget<F: PrimeField + Absorb, const PREC: u32, const MAX_VERTICES: usize>(
     polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
     num_vertices: &FpVar<F>,
+    mode_id: &FpVar<F>,
+    cfg: &PoseidonConfig<F>,
+) -> Result<FpVar<F>, SynthesisError> {
+    hash_polygon_gadget_with_padding(
+        polygon,
+        num_vertices,
+        mode_id,
+        cfg,
+        POLYGON_HASH_PADDING_MODE,
+    )
+}
+
+/// [`hash_polygon_gadget`] under an explicit [`PaddingMode`]; matches
+/// `polygon_ops::hash_polygon_with_padding`.
+pub fn hash_polygon_gadget_with_padding<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+>(
+    polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
+    num_vertices: &FpVar<F>,
+    mode_id: &FpVar<F>,
+    cfg: &PoseidonConfig<F>,
+    padding: PaddingMode,
+) -> Result<FpVar<F>, SynthesisError> {
+    hash_polygon_gadget_in_domain(
+        polygon,
+        num_vertices,
+        core::slice::from_ref(mode_id),
+        cfg,
+        padding,
+    )
+}
+
+/// In-circuit [`hash_polygon_with_context`]; `arena_id` and `game_mode`
+/// take the place of the mode id.
+pub fn hash_polygon_with_context_gadget<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+>(
+    polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
+    num_vertices: &FpVar<F>,
+    arena_id: &FpVar<F>,
+    game_mode: &FpVar<F>,
+    cfg: &PoseidonConfig<F>,
+) -> Result<FpVar<F>, SynthesisError> {
+    hash_polygon_gadget_in_domain(
+        polygon,
+        num_vertices,
+        &[arena_id.clone(), game_mode.clone()],
+        cfg,
+        POLYGON_HASH_PADDING_MODE,
+    )
+}
+
+// absorbs `domain`, then the vertex count, then one leaf per slot
+fn hash_polygon_gadget_in_domain<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+>(
+    polygon: &[Point2DDecVar<F, PREC>; MAX_VERTICES],
+    num_vertices: &FpVar<F>,
+    domain: &[FpVar<F>],
     cfg: &PoseidonConfig<F>,
+    padding: PaddingMode,
 ) -> Result<FpVar<F>, SynthesisError> {
     use ark_crypto_primitives::sponge::poseidon::constraints::PoseidonSpongeVar;
     use ark_r1cs_std::{boolean::Boolean, prelude::R1CSVar};
@@ -184,28 +430,35 @@ pub fn hash_polygon_gadget<F: PrimeField + Absorb, const PREC: u32, const MAX_VE
     let cs = num_vertices.cs();
     let one = FpVar::<F>::constant(F::one());
     let zero = FpVar::<F>::zero();
+    let separator = FpVar::<F>::constant(F::from(POLYGON_HASH_SEPARATOR));
 
-    let mut sponge = PoseidonSpongeVar::<F>::new(cs, cfg);
+    let mut sponge = PoseidonSpongeVar::<F>::new(cs.clone(), cfg);
 
+    for d in domain {
+        sponge.absorb(d)?;
+    }
     sponge.absorb(num_vertices)?;
 
-    for i in 0..MAX_VERTICES {
+    for (i, v) in polygon.iter().enumerate() {
         let i_const = FpVar::<F>::constant(F::from(i as u64));
         let active_i = i_const.is_cmp_unchecked(num_vertices, Ordering::Less, false)?;
 
         let flag_f = Boolean::select(&active_i, &one, &zero)?;
 
-        let v = &polygon[i];
-
-        let x_val = &v.x.val * &flag_f;
-        let x_sign = Boolean::select(&v.x.neg, &one, &zero)? * &flag_f;
-        sponge.absorb(&x_val)?;
-        sponge.absorb(&x_sign)?;
+        // leaf H(x_val, x_sign, y_val, y_sign), zeroed past num_vertices
+        let mut leaf_sponge = PoseidonSpongeVar::<F>::new(cs.clone(), cfg);
+        leaf_sponge.absorb(&v.x.val)?;
+        leaf_sponge.absorb(&Boolean::select(&v.x.neg, &one, &zero)?)?;
+        leaf_sponge.absorb(&v.y.val)?;
+        leaf_sponge.absorb(&Boolean::select(&v.y.neg, &one, &zero)?)?;
+        let mut leaf = &leaf_sponge.squeeze_field_elements(1)?[0] * &flag_f;
+        if padding == PaddingMode::HashSeparator {
+            // the first padded slot, i == num_vertices, holds the separator
+            let first_pad = i_const.is_eq(num_vertices)?;
+            leaf = first_pad.select(&separator, &leaf)?;
+        }
 
-        let y_val = &v.y.val * &flag_f;
-        let y_sign = Boolean::select(&v.y.neg, &one, &zero)? * &flag_f;
-        sponge.absorb(&y_val)?;
-        sponge.absorb(&y_sign)?;
+        sponge.absorb(&leaf)?;
     }
 
     Ok(sponge.squeeze_field_elements(1)?[0].clone())
@@ -224,29 +477,358 @@ pub struct PointInMapCircuit<
     pub public_is_in_map: bool,
     pub public_polygon_hashes: [F; MAX_POLYGON_HASHES],
 
+    pub mode_id: u32,
     pub poseidon_config: PoseidonConfig<F>,
+
+    /// constrain the polygon to be convex ([`is_convex_polygon_gadget`]);
+    /// changes the circuit, so keys must be set up with the same value
+    pub enforce_convex: bool,
+    /// make the polygon public inputs after the map hashes (and arena
+    /// version), laid out as [`public_polygon_inputs`]; like
+    /// `enforce_convex`, needs its own keys
+    pub is_public_polygon: bool,
+    /// commit the map to an [`ArenaVersion`], a public input right after
+    /// the map hashes; also needs its own keys
+    pub arena_version: Option<ArenaVersion<F>>,
+    /// hash the polygon with [`hash_polygon_with_context`] instead of
+    /// `mode_id`, the context public right after the arena version; also
+    /// needs its own keys
+    pub arena_context: Option<ArenaContext>,
+}
+
+/// Arena and game mode a proof is bound to, as public inputs `arena_id`
+/// then `game_mode`. Map hashes must come from [`hash_polygon_with_context`]
+/// with the same values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaContext {
+    pub arena_id: u64,
+    pub game_mode: u64,
+}
+
+impl ArenaContext {
+    /// The two public inputs, in circuit order.
+    pub fn public_inputs<F: PrimeField>(&self) -> [F; 2] {
+        [F::from(self.arena_id), F::from(self.game_mode)]
+    }
+}
+
+/// Public `version = H(map hashes ‖ nonce)` pinning a proof to one map
+/// layout. Hashes are absorbed in slot order, so callers sort them first
+/// for a layout to have one version; a new nonce retires every old proof.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArenaVersion<F: PrimeField> {
+    pub version: F,
+    /// private; only the version is revealed
+    pub nonce: F,
+}
+
+impl<F: PrimeField + Absorb> ArenaVersion<F> {
+    pub fn new(map_hashes: &[F], nonce: F, cfg: &PoseidonConfig<F>) -> Self {
+        let mut sponge = PoseidonSponge::<F>::new(cfg);
+        for h in map_hashes {
+            sponge.absorb(h);
+        }
+        sponge.absorb(&nonce);
+        Self {
+            version: sponge.squeeze_field_elements(1)[0],
+            nonce,
+        }
+    }
 }
 
 impl<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize, const MAX_POLYGON_HASHES: usize>
     PointInMapCircuit<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>
 {
+    /// Positional constructor; panics where [`CircuitBuilder::build`] would
+    /// return an error.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         private_point: Point2DDec<F, PREC>,
         private_polygon_vertices: [Point2DDec<F, PREC>; MAX_VERTICES],
         private_num_vertices: u64,
         public_is_in_map: bool,
         public_polygon_hashes: [F; MAX_POLYGON_HASHES],
+        mode_id: u32,
         poseidon_config: PoseidonConfig<F>,
+        enforce_convex: bool,
     ) -> Self {
-        assert!(private_num_vertices as usize <= MAX_VERTICES);
+        Self::builder()
+            .point(private_point)
+            .polygon(private_polygon_vertices, private_num_vertices)
+            .flag(public_is_in_map)
+            .map_hashes(public_polygon_hashes)
+            .mode_id(mode_id)
+            .poseidon(poseidon_config)
+            .enforce_convex(enforce_convex)
+            .build()
+            .unwrap_or_else(|e| panic!("invalid PointInMapCircuit: {e}"))
+    }
+
+    pub fn builder() -> CircuitBuilder<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES> {
+        CircuitBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircuitBuildError {
+    /// `rate + capacity` disagrees with the width `ark`/`mds` were built for.
+    PoseidonWidthMismatch {
+        expected: usize,
+        got: usize,
+    },
+    TooManyVertices {
+        got: u64,
+        max: usize,
+    },
+    MissingField(&'static str),
+    /// an [`ArenaContext`] replaces the mode id in the polygon hash, so a
+    /// nonzero `mode_id` would be ignored
+    ModeIdWithArenaContext(u32),
+}
+
+impl fmt::Display for CircuitBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PoseidonWidthMismatch { expected, got } => write!(
+                f,
+                "poseidon width mismatch: rate + capacity = {expected}, mds is {got}×{got}"
+            ),
+            Self::TooManyVertices { got, max } => {
+                write!(f, "polygon has {got} vertices, circuit allows {max}")
+            }
+            Self::MissingField(name) => write!(f, "circuit builder is missing `{name}`"),
+            Self::ModeIdWithArenaContext(mode_id) => write!(
+                f,
+                "mode_id {mode_id} is unused under an arena context; pass it as game_mode"
+            ),
+        }
+    }
+}
 
+impl std::error::Error for CircuitBuildError {}
+
+/// Step-by-step construction of a [`PointInMapCircuit`]. `point`, `polygon`
+/// and `poseidon` are required; the flag, map hashes and mode default to
+/// `false`, zeros and 0, convexity is not enforced, the polygon is private
+/// and there is no arena version or context.
+pub struct CircuitBuilder<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+    const MAX_POLYGON_HASHES: usize,
+> {
+    point: Option<Point2DDec<F, PREC>>,
+    polygon: Option<([Point2DDec<F, PREC>; MAX_VERTICES], u64)>,
+    flag: bool,
+    map_hashes: [F; MAX_POLYGON_HASHES],
+    mode_id: u32,
+    poseidon: Option<PoseidonConfig<F>>,
+    enforce_convex: bool,
+    is_public_polygon: bool,
+    arena_version: Option<ArenaVersion<F>>,
+    arena_context: Option<ArenaContext>,
+}
+
+impl<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize, const MAX_POLYGON_HASHES: usize>
+    Default for CircuitBuilder<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>
+{
+    fn default() -> Self {
         Self {
-            private_point,
-            private_polygon_vertices,
-            private_num_vertices,
-            public_is_in_map,
-            public_polygon_hashes,
-            poseidon_config,
+            point: None,
+            polygon: None,
+            flag: false,
+            map_hashes: [F::zero(); MAX_POLYGON_HASHES],
+            mode_id: 0,
+            poseidon: None,
+            enforce_convex: false,
+            is_public_polygon: false,
+            arena_version: None,
+            arena_context: None,
+        }
+    }
+}
+
+impl<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize, const MAX_POLYGON_HASHES: usize>
+    CircuitBuilder<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>
+{
+    pub fn point(mut self, point: Point2DDec<F, PREC>) -> Self {
+        self.point = Some(point);
+        self
+    }
+
+    pub fn polygon(mut self, vertices: [Point2DDec<F, PREC>; MAX_VERTICES], n: u64) -> Self {
+        self.polygon = Some((vertices, n));
+        self
+    }
+
+    pub fn flag(mut self, is_in_map: bool) -> Self {
+        self.flag = is_in_map;
+        self
+    }
+
+    pub fn map_hashes(mut self, hashes: [F; MAX_POLYGON_HASHES]) -> Self {
+        self.map_hashes = hashes;
+        self
+    }
+
+    pub fn mode_id(mut self, mode_id: u32) -> Self {
+        self.mode_id = mode_id;
+        self
+    }
+
+    pub fn poseidon(mut self, cfg: PoseidonConfig<F>) -> Self {
+        self.poseidon = Some(cfg);
+        self
+    }
+
+    pub fn enforce_convex(mut self, enforce: bool) -> Self {
+        self.enforce_convex = enforce;
+        self
+    }
+
+    pub fn is_public_polygon(mut self, public: bool) -> Self {
+        self.is_public_polygon = public;
+        self
+    }
+
+    pub fn arena_version(mut self, version: ArenaVersion<F>) -> Self {
+        self.arena_version = Some(version);
+        self
+    }
+
+    pub fn arena_context(mut self, context: ArenaContext) -> Self {
+        self.arena_context = Some(context);
+        self
+    }
+
+    pub fn build(
+        self,
+    ) -> Result<PointInMapCircuit<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>, CircuitBuildError>
+    {
+        let point = self.point.ok_or(CircuitBuildError::MissingField("point"))?;
+        let (vertices, n) = self
+            .polygon
+            .ok_or(CircuitBuildError::MissingField("polygon"))?;
+        let cfg = self
+            .poseidon
+            .ok_or(CircuitBuildError::MissingField("poseidon"))?;
+
+        if n as usize > MAX_VERTICES {
+            return Err(CircuitBuildError::TooManyVertices {
+                got: n,
+                max: MAX_VERTICES,
+            });
+        }
+        // a sponge whose constants are sized for another width hashes silently wrong
+        let expected = cfg.rate + cfg.capacity;
+        if cfg.mds.len() != expected {
+            return Err(CircuitBuildError::PoseidonWidthMismatch {
+                expected,
+                got: cfg.mds.len(),
+            });
+        }
+        if self.arena_context.is_some() && self.mode_id != 0 {
+            return Err(CircuitBuildError::ModeIdWithArenaContext(self.mode_id));
+        }
+
+        Ok(PointInMapCircuit {
+            private_point: point,
+            private_polygon_vertices: vertices,
+            private_num_vertices: n,
+            public_is_in_map: self.flag,
+            public_polygon_hashes: self.map_hashes,
+            mode_id: self.mode_id,
+            poseidon_config: cfg,
+            enforce_convex: self.enforce_convex,
+            is_public_polygon: self.is_public_polygon,
+            arena_version: self.arena_version,
+            arena_context: self.arena_context,
+        })
+    }
+}
+
+/// Public inputs a circuit built with `is_public_polygon` adds after its map
+/// hashes, arena version and context: `n`, then each of the `MAX_VERTICES` slots as `x.val, x.neg,
+/// y.val, y.neg`. Slots from `n` on are padding.
+pub fn public_polygon_inputs<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    n: usize,
+) -> Vec<F> {
+    let sign = |neg: bool| if neg { F::one() } else { F::zero() };
+    let mut inputs = Vec::with_capacity(1 + 4 * MAX_VERTICES);
+    inputs.push(F::from(n as u64));
+    for v in polygon {
+        inputs.extend([v.x.val, sign(v.x.neg), v.y.val, sign(v.y.neg)]);
+    }
+    inputs
+}
+
+/// Native view of the witnesses a `PointInMapCircuit` was built with.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+pub struct CircuitDescription {
+    pub point_x_f64: f64,
+    pub point_y_f64: f64,
+    pub num_vertices: usize,
+    pub inside_polygon_native: bool,
+    pub hashes_found: usize,
+    pub final_flag: bool,
+}
+
+// for server logs: flags and counts only, never the player's position
+impl fmt::Display for CircuitDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} vertices, inside: {}, hashes found: {}, in map: {}",
+            self.num_vertices, self.inside_polygon_native, self.hashes_found, self.final_flag
+        )
+    }
+}
+
+impl<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+    const MAX_POLYGON_HASHES: usize,
+> PointInMapCircuit<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>
+{
+    /// Re-run the circuit's checks natively, for debugging failed proofs.
+    pub fn describe(&self) -> CircuitDescription {
+        let n = self.private_num_vertices as usize;
+        let inside = is_point_in_polygon::<F, PREC, MAX_VERTICES>(
+            &self.private_point,
+            &self.private_polygon_vertices,
+            n,
+            BoundaryBehavior::CountAsInside,
+        );
+        let hash = match self.arena_context {
+            Some(ctx) => hash_polygon_with_context::<F, PREC, MAX_VERTICES>(
+                &self.private_polygon_vertices,
+                n,
+                ctx.arena_id,
+                ctx.game_mode,
+                &self.poseidon_config,
+            ),
+            None => hash_polygon::<F, PREC, MAX_VERTICES>(
+                &self.private_polygon_vertices,
+                n,
+                self.mode_id,
+                &self.poseidon_config,
+            ),
+        };
+        let hashes_found = self
+            .public_polygon_hashes
+            .iter()
+            .filter(|h| **h == hash)
+            .count();
+
+        CircuitDescription {
+            point_x_f64: self.private_point.x.to_f64(),
+            point_y_f64: self.private_point.y.to_f64(),
+            num_vertices: n,
+            inside_polygon_native: inside,
+            hashes_found,
+            final_flag: inside && hashes_found > 0,
         }
     }
 }
@@ -259,7 +841,14 @@ impl<
 > ConstraintSynthesizer<F> for PointInMapCircuit<F, PREC, MAX_VERTICES, MAX_POLYGON_HASHES>
 {
     fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
-        use ark_r1cs_std::{alloc::AllocVar, prelude::*};
+        use ark_crypto_primitives::sponge::poseidon::constraints::PoseidonSpongeVar;
+        use ark_r1cs_std::{
+            alloc::{AllocVar, AllocationMode},
+            prelude::*,
+        };
+
+        // The allocation order below is part of the keys: moving any
+        // allocation, even of a witness, needs keys set up afresh.
 
         /* ────────── 1. allocate PRIVATE witnesses ────────── */
 
@@ -269,18 +858,8 @@ impl<
             y: DecVar::new_witness(cs.clone(), || Ok(self.private_point.y))?,
         };
 
-        // polygon vertices
-        let poly_var: [Point2DDecVar<F, PREC>; MAX_VERTICES] = core::array::from_fn(|i| {
-            let v = self.private_polygon_vertices[i];
-            Point2DDecVar {
-                x: DecVar::new_witness(cs.clone(), || Ok(v.x)).unwrap(),
-                y: DecVar::new_witness(cs.clone(), || Ok(v.y)).unwrap(),
-            }
-        });
-
-        // num_vertices
-        let num_vert_var =
-            FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(self.private_num_vertices)))?;
+        // game mode (hash domain separator)
+        let mode_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(self.mode_id as u64)))?;
 
         /* ────────── 2. allocate PUBLIC inputs ─────────────── */
 
@@ -298,6 +877,52 @@ impl<
             FpVar::<F>::new_input(cs.clone(), || Ok(self.public_polygon_hashes[i])).unwrap()
         });
 
+        // arena version: H(hashes ‖ nonce) must be the public version
+        if let Some(arena) = self.arena_version {
+            let version_var = FpVar::<F>::new_input(cs.clone(), || Ok(arena.version))?;
+            let nonce_var = FpVar::<F>::new_witness(cs.clone(), || Ok(arena.nonce))?;
+            let mut sponge = PoseidonSpongeVar::<F>::new(cs.clone(), &self.poseidon_config);
+            for h in &pub_hash_vars {
+                sponge.absorb(h)?;
+            }
+            sponge.absorb(&nonce_var)?;
+            sponge.squeeze_field_elements(1)?[0].enforce_equal(&version_var)?;
+        }
+
+        // arena context: public, and the polygon hash's domain
+        let context_vars = match self.arena_context {
+            Some(ctx) => {
+                let [arena_id, game_mode] = ctx.public_inputs::<F>();
+                Some((
+                    FpVar::<F>::new_input(cs.clone(), || Ok(arena_id))?,
+                    FpVar::<F>::new_input(cs.clone(), || Ok(game_mode))?,
+                ))
+            }
+            None => None,
+        };
+
+        // num_vertices and polygon vertices: witnesses, or inputs after the
+        // hashes (and arena version and context). Every slot is allocated either way, so the number of
+        // public inputs does not depend on how many vertices are in use.
+        let poly_mode = if self.is_public_polygon {
+            AllocationMode::Input
+        } else {
+            AllocationMode::Witness
+        };
+        let num_vert_var = FpVar::<F>::new_variable(
+            cs.clone(),
+            || Ok(F::from(self.private_num_vertices)),
+            poly_mode,
+        )?;
+        let poly_var: [Point2DDecVar<F, PREC>; MAX_VERTICES] = core::array::from_fn(|i| {
+            Point2DDecVar::new_variable(
+                cs.clone(),
+                || Ok(self.private_polygon_vertices[i]),
+                poly_mode,
+            )
+            .unwrap()
+        });
+
         /* ────────── 3. in-circuit computations ───────────── */
 
         // 3a. point-in-polygon
@@ -305,14 +930,27 @@ impl<
             &point_var,
             &poly_var,
             &num_vert_var,
+            BoundaryBehavior::CountAsInside,
         )?;
 
         // 3b. polygon hash
-        let hash_var = hash_polygon_gadget::<F, PREC, MAX_VERTICES>(
-            &poly_var,
-            &num_vert_var,
-            &self.poseidon_config,
-        )?;
+        let hash_var = match &context_vars {
+            Some((arena_id, game_mode)) => {
+                hash_polygon_with_context_gadget::<F, PREC, MAX_VERTICES>(
+                    &poly_var,
+                    &num_vert_var,
+                    arena_id,
+                    game_mode,
+                    &self.poseidon_config,
+                )?
+            }
+            None => hash_polygon_gadget::<F, PREC, MAX_VERTICES>(
+                &poly_var,
+                &num_vert_var,
+                &mode_var,
+                &self.poseidon_config,
+            )?,
+        };
 
         // 3c. hash matches any public hash?
         let mut match_any = Boolean::constant(false);
@@ -324,6 +962,12 @@ impl<
         // 3d. computed “is-in-map” flag  = inside ∧ match_any
         let in_map_b = &inside_b & &match_any;
 
+        // 3e. optionally refuse concave zones outright
+        if self.enforce_convex {
+            is_convex_polygon_gadget::<F, PREC, MAX_VERTICES>(&poly_var, &num_vert_var)?
+                .enforce_equal(&Boolean::TRUE)?;
+        }
+
         /* 4. enforce public equality ------------------------------------- */
         let one = FpVar::<F>::constant(F::one());
         let zero = FpVar::<F>::zero();
@@ -341,8 +985,9 @@ mod tests {
     use super::*;
 
     // ---------- field & Poseidon config ----------
+    use crate::zk::poseidon::default_poseidon_config;
     use ark_bn254::Fr;
-    use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+    use ark_ff::Zero;
 
     // ---------- gadgets / R1CS ----------
     use ark_r1cs_std::{R1CSVar, alloc::AllocVar};
@@ -355,29 +1000,14 @@ mod tests {
     const MAX: usize = CIRCUIT_MAX_VERTICES;
     const PREC: u32 = CIRCUIT_PRECISION;
 
-    // Poseidon parameters: width 3, α = 17, 8 full + 31 partial rounds
-    fn poseidon_cfg() -> PoseidonConfig<Fr> {
-        let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(Fr::MODULUS_BIT_SIZE as u64, 3, 8, 31, 0);
-
-        PoseidonConfig {
-            full_rounds: 8,
-            partial_rounds: 31,
-            alpha: 17,
-            ark,
-            mds,
-            rate: 2,
-            capacity: 1,
-        }
-    }
-
     // ------- helper: random convex-ish polygon -----------------
     fn random_polygon(rng: &mut ThreadRng, n: usize) -> [Point2DDec<F, PREC>; MAX] {
         debug_assert!(n <= MAX);
         let mut arr = core::array::from_fn(|_| Point2DDec::from_f64(0.0, 0.0));
-        for i in 0..n {
+        for p in arr.iter_mut().take(n) {
             let x = rng.random_range(-100.0..100.0);
             let y = rng.random_range(-100.0..100.0);
-            arr[i] = Point2DDec::from_f64(x, y);
+            *p = Point2DDec::from_f64(x, y);
         }
         arr
     }
@@ -407,11 +1037,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_comp_dec_all_signs() {
+        let dec = |v: u64, neg: bool| Dec::<F, PREC> {
+            val: F::from(v),
+            neg,
+        };
+        for (l, r, lt) in [
+            (dec(3, false), dec(5, false), true),
+            (dec(5, false), dec(3, false), false),
+            (dec(3, true), dec(5, false), true),
+            (dec(5, false), dec(3, true), false),
+            (dec(5, true), dec(3, true), true),
+            (dec(3, true), dec(5, true), false),
+            (dec(0, false), dec(0, false), false),
+            (dec(0, true), dec(0, false), false),
+        ] {
+            let case = format!("{:?} < {:?}", l, r);
+            assert_eq!(comp_dec_less_than(&l, &r), lt, "{case}");
+
+            let cs = ConstraintSystem::<F>::new_ref();
+            let lv = DecVar::new_witness(cs.clone(), || Ok(l)).unwrap();
+            let rv = DecVar::new_witness(cs.clone(), || Ok(r)).unwrap();
+            let gadget = comp_dec_less_than_gadget(&lv, &rv).unwrap();
+            assert_eq!(gadget.value().unwrap(), lt, "{case}");
+            assert!(cs.is_satisfied().unwrap(), "{case}");
+        }
+    }
+
     // --------------- round-trip test ----------------------------
+    #[test]
+    fn less_than_or_equal_matches_native() {
+        let vals = [-2.5, -1.0, 0.0, 1.0, 2.5];
+        for &a in &vals {
+            for &b in &vals {
+                let (l, r) = (Dec::<F, PREC>::from_f64(a), Dec::<F, PREC>::from_f64(b));
+                assert_eq!(comp_dec_less_than_or_equal(&l, &r), a <= b, "{a} <= {b}");
+
+                let cs = ConstraintSystem::<F>::new_ref();
+                let lv = DecVar::new_witness(cs.clone(), || Ok(l)).unwrap();
+                let rv = DecVar::new_witness(cs.clone(), || Ok(r)).unwrap();
+                let le = comp_dec_less_than_or_equal_gadget(&lv, &rv).unwrap();
+                assert_eq!(le.value().unwrap(), a <= b, "{a} <= {b}");
+                assert!(cs.is_satisfied().unwrap());
+            }
+        }
+    }
+
     #[test]
     fn helpers_match_bn254() {
         let mut rng: ThreadRng = rng();
-        let cfg = poseidon_cfg();
+        let cfg = default_poseidon_config();
 
         for _ in 0..8 {
             // random vertex count 3‥=MAX
@@ -425,8 +1101,13 @@ mod tests {
             };
 
             // ---------- native ----------
-            let inside_native = is_point_in_polygon::<F, PREC, MAX>(&point, &poly, n);
-            let hash_native = hash_polygon::<F, PREC, MAX>(&poly, n, &cfg);
+            let inside_native = is_point_in_polygon::<F, PREC, MAX>(
+                &point,
+                &poly,
+                n,
+                BoundaryBehavior::CountAsInside,
+            );
+            let hash_native = hash_polygon::<F, PREC, MAX>(&poly, n, 0, &cfg);
 
             // ---------- gadget ----------
             let cs = ConstraintSystem::<F>::new_ref();
@@ -435,10 +1116,17 @@ mod tests {
             let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
             let point_var = alloc_point_var::<PREC>(cs.clone(), &point);
 
-            let inside_gadget =
-                is_point_in_polygon_gadget::<F, PREC, MAX>(&point_var, &poly_var, &n_var).unwrap();
+            let inside_gadget = is_point_in_polygon_gadget::<F, PREC, MAX>(
+                &point_var,
+                &poly_var,
+                &n_var,
+                BoundaryBehavior::CountAsInside,
+            )
+            .unwrap();
 
-            let hash_gadget = hash_polygon_gadget::<F, PREC, MAX>(&poly_var, &n_var, &cfg).unwrap();
+            let mode_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::zero())).unwrap();
+            let hash_gadget =
+                hash_polygon_gadget::<F, PREC, MAX>(&poly_var, &n_var, &mode_var, &cfg).unwrap();
 
             // compare
             assert_eq!(inside_native, inside_gadget.value().unwrap());
@@ -446,4 +1134,685 @@ mod tests {
             assert!(cs.is_satisfied().unwrap());
         }
     }
+
+    // ------- helper: regular CCW n-gon of radius 100 around the origin -------
+    fn regular_polygon(n: usize) -> [Point2DDec<F, PREC>; MAX] {
+        let mut arr = core::array::from_fn(|_| Point2DDec::from_f64(0.0, 0.0));
+        for (k, p) in arr.iter_mut().take(n).enumerate() {
+            let theta = core::f64::consts::TAU * k as f64 / n as f64;
+            *p = Point2DDec::from_f64(100.0 * theta.cos(), 100.0 * theta.sin());
+        }
+        arr
+    }
+
+    #[test]
+    fn const_n_gadget_matches_and_saves_constraints() {
+        let poly = regular_polygon(MAX);
+
+        for (px, py) in [(1.0, 2.0), (150.0, 0.0), (-60.0, -60.0), (0.0, 99.0)] {
+            let point = Point2DDec::from_f64(px, py);
+            let native = is_point_in_polygon::<F, PREC, MAX>(
+                &point,
+                &poly,
+                MAX,
+                BoundaryBehavior::CountAsInside,
+            );
+
+            // variable n
+            let cs_var = ConstraintSystem::<F>::new_ref();
+            let n_var =
+                FpVar::<F>::new_witness(cs_var.clone(), || Ok(F::from(MAX as u64))).unwrap();
+            let poly_var = alloc_polygon_var::<PREC>(cs_var.clone(), &poly);
+            let point_var = alloc_point_var::<PREC>(cs_var.clone(), &point);
+            let var_n = is_point_in_polygon_gadget::<F, PREC, MAX>(
+                &point_var,
+                &poly_var,
+                &n_var,
+                BoundaryBehavior::CountAsInside,
+            )
+            .unwrap();
+
+            // constant n
+            let cs_const = ConstraintSystem::<F>::new_ref();
+            let poly_var = alloc_polygon_var::<PREC>(cs_const.clone(), &poly);
+            let point_var = alloc_point_var::<PREC>(cs_const.clone(), &point);
+            let const_n =
+                is_point_in_polygon_gadget_const_n::<F, PREC, MAX, MAX>(&point_var, &poly_var)
+                    .unwrap();
+
+            assert_eq!(native, var_n.value().unwrap());
+            assert_eq!(native, const_n.value().unwrap());
+            assert!(cs_var.is_satisfied().unwrap());
+            assert!(cs_const.is_satisfied().unwrap());
+            assert!(cs_const.num_constraints() < cs_var.num_constraints());
+        }
+    }
+
+    #[test]
+    fn const_n_gadget_pentagon_wraps_at_n() {
+        let poly = regular_polygon(5);
+        let point = Point2DDec::from_f64(1.0, 2.0);
+        assert!(is_point_in_polygon::<F, PREC, MAX>(
+            &point,
+            &poly,
+            5,
+            BoundaryBehavior::CountAsInside
+        ));
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+        let point_var = alloc_point_var::<PREC>(cs.clone(), &point);
+        let inside =
+            is_point_in_polygon_gadget_const_n::<F, PREC, MAX, 5>(&point_var, &poly_var).unwrap();
+
+        assert!(inside.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn h3_pentagon_ignores_padding_vertex() {
+        use std::str::FromStr;
+
+        use h3o::{CellIndex, LatLng};
+
+        use crate::zk::polygon_ops::{gps_to_web_mercator, polygon_from_h3_cell};
+
+        // base cell 4, one of the twelve resolution-0 pentagons
+        let cell = CellIndex::from_str("8009fffffffffff").unwrap();
+        assert!(cell.is_pentagon());
+        let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX>(cell);
+        assert_eq!(n, 5);
+        // the sixth slot is the `(0, 0)` padding
+        assert!(poly[5].x.val.is_zero() && poly[5].y.val.is_zero());
+
+        // either side of the closing edge 4 → 0; wrapping at `MAX` instead
+        // of `n` would test the edge 4 → padding in its place
+        let centre = LatLng::from(cell);
+        let boundary = cell.boundary();
+        let (v4, v0) = (boundary[4], boundary[0]);
+        let near_edge = |c: fn(LatLng) -> f64, outward: f64| {
+            let mid = (c(v4) + c(v0)) / 2.0;
+            mid + outward * (mid - c(centre))
+        };
+        for (lat, lon, expected) in [
+            (centre.lat(), centre.lng(), true),
+            (
+                near_edge(LatLng::lat, -0.05),
+                near_edge(LatLng::lng, -0.05),
+                true,
+            ),
+            (
+                near_edge(LatLng::lat, 0.05),
+                near_edge(LatLng::lng, 0.05),
+                false,
+            ),
+            (-30.0, -60.0, false),
+        ] {
+            let (x, y) = gps_to_web_mercator(lon, lat);
+            let point = Point2DDec::from_f64(x, y);
+            let native = is_point_in_polygon::<F, PREC, MAX>(
+                &point,
+                &poly,
+                n,
+                BoundaryBehavior::CountAsInside,
+            );
+            assert_eq!(native, expected, "({lat}, {lon})");
+
+            let cs = ConstraintSystem::<F>::new_ref();
+            let point_var = alloc_point_var::<PREC>(cs.clone(), &point);
+            let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+            let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+            let gadget = is_point_in_polygon_gadget::<F, PREC, MAX>(
+                &point_var,
+                &poly_var,
+                &n_var,
+                BoundaryBehavior::CountAsInside,
+            )
+            .unwrap();
+            assert_eq!(gadget.value().unwrap(), expected, "({lat}, {lon})");
+            assert!(cs.is_satisfied().unwrap());
+        }
+    }
+
+    #[test]
+    fn boundary_behavior_decides_edge_points() {
+        // square (0,0)-(10,10); (5, 0) sits on the bottom edge, (10, 10) on a corner
+        let mut square = [Point2DDec::from_f64(0.0, 0.0); MAX];
+        for (p, (x, y)) in
+            square
+                .iter_mut()
+                .zip([(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)])
+        {
+            *p = Point2DDec::from_f64(x, y);
+        }
+
+        for (px, py, on_boundary) in [(5.0, 0.0, true), (10.0, 10.0, true), (5.0, 5.0, false)] {
+            let point = Point2DDec::from_f64(px, py);
+            for (boundary, expected) in [
+                (BoundaryBehavior::CountAsInside, true),
+                (BoundaryBehavior::CountAsOutside, !on_boundary),
+            ] {
+                let native = is_point_in_polygon::<F, PREC, MAX>(&point, &square, 4, boundary);
+                assert_eq!(native, expected, "({px}, {py}) {boundary:?}");
+
+                let cs = ConstraintSystem::<F>::new_ref();
+                let point_var = alloc_point_var::<PREC>(cs.clone(), &point);
+                let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &square);
+                let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(4u64))).unwrap();
+                let gadget = is_point_in_polygon_gadget::<F, PREC, MAX>(
+                    &point_var, &poly_var, &n_var, boundary,
+                )
+                .unwrap();
+                assert_eq!(
+                    gadget.value().unwrap(),
+                    expected,
+                    "({px}, {py}) {boundary:?}"
+                );
+                assert!(cs.is_satisfied().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn triangle_centroid_is_inside() {
+        let h = 3f64.sqrt() / 2.0;
+        let corners = [(0.0, 0.0), (1.0, 0.0), (0.5, h)];
+        let mut triangle = [Point2DDec::from_f64(0.0, 0.0); MAX];
+        for (p, (x, y)) in triangle.iter_mut().zip(corners) {
+            *p = Point2DDec::from_f64(x, y);
+        }
+        let centroid = Point2DDec::from_f64(0.5, h / 3.0);
+
+        assert!(is_point_in_polygon::<F, PREC, MAX>(
+            &centroid,
+            &triangle,
+            3,
+            BoundaryBehavior::CountAsInside
+        ));
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let point_var = alloc_point_var::<PREC>(cs.clone(), &centroid);
+        let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &triangle);
+        let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(3u64))).unwrap();
+        let inside = is_point_in_polygon_gadget::<F, PREC, MAX>(
+            &point_var,
+            &poly_var,
+            &n_var,
+            BoundaryBehavior::CountAsInside,
+        )
+        .unwrap();
+        assert!(inside.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn collinear_vertices_keep_their_edge() {
+        // (1,0) splits the bottom edge; a repeated (2,0) adds a zero-length edge
+        let polygons: [&[(f64, f64)]; 2] = [
+            &[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)],
+            &[(0.0, 0.0), (2.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)],
+        ];
+        for corners in polygons {
+            let n = corners.len();
+            let mut poly = [Point2DDec::from_f64(corners[0].0, corners[0].1); MAX];
+            for (p, &(x, y)) in poly.iter_mut().zip(corners) {
+                *p = Point2DDec::from_f64(x, y);
+            }
+
+            for (px, py, inside, on_boundary) in [
+                (1.0, -0.001, false, false),
+                (1.0, 0.5, true, false),
+                (1.0, 0.0, true, true),
+            ] {
+                let point = Point2DDec::from_f64(px, py);
+                for (boundary, expected) in [
+                    (BoundaryBehavior::CountAsInside, inside),
+                    (BoundaryBehavior::CountAsOutside, inside && !on_boundary),
+                ] {
+                    let native = is_point_in_polygon::<F, PREC, MAX>(&point, &poly, n, boundary);
+                    assert_eq!(native, expected, "{corners:?} ({px}, {py}) {boundary:?}");
+
+                    let cs = ConstraintSystem::<F>::new_ref();
+                    let point_var = alloc_point_var::<PREC>(cs.clone(), &point);
+                    let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+                    let n_var =
+                        FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+                    let gadget = is_point_in_polygon_gadget::<F, PREC, MAX>(
+                        &point_var, &poly_var, &n_var, boundary,
+                    )
+                    .unwrap();
+                    assert_eq!(
+                        gadget.value().unwrap(),
+                        expected,
+                        "{corners:?} ({px}, {py})"
+                    );
+                    assert!(cs.is_satisfied().unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mode_id_separates_polygon_hashes() {
+        let cfg = default_poseidon_config();
+        let poly = regular_polygon(MAX);
+
+        let hashes: Vec<F> = (0..3)
+            .map(|mode| hash_polygon::<F, PREC, MAX>(&poly, MAX, mode, &cfg))
+            .collect();
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[1], hashes[2]);
+
+        for (mode, native) in hashes.iter().enumerate() {
+            let cs = ConstraintSystem::<F>::new_ref();
+            let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(MAX as u64))).unwrap();
+            let mode_var =
+                FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(mode as u64))).unwrap();
+            let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+
+            let gadget =
+                hash_polygon_gadget::<F, PREC, MAX>(&poly_var, &n_var, &mode_var, &cfg).unwrap();
+            assert_eq!(*native, gadget.value().unwrap());
+            assert!(cs.is_satisfied().unwrap());
+        }
+    }
+
+    #[test]
+    fn arena_context_separates_polygon_hashes() {
+        let cfg = default_poseidon_config();
+        let poly = regular_polygon(MAX);
+
+        let hashes: Vec<F> = [(1, 0), (2, 0), (1, 1)]
+            .iter()
+            .map(|&(arena, mode)| {
+                hash_polygon_with_context::<F, PREC, MAX>(&poly, MAX, arena, mode, &cfg)
+            })
+            .collect();
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg));
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(MAX as u64))).unwrap();
+        let arena_var = FpVar::<F>::new_input(cs.clone(), || Ok(F::from(2u64))).unwrap();
+        let mode_var = FpVar::<F>::new_input(cs.clone(), || Ok(F::zero())).unwrap();
+        let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+        let gadget = hash_polygon_with_context_gadget::<F, PREC, MAX>(
+            &poly_var, &n_var, &arena_var, &mode_var, &cfg,
+        )
+        .unwrap();
+        assert_eq!(gadget.value().unwrap(), hashes[1]);
+
+        // a map hashed for arena 1 proves nothing in arena 2
+        let mut map = [F::zero(); 4];
+        map[0] = hashes[0];
+        let satisfied = |arena_id: u64| {
+            let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+                .point(Point2DDec::from_f64(0.0, 0.0))
+                .polygon(poly, MAX as u64)
+                .flag(true)
+                .map_hashes(map)
+                .poseidon(cfg.clone())
+                .arena_context(ArenaContext {
+                    arena_id,
+                    game_mode: 0,
+                })
+                .build()
+                .unwrap();
+            assert_eq!(circuit.describe().final_flag, arena_id == 1);
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            // the constant one, the flag, the hashes, arena id and game mode
+            assert_eq!(cs.num_instance_variables(), 1 + 1 + 4 + 2);
+            cs.is_satisfied().unwrap()
+        };
+        assert!(satisfied(1));
+        assert!(!satisfied(2));
+
+        let both = PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+            .point(Point2DDec::from_f64(0.0, 0.0))
+            .polygon(poly, MAX as u64)
+            .mode_id(3)
+            .poseidon(cfg.clone())
+            .arena_context(ArenaContext {
+                arena_id: 1,
+                game_mode: 3,
+            })
+            .build();
+        assert_eq!(
+            both.err(),
+            Some(CircuitBuildError::ModeIdWithArenaContext(3))
+        );
+    }
+
+    #[test]
+    fn circuit_constants_are_valid() {
+        assert!(circuit_params_valid());
+    }
+
+    #[test]
+    fn polygon_hash_with_few_active_vertices() {
+        let cfg = default_poseidon_config();
+        // same slots every time: only the active count moves
+        let poly = regular_polygon(MAX);
+
+        let hashes: Vec<F> = (0..=3)
+            .map(|n| {
+                let native = hash_polygon::<F, PREC, MAX>(&poly, n, 0, &cfg);
+
+                let cs = ConstraintSystem::<F>::new_ref();
+                let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+                let mode_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::zero())).unwrap();
+                let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+                let gadget =
+                    hash_polygon_gadget::<F, PREC, MAX>(&poly_var, &n_var, &mode_var, &cfg)
+                        .unwrap();
+                assert_eq!(native, gadget.value().unwrap(), "n = {n}");
+                assert!(cs.is_satisfied().unwrap(), "n = {n}");
+                native
+            })
+            .collect();
+
+        assert_ne!(hashes[0], hashes[1], "n = 0 vs n = 1");
+        assert_ne!(hashes[1], hashes[2], "n = 1 vs n = 2");
+        assert_ne!(hashes[2], hashes[3], "n = 2 vs n = 3");
+    }
+
+    #[test]
+    fn padding_modes_hash_apart_and_gadget_matches() {
+        use crate::zk::polygon_ops::hash_polygon_with_padding;
+
+        let cfg = default_poseidon_config();
+        for n in [4, 5, MAX] {
+            let poly = regular_polygon(n);
+            let hash =
+                |padding| hash_polygon_with_padding::<F, PREC, MAX>(&poly, n, 0, &cfg, padding);
+            let (zero_fill, separated) = (
+                hash(PaddingMode::ZeroFill),
+                hash(PaddingMode::HashSeparator),
+            );
+            // a full polygon has no padding to separate
+            assert_eq!(zero_fill == separated, n == MAX, "n = {n}");
+            assert_eq!(zero_fill, hash_polygon::<F, PREC, MAX>(&poly, n, 0, &cfg));
+
+            for (padding, native) in [
+                (PaddingMode::ZeroFill, zero_fill),
+                (PaddingMode::HashSeparator, separated),
+            ] {
+                let cs = ConstraintSystem::<F>::new_ref();
+                let n_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+                let mode_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::zero())).unwrap();
+                let poly_var = alloc_polygon_var::<PREC>(cs.clone(), &poly);
+
+                let gadget = hash_polygon_gadget_with_padding::<F, PREC, MAX>(
+                    &poly_var, &n_var, &mode_var, &cfg, padding,
+                )
+                .unwrap();
+                assert_eq!(native, gadget.value().unwrap(), "n = {n}, {padding:?}");
+                assert!(cs.is_satisfied().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn builder_rejects_mismatched_poseidon_width() {
+        let poly = regular_polygon(MAX);
+        let builder = || {
+            PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+                .point(Point2DDec::from_f64(1.0, 1.0))
+                .polygon(poly, MAX as u64)
+        };
+
+        assert!(
+            builder()
+                .poseidon(default_poseidon_config())
+                .build()
+                .is_ok()
+        );
+        assert_eq!(
+            builder().build().err(),
+            Some(CircuitBuildError::MissingField("poseidon"))
+        );
+
+        let mut cfg = default_poseidon_config();
+        cfg.rate = 3; // ark/mds still sized for width 3
+        assert_eq!(
+            builder().poseidon(cfg).build().err(),
+            Some(CircuitBuildError::PoseidonWidthMismatch {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn public_polygon_adds_its_vertices_to_the_public_inputs() {
+        let cfg = default_poseidon_config();
+        let poly = regular_polygon(MAX);
+        let mut hashes = [F::zero(); 4];
+        hashes[0] = hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg);
+
+        let synthesize = |public: bool| {
+            let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+                .point(Point2DDec::from_f64(0.0, 0.0))
+                .polygon(poly, MAX as u64)
+                .flag(true)
+                .map_hashes(hashes)
+                .poseidon(cfg.clone())
+                .is_public_polygon(public)
+                .build()
+                .unwrap();
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            assert!(cs.is_satisfied().unwrap());
+            cs
+        };
+
+        let private = synthesize(false);
+        let public = synthesize(true);
+        // the constant one, the flag, the hashes
+        assert_eq!(private.num_instance_variables(), 1 + 1 + 4);
+        assert_eq!(
+            public.num_instance_variables(),
+            private.num_instance_variables() + 1 + 4 * MAX
+        );
+
+        let instance = public.borrow().unwrap().instance_assignment.clone();
+        assert_eq!(
+            instance[1 + 1 + 4..],
+            public_polygon_inputs::<F, PREC, MAX>(&poly, MAX)[..]
+        );
+    }
+
+    #[test]
+    fn arena_version_rejects_other_layouts() {
+        let cfg = default_poseidon_config();
+        let poly = regular_polygon(MAX);
+        let mut hashes = [F::zero(); 4];
+        hashes[0] = hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg);
+        let current = ArenaVersion::new(&hashes, F::from(1u64), &cfg);
+
+        let satisfied = |hashes: [F; 4], arena: ArenaVersion<F>| {
+            let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+                .point(Point2DDec::from_f64(0.0, 0.0))
+                .polygon(poly, MAX as u64)
+                .flag(true)
+                .map_hashes(hashes)
+                .poseidon(cfg.clone())
+                .arena_version(arena)
+                .build()
+                .unwrap();
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            // the constant one, the flag, the hashes, the version
+            assert_eq!(cs.num_instance_variables(), 1 + 1 + 4 + 1);
+            cs.is_satisfied().unwrap()
+        };
+        assert!(satisfied(hashes, current));
+
+        // a proof for a version the verifier no longer accepts
+        let old = ArenaVersion::new(&hashes, F::from(0u64), &cfg);
+        assert_ne!(old.version, current.version);
+        assert!(satisfied(hashes, old));
+        assert!(!satisfied(
+            hashes,
+            ArenaVersion {
+                version: current.version,
+                nonce: old.nonce
+            }
+        ));
+
+        // the same version cannot vouch for a different map
+        let mut edited = hashes;
+        edited[3] = F::from(7u64);
+        assert!(!satisfied(edited, current));
+        let mut reordered = hashes;
+        reordered.swap(0, 1);
+        assert!(!satisfied(reordered, current));
+    }
+
+    #[test]
+    fn large_circuit_proves_ten_vertex_zone() {
+        const LARGE: usize = CIRCUIT_MAX_VERTICES_LARGE;
+        let cfg = default_poseidon_config();
+
+        // CCW decagon of radius 100; the spare slots stay at the origin, so
+        // the last edge must close onto vertex 0, not onto slot 10
+        let mut poly = [Point2DDec::<F, PREC>::from_f64(0.0, 0.0); LARGE];
+        for (k, p) in poly.iter_mut().take(10).enumerate() {
+            let theta = core::f64::consts::TAU * k as f64 / 10.0;
+            *p = Point2DDec::from_f64(100.0 * theta.cos(), 100.0 * theta.sin());
+        }
+        let mut hashes = [F::zero(); 4];
+        hashes[1] = hash_polygon::<F, PREC, LARGE>(&poly, 10, 0, &cfg);
+
+        for ((px, py), inside) in [
+            ((0.0, 0.0), true),
+            ((80.0, 50.0), true),
+            ((90.0, 60.0), false),
+        ] {
+            let point = Point2DDec::from_f64(px, py);
+            let native = is_point_in_polygon::<F, PREC, LARGE>(
+                &point,
+                &poly,
+                10,
+                BoundaryBehavior::CountAsInside,
+            );
+            assert_eq!(native, inside, "({px}, {py})");
+
+            let circuit = PointInMapCircuit::<F, PREC, LARGE, 4>::new(
+                point,
+                poly,
+                10,
+                native,
+                hashes,
+                0,
+                cfg.clone(),
+                false,
+            );
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            assert!(cs.is_satisfied().unwrap(), "({px}, {py})");
+        }
+    }
+
+    #[test]
+    fn describe_reports_native_checks() {
+        let cfg = default_poseidon_config();
+        let poly = regular_polygon(MAX);
+        let mut hashes = [F::from(7u64); 4];
+        hashes[2] = hash_polygon::<F, PREC, MAX>(&poly, MAX, 0, &cfg);
+
+        let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::new(
+            Point2DDec::from_f64(1.5, -2.25),
+            poly,
+            MAX as u64,
+            true,
+            hashes,
+            0,
+            cfg,
+            false,
+        );
+        let desc = circuit.describe();
+        assert_eq!(
+            desc,
+            CircuitDescription {
+                point_x_f64: 1.5,
+                point_y_f64: -2.25,
+                num_vertices: MAX,
+                inside_polygon_native: true,
+                hashes_found: 1,
+                final_flag: true,
+            }
+        );
+        assert_eq!(
+            desc.to_string(),
+            "6 vertices, inside: true, hashes found: 1, in map: true"
+        );
+    }
+
+    #[test]
+    fn convexity_gadget_matches_native() {
+        let polygon = |pts: &[(f64, f64)]| -> [Point2DDec<F, PREC>; MAX] {
+            core::array::from_fn(|i| {
+                let (x, y) = pts.get(i).copied().unwrap_or((0.0, 0.0));
+                Point2DDec::from_f64(x, y)
+            })
+        };
+        let square = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let cw_square = polygon(&[(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)]);
+        // notch at (2, 1) pointing into the square
+        let dart = polygon(&[(0.0, 0.0), (2.0, 1.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let segment = polygon(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+
+        for (name, poly, n, convex) in [
+            ("hexagon", regular_polygon(MAX), MAX, true),
+            ("pentagon", regular_polygon(5), 5, true),
+            ("square", square, 4, true),
+            ("cw square", cw_square, 4, true),
+            ("dart", dart, 5, false),
+            ("segment", segment, 3, false),
+            ("two vertices", square, 2, false),
+        ] {
+            assert_eq!(
+                is_convex_polygon::<F, PREC, MAX>(&poly, n),
+                convex,
+                "{name}"
+            );
+
+            let cs = ConstraintSystem::<F>::new_ref();
+            let pv = alloc_polygon_var(cs.clone(), &poly);
+            let nv = FpVar::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+            let gadget = is_convex_polygon_gadget::<F, PREC, MAX>(&pv, &nv).unwrap();
+            assert_eq!(gadget.value().unwrap(), convex, "{name}");
+            assert!(cs.is_satisfied().unwrap(), "{name}");
+        }
+
+        // with `enforce_convex`, a concave zone has no satisfying witness
+        let cfg = default_poseidon_config();
+        for (poly, n, convex) in [(square, 4, true), (dart, 5, false)] {
+            let mut hashes = [F::zero(); 4];
+            hashes[0] = hash_polygon::<F, PREC, MAX>(&poly, n, 0, &cfg);
+            let point = Point2DDec::from_f64(3.0, 3.0);
+            let inside = is_point_in_polygon::<F, PREC, MAX>(
+                &point,
+                &poly,
+                n,
+                BoundaryBehavior::CountAsInside,
+            );
+            let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::builder()
+                .point(point)
+                .polygon(poly, n as u64)
+                .flag(inside)
+                .map_hashes(hashes)
+                .poseidon(cfg.clone())
+                .enforce_convex(true)
+                .build()
+                .unwrap();
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            assert_eq!(cs.is_satisfied().unwrap(), convex);
+        }
+    }
 }
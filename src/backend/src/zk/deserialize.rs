@@ -0,0 +1,90 @@
+//! Decoding of base-64 and uncompressed arkworks bytes that keeps the
+//! arkworks diagnostic (e.g. a point not on the curve) for the caller.
+
+use std::fmt;
+
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
+
+#[derive(Debug)]
+pub enum DeserializeError {
+    /// `what` is not valid base-64
+    Base64 { what: String },
+    /// arkworks rejected the bytes of `what`
+    Ark {
+        what: String,
+        source: SerializationError,
+    },
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64 { what } => write!(f, "base64 decode ({what}) failed"),
+            Self::Ark { what, source } => write!(f, "{what} deserialise failed: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Base64 { .. } => None,
+            Self::Ark { source, .. } => Some(source),
+        }
+    }
+}
+
+/// `T` from its uncompressed bytes; `what` names it in the error.
+pub fn deserialize_uncompressed<T: CanonicalDeserialize>(
+    what: &str,
+    bytes: &[u8],
+) -> Result<T, DeserializeError> {
+    T::deserialize_uncompressed(bytes).map_err(|source| DeserializeError::Ark {
+        what: what.to_owned(),
+        source,
+    })
+}
+
+/// [`deserialize_uncompressed`] of base-64 text.
+pub fn deserialize_b64_uncompressed<T: CanonicalDeserialize>(
+    what: &str,
+    b64: &str,
+) -> Result<T, DeserializeError> {
+    let bytes = B64.decode(b64).map_err(|_| DeserializeError::Base64 {
+        what: what.to_owned(),
+    })?;
+    deserialize_uncompressed(what, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::{Fq, G1Affine};
+    use ark_serialize::CanonicalSerialize;
+
+    #[test]
+    fn point_off_the_curve_keeps_the_arkworks_reason() {
+        let mut bytes = Vec::new();
+        (Fq::from(1u64), Fq::from(1u64))
+            .serialize_uncompressed(&mut bytes)
+            .unwrap();
+        // (1, 1) is not on y² = x³ + 3; the flags byte stays clear
+        let err = deserialize_uncompressed::<G1Affine>("G1", &bytes).unwrap_err();
+        assert!(matches!(
+            err,
+            DeserializeError::Ark {
+                source: SerializationError::InvalidData,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            format!("G1 deserialise failed: {}", SerializationError::InvalidData)
+        );
+
+        let err = deserialize_b64_uncompressed::<G1Affine>("G1", "not*base64!").unwrap_err();
+        assert_eq!(err.to_string(), "base64 decode (G1) failed");
+    }
+}
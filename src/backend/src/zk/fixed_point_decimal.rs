@@ -1,45 +1,246 @@
-use std::borrow::Borrow;
+use std::{borrow::Borrow, cmp::Ordering};
 
 use ark_ff::PrimeField;
 use ark_r1cs_std::{
+    R1CSVar,
     alloc::{AllocVar, AllocationMode},
+    eq::EqGadget,
     fields::{FieldVar, fp::FpVar},
     prelude::{Boolean, ToBitsGadget},
 };
 use ark_relations::r1cs::{Namespace, SynthesisError};
 
-#[derive(Copy, Clone)]
+use crate::zk::range_proof::range_proof_gadget;
+
+/// Little-endian 64-bit limbs of an unsigned value below `2^256`.
+type U256 = [u64; 4];
+
+// `None` when the sum reaches `2^256`
+fn u256_add(a: U256, b: U256) -> Option<U256> {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    (!carry).then_some(out)
+}
+
+// `a - b`; callers ensure `a >= b`
+fn u256_sub(a: U256, b: U256) -> U256 {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 || b2;
+    }
+    out
+}
+
+fn u256_cmp(a: &U256, b: &U256) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+fn u128_from_u256(x: U256) -> Option<u128> {
+    match x {
+        [lo, hi, 0, 0] => Some(lo as u128 | (hi as u128) << 64),
+        _ => None,
+    }
+}
+
+fn u256_to_f64(x: U256) -> f64 {
+    x.iter()
+        .rev()
+        .fold(0.0, |acc, &limb| acc * 2f64.powi(64) + limb as f64)
+}
+
+/// ⌊√n⌋ by Newton-Raphson, `r ← (r + n/r) / 2`, in at most `iterations`
+/// steps. The guess starts above the root so the iterates only decrease;
+/// they stop once they reach ⌊√n⌋.
+fn isqrt_newton(n: u128, iterations: usize) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    // the f64 root is off by up to 2^-52 of itself once `n` passes 2^53,
+    // more than the `+ 2` covers above ~2^108
+    let guess = (n as f64).sqrt() as u128;
+    let mut r = guess + (guess >> 52) + 2;
+    for _ in 0..iterations {
+        let next = (r + n / r) / 2;
+        if next >= r {
+            break;
+        }
+        r = next;
+    }
+    r
+}
+
+fn field_element_from_u256<F: PrimeField>(x: U256) -> F {
+    let bytes: Vec<u8> = x.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+    F::from_le_bytes_mod_order(&bytes)
+}
+
+/// `10^PREC`, the raw value of 1.0 at `PREC` decimal places. A `const fn`
+/// so [`Dec::SCALE`] is folded at compile time for every `PREC`.
+pub const fn scale_factor<const PREC: u32>() -> u128 {
+    10u128.pow(PREC)
+}
+
+// every precision up to 18 has a scale that fits a u64
+const _: () = assert!(scale_factor::<18>() <= u64::MAX as u128);
+const _: u128 = scale_factor::<8>();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecPrecisionError {
+    /// `input` has digits past the `prec`-th decimal place
+    PrecisionLoss {
+        input: f64,
+        prec: u32,
+    },
+    NotFinite(f64),
+}
+
+impl std::fmt::Display for DecPrecisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PrecisionLoss { input, prec } => {
+                write!(f, "{input} has more than {prec} decimal places")
+            }
+            Self::NotFinite(input) => write!(f, "{input} is not a finite number"),
+        }
+    }
+}
+
+impl std::error::Error for DecPrecisionError {}
+
+/// A raw magnitude handed to [`Dec::from_bigint`] that is not below the
+/// field modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigIntOutOfRange;
+
+impl std::fmt::Display for BigIntOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bigint exceeds the field modulus")
+    }
+}
+
+impl std::error::Error for BigIntOutOfRange {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Dec<F: PrimeField, const PREC: u32> {
     pub val: F,
     pub neg: bool,
 }
 
 impl<F: PrimeField, const PREC: u32> Dec<F, PREC> {
-    pub const SCALE: u128 = 10u128.pow(PREC);
+    pub const SCALE: u128 = scale_factor::<PREC>();
 
+    /// Rounds to `PREC` decimal places; same as [`Self::from_f64_lossy`].
     pub fn from_f64(x: f64) -> Self {
-        let neg = x.is_sign_negative();
+        Self::from_f64_lossy(x)
+    }
+
+    /// `x` rounded to the nearest multiple of `10^-PREC`. Anything that
+    /// rounds to zero, `-0.0` included, is the one zero with `neg` unset.
+    pub fn from_f64_lossy(x: f64) -> Self {
         let val = (x.abs() * Self::SCALE as f64).round() as u128;
 
         Self {
             val: F::from(val),
-            neg: neg,
+            neg: x.is_sign_negative() && val != 0,
         }
     }
 
-    fn signed_add_u128(a_neg: bool, a_mag: u128, b_neg: bool, b_mag: u128) -> (bool, u128) {
+    /// [`Self::from_f64`], refusing values it would round.
+    ///
+    /// Up to `PREC` decimal places pass: the `f64` nearest such a decimal
+    /// is off from it by at most a few ulps of `x * 10^PREC`, which is the
+    /// tolerance.
+    pub fn try_from_f64(x: f64) -> Result<Self, DecPrecisionError> {
+        if !x.is_finite() {
+            return Err(DecPrecisionError::NotFinite(x));
+        }
+        let scaled = x.abs() * Self::SCALE as f64;
+        let tolerance = (4.0 * f64::EPSILON * scaled).max(1e-9);
+        if (scaled - scaled.round()).abs() > tolerance {
+            return Err(DecPrecisionError::PrecisionLoss {
+                input: x,
+                prec: PREC,
+            });
+        }
+        Ok(Self::from_f64_lossy(x))
+    }
+
+    pub fn to_f64(self) -> f64 {
+        let mag = u256_to_f64(Self::u256_from_field_element(self.val)) / Self::SCALE as f64;
+        if self.neg { -mag } else { mag }
+    }
+
+    /// `f32` carries ~7 significant digits, so a GPS coordinate keeps only
+    /// about 2 reliable decimal places at `PREC = 8`; the rest is rounding
+    /// noise. Prefer [`Dec::from_f64`].
+    ///
+    /// Deprecated for every `PREC`: attributes cannot depend on a const
+    /// generic, and the circuit runs at `PREC = 8`.
+    #[deprecated(note = "use from_f64 for PREC >= 4")]
+    pub fn from_f32(x: f32) -> Self {
+        let val = (x.abs() * Self::SCALE as f32).round() as u128;
+
+        Self {
+            val: F::from(val),
+            neg: x.is_sign_negative() && val != 0,
+        }
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Raw magnitude and sign, for serialization only: `val` is taken as
+    /// is, **not** scaled by `10^PREC`, so `from_bigint(1.into(), false)` is
+    /// `10^-PREC`, not `1`. `bigint` must be below the field modulus.
+    pub fn from_bigint(
+        bigint: <F as PrimeField>::BigInt,
+        neg: bool,
+    ) -> Result<Self, BigIntOutOfRange> {
+        let val = F::from_bigint(bigint).ok_or(BigIntOutOfRange)?;
+        Ok(Self { val, neg })
+    }
+
+    /// Raw magnitude and sign, for serialization only; the inverse of
+    /// [`Dec::from_bigint`] and likewise unaware of `PREC`.
+    pub fn into_bigint(self) -> (<F as PrimeField>::BigInt, bool) {
+        (self.val.into_bigint(), self.neg)
+    }
+
+    // `None` when equal signs add up past `2^256`
+    fn signed_add_u256(a_neg: bool, a_mag: U256, b_neg: bool, b_mag: U256) -> Option<(bool, U256)> {
         if a_neg == b_neg {
-            (a_neg, a_mag.saturating_add(b_mag))
+            Some((a_neg, u256_add(a_mag, b_mag)?))
+        } else if u256_cmp(&a_mag, &b_mag) != Ordering::Less {
+            Some((a_neg, u256_sub(a_mag, b_mag)))
         } else {
-            if a_mag >= b_mag {
-                (a_neg, a_mag - b_mag)
-            } else {
-                (b_neg, b_mag - a_mag)
-            }
+            Some((b_neg, u256_sub(b_mag, a_mag)))
         }
     }
 
-    fn u128_from_field_element(f: F) -> u128 {
+    /// All four 64-bit limbs of `f`, least significant first; fields wider
+    /// than 256 bits lose their top limbs.
+    pub(crate) fn u256_from_field_element(f: F) -> U256 {
+        let mut limbs = [0u64; 4];
+        for (dst, src) in limbs.iter_mut().zip(f.into_bigint().as_ref()) {
+            *dst = *src;
+        }
+        limbs
+    }
+
+    /// Low 128 bits of `f`, for values range-checked to fit (sector
+    /// bearings and radii); use [`Self::u256_from_field_element`] otherwise.
+    pub(crate) fn u128_from_field_element(f: F) -> u128 {
         let bigint = f.into_bigint();
         let limbs = bigint.as_ref();
 
@@ -50,19 +251,29 @@ impl<F: PrimeField, const PREC: u32> Dec<F, PREC> {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, rhs: Self) -> Self {
-        let a_u128 = Self::u128_from_field_element(self.val);
-        let b_u128 = Self::u128_from_field_element(rhs.val);
+        let a = Self::u256_from_field_element(self.val);
+        let b = Self::u256_from_field_element(rhs.val);
 
-        let (res_neg, res_val_u128) = Self::signed_add_u128(self.neg, a_u128, rhs.neg, b_u128);
-        let res_neg = if res_val_u128 == 0 { false } else { res_neg };
+        // only fields of 256 bits can overflow: the signs agree, and the
+        // magnitudes add mod `p` as the field does
+        let Some((res_neg, res_val)) = Self::signed_add_u256(self.neg, a, rhs.neg, b) else {
+            let val = self.val + rhs.val;
+            return Self {
+                val,
+                neg: self.neg && !val.is_zero(),
+            };
+        };
+        let res_neg = if res_val == [0; 4] { false } else { res_neg };
 
         Self {
-            val: F::from(res_val_u128),
+            val: field_element_from_u256(res_val),
             neg: res_neg,
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, rhs: Self) -> Self {
         let mut r_negated = rhs;
         if rhs.val == F::zero() {
@@ -73,6 +284,7 @@ impl<F: PrimeField, const PREC: u32> Dec<F, PREC> {
         self.add(r_negated)
     }
 
+    /// Multiplies in `F`, so magnitudes of any width are kept (mod `p`).
     pub fn mul_unscaled(self, rhs: Self) -> Self {
         let result_val = self.val * rhs.val;
         let result_neg = if result_val.is_zero() {
@@ -86,8 +298,139 @@ impl<F: PrimeField, const PREC: u32> Dec<F, PREC> {
             neg: result_neg,
         }
     }
+
+    pub fn zero() -> Self {
+        Self {
+            val: F::zero(),
+            neg: false,
+        }
+    }
+
+    /// Square root, rounded down to the last of the `PREC` places: the raw
+    /// magnitude is `⌊√(val · 10^PREC)⌋`. Matches [`sqrt_gadget`].
+    ///
+    /// Panics if `self` is negative or `val · 10^PREC` overflows `u128`.
+    pub fn sqrt(self) -> Self {
+        assert!(
+            !self.neg || self.val.is_zero(),
+            "square root of a negative Dec"
+        );
+        let n = u128_from_u256(Self::u256_from_field_element(self.val))
+            .and_then(|val| val.checked_mul(Self::SCALE))
+            .expect("Dec::sqrt input too large");
+
+        Self {
+            val: F::from(isqrt_newton(n, 128)),
+            neg: false,
+        }
+    }
+
+    /// Sum of all items (empty → zero).
+    pub fn sum<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::zero(), Self::add)
+    }
+
+    /// Unscaled product of all items (empty → raw `1`); like `mul_unscaled`,
+    /// the result carries `PREC * n` decimal places.
+    pub fn product<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        let one = Self {
+            val: F::one(),
+            neg: false,
+        };
+        iter.into_iter().fold(one, Self::mul_unscaled)
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> core::iter::Sum for Dec<F, PREC> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Dec::sum(iter)
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> core::iter::Product for Dec<F, PREC> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Dec::product(iter)
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> std::ops::Add for Dec<F, PREC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dec::add(self, rhs)
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> std::ops::Sub for Dec<F, PREC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dec::sub(self, rhs)
+    }
+}
+
+/// [`Dec::mul_unscaled`]: the product carries `2·PREC` places.
+impl<F: PrimeField, const PREC: u32> std::ops::Mul for Dec<F, PREC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_unscaled(rhs)
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> std::ops::AddAssign for Dec<F, PREC> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = Dec::add(*self, rhs);
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> std::ops::SubAssign for Dec<F, PREC> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = Dec::sub(*self, rhs);
+    }
 }
 
+/// Newton steps [`sqrt_gadget`] needs for any geographic distance when the
+/// hint is an `f64` square root.
+pub const SQRT_ITERATIONS: usize = 3;
+
+// width of the range checks on the root and the remainder; the root itself
+// is capped at `u64::MAX` so `r²` cannot wrap the field
+const SQRT_RANGE_BITS: usize = 128;
+
+/// [`Dec::sqrt`] in-circuit: the root is witnessed by running `ITERATIONS`
+/// Newton steps from an `f64` hint, then pinned to the rounded-down root by
+/// `r² <= x·10^PREC <= r² + 2r`. Too few iterations leave the witness off
+/// the root and the constraint system unsatisfied.
+///
+/// `x` must be a non-negative witness.
+pub fn sqrt_gadget<F: PrimeField, const PREC: u32, const ITERATIONS: usize>(
+    x: &DecVar<F, PREC>,
+) -> Result<DecVar<F, PREC>, SynthesisError> {
+    x.neg.enforce_equal(&Boolean::FALSE)?;
+    let n = &x.val * F::from(Dec::<F, PREC>::SCALE);
+
+    let r = FpVar::new_witness(x.val.cs(), || {
+        let n = u128_from_u256(Dec::<F, PREC>::u256_from_field_element(n.value()?))
+            .ok_or(SynthesisError::Unsatisfiable)?;
+        Ok(F::from(isqrt_newton(n, ITERATIONS)))
+    })?;
+
+    let zero = FpVar::<F>::zero();
+    let max_root = FpVar::constant(F::from(u64::MAX));
+    range_proof_gadget::<F, SQRT_RANGE_BITS>(&r, &zero, &max_root)?
+        .enforce_equal(&Boolean::TRUE)?;
+    let rem = &n - &r * &r;
+    range_proof_gadget::<F, SQRT_RANGE_BITS>(&rem, &zero, &r.double()?)?
+        .enforce_equal(&Boolean::TRUE)?;
+
+    Ok(DecVar {
+        val: r,
+        neg: Boolean::FALSE,
+    })
+}
+
+#[derive(Clone)]
 pub struct DecVar<F: PrimeField, const PREC: u32> {
     pub val: FpVar<F>,
     pub neg: Boolean<F>,
@@ -144,6 +487,14 @@ impl<F: PrimeField, const PREC: u32> DecVar<F, PREC> {
         })
     }
 
+    /// `-self`; zero stays `+0`, so equal values keep equal signs.
+    pub fn negate(&self) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            val: self.val.clone(),
+            neg: !&self.neg & !self.val.is_zero()?,
+        })
+    }
+
     pub fn mul_unscaled(&self, rhs: &Self) -> Result<Self, SynthesisError> {
         let result_val = &self.val * &rhs.val;
         let result_is_zero = result_val.is_zero()?;
@@ -155,4 +506,540 @@ impl<F: PrimeField, const PREC: u32> DecVar<F, PREC> {
             neg: result_neg,
         })
     }
+
+    /// Hard constraint: magnitude and sign both match.
+    pub fn enforce_equal(&self, other: &Self) -> Result<(), SynthesisError> {
+        self.val.enforce_equal(&other.val)?;
+        self.neg.enforce_equal(&other.neg)
+    }
+
+    pub fn is_equal(&self, other: &Self) -> Result<Boolean<F>, SynthesisError> {
+        Ok(self.val.is_eq(&other.val)? & self.neg.is_eq(&other.neg)?)
+    }
+
+    /// `t` when `cond` holds, else `f`; magnitude and sign selected separately.
+    pub fn conditional_select(
+        cond: &Boolean<F>,
+        t: &Self,
+        f: &Self,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            val: cond.select(&t.val, &f.val)?,
+            neg: cond.select(&t.neg, &f.neg)?,
+        })
+    }
+
+    pub fn zero() -> Self {
+        Self {
+            val: FpVar::zero(),
+            neg: Boolean::FALSE,
+        }
+    }
+
+    pub fn sum<I: IntoIterator<Item = Self>>(iter: I) -> Result<Self, SynthesisError> {
+        iter.into_iter()
+            .try_fold(Self::zero(), |acc, x| acc.add(&x))
+    }
+
+    pub fn product<I: IntoIterator<Item = Self>>(iter: I) -> Result<Self, SynthesisError> {
+        let one = Self {
+            val: FpVar::one(),
+            neg: Boolean::FALSE,
+        };
+        iter.into_iter()
+            .try_fold(one, |acc, x| acc.mul_unscaled(&x))
+    }
+}
+
+// Only on references: an owned `impl Add for DecVar` would shadow the
+// inherent `add(&self, ..)` in method calls. Panics on `SynthesisError`, as
+// the `FpVar` operators do.
+impl<F: PrimeField, const PREC: u32> std::ops::Add for &DecVar<F, PREC> {
+    type Output = DecVar<F, PREC>;
+
+    fn add(self, rhs: Self) -> DecVar<F, PREC> {
+        DecVar::add(self, rhs).expect("DecVar addition failed")
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> std::ops::Sub for &DecVar<F, PREC> {
+    type Output = DecVar<F, PREC>;
+
+    fn sub(self, rhs: Self) -> DecVar<F, PREC> {
+        DecVar::sub(self, rhs).expect("DecVar subtraction failed")
+    }
+}
+
+/// Signed fixed-point arithmetic shared by [`Dec`] and [`DecVar`], so one
+/// generic function serves both the native check and the gadget.
+///
+/// Not a field: there is no inverse, and `mul_unscaled` returns `2·PREC`
+/// places. The native ops never fail; the `Result` is for [`DecVar`].
+pub trait SignedArith<F: PrimeField, const PREC: u32>: Sized {
+    fn add(&self, rhs: &Self) -> Result<Self, SynthesisError>;
+    fn sub(&self, rhs: &Self) -> Result<Self, SynthesisError>;
+    fn mul_unscaled(&self, rhs: &Self) -> Result<Self, SynthesisError>;
+    /// `-self`; zero stays non-negative.
+    fn neg(&self) -> Result<Self, SynthesisError>;
+    fn zero() -> Self;
+    /// `1.0`, i.e. raw `10^PREC`.
+    fn one() -> Self;
+    fn abs(&self) -> Self;
+    /// `-1.0`, `0` or `1.0`, like `f64::signum` but with `signum(0) == 0`.
+    fn signum(&self) -> Result<Self, SynthesisError>;
+}
+
+impl<F: PrimeField, const PREC: u32> SignedArith<F, PREC> for Dec<F, PREC> {
+    fn add(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        Ok(Dec::add(*self, *rhs))
+    }
+
+    fn sub(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        Ok(Dec::sub(*self, *rhs))
+    }
+
+    fn mul_unscaled(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        Ok(Dec::mul_unscaled(*self, *rhs))
+    }
+
+    fn neg(&self) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            val: self.val,
+            neg: !self.neg && !self.val.is_zero(),
+        })
+    }
+
+    fn zero() -> Self {
+        Dec::zero()
+    }
+
+    fn one() -> Self {
+        Self {
+            val: F::from(Self::SCALE),
+            neg: false,
+        }
+    }
+
+    fn abs(&self) -> Self {
+        Self {
+            val: self.val,
+            neg: false,
+        }
+    }
+
+    fn signum(&self) -> Result<Self, SynthesisError> {
+        if self.val.is_zero() {
+            return Ok(Dec::zero());
+        }
+        Ok(Self {
+            neg: self.neg,
+            ..<Self as SignedArith<F, PREC>>::one()
+        })
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> SignedArith<F, PREC> for DecVar<F, PREC> {
+    fn add(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        DecVar::add(self, rhs)
+    }
+
+    fn sub(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        DecVar::sub(self, rhs)
+    }
+
+    fn mul_unscaled(&self, rhs: &Self) -> Result<Self, SynthesisError> {
+        DecVar::mul_unscaled(self, rhs)
+    }
+
+    fn neg(&self) -> Result<Self, SynthesisError> {
+        self.negate()
+    }
+
+    fn zero() -> Self {
+        DecVar::zero()
+    }
+
+    fn one() -> Self {
+        Self {
+            val: FpVar::constant(F::from(Dec::<F, PREC>::SCALE)),
+            neg: Boolean::FALSE,
+        }
+    }
+
+    fn abs(&self) -> Self {
+        Self {
+            val: self.val.clone(),
+            neg: Boolean::FALSE,
+        }
+    }
+
+    fn signum(&self) -> Result<Self, SynthesisError> {
+        let is_zero = self.val.is_zero()?;
+        let one = FpVar::constant(F::from(Dec::<F, PREC>::SCALE));
+        Ok(Self {
+            val: is_zero.select(&FpVar::zero(), &one)?,
+            neg: &self.neg & !is_zero,
+        })
+    }
+}
+
+/// `Σ aᵢ·bᵢ` with `2·PREC` places, over [`Dec`] or [`DecVar`].
+pub fn signed_inner_product<F, const PREC: u32, T>(a: &[T], b: &[T]) -> Result<T, SynthesisError>
+where
+    F: PrimeField,
+    T: SignedArith<F, PREC>,
+{
+    a.iter()
+        .zip(b)
+        .try_fold(T::zero(), |acc, (x, y)| acc.add(&x.mul_unscaled(y)?))
+}
+
+/// Native [`signed_inner_product`] of two `N`-vectors.
+pub fn inner_product<F: PrimeField, const PREC: u32, const N: usize>(
+    a: &[Dec<F, PREC>; N],
+    b: &[Dec<F, PREC>; N],
+) -> Dec<F, PREC> {
+    signed_inner_product(a, b).expect("native Dec arithmetic cannot fail")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::Fr;
+    use ark_ff::{Field, One, Zero};
+    use ark_r1cs_std::R1CSVar;
+    use ark_relations::r1cs::{ConstraintSystem, ConstraintSystemRef};
+
+    type F = Fr;
+    const PREC: u32 = 8;
+
+    fn decs(xs: &[f64]) -> Vec<Dec<F, PREC>> {
+        xs.iter().map(|&x| Dec::from_f64(x)).collect()
+    }
+
+    #[test]
+    fn bigint_round_trip_keeps_raw_magnitude() {
+        for x in decs(&[0.0, 1.0, -2.5, 123456.789, -0.00000001]) {
+            let (bigint, neg) = x.into_bigint();
+            assert_eq!(Dec::<F, PREC>::from_bigint(bigint, neg), Ok(x));
+        }
+
+        // no scaling: raw 150000000 is 1.5 at PREC = 8
+        let (bigint, neg) = Dec::<F, PREC>::from_f64(-1.5).into_bigint();
+        assert_eq!(bigint, F::from(150_000_000u64).into_bigint());
+        assert!(neg);
+        let raw_one = Dec::<F, PREC>::from_bigint(F::one().into_bigint(), false).unwrap();
+        assert_eq!(raw_one.to_f64(), 1e-8);
+
+        assert_eq!(
+            Dec::<F, PREC>::from_bigint(F::MODULUS, false),
+            Err(BigIntOutOfRange)
+        );
+    }
+
+    #[test]
+    fn sum_and_product_match_gadgets() {
+        let xs = decs(&[1.5, -4.25, 2.0, -0.75]);
+
+        let sum: Dec<F, PREC> = xs.iter().copied().sum();
+        let prod: Dec<F, PREC> = xs.iter().copied().product();
+        assert_eq!(sum.val, Dec::<F, PREC>::from_f64(1.5).val); // -1.5
+        assert!(sum.neg);
+        assert!(!prod.neg); // two negatives
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let vars: Vec<_> = xs
+            .iter()
+            .map(|x| DecVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+        let sum_var = DecVar::sum(vars.iter().cloned()).unwrap();
+        let prod_var = DecVar::product(vars).unwrap();
+
+        assert_eq!(sum_var.val.value().unwrap(), sum.val);
+        assert_eq!(sum_var.neg.value().unwrap(), sum.neg);
+        assert_eq!(prod_var.val.value().unwrap(), prod.val);
+        assert_eq!(prod_var.neg.value().unwrap(), prod.neg);
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    fn alloc_pair(a: f64, b: f64) -> (ConstraintSystemRef<F>, DecVar<F, PREC>, DecVar<F, PREC>) {
+        let cs = ConstraintSystem::<F>::new_ref();
+        let a = DecVar::new_witness(cs.clone(), || Ok(Dec::<F, PREC>::from_f64(a))).unwrap();
+        let b = DecVar::new_witness(cs.clone(), || Ok(Dec::<F, PREC>::from_f64(b))).unwrap();
+        (cs, a, b)
+    }
+
+    #[test]
+    fn enforce_equal_satisfied_for_identical_values() {
+        let (cs, a, b) = alloc_pair(-12.5, -12.5);
+        assert!(a.is_equal(&b).unwrap().value().unwrap());
+        a.enforce_equal(&b).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn enforce_equal_unsatisfied_on_mismatch() {
+        // same magnitude, opposite sign
+        let (cs, a, b) = alloc_pair(3.0, -3.0);
+        assert!(!a.is_equal(&b).unwrap().value().unwrap());
+        a.enforce_equal(&b).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        let (cs, a, b) = alloc_pair(3.0, 3.000_000_01);
+        assert!(!a.is_equal(&b).unwrap().value().unwrap());
+        a.enforce_equal(&b).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn try_from_f64_refuses_digits_past_prec() {
+        for x in [53.12345678, -122.4194155, 0.1, 180.0, 0.0, 1e-8] {
+            assert_eq!(
+                Dec::<F, PREC>::try_from_f64(x),
+                Ok(Dec::from_f64_lossy(x)),
+                "{x}"
+            );
+        }
+        for x in [53.123456789, 53.1234567891, 1e-9] {
+            assert_eq!(
+                Dec::<F, PREC>::try_from_f64(x),
+                Err(DecPrecisionError::PrecisionLoss {
+                    input: x,
+                    prec: PREC
+                }),
+                "{x}"
+            );
+        }
+        assert!(matches!(
+            Dec::<F, PREC>::try_from_f64(f64::NAN),
+            Err(DecPrecisionError::NotFinite(_))
+        ));
+    }
+
+    #[test]
+    fn values_rounding_to_zero_are_positive_zero() {
+        for x in [-0.0, -1e-12, 1e-12] {
+            assert_eq!(Dec::<F, PREC>::from_f64(x), Dec::zero(), "{x}");
+        }
+        assert!(Dec::<F, PREC>::from_f64(-1e-8).neg);
+    }
+
+    #[test]
+    fn negate_twice_is_identity() {
+        for x in [12.5, -3.25, 0.0] {
+            let cs = ConstraintSystem::<F>::new_ref();
+            let var = DecVar::new_witness(cs.clone(), || Ok(Dec::<F, PREC>::from_f64(x))).unwrap();
+            let neg = var.negate().unwrap();
+            assert_eq!(neg.neg.value().unwrap(), x > 0.0);
+            neg.negate().unwrap().enforce_equal(&var).unwrap();
+            assert!(cs.is_satisfied().unwrap(), "{x}");
+        }
+    }
+
+    #[test]
+    fn conditional_select_picks_each_branch() {
+        let (cs, a, b) = alloc_pair(-7.25, 4.5);
+        for pick_a in [true, false] {
+            let cond = Boolean::new_witness(cs.clone(), || Ok(pick_a)).unwrap();
+            let chosen = DecVar::conditional_select(&cond, &a, &b).unwrap();
+            let expected = if pick_a { &a } else { &b };
+            chosen.enforce_equal(expected).unwrap();
+            assert_eq!(chosen.val.value().unwrap(), expected.val.value().unwrap());
+            assert_eq!(chosen.neg.value().unwrap(), expected.neg.value().unwrap());
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn f32_loses_precision_against_f64() {
+        // San Francisco latitude, as a phone might report it
+        let lat = 37.774_929;
+        let from_f64 = Dec::<F, PREC>::from_f64(lat);
+        let from_f32 = Dec::<F, PREC>::from_f32(lat as f32);
+
+        assert_ne!(from_f32.val, from_f64.val);
+        assert!((from_f32.to_f64() - lat).abs() < 1e-2);
+        assert!((from_f64.to_f64() - lat).abs() < 1e-8);
+        assert_eq!(from_f64.to_f32(), lat as f32);
+
+        // zero has one encoding here too
+        for x in [-0.0f32, -1e-12] {
+            assert_eq!(Dec::<F, PREC>::from_f32(x), Dec::zero(), "{x}");
+        }
+    }
+
+    #[test]
+    fn arithmetic_keeps_limbs_above_2_pow_128() {
+        let two_pow = |n: u64| F::from(2u64).pow([n]);
+        let big = |n: u64, neg: bool| Dec::<F, PREC> {
+            val: two_pow(n),
+            neg,
+        };
+
+        assert_eq!(
+            Dec::<F, PREC>::u256_from_field_element(two_pow(200)),
+            [0, 0, 0, 1 << 8]
+        );
+        assert_eq!(big(130, false).to_f64(), 2f64.powi(130) / 1e8);
+        assert_eq!(big(129, true).to_f64(), -(2f64.powi(129) / 1e8));
+
+        let sum = big(129, false).add(big(129, false));
+        assert_eq!(sum, big(130, false));
+        let diff = big(130, false).sub(big(130, false).add(big(0, false)));
+        assert_eq!(diff, big(0, true));
+        let diff = big(200, true).sub(big(129, true));
+        assert_eq!(diff.val, two_pow(200) - two_pow(129));
+        assert!(diff.neg);
+        assert_eq!(big(140, false).sub(big(140, false)), Dec::zero());
+
+        let prod = big(100, true).mul_unscaled(big(100, false));
+        assert_eq!(prod, big(200, true));
+
+        // overflow is reported, not saturated
+        assert_eq!(u256_add([u64::MAX; 4], [1, 0, 0, 0]), None);
+        assert_eq!(
+            u256_add([u64::MAX, 0, 0, 0], [1, 0, 0, 0]),
+            Some([0, 1, 0, 0])
+        );
+    }
+
+    #[test]
+    fn sqrt_matches_gadget_and_rounds_down() {
+        // 0 m to 20,000 km, squared
+        for x in [0.0, 1e-8, 2.0, 9.0, 1234.5678, 4e8, 4e14] {
+            let dec = Dec::<F, PREC>::from_f64(x);
+            let root = dec.sqrt();
+            assert!(
+                (root.to_f64() - x.sqrt()).abs() < 1e-8 + x.sqrt() * 1e-15,
+                "√{x}"
+            );
+
+            let cs = ConstraintSystem::<F>::new_ref();
+            let var = DecVar::new_witness(cs.clone(), || Ok(dec)).unwrap();
+            let root_var = sqrt_gadget::<F, PREC, SQRT_ITERATIONS>(&var).unwrap();
+            assert_eq!(root_var.val.value().unwrap(), root.val, "√{x}");
+            assert!(cs.is_satisfied().unwrap(), "√{x}");
+        }
+        assert_eq!(Dec::<F, PREC>::from_f64(9.0).sqrt(), Dec::from_f64(3.0));
+        // √2 at 8 places is 1.41421356(23…), rounded down
+        assert_eq!(
+            Dec::<F, PREC>::from_f64(2.0).sqrt(),
+            Dec::from_f64(1.414_213_56)
+        );
+    }
+
+    #[test]
+    fn isqrt_is_exact_up_to_u128_max() {
+        let root = u64::MAX as u128;
+        let near_max = root - 512;
+        let above_2_126 = (1 << 63) + 3;
+        for n in [
+            u128::MAX,
+            u128::MAX - 1,
+            root * root,
+            root * root - 1,
+            // the old `f64` guess started below these roots
+            near_max * near_max,
+            above_2_126 * above_2_126,
+            (1 << 120) - 1,
+        ] {
+            assert_eq!(isqrt_newton(n, 128), n.isqrt(), "{n}");
+        }
+    }
+
+    #[test]
+    fn sqrt_gadget_rejects_a_wrong_root() {
+        let cs = ConstraintSystem::<F>::new_ref();
+        let x = DecVar::new_witness(cs.clone(), || Ok(Dec::<F, PREC>::from_f64(2.0))).unwrap();
+        // no Newton steps: the witness stays on the f64 guess, above the root
+        sqrt_gadget::<F, PREC, 0>(&x).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let x = DecVar::new_witness(cs.clone(), || Ok(Dec::<F, PREC>::from_f64(-4.0))).unwrap();
+        sqrt_gadget::<F, PREC, SQRT_ITERATIONS>(&x).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn signed_arith_matches_between_dec_and_gadget() {
+        let a = decs(&[1.5, -2.0, 0.25]);
+        let b = decs(&[-4.0, -0.5, 8.0]);
+        // -6 + 1 + 2, at 16 places
+        let dot = inner_product::<F, PREC, 3>(&[a[0], a[1], a[2]], &[b[0], b[1], b[2]]);
+        assert_eq!(
+            dot.val,
+            F::from(3u64) * F::from(Dec::<F, PREC>::SCALE).square()
+        );
+        assert!(dot.neg);
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let alloc = |xs: &[Dec<F, PREC>]| -> Vec<DecVar<F, PREC>> {
+            xs.iter()
+                .map(|x| DecVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect()
+        };
+        let (a_var, b_var) = (alloc(&a), alloc(&b));
+        let dot_var = signed_inner_product(&a_var, &b_var).unwrap();
+        assert_eq!(dot_var.val.value().unwrap(), dot.val);
+        assert_eq!(dot_var.neg.value().unwrap(), dot.neg);
+
+        let xs = decs(&[-3.5, 0.0, 2.0]);
+        for (x, var) in xs.iter().zip(alloc(&xs)) {
+            let native = [
+                SignedArith::neg(x).unwrap(),
+                SignedArith::abs(x),
+                x.signum().unwrap(),
+            ];
+            let gadget = [
+                SignedArith::neg(&var).unwrap(),
+                SignedArith::abs(&var),
+                var.signum().unwrap(),
+            ];
+            for (n, g) in native.iter().zip(&gadget) {
+                assert_eq!(g.val.value().unwrap(), n.val, "{}", x.to_f64());
+                assert_eq!(g.neg.value().unwrap(), n.neg, "{}", x.to_f64());
+            }
+        }
+        assert!(cs.is_satisfied().unwrap());
+
+        let x = Dec::<F, PREC>::from_f64(-3.5);
+        assert_eq!(SignedArith::neg(&x).unwrap(), Dec::from_f64(3.5));
+        assert_eq!(x.signum().unwrap(), Dec::from_f64(-1.0));
+        assert_eq!(
+            <Dec<F, PREC> as SignedArith<F, PREC>>::one(),
+            Dec::from_f64(1.0)
+        );
+    }
+
+    #[test]
+    fn operators_match_methods() {
+        let [a, b, c] = [1.5, -4.25, 0.5].map(Dec::<F, PREC>::from_f64);
+        assert_eq!(a + b - c, Dec::from_f64(-3.25));
+        assert_eq!(a - a, Dec::zero());
+        assert_eq!(b * c, b.mul_unscaled(c));
+        assert!((b * c).neg);
+
+        let mut acc = a;
+        acc += b;
+        acc -= c;
+        assert_eq!(acc, a + b - c);
+
+        let (cs, a_var, b_var) = alloc_pair(1.5, -4.25);
+        for (var, native) in [(&a_var + &b_var, a + b), (&a_var - &b_var, a - b)] {
+            assert_eq!(var.val.value().unwrap(), native.val);
+            assert_eq!(var.neg.value().unwrap(), native.neg);
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn empty_sum_and_product() {
+        let sum = Dec::<F, PREC>::sum([]);
+        let prod = Dec::<F, PREC>::product([]);
+        assert_eq!(sum.val, F::zero());
+        assert_eq!(prod.val, F::one());
+    }
 }
@@ -0,0 +1,339 @@
+//! Arena layouts exported by map editors as a GeoJSON `FeatureCollection`:
+//! one `Polygon` feature per zone, named by its `name` property.
+//!
+//! Positions are `[lon, lat]` (RFC 7946) and rings repeat their first
+//! position at the end; the closing position is dropped before counting
+//! vertices. Clockwise rings are reversed, as RFC 7946 only recommends
+//! counter-clockwise. Zones with holes or a concave ring are refused: the
+//! circuit has no way to subtract holes, and its containment test is only
+//! correct for convex polygons.
+
+use std::{collections::HashMap, fmt};
+
+use ark_bn254::Fr;
+use serde::Deserialize;
+
+use crate::zk::{
+    circuit::is_convex_polygon,
+    point_2d::Point2DDec,
+    polygon_ops::{ensure_ccw_winding, polygon_from_gps},
+};
+
+/// Zone polygons by name, projected and padded as `polygon_from_gps`
+/// returns them, wound counter-clockwise.
+pub type Arena<const PREC: u32, const MAX_VERTS: usize> =
+    HashMap<String, ([Point2DDec<Fr, PREC>; MAX_VERTS], usize)>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArenaLoadError {
+    /// not JSON, or not shaped like GeoJSON
+    Malformed(String),
+    /// the top-level `type` is not `FeatureCollection`
+    NotAFeatureCollection(String),
+    /// `index` is the feature's position in `features`
+    MissingName {
+        index: usize,
+    },
+    DuplicateZone(String),
+    UnsupportedGeometry {
+        zone: String,
+        geometry: String,
+    },
+    TooFewVertices {
+        zone: String,
+        count: usize,
+    },
+    TooManyVertices {
+        zone: String,
+        count: usize,
+    },
+    NotConvex(String),
+}
+
+impl fmt::Display for ArenaLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(e) => write!(f, "malformed GeoJSON: {e}"),
+            Self::NotAFeatureCollection(kind) => {
+                write!(f, "expected a FeatureCollection, got {kind:?}")
+            }
+            Self::MissingName { index } => write!(f, "feature {index} has no \"name\" property"),
+            Self::DuplicateZone(zone) => write!(f, "zone {zone:?} appears more than once"),
+            Self::UnsupportedGeometry { zone, geometry } => {
+                write!(
+                    f,
+                    "zone {zone:?}: {geometry} is not supported, only Polygon"
+                )
+            }
+            Self::TooFewVertices { zone, count } => {
+                write!(f, "zone {zone:?} has {count} vertices, need at least 3")
+            }
+            Self::TooManyVertices { zone, count } => {
+                write!(
+                    f,
+                    "zone {zone:?} has {count} vertices, the circuit takes fewer"
+                )
+            }
+            Self::NotConvex(zone) => write!(f, "zone {zone:?} is not convex"),
+        }
+    }
+}
+
+impl std::error::Error for ArenaLoadError {}
+
+#[derive(Deserialize)]
+struct FeatureCollection {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    features: Vec<Feature>,
+}
+
+#[derive(Deserialize)]
+struct Feature {
+    #[serde(default)]
+    properties: Option<Properties>,
+    geometry: Option<Geometry>,
+}
+
+#[derive(Deserialize)]
+struct Properties {
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Geometry {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    coordinates: serde_json::Value,
+}
+
+/// Parse a `FeatureCollection` of named `Polygon` zones.
+pub fn load_arena_from_geojson<const PREC: u32, const MAX_VERTS: usize>(
+    json: &str,
+) -> Result<Arena<PREC, MAX_VERTS>, ArenaLoadError> {
+    let collection: FeatureCollection =
+        serde_json::from_str(json).map_err(|e| ArenaLoadError::Malformed(e.to_string()))?;
+    if collection.kind != "FeatureCollection" {
+        return Err(ArenaLoadError::NotAFeatureCollection(collection.kind));
+    }
+
+    let mut arena = Arena::with_capacity(collection.features.len());
+    for (index, feature) in collection.features.into_iter().enumerate() {
+        let zone = feature
+            .properties
+            .and_then(|p| p.name)
+            .ok_or(ArenaLoadError::MissingName { index })?;
+        if arena.contains_key(&zone) {
+            return Err(ArenaLoadError::DuplicateZone(zone));
+        }
+
+        let ring = outer_ring(&zone, feature.geometry)?;
+        if ring.len() < 3 {
+            return Err(ArenaLoadError::TooFewVertices {
+                zone,
+                count: ring.len(),
+            });
+        }
+        if ring.len() > MAX_VERTS {
+            return Err(ArenaLoadError::TooManyVertices {
+                zone,
+                count: ring.len(),
+            });
+        }
+
+        let (mut poly, n) = polygon_from_gps::<Fr, PREC, MAX_VERTS>(&ring);
+        ensure_ccw_winding(&mut poly, n);
+        if !is_convex_polygon(&poly, n) {
+            return Err(ArenaLoadError::NotConvex(zone));
+        }
+        arena.insert(zone, (poly, n));
+    }
+    Ok(arena)
+}
+
+// `[lat, lon]` vertices of a hole-free Polygon, closing position dropped
+fn outer_ring(zone: &str, geometry: Option<Geometry>) -> Result<Vec<[f64; 2]>, ArenaLoadError> {
+    let geometry = geometry.ok_or_else(|| ArenaLoadError::UnsupportedGeometry {
+        zone: zone.to_owned(),
+        geometry: "null geometry".to_owned(),
+    })?;
+    if geometry.kind != "Polygon" {
+        return Err(ArenaLoadError::UnsupportedGeometry {
+            zone: zone.to_owned(),
+            geometry: geometry.kind,
+        });
+    }
+
+    let rings: Vec<Vec<Vec<f64>>> = serde_json::from_value(geometry.coordinates)
+        .map_err(|e| ArenaLoadError::Malformed(format!("zone {zone:?}: {e}")))?;
+    let ring = match rings.as_slice() {
+        [ring] => ring,
+        [] => return Ok(Vec::new()),
+        _ => {
+            return Err(ArenaLoadError::UnsupportedGeometry {
+                zone: zone.to_owned(),
+                geometry: "Polygon with holes".to_owned(),
+            });
+        }
+    };
+
+    let mut vertices = ring
+        .iter()
+        .map(|pos| match pos.as_slice() {
+            [lon, lat, ..] => Ok([*lat, *lon]),
+            _ => Err(ArenaLoadError::Malformed(format!(
+                "zone {zone:?}: position {pos:?} needs a longitude and a latitude"
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    Ok(vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::zk::{
+        circuit::{BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, is_point_in_polygon},
+        polygon_ops::polygon_area,
+    };
+
+    // spawn, a square arena centre and a hexagonal loot zone around SF
+    const ARENA_3_ZONES: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "name": "spawn" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[
+                        [-122.43, 37.77], [-122.42, 37.77], [-122.425, 37.78], [-122.43, 37.77]
+                    ]]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": { "name": "centre" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[
+                        [-122.41, 37.77], [-122.40, 37.77], [-122.40, 37.78],
+                        [-122.41, 37.78], [-122.41, 37.77]
+                    ]]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": { "name": "loot" },
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[
+                        [-122.39, 37.775], [-122.385, 37.77], [-122.38, 37.77],
+                        [-122.375, 37.775], [-122.38, 37.78], [-122.385, 37.78],
+                        [-122.39, 37.775]
+                    ]]
+                }
+            }
+        ]
+    }"#;
+
+    type TestArena = Arena<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>;
+
+    fn load(json: &str) -> Result<TestArena, ArenaLoadError> {
+        load_arena_from_geojson::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES>(json)
+    }
+
+    #[test]
+    fn loads_every_zone_by_name() {
+        let arena = load(ARENA_3_ZONES).unwrap();
+        assert_eq!(arena.len(), 3);
+        assert_eq!(arena["spawn"].1, 3);
+        assert_eq!(arena["centre"].1, 4);
+        assert_eq!(arena["loot"].1, 6);
+
+        let (poly, n) = arena["centre"];
+        let inside = Point2DDec::from_lat_lon(37.775, -122.405);
+        let outside = Point2DDec::from_lat_lon(37.775, -122.415);
+        assert!(is_point_in_polygon(
+            &inside,
+            &poly,
+            n,
+            BoundaryBehavior::CountAsInside
+        ));
+        assert!(!is_point_in_polygon(
+            &outside,
+            &poly,
+            n,
+            BoundaryBehavior::CountAsInside
+        ));
+    }
+
+    #[test]
+    fn refuses_zones_the_circuit_cannot_hold() {
+        let octagon: Vec<String> = (0..=8)
+            .map(|i| {
+                let a = (i % 8) as f64 * std::f64::consts::FRAC_PI_4;
+                format!("[{}, {}]", -122.4 + 0.01 * a.cos(), 37.77 + 0.01 * a.sin())
+            })
+            .collect();
+        let json = format!(
+            r#"{{"type": "FeatureCollection", "features": [{{"type": "Feature",
+                "properties": {{"name": "big"}},
+                "geometry": {{"type": "Polygon", "coordinates": [[{}]]}}}}]}}"#,
+            octagon.join(", ")
+        );
+        assert_eq!(
+            load(&json),
+            Err(ArenaLoadError::TooManyVertices {
+                zone: "big".into(),
+                count: 8
+            })
+        );
+
+        let point = r#"{"type": "FeatureCollection", "features": [{"type": "Feature",
+            "properties": {"name": "pin"},
+            "geometry": {"type": "Point", "coordinates": [-122.4, 37.77]}}]}"#;
+        assert!(matches!(
+            load(point),
+            Err(ArenaLoadError::UnsupportedGeometry { zone, .. }) if zone == "pin"
+        ));
+
+        let unnamed = r#"{"type": "FeatureCollection", "features": [{"type": "Feature",
+            "properties": {}, "geometry": null}]}"#;
+        assert_eq!(load(unnamed), Err(ArenaLoadError::MissingName { index: 0 }));
+
+        let notch = zone(
+            "notch",
+            "[-122.41, 37.77], [-122.40, 37.77], [-122.40, 37.78], \
+             [-122.405, 37.775], [-122.41, 37.78], [-122.41, 37.77]",
+        );
+        assert_eq!(load(&notch), Err(ArenaLoadError::NotConvex("notch".into())));
+    }
+
+    #[test]
+    fn clockwise_rings_are_reversed() {
+        let cw = zone(
+            "centre",
+            "[-122.41, 37.77], [-122.41, 37.78], [-122.40, 37.78], \
+             [-122.40, 37.77], [-122.41, 37.77]",
+        );
+        let (poly, n) = load(&cw).unwrap()["centre"];
+        assert!(polygon_area(&poly, n) > 0.0);
+        assert_eq!(poly[0], Point2DDec::from_lat_lon(37.77, -122.41));
+    }
+
+    // a collection holding one Polygon zone with the given ring positions
+    fn zone(name: &str, ring: &str) -> String {
+        format!(
+            r#"{{"type": "FeatureCollection", "features": [{{"type": "Feature",
+                "properties": {{"name": "{name}"}},
+                "geometry": {{"type": "Polygon", "coordinates": [[{ring}]]}}}}]}}"#
+        )
+    }
+}
@@ -0,0 +1,219 @@
+//! Maps (lists of H3 cell indices) published outside the request body.
+//!
+//! Arena operators pin their map to IPFS as a JSON array of cell indices, so
+//! players can fetch and check the same list the server proves against. A
+//! CID names its content, so a fetched map never goes stale: it stays in the
+//! caller's [`IpfsMapCache`] until pushed out by newer ones.
+//!
+//! A map on local disk (`H3_MAP_FILE`) may also be JSON Lines, one cell per
+//! line, which is easier to append to than an array.
+
+use std::{fmt, fs, io::Read, path::Path, time::Duration};
+
+use crate::cache::LruCache;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest map body a gateway may send; ~50k cells of JSON.
+pub const MAX_IPFS_MAP_BYTES: u64 = 1 << 20;
+
+/// Fetched maps by CID.
+pub type IpfsMapCache = LruCache<String, Vec<String>>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MapLoadError {
+    InvalidCid(String),
+    /// the gateway could not be reached
+    Fetch(String),
+    /// the gateway answered with a non-2xx status
+    Status(u16),
+    /// the body is longer than [`MAX_IPFS_MAP_BYTES`]; the length if known
+    TooLarge(Option<u64>),
+    /// the content is not a JSON array of strings
+    Malformed(String),
+}
+
+impl fmt::Display for MapLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCid(cid) => write!(f, "invalid IPFS CID: {cid:?}"),
+            Self::Fetch(e) => write!(f, "IPFS fetch failed: {e}"),
+            Self::Status(code) => write!(f, "IPFS gateway returned HTTP {code}"),
+            Self::TooLarge(Some(len)) => write!(
+                f,
+                "IPFS map is {len} bytes, the limit is {MAX_IPFS_MAP_BYTES}"
+            ),
+            Self::TooLarge(None) => {
+                write!(f, "IPFS map is over the {MAX_IPFS_MAP_BYTES}-byte limit")
+            }
+            Self::Malformed(e) => write!(f, "IPFS map is not a list of H3 cells: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for MapLoadError {}
+
+/// Fetch the map pinned under `cid` from `gateway`, or take it from `cache`.
+///
+/// Blocks on the network; call it from `web::block`, not an async handler.
+pub fn load_map_from_gateway(
+    gateway: &str,
+    cid: &str,
+    cache: &IpfsMapCache,
+) -> Result<Vec<String>, MapLoadError> {
+    // CIDs are base32/base58: anything else could escape the gateway path
+    if cid.is_empty() || !cid.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(MapLoadError::InvalidCid(cid.to_owned()));
+    }
+    if let Some(map) = cache.get(&cid.to_owned()) {
+        return Ok(map);
+    }
+
+    let url = format!("{}/{cid}", gateway.trim_end_matches('/'));
+    let client = reqwest::blocking::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| MapLoadError::Fetch(e.to_string()))?;
+    let resp = client
+        .get(&url)
+        .send()
+        .map_err(|e| MapLoadError::Fetch(e.to_string()))?;
+    if !resp.status().is_success() {
+        return Err(MapLoadError::Status(resp.status().as_u16()));
+    }
+    if let Some(len) = resp
+        .content_length()
+        .filter(|&len| len > MAX_IPFS_MAP_BYTES)
+    {
+        return Err(MapLoadError::TooLarge(Some(len)));
+    }
+    // the length header is optional, so cap the read as well
+    let mut body = Vec::new();
+    resp.take(MAX_IPFS_MAP_BYTES + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| MapLoadError::Fetch(e.to_string()))?;
+    if body.len() as u64 > MAX_IPFS_MAP_BYTES {
+        return Err(MapLoadError::TooLarge(None));
+    }
+    let map: Vec<String> =
+        serde_json::from_slice(&body).map_err(|e| MapLoadError::Malformed(e.to_string()))?;
+
+    cache.insert(cid.to_owned(), map.clone());
+    Ok(map)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MapFileError {
+    Io(String),
+    /// `line` is 1-based
+    ParseError {
+        line: usize,
+        cause: String,
+    },
+}
+
+impl fmt::Display for MapFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "cannot read map file: {e}"),
+            Self::ParseError { line, cause } => write!(f, "map file line {line}: {cause}"),
+        }
+    }
+}
+
+impl std::error::Error for MapFileError {}
+
+/// Read a map from disk, either a JSON array of cell indices (the file
+/// starts with `[`) or JSON Lines: one cell per line, quoted or bare, with
+/// blank lines and `//` comments skipped.
+pub fn load_h3_map_from_file(path: &Path) -> Result<Vec<String>, MapFileError> {
+    let text = fs::read_to_string(path).map_err(|e| MapFileError::Io(e.to_string()))?;
+    if text.trim_start().starts_with('[') {
+        return serde_json::from_str(&text).map_err(|e| MapFileError::ParseError {
+            line: e.line(),
+            cause: e.to_string(),
+        });
+    }
+
+    let mut map = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let token = line.trim();
+        if token.is_empty() || token.starts_with("//") {
+            continue;
+        }
+        let parse_error = |cause: String| MapFileError::ParseError { line: i + 1, cause };
+        let cell = if token.starts_with('"') {
+            serde_json::from_str(token).map_err(|e| parse_error(e.to_string()))?
+        } else if token.chars().all(|c| c.is_ascii_alphanumeric()) {
+            token.to_owned()
+        } else {
+            return Err(parse_error(format!("not an H3 cell index: {token:?}")));
+        };
+        map.push(cell);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env, path::PathBuf};
+
+    fn map_file(contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("h3-map-{}", uuid::Uuid::new_v4()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn load(contents: &str) -> Result<Vec<String>, MapFileError> {
+        let path = map_file(contents);
+        let map = load_h3_map_from_file(&path);
+        fs::remove_file(path).unwrap();
+        map
+    }
+
+    const CELLS: [&str; 2] = ["872830828ffffff", "87283082effffff"];
+
+    #[test]
+    fn reads_json_array() {
+        assert_eq!(
+            load(&serde_json::to_string(&CELLS).unwrap()),
+            Ok(CELLS.map(String::from).to_vec())
+        );
+        assert!(matches!(
+            load("[\n  \"872830828ffffff\",\n  oops\n]"),
+            Err(MapFileError::ParseError { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn reads_json_lines() {
+        assert_eq!(
+            load("\"872830828ffffff\"\n\"87283082effffff\"\n"),
+            Ok(CELLS.map(String::from).to_vec())
+        );
+        assert!(matches!(
+            load("872830828ffffff\n\"87283082effffff\n"),
+            Err(MapFileError::ParseError { line: 2, .. })
+        ));
+        assert_eq!(
+            load("872830828ffffff\n87283082e ffffff\n"),
+            Err(MapFileError::ParseError {
+                line: 2,
+                cause: "not an H3 cell index: \"87283082e ffffff\"".into()
+            })
+        );
+    }
+
+    #[test]
+    fn json_lines_skip_comments_and_blank_lines() {
+        let jsonl =
+            "// arena 1, downtown\n\n872830828ffffff\n  // north edge\n\"87283082effffff\"\n\n";
+        assert_eq!(load(jsonl), Ok(CELLS.map(String::from).to_vec()));
+        assert!(matches!(
+            load_h3_map_from_file(Path::new("/nonexistent/map.jsonl")),
+            Err(MapFileError::Io(_))
+        ));
+    }
+}
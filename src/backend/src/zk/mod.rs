@@ -1,3 +1,25 @@
+pub mod batch_prove;
 pub mod circuit;
+pub mod deserialize;
 pub mod fixed_point_decimal;
+pub mod geo_import;
+pub mod map;
+pub mod non_membership;
+pub mod pedersen;
 pub mod point_2d;
+pub mod polygon_ops;
+pub mod poseidon;
+pub mod proof_encoding;
+pub mod proof_transcript;
+pub mod public_inputs;
+pub mod range_proof;
+pub mod sector;
+pub mod snarkjs_compat;
+pub mod types;
+pub mod verify;
+pub mod witness;
+
+pub use snarkjs_compat::{
+    SnarkjsCompatError, SnarkjsProof, proof_to_snarkjs_json, public_inputs_to_snarkjs,
+    snarkjs_json_to_proof, snarkjs_to_public_inputs,
+};
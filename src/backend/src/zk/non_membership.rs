@@ -0,0 +1,316 @@
+//! Prove a private point lies outside every zone of a public polygon set
+//! ("I am not in the spawn-protected area").
+//!
+//! Each private zone polygon is hashed in-circuit and tied to the public hash
+//! list in both directions: every supplied zone must be listed, and every
+//! non-zero listed hash must be supplied, so no zone can be left out.
+
+use std::cmp::Ordering;
+
+use ark_crypto_primitives::sponge::{Absorb, poseidon::PoseidonConfig};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{FieldVar, fp::FpVar},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::zk::{
+    circuit::{
+        BoundaryBehavior, hash_polygon_gadget, is_point_in_polygon, is_point_in_polygon_gadget,
+    },
+    fixed_point_decimal::DecVar,
+    point_2d::{Point2DDec, Point2DDecVar},
+};
+
+/// Zone polygons (and public zone hashes) accepted by `/prove-outside`.
+pub const NON_MEMBERSHIP_MAX_ZONES: usize = 8;
+
+pub fn is_point_outside_all_polygons<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_POLYS: usize,
+>(
+    point: &Point2DDec<F, PREC>,
+    polygons: &[[Point2DDec<F, PREC>; MAX_VERTS]; MAX_POLYS],
+    num_vertices: &[usize; MAX_POLYS],
+    n_polys: usize,
+) -> bool {
+    !polygons
+        .iter()
+        .zip(num_vertices)
+        .take(n_polys)
+        .any(|(poly, &n)| {
+            is_point_in_polygon::<F, PREC, MAX_VERTS>(
+                point,
+                poly,
+                n,
+                BoundaryBehavior::CountAsInside,
+            )
+        })
+}
+
+// number of active polygons containing the point must be zero
+pub fn is_point_outside_all_polygons_gadget<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_POLYS: usize,
+>(
+    point: &Point2DDecVar<F, PREC>,
+    polygons: &[[Point2DDecVar<F, PREC>; MAX_VERTS]; MAX_POLYS],
+    num_vertices: &[FpVar<F>; MAX_POLYS],
+    n_polys: &FpVar<F>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let one = FpVar::<F>::one();
+    let zero = FpVar::<F>::zero();
+    let mut inside_count = zero.clone();
+
+    for (i, (poly, n)) in polygons.iter().zip(num_vertices).enumerate() {
+        let i_const = FpVar::<F>::constant(F::from(i as u64));
+        let active = i_const.is_cmp_unchecked(n_polys, Ordering::Less, false)?;
+        let inside = is_point_in_polygon_gadget::<F, PREC, MAX_VERTS>(
+            point,
+            poly,
+            n,
+            BoundaryBehavior::CountAsInside,
+        )?;
+
+        inside_count += Boolean::select(&(active & inside), &one, &zero)?;
+    }
+
+    inside_count.is_zero()
+}
+
+pub struct NonMembershipCircuit<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_POLYS: usize,
+    const MAX_HASHES: usize,
+> {
+    pub private_point: Point2DDec<F, PREC>,
+    pub private_polygons: [[Point2DDec<F, PREC>; MAX_VERTS]; MAX_POLYS],
+    pub private_num_vertices: [u64; MAX_POLYS],
+    pub private_num_polygons: u64,
+
+    pub public_result_flag: bool,
+    pub public_polygon_hashes: [F; MAX_HASHES],
+
+    pub mode_id: u32,
+    pub poseidon_config: PoseidonConfig<F>,
+}
+
+impl<
+    F: PrimeField,
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_POLYS: usize,
+    const MAX_HASHES: usize,
+> NonMembershipCircuit<F, PREC, MAX_VERTS, MAX_POLYS, MAX_HASHES>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        private_point: Point2DDec<F, PREC>,
+        private_polygons: [[Point2DDec<F, PREC>; MAX_VERTS]; MAX_POLYS],
+        private_num_vertices: [u64; MAX_POLYS],
+        private_num_polygons: u64,
+        public_result_flag: bool,
+        public_polygon_hashes: [F; MAX_HASHES],
+        mode_id: u32,
+        poseidon_config: PoseidonConfig<F>,
+    ) -> Self {
+        assert!(private_num_polygons as usize <= MAX_POLYS);
+        assert!(
+            private_num_vertices
+                .iter()
+                .all(|&n| n as usize <= MAX_VERTS)
+        );
+
+        Self {
+            private_point,
+            private_polygons,
+            private_num_vertices,
+            private_num_polygons,
+            public_result_flag,
+            public_polygon_hashes,
+            mode_id,
+            poseidon_config,
+        }
+    }
+}
+
+/// Public inputs in circuit order: result flag, then the zone hashes.
+pub fn non_membership_public_inputs<F: PrimeField, const MAX_HASHES: usize>(
+    result_flag: bool,
+    polygon_hashes: &[F; MAX_HASHES],
+) -> Vec<F> {
+    let mut inputs = Vec::with_capacity(1 + MAX_HASHES);
+    inputs.push(if result_flag { F::one() } else { F::zero() });
+    inputs.extend_from_slice(polygon_hashes);
+    inputs
+}
+
+impl<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTS: usize,
+    const MAX_POLYS: usize,
+    const MAX_HASHES: usize,
+> ConstraintSynthesizer<F> for NonMembershipCircuit<F, PREC, MAX_VERTS, MAX_POLYS, MAX_HASHES>
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        /* ────────── 1. private witnesses ────────── */
+        let point_var = Point2DDecVar {
+            x: DecVar::new_witness(cs.clone(), || Ok(self.private_point.x))?,
+            y: DecVar::new_witness(cs.clone(), || Ok(self.private_point.y))?,
+        };
+
+        let polys_var: [[Point2DDecVar<F, PREC>; MAX_VERTS]; MAX_POLYS] =
+            core::array::from_fn(|p| {
+                core::array::from_fn(|i| {
+                    let v = self.private_polygons[p][i];
+                    Point2DDecVar {
+                        x: DecVar::new_witness(cs.clone(), || Ok(v.x)).unwrap(),
+                        y: DecVar::new_witness(cs.clone(), || Ok(v.y)).unwrap(),
+                    }
+                })
+            });
+
+        let num_verts_var: [FpVar<F>; MAX_POLYS] = core::array::from_fn(|p| {
+            FpVar::new_witness(cs.clone(), || Ok(F::from(self.private_num_vertices[p]))).unwrap()
+        });
+
+        let n_polys_var =
+            FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(self.private_num_polygons)))?;
+
+        let mode_var = FpVar::<F>::new_witness(cs.clone(), || Ok(F::from(self.mode_id as u64)))?;
+
+        /* ────────── 2. public inputs ────────────── */
+        let pub_flag = Boolean::new_input(cs.clone(), || Ok(self.public_result_flag))?;
+
+        let pub_hash_vars: [FpVar<F>; MAX_HASHES] = core::array::from_fn(|j| {
+            FpVar::new_input(cs.clone(), || Ok(self.public_polygon_hashes[j])).unwrap()
+        });
+
+        /* ────────── 3. in-circuit computations ──── */
+
+        // 3a. outside every active zone
+        let outside = is_point_outside_all_polygons_gadget::<F, PREC, MAX_VERTS, MAX_POLYS>(
+            &point_var,
+            &polys_var,
+            &num_verts_var,
+            &n_polys_var,
+        )?;
+
+        // 3b. active zone hashes
+        let mut active = Vec::with_capacity(MAX_POLYS);
+        let mut hashes = Vec::with_capacity(MAX_POLYS);
+        for (p, (poly, n)) in polys_var.iter().zip(&num_verts_var).enumerate() {
+            let p_const = FpVar::<F>::constant(F::from(p as u64));
+            active.push(p_const.is_cmp_unchecked(&n_polys_var, Ordering::Less, false)?);
+            hashes.push(hash_polygon_gadget::<F, PREC, MAX_VERTS>(
+                poly,
+                n,
+                &mode_var,
+                &self.poseidon_config,
+            )?);
+        }
+
+        // 3c. every active zone is listed, every listed hash is supplied
+        let mut all_listed = Boolean::TRUE;
+        let mut all_covered = Boolean::TRUE;
+        let mut covered = vec![Boolean::FALSE; MAX_HASHES];
+        for (hash, is_active) in hashes.iter().zip(&active) {
+            let mut listed = Boolean::FALSE;
+            for (h, cov) in pub_hash_vars.iter().zip(covered.iter_mut()) {
+                let eq = hash.is_eq(h)? & is_active;
+                listed |= &eq;
+                *cov |= &eq;
+            }
+            all_listed &= &(!is_active | listed);
+        }
+        for (h, cov) in pub_hash_vars.iter().zip(covered) {
+            all_covered &= &(h.is_zero()? | cov);
+        }
+
+        /* 4. enforce public flag ------------------------------------------ */
+        pub_flag.enforce_equal(&(outside & all_listed & all_covered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::Fr;
+
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::zk::{
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        polygon_ops::hash_polygon,
+        poseidon::default_poseidon_config,
+    };
+
+    type F = Fr;
+    const PREC: u32 = CIRCUIT_PRECISION;
+    const MAX: usize = CIRCUIT_MAX_VERTICES;
+    const POLYS: usize = 2;
+
+    // regular CCW hexagon of radius 10 around (cx, cy)
+    fn hexagon(cx: f64, cy: f64) -> [Point2DDec<F, PREC>; MAX] {
+        core::array::from_fn(|k| {
+            let theta = std::f64::consts::TAU * k as f64 / MAX as f64;
+            Point2DDec::from_f64(cx + 10.0 * theta.cos(), cy + 10.0 * theta.sin())
+        })
+    }
+
+    // both zones listed; `(result flag, constraints satisfied)`
+    fn prove_outside(px: f64, py: f64, supplied: usize, claimed: bool) -> (bool, bool) {
+        let cfg = default_poseidon_config();
+        let zones = [hexagon(0.0, 0.0), hexagon(30.0, 0.0)];
+        let hashes = zones.map(|z| hash_polygon::<F, PREC, MAX>(&z, MAX, 0, &cfg));
+        let point = Point2DDec::from_f64(px, py);
+
+        let native =
+            is_point_outside_all_polygons::<F, PREC, MAX, POLYS>(&point, &zones, &[MAX; POLYS], 2);
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        NonMembershipCircuit::<F, PREC, MAX, POLYS, POLYS>::new(
+            point,
+            zones,
+            [MAX as u64; POLYS],
+            supplied as u64,
+            claimed,
+            hashes,
+            0,
+            cfg,
+        )
+        .generate_constraints(cs.clone())
+        .unwrap();
+        (native, cs.is_satisfied().unwrap())
+    }
+
+    #[test]
+    fn point_between_zones_is_outside() {
+        assert_eq!(prove_outside(15.0, 0.0, 2, true), (true, true));
+        assert_eq!(prove_outside(15.0, 0.0, 2, false), (true, false));
+    }
+
+    #[test]
+    fn point_inside_a_zone_cannot_claim_outside() {
+        assert_eq!(prove_outside(31.0, 1.0, 2, true), (false, false));
+        assert_eq!(prove_outside(31.0, 1.0, 2, false), (false, true));
+    }
+
+    #[test]
+    fn omitting_a_listed_zone_is_rejected() {
+        // only the first zone supplied; the point sits in the second
+        assert!(!prove_outside(31.0, 1.0, 1, true).1);
+        assert!(prove_outside(31.0, 1.0, 1, false).1);
+    }
+}
@@ -0,0 +1,186 @@
+//! Pedersen commitments on BN254 G1, for clients that want their submitted
+//! coordinates hidden rather than hashed with Poseidon.
+//!
+//! `C = v·G + r·H` hides `v` perfectly and binds it as long as nobody knows
+//! `log_G(H)`; [`pedersen_generators`] hashes `H` onto the curve so nobody does.
+//!
+//! G1 coordinates live in the BN254 *base* field `Fq`, so [`CommitmentCircuit`]
+//! is a circuit over `Fq`, not the `Fr` every other circuit here uses. The
+//! server's BN254 Groth16 keys cannot prove it: that takes a curve whose
+//! scalar field is `Fq` (e.g. Grumpkin). Until then the circuit pins down the
+//! relation a commitment proof has to check.
+
+use ark_bn254::{Fq, Fr, G1Affine, G1Projective, constraints::GVar as G1Var};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, One, PrimeField};
+use ark_r1cs_std::{alloc::AllocVar, boolean::Boolean, eq::EqGadget, groups::CurveVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use sha2::{Digest, Sha256};
+
+use crate::zk::{fixed_point_decimal::Dec, point_2d::Point2DDec};
+
+const GENERATOR_H_TAG: &[u8] = b"zk-shroud-arena/pedersen/H";
+
+/// `G` is the standard G1 generator; `H` is hashed to the curve from a fixed
+/// tag by try-and-increment. G1 has cofactor 1, so any curve point is in the
+/// prime-order group.
+pub fn pedersen_generators() -> (G1Affine, G1Affine) {
+    let mut x = Fq::from_le_bytes_mod_order(&Sha256::digest(GENERATOR_H_TAG));
+    let h = loop {
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, false) {
+            break p;
+        }
+        x += Fq::one();
+    };
+    (G1Affine::generator(), h)
+}
+
+/// `value·G + randomness·H`; scalars are taken modulo the G1 group order.
+pub fn pedersen_commit<F: PrimeField>(
+    value: F,
+    randomness: F,
+    generator_g: G1Affine,
+    generator_h: G1Affine,
+) -> G1Affine {
+    (generator_g.mul_bigint(value.into_bigint()) + generator_h.mul_bigint(randomness.into_bigint()))
+        .into_affine()
+}
+
+/// In-circuit [`pedersen_commit`]; scalars come as little-endian bits.
+pub fn pedersen_commit_gadget(
+    value_bits: &[Boolean<Fq>],
+    randomness_bits: &[Boolean<Fq>],
+    generator_g: &G1Var,
+    generator_h: &G1Var,
+) -> Result<G1Var, SynthesisError> {
+    let v = generator_g.scalar_mul_le(value_bits.iter())?;
+    let r = generator_h.scalar_mul_le(randomness_bits.iter())?;
+    Ok(v + r)
+}
+
+// a signed coordinate as one scalar: `-val` when negative
+fn dec_scalar<const PREC: u32>(d: Dec<Fr, PREC>) -> Fr {
+    if d.neg { -d.val } else { d.val }
+}
+
+fn alloc_scalar_bits(
+    cs: ConstraintSystemRef<Fq>,
+    scalar: Fr,
+) -> Result<Vec<Boolean<Fq>>, SynthesisError> {
+    scalar
+        .into_bigint()
+        .to_bits_le()
+        .into_iter()
+        .take(Fr::MODULUS_BIT_SIZE as usize)
+        .map(|bit| Boolean::new_witness(cs.clone(), || Ok(bit)))
+        .collect()
+}
+
+/// Commitments to a point's `x` and `y`, one randomness each.
+pub fn commit_point<const PREC: u32>(
+    point: &Point2DDec<Fr, PREC>,
+    randomness: [Fr; 2],
+) -> [G1Affine; 2] {
+    let (g, h) = pedersen_generators();
+    [
+        pedersen_commit(dec_scalar(point.x), randomness[0], g, h),
+        pedersen_commit(dec_scalar(point.y), randomness[1], g, h),
+    ]
+}
+
+/// Proves knowledge of a point and randomness opening two public commitments
+/// (see [`commit_point`]).
+pub struct CommitmentCircuit<const PREC: u32> {
+    pub private_point: Point2DDec<Fr, PREC>,
+    pub private_randomness: [Fr; 2],
+
+    pub public_commitments: [G1Affine; 2],
+}
+
+impl<const PREC: u32> CommitmentCircuit<PREC> {
+    pub fn new(
+        private_point: Point2DDec<Fr, PREC>,
+        private_randomness: [Fr; 2],
+        public_commitments: [G1Affine; 2],
+    ) -> Self {
+        Self {
+            private_point,
+            private_randomness,
+            public_commitments,
+        }
+    }
+}
+
+impl<const PREC: u32> ConstraintSynthesizer<Fq> for CommitmentCircuit<PREC> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> Result<(), SynthesisError> {
+        let (g, h) = pedersen_generators();
+        let g = G1Var::constant(g.into());
+        let h = G1Var::constant(h.into());
+
+        let coords = [self.private_point.x, self.private_point.y].map(dec_scalar);
+        for ((coord, randomness), commitment) in coords
+            .into_iter()
+            .zip(self.private_randomness)
+            .zip(self.public_commitments)
+        {
+            let value_bits = alloc_scalar_bits(cs.clone(), coord)?;
+            let randomness_bits = alloc_scalar_bits(cs.clone(), randomness)?;
+            let expected = G1Var::new_input(cs.clone(), || Ok(G1Projective::from(commitment)))?;
+
+            pedersen_commit_gadget(&value_bits, &randomness_bits, &g, &h)?
+                .enforce_equal(&expected)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::zk::circuit::CIRCUIT_PRECISION;
+
+    const PREC: u32 = CIRCUIT_PRECISION;
+
+    #[test]
+    fn commitments_hide_and_add() {
+        let (g, h) = pedersen_generators();
+        assert_ne!(g, h);
+        assert!(h.is_on_curve() && h.is_in_correct_subgroup_assuming_on_curve());
+
+        let (a, b) = (Fr::from(1_234u64), Fr::from(5_678u64));
+        let (r1, r2) = (Fr::from(17u64), Fr::from(99u64));
+        assert_ne!(
+            pedersen_commit(a, r1, g, h),
+            pedersen_commit(a, r2, g, h),
+            "randomness blinds the value"
+        );
+        assert_eq!(
+            (pedersen_commit(a, r1, g, h) + pedersen_commit(b, r2, g, h)).into_affine(),
+            pedersen_commit(a + b, r1 + r2, g, h)
+        );
+    }
+
+    #[test]
+    fn circuit_opens_only_the_committed_point() {
+        let point = Point2DDec::<Fr, PREC>::from_f64(-13_627_361.5, 4_548_863.25);
+        let randomness = [Fr::from(0xdead_beefu64), Fr::from(0x0bad_cafeu64)];
+        let commitments = commit_point(&point, randomness);
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        CommitmentCircuit::new(point, randomness, commitments)
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        // the sign is committed too
+        let mirrored = Point2DDec::<Fr, PREC>::from_f64(13_627_361.5, 4_548_863.25);
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        CommitmentCircuit::new(mirrored, randomness, commitments)
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
@@ -1,8 +1,15 @@
+use std::borrow::Borrow;
+
 use ark_ff::PrimeField;
+use ark_r1cs_std::alloc::{AllocVar, AllocationMode};
+use ark_relations::r1cs::{Namespace, SynthesisError};
 
-use crate::zk::fixed_point_decimal::{Dec, DecVar};
+use crate::zk::{
+    fixed_point_decimal::{Dec, DecVar, SignedArith},
+    polygon_ops::{gps_to_web_mercator, web_mercator_to_gps},
+};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Point2DDec<F: PrimeField, const PREC: u32> {
     pub x: Dec<F, PREC>,
     pub y: Dec<F, PREC>,
@@ -15,9 +22,179 @@ impl<F: PrimeField, const PREC: u32> Point2DDec<F, PREC> {
             y: Dec::from_f64(y),
         }
     }
+
+    /// The Web-Mercator point of a GPS fix, as the circuit sees it.
+    pub fn from_lat_lon(lat_deg: f64, lon_deg: f64) -> Self {
+        let (x, y) = gps_to_web_mercator(lon_deg, lat_deg);
+        Self::from_f64(x, y)
+    }
+
+    /// Inverse of [`Self::from_lat_lon`]: `(lat_deg, lon_deg)`.
+    pub fn to_lat_lon(self) -> (f64, f64) {
+        let (lon, lat) = web_mercator_to_gps(self.x.to_f64(), self.y.to_f64());
+        (lat, lon)
+    }
+
+    /// Quarter turn about the origin: `(x, y) → (-y, x)`. Exact, unlike a
+    /// rotation through `f64`.
+    pub fn rotate_90_ccw(self) -> Self {
+        Self {
+            x: negated(self.y),
+            y: self.x,
+        }
+    }
+
+    /// `(x, y) → (y, -x)`; undoes [`Self::rotate_90_ccw`].
+    pub fn rotate_90_cw(self) -> Self {
+        Self {
+            x: self.y,
+            y: negated(self.x),
+        }
+    }
+
+    /// [`Self::rotate_90_ccw`] `n % 4` times.
+    pub fn rotate_n_times_ccw(self, n: u32) -> Self {
+        (0..n % 4).fold(self, |p, _| p.rotate_90_ccw())
+    }
+}
+
+fn negated<F: PrimeField, const PREC: u32>(d: Dec<F, PREC>) -> Dec<F, PREC> {
+    SignedArith::neg(&d).expect("native negation cannot fail")
 }
 
 pub struct Point2DDecVar<F: PrimeField, const PREC: u32> {
     pub x: DecVar<F, PREC>,
     pub y: DecVar<F, PREC>,
 }
+
+impl<F: PrimeField, const PREC: u32> Point2DDecVar<F, PREC> {
+    /// Reflection through the origin: both coordinates negated.
+    pub fn negate(&self) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            x: self.x.negate()?,
+            y: self.y.negate()?,
+        })
+    }
+}
+
+/// [`Point2DDec::rotate_90_ccw`] in-circuit: `(x, y) → (-y, x)`.
+pub fn rotate_90_ccw_gadget<F: PrimeField, const PREC: u32>(
+    p: &Point2DDecVar<F, PREC>,
+) -> Result<Point2DDecVar<F, PREC>, SynthesisError> {
+    Ok(Point2DDecVar {
+        x: p.y.negate()?,
+        y: p.x.clone(),
+    })
+}
+
+/// `x` then `y`, each as [`DecVar`] allocates it: magnitude, then sign. As
+/// an input that is four public field elements per point.
+impl<F: PrimeField, const PREC: u32> AllocVar<Point2DDec<F, PREC>, F> for Point2DDecVar<F, PREC> {
+    fn new_variable<T: Borrow<Point2DDec<F, PREC>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let point = *f()?.borrow();
+        Ok(Self {
+            x: DecVar::new_variable(ns.clone(), || Ok(point.x), mode)?,
+            y: DecVar::new_variable(ns, || Ok(point.y), mode)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::Fr;
+    use ark_r1cs_std::eq::EqGadget;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::zk::circuit::{
+        BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, is_point_in_polygon,
+    };
+
+    type P = Point2DDec<Fr, CIRCUIT_PRECISION>;
+
+    #[test]
+    fn lat_lon_round_trips() {
+        for (lat, lon) in [
+            (0.0, 0.0),
+            (13.7563, 100.5018),
+            (-33.8688, 151.2093),
+            (64.1466, -21.9426),
+            (-85.0, -179.999),
+        ] {
+            let p = Point2DDec::<Fr, CIRCUIT_PRECISION>::from_lat_lon(lat, lon);
+            let (lat2, lon2) = p.to_lat_lon();
+            assert!((lat - lat2).abs() < 1e-6, "lat {lat} -> {lat2}");
+            assert!((lon - lon2).abs() < 1e-6, "lon {lon} -> {lon2}");
+        }
+    }
+
+    #[test]
+    fn rotations_match_their_formulas() {
+        let p = P::from_f64(3.5, -1.25);
+        assert_eq!(p.rotate_90_ccw(), P::from_f64(1.25, 3.5));
+        assert_eq!(p.rotate_90_cw(), P::from_f64(-1.25, -3.5));
+        assert_eq!(p.rotate_90_ccw().rotate_90_cw(), p);
+        assert_eq!(p.rotate_n_times_ccw(2), P::from_f64(-3.5, 1.25));
+        assert_eq!(p.rotate_n_times_ccw(7), p.rotate_90_cw());
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let var = Point2DDecVar::new_witness(cs.clone(), || Ok(p)).unwrap();
+        let rotated = rotate_90_ccw_gadget(&var).unwrap();
+        let expected = Point2DDecVar::new_witness(cs.clone(), || Ok(p.rotate_90_ccw())).unwrap();
+        rotated.x.enforce_equal(&expected.x).unwrap();
+        rotated.y.enforce_equal(&expected.y).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    // seed that failed before `from_f64` canonicalised zero: `cos(3π/2)` is
+    // -1.8e-16, which rounded to a zero with `neg` set, and four turns
+    // brought it back as the positive zero
+    #[test]
+    fn four_quarter_turns_of_a_rounded_negative_zero() {
+        let a = 3.0 * std::f64::consts::TAU / 4.0;
+        let v = P::from_f64(a.cos(), a.sin());
+        assert_eq!((0..4).fold(v, |p, _| p.rotate_90_ccw()), v);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn quarter_turns_preserve_containment(
+            cx in -1000i32..=1000,
+            cy in -1000i32..=1000,
+            r in 1u32..=500,
+            n in 3usize..=CIRCUIT_MAX_VERTICES,
+            turns in 0u32..4,
+        ) {
+            let (cx, cy, r) = (cx as f64, cy as f64, r as f64);
+            let at = |radius: f64, k: usize| {
+                let a = k as f64 * std::f64::consts::TAU / n as f64;
+                P::from_f64(cx + radius * a.cos(), cy + radius * a.sin())
+            };
+            let poly: [P; CIRCUIT_MAX_VERTICES] = core::array::from_fn(|k| at(r, k % n));
+            let turned = poly.map(|v| v.rotate_n_times_ccw(turns));
+
+            let four_turns = poly.map(|v| (0..4).fold(v, |p, _| p.rotate_90_ccw()));
+            proptest::prop_assert_eq!(four_turns, poly);
+            let inside = |p: &P, poly: &[P; CIRCUIT_MAX_VERTICES]| {
+                is_point_in_polygon(p, poly, n, BoundaryBehavior::CountAsInside)
+            };
+            // centre and a point well inside the inradius, then one beyond the vertices
+            let probes = [
+                (P::from_f64(cx, cy), true),
+                (at(r / 3.0, 1), true),
+                (at(2.0 * r, 0), false),
+            ];
+            for (p, expected) in probes {
+                proptest::prop_assert_eq!(inside(&p, &poly), expected);
+                let p = p.rotate_n_times_ccw(turns);
+                proptest::prop_assert_eq!(inside(&p, &turned), expected);
+            }
+        }
+    }
+}
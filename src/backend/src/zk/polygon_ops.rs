@@ -0,0 +1,720 @@
+//! Native polygon construction and queries shared by the circuits and the API.
+//!
+//! Coordinates are EPSG-3857 Web-Mercator metres: `x` grows east and `y` grows
+//! north. Polygons are fixed `[Point2DDec; MAX]` arrays with a vertex count
+//! `n`; slots `>= n` are padding and ignored everywhere.
+//!
+//! Vertices run counter-clockwise, the order `is_point_in_polygon` relies on
+//! (a point is inside when it lies left of or on every edge);
+//! [`ensure_ccw_winding`] puts H3 boundaries in it. [`polygon_area`] is
+//! therefore positive for a well-formed polygon and negative when the
+//! winding is reversed.
+//!
+//! Area, centroid and simplicity are computed in `f64` for diagnostics only;
+//! nothing here is constrained in-circuit except through [`hash_polygon`].
+
+use ark_crypto_primitives::sponge::{
+    Absorb, CryptographicSponge,
+    poseidon::{PoseidonConfig, PoseidonSponge},
+};
+use std::collections::HashSet;
+
+use ark_ff::PrimeField;
+use h3o::{CellIndex, LatLng, Resolution};
+
+use crate::zk::{
+    circuit::{BoundaryBehavior, comp_dec_less_than, is_point_in_polygon},
+    point_2d::Point2DDec,
+};
+
+/// Largest `|x|` or `|y|` a projected coordinate may have: `2πR`, where `x`
+/// ends for longitudes unwrapped past +180° and `y` reaches latitude ~±89.6°.
+pub const WEB_MERCATOR_MAX_ABS: f64 = 2.0 * std::f64::consts::PI * 6_378_137.0;
+
+/// EPSG-3857 Web-Mercator projection.
+pub fn gps_to_web_mercator(lon_deg: f64, lat_deg: f64) -> (f64, f64) {
+    const R: f64 = 6_378_137.0;
+    let x = R * lon_deg.to_radians();
+    let y = R * ((90.0 + lat_deg).to_radians() / 2.0).tan().ln();
+    (x, y)
+}
+
+/// Inverse of [`gps_to_web_mercator`]: `(lon_deg, lat_deg)`.
+pub fn web_mercator_to_gps(x: f64, y: f64) -> (f64, f64) {
+    const R: f64 = 6_378_137.0;
+    let lon = (x / R).to_degrees();
+    let lat = (2.0 * (y / R).exp().atan()).to_degrees() - 90.0;
+    (lon, lat)
+}
+
+/// Whether `cell` straddles ±180° longitude: some boundary edge jumps more
+/// than 180° (e.g. from +179° to −179°).
+pub fn crosses_antimeridian(cell: CellIndex) -> bool {
+    let lons: Vec<f64> = cell.boundary().iter().map(|ll| ll.lng()).collect();
+    lons.iter()
+        .zip(lons.iter().cycle().skip(1))
+        .any(|(a, b)| (a - b).abs() > 180.0)
+}
+
+/// `lon` as projected for a cell that [`crosses_antimeridian`]: negative
+/// longitudes move past +180° so the cell stays in one piece. Points tested
+/// against such a cell need the same shift.
+pub fn unwrap_antimeridian_lon(lon: f64) -> f64 {
+    if lon < 0.0 { lon + 360.0 } else { lon }
+}
+
+/// Project an H3 cell boundary to Web-Mercator, padded to `MAX_VERTICES`,
+/// wound counter-clockwise whatever order H3 returned it in. Cells on the
+/// antimeridian are unwrapped with [`unwrap_antimeridian_lon`].
+pub fn polygon_from_h3_cell<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    cell: CellIndex,
+) -> ([Point2DDec<F, PREC>; MAX_VERTICES], usize) {
+    let boundary = cell.boundary();
+    let n = boundary.len().min(MAX_VERTICES);
+    let unwrap = crosses_antimeridian(cell);
+
+    let mut poly = [Point2DDec::<F, PREC>::from_f64(0.0, 0.0); MAX_VERTICES];
+    for (i, ll) in boundary.iter().take(n).enumerate() {
+        let lon = if unwrap {
+            unwrap_antimeridian_lon(ll.lng())
+        } else {
+            ll.lng()
+        };
+        let (x, y) = gps_to_web_mercator(lon, ll.lat());
+        poly[i] = Point2DDec::from_f64(x, y);
+    }
+    ensure_ccw_winding(&mut poly, n);
+    (poly, n)
+}
+
+/// Project `[lat, lon]` vertices to Web-Mercator, padded to `MAX_VERTICES`
+/// with the origin like [`polygon_from_h3_cell`].
+pub fn polygon_from_gps<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    vertices: &[[f64; 2]],
+) -> ([Point2DDec<F, PREC>; MAX_VERTICES], usize) {
+    let n = vertices.len().min(MAX_VERTICES);
+    let mut poly = [Point2DDec::<F, PREC>::from_f64(0.0, 0.0); MAX_VERTICES];
+    for (p, &[lat, lon]) in poly.iter_mut().zip(&vertices[..n]) {
+        let (x, y) = gps_to_web_mercator(lon, lat);
+        *p = Point2DDec::from_f64(x, y);
+    }
+    (poly, n)
+}
+
+/// Lower-left and upper-right corners, or `None` for an empty polygon.
+pub fn bounding_box<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+) -> Option<(Point2DDec<F, PREC>, Point2DDec<F, PREC>)> {
+    let (first, rest) = polygon[..num_vertices.min(MAX_VERTICES)].split_first()?;
+
+    let (mut min, mut max) = (*first, *first);
+    for v in rest {
+        if comp_dec_less_than(&v.x, &min.x) {
+            min.x = v.x;
+        }
+        if comp_dec_less_than(&v.y, &min.y) {
+            min.y = v.y;
+        }
+        if comp_dec_less_than(&max.x, &v.x) {
+            max.x = v.x;
+        }
+        if comp_dec_less_than(&max.y, &v.y) {
+            max.y = v.y;
+        }
+    }
+    Some((min, max))
+}
+
+/// Cells at `resolution` whose centre lies in the polygon, edges included,
+/// sorted by index: the reverse of [`polygon_from_h3_cell`], for turning a
+/// zone boundary into an `h3_map`.
+///
+/// The search walks every cell centred in the polygon's bounding box, so a
+/// large zone at a fine resolution is a lot of work; `None` once it would
+/// visit more than `max_cells`.
+pub fn polygon_to_h3_cells<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    resolution: Resolution,
+    max_cells: usize,
+) -> Option<Vec<CellIndex>> {
+    let Some((min, max)) = bounding_box(polygon, num_vertices) else {
+        return Some(Vec::new());
+    };
+    let (min, max) = (
+        (min.x.to_f64(), min.y.to_f64()),
+        (max.x.to_f64(), max.y.to_f64()),
+    );
+    let centre = |cell: CellIndex| {
+        let ll = LatLng::from(cell);
+        gps_to_web_mercator(ll.lng(), ll.lat())
+    };
+    let in_box = |(x, y): (f64, f64)| (min.0..=max.0).contains(&x) && (min.1..=max.1).contains(&y);
+
+    // flood the cells centred in the bounding box, from the one under its middle
+    let (lon, lat) = web_mercator_to_gps((min.0 + max.0) / 2.0, (min.1 + max.1) / 2.0);
+    let Ok(middle) = LatLng::new(lat, lon) else {
+        return Some(Vec::new());
+    };
+    let seed = middle.to_cell(resolution);
+    let mut seen = HashSet::from([seed]);
+    let mut queue = vec![seed];
+    let mut cells = Vec::new();
+    while let Some(cell) = queue.pop() {
+        let (x, y) = centre(cell);
+        if is_point_in_polygon::<F, PREC, MAX_VERTICES>(
+            &Point2DDec::from_f64(x, y),
+            polygon,
+            num_vertices,
+            BoundaryBehavior::CountAsInside,
+        ) {
+            cells.push(cell);
+        }
+        for next in cell.grid_disk::<Vec<_>>(1) {
+            if in_box(centre(next)) && seen.insert(next) {
+                if seen.len() > max_cells {
+                    return None;
+                }
+                queue.push(next);
+            }
+        }
+    }
+    cells.sort_unstable();
+    Some(cells)
+}
+
+fn to_f64_vertices<F: PrimeField, const PREC: u32>(
+    polygon: &[Point2DDec<F, PREC>],
+) -> Vec<(f64, f64)> {
+    polygon
+        .iter()
+        .map(|v| (v.x.to_f64(), v.y.to_f64()))
+        .collect()
+}
+
+// edges (v_i, v_{i+1}) with the closing edge back to v_0
+fn edges(vs: &[(f64, f64)]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    vs.iter()
+        .enumerate()
+        .map(|(i, &a)| (a, vs[(i + 1) % vs.len()]))
+}
+
+/// Shoelace area: positive for counter-clockwise vertices.
+pub fn polygon_area<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+) -> f64 {
+    if num_vertices < 3 {
+        return 0.0;
+    }
+    let vs = to_f64_vertices(&polygon[..num_vertices.min(MAX_VERTICES)]);
+    edges(&vs).map(|(a, b)| a.0 * b.1 - b.0 * a.1).sum::<f64>() / 2.0
+}
+
+/// Reverse a clockwise polygon in place, keeping vertex 0 first so a
+/// boundary that already was counter-clockwise hashes the same. Padding is
+/// left alone.
+pub fn ensure_ccw_winding<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &mut [Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+) {
+    if polygon_area(polygon, num_vertices) < 0.0 {
+        polygon[1..num_vertices.min(MAX_VERTICES)].reverse();
+    }
+}
+
+/// Area-weighted centroid, or `None` when the polygon has no area.
+pub fn polygon_centroid<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+) -> Option<(f64, f64)> {
+    let area = polygon_area(polygon, num_vertices);
+    if area == 0.0 {
+        return None;
+    }
+
+    // shift to the first vertex so Mercator-sized coordinates keep precision
+    let vs = to_f64_vertices(&polygon[..num_vertices.min(MAX_VERTICES)]);
+    let origin = vs[0];
+    let local: Vec<_> = vs
+        .iter()
+        .map(|&(x, y)| (x - origin.0, y - origin.1))
+        .collect();
+
+    let (mut cx, mut cy) = (0.0, 0.0);
+    for (a, b) in edges(&local) {
+        let cross = a.0 * b.1 - b.0 * a.1;
+        cx += (a.0 + b.0) * cross;
+        cy += (a.1 + b.1) * cross;
+    }
+    Some((origin.0 + cx / (6.0 * area), origin.1 + cy / (6.0 * area)))
+}
+
+// > 0 when `c` is left of a→b
+fn orient(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+fn segments_intersect(p: ((f64, f64), (f64, f64)), q: ((f64, f64), (f64, f64))) -> bool {
+    let (d1, d2) = (orient(q.0, q.1, p.0), orient(q.0, q.1, p.1));
+    let (d3, d4) = (orient(p.0, p.1, q.0), orient(p.0, p.1, q.1));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+
+    // collinear touching
+    let on_segment = |s: ((f64, f64), (f64, f64)), c: (f64, f64)| {
+        c.0 >= s.0.0.min(s.1.0)
+            && c.0 <= s.0.0.max(s.1.0)
+            && c.1 >= s.0.1.min(s.1.1)
+            && c.1 <= s.0.1.max(s.1.1)
+    };
+    (d1 == 0.0 && on_segment(q, p.0))
+        || (d2 == 0.0 && on_segment(q, p.1))
+        || (d3 == 0.0 && on_segment(p, q.0))
+        || (d4 == 0.0 && on_segment(p, q.1))
+}
+
+/// At least three vertices and no two non-adjacent edges touch.
+pub fn is_simple_polygon<F: PrimeField, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+) -> bool {
+    let n = num_vertices.min(MAX_VERTICES);
+    if n < 3 {
+        return false;
+    }
+
+    let vs = to_f64_vertices(&polygon[..n]);
+    let edges: Vec<_> = edges(&vs).collect();
+    for i in 0..n {
+        for j in i + 1..n {
+            let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+            if !adjacent && segments_intersect(edges[i], edges[j]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Per-vertex leaf `H(x_val, x_neg, y_val, y_neg)`; slots `>= num_vertices`
+/// are zero. Cache these to rehash a polygon after a single vertex edit.
+pub fn hash_polygon_leaves<F: PrimeField + Absorb, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    cfg: &PoseidonConfig<F>,
+) -> [F; MAX_VERTICES] {
+    assert!(num_vertices <= MAX_VERTICES, "num_vertices out of range");
+
+    core::array::from_fn(|i| {
+        if i >= num_vertices {
+            return F::zero();
+        }
+        let v = &polygon[i];
+        let mut sponge = PoseidonSponge::<F>::new(cfg);
+        sponge.absorb(&v.x.val);
+        sponge.absorb(&F::from(v.x.neg));
+        sponge.absorb(&v.y.val);
+        sponge.absorb(&F::from(v.y.neg));
+        sponge.squeeze_field_elements(1)[0]
+    })
+}
+
+// `mode_id` is absorbed first so the same cell hashes differently per game
+// mode (deathmatch, capture-the-flag, safe zone, …).
+pub fn hash_polygon_from_leaves<F: PrimeField + Absorb, const MAX_VERTICES: usize>(
+    leaves: &[F; MAX_VERTICES],
+    num_vertices: usize,
+    mode_id: u32,
+    cfg: &PoseidonConfig<F>,
+) -> F {
+    assert!(num_vertices <= MAX_VERTICES, "num_vertices out of range");
+
+    let mut sponge = PoseidonSponge::<F>::new(cfg);
+
+    sponge.absorb(&F::from(mode_id as u64));
+    sponge.absorb(&F::from(num_vertices as u64));
+    for leaf in leaves {
+        sponge.absorb(leaf);
+    }
+
+    sponge.squeeze_field_elements(1)[0]
+}
+
+/// How [`hash_polygon`] fills the slots past `num_vertices`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaddingMode {
+    /// every padded leaf is zero
+    ZeroFill,
+    /// the first padded leaf is [`POLYGON_HASH_SEPARATOR`], the rest zero
+    HashSeparator,
+}
+
+/// Padding every polygon hash uses; changing it changes every map hash.
+pub const POLYGON_HASH_PADDING_MODE: PaddingMode = PaddingMode::ZeroFill;
+
+/// Leaf marking the end of the active vertices under
+/// [`PaddingMode::HashSeparator`].
+pub const POLYGON_HASH_SEPARATOR: u64 = 0xDEAD_BEEF;
+
+/// Native twin of `circuit::hash_polygon_gadget`.
+pub fn hash_polygon<F: PrimeField + Absorb, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    mode_id: u32,
+    cfg: &PoseidonConfig<F>,
+) -> F {
+    hash_polygon_with_padding(
+        polygon,
+        num_vertices,
+        mode_id,
+        cfg,
+        POLYGON_HASH_PADDING_MODE,
+    )
+}
+
+/// [`hash_polygon`] under an explicit [`PaddingMode`].
+pub fn hash_polygon_with_padding<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    mode_id: u32,
+    cfg: &PoseidonConfig<F>,
+    padding: PaddingMode,
+) -> F {
+    let leaves = padded_leaves(polygon, num_vertices, cfg, padding);
+    hash_polygon_from_leaves(&leaves, num_vertices, mode_id, cfg)
+}
+
+fn padded_leaves<F: PrimeField + Absorb, const PREC: u32, const MAX_VERTICES: usize>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    cfg: &PoseidonConfig<F>,
+    padding: PaddingMode,
+) -> [F; MAX_VERTICES] {
+    let mut leaves = hash_polygon_leaves(polygon, num_vertices, cfg);
+    if padding == PaddingMode::HashSeparator && num_vertices < MAX_VERTICES {
+        leaves[num_vertices] = F::from(POLYGON_HASH_SEPARATOR);
+    }
+    leaves
+}
+
+/// [`hash_polygon`] bound to one arena: `arena_id` and `game_mode` are
+/// absorbed ahead of the vertex count, so the same cell hashes differently
+/// in every arena and a proof cannot be replayed across them. Native twin of
+/// `circuit::hash_polygon_with_context_gadget`.
+pub fn hash_polygon_with_context<
+    F: PrimeField + Absorb,
+    const PREC: u32,
+    const MAX_VERTICES: usize,
+>(
+    polygon: &[Point2DDec<F, PREC>; MAX_VERTICES],
+    num_vertices: usize,
+    arena_id: u64,
+    game_mode: u64,
+    cfg: &PoseidonConfig<F>,
+) -> F {
+    let leaves = padded_leaves(polygon, num_vertices, cfg, POLYGON_HASH_PADDING_MODE);
+
+    let mut sponge = PoseidonSponge::<F>::new(cfg);
+    sponge.absorb(&F::from(arena_id));
+    sponge.absorb(&F::from(game_mode));
+    sponge.absorb(&F::from(num_vertices as u64));
+    for leaf in &leaves {
+        sponge.absorb(leaf);
+    }
+    sponge.squeeze_field_elements(1)[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use ark_bn254::Fr;
+    use ark_ff::Zero;
+
+    use crate::zk::{
+        circuit::{
+            BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_MAX_VERTICES_LARGE, CIRCUIT_PRECISION,
+            is_point_in_polygon,
+        },
+        poseidon::default_poseidon_config,
+    };
+
+    type F = Fr;
+    const MAX: usize = CIRCUIT_MAX_VERTICES;
+    const PREC: u32 = CIRCUIT_PRECISION;
+
+    fn polygon(vs: &[(f64, f64)]) -> [Point2DDec<F, PREC>; MAX] {
+        let mut poly = [Point2DDec::from_f64(0.0, 0.0); MAX];
+        for (p, &(x, y)) in poly.iter_mut().zip(vs) {
+            *p = Point2DDec::from_f64(x, y);
+        }
+        poly
+    }
+
+    #[test]
+    fn square_area_centroid_and_bounds() {
+        let ccw = polygon(&[(-1.0, 2.0), (3.0, 2.0), (3.0, 6.0), (-1.0, 6.0)]);
+        let cw = polygon(&[(-1.0, 2.0), (-1.0, 6.0), (3.0, 6.0), (3.0, 2.0)]);
+
+        assert_eq!(polygon_area(&ccw, 4), 16.0);
+        assert_eq!(polygon_area(&cw, 4), -16.0);
+        assert_eq!(polygon_centroid(&ccw, 4), Some((1.0, 4.0)));
+        assert_eq!(polygon_centroid(&ccw, 2), None);
+
+        let (min, max) = bounding_box(&ccw, 4).unwrap();
+        assert_eq!((min.x.to_f64(), min.y.to_f64()), (-1.0, 2.0));
+        assert_eq!((max.x.to_f64(), max.y.to_f64()), (3.0, 6.0));
+        assert!(bounding_box(&ccw, 0).is_none());
+    }
+
+    #[test]
+    fn bow_tie_is_not_simple() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let bow_tie = polygon(&[(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)]);
+
+        assert!(is_simple_polygon(&square, 4));
+        assert!(!is_simple_polygon(&bow_tie, 4));
+        assert!(!is_simple_polygon(&square, 2));
+    }
+
+    #[test]
+    fn h3_cell_is_a_simple_ccw_polygon_around_its_centre() {
+        let cell = CellIndex::from_str("872830828ffffff").unwrap();
+        let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX>(cell);
+        assert_eq!(n, 6);
+        assert!(is_simple_polygon(&poly, n));
+        assert!(polygon_area(&poly, n) > 0.0);
+
+        let (cx, cy) = polygon_centroid(&poly, n).unwrap();
+        let centre = Point2DDec::from_f64(cx, cy);
+        assert!(is_point_in_polygon::<F, PREC, MAX>(
+            &centre,
+            &poly,
+            n,
+            BoundaryBehavior::CountAsInside
+        ));
+    }
+
+    #[test]
+    fn clockwise_southern_cell_is_rewound_before_proving() {
+        use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+        use h3o::{LatLng, Resolution};
+
+        use crate::zk::circuit::PointInMapCircuit;
+
+        let cfg = default_poseidon_config();
+        // Sydney
+        let centre = LatLng::new(-33.8688, 151.2093).unwrap();
+        let cell = centre.to_cell(Resolution::Seven);
+        let (ccw, n) = polygon_from_h3_cell::<F, PREC, MAX>(cell);
+        assert!(polygon_area(&ccw, n) > 0.0);
+        let hash = hash_polygon::<F, PREC, MAX>(&ccw, n, 0, &cfg);
+
+        let mut cw = ccw;
+        cw[1..n].reverse();
+        assert!(polygon_area(&cw, n) < 0.0);
+
+        let (x, y) = gps_to_web_mercator(centre.lng(), centre.lat());
+        let point = Point2DDec::from_f64(x, y);
+        let satisfied = |poly| {
+            let mut hashes = [F::zero(); 4];
+            hashes[0] = hash;
+            let circuit = PointInMapCircuit::<F, PREC, MAX, 4>::new(
+                point,
+                poly,
+                n as u64,
+                true,
+                hashes,
+                0,
+                cfg.clone(),
+                false,
+            );
+            let cs = ConstraintSystem::<F>::new_ref();
+            circuit.generate_constraints(cs.clone()).unwrap();
+            cs.is_satisfied().unwrap()
+        };
+
+        // every edge test flips sign, and the hash misses the map
+        assert!(satisfied(ccw));
+        assert!(!satisfied(cw));
+
+        ensure_ccw_winding(&mut cw, n);
+        assert_eq!(to_f64_vertices(&cw), to_f64_vertices(&ccw));
+        assert_eq!(hash_polygon::<F, PREC, MAX>(&cw, n, 0, &cfg), hash);
+        assert!(satisfied(cw));
+    }
+
+    #[test]
+    fn antimeridian_cells_are_projected_in_one_piece() {
+        use h3o::{LatLng, Resolution};
+
+        // Fiji and the Aleutian Islands
+        for lat in [-17.0, 51.5] {
+            let cell = LatLng::new(lat, 180.0).unwrap().to_cell(Resolution::Five);
+            assert!(crosses_antimeridian(cell), "{cell} at lat {lat}");
+
+            let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX>(cell);
+            let (min, max) = bounding_box(&poly, n).unwrap();
+            // a res-5 cell is ~20 km across, not the width of the world
+            assert!(max.x.sub(min.x).to_f64() < 50_000.0);
+            assert!(polygon_area(&poly, n) > 0.0);
+
+            // ~100 m either side of ±180°, shifted as `/prove` shifts them
+            for lon in [179.999, -179.999] {
+                let ll = LatLng::new(lat, lon).unwrap();
+                assert_eq!(ll.to_cell(Resolution::Five), cell);
+                let (x, y) = gps_to_web_mercator(unwrap_antimeridian_lon(lon), lat);
+                assert!(is_point_in_polygon::<F, PREC, MAX>(
+                    &Point2DDec::from_f64(x, y),
+                    &poly,
+                    n,
+                    BoundaryBehavior::CountAsInside,
+                ));
+            }
+        }
+
+        let sf = LatLng::new(37.7749, -122.4194).unwrap();
+        assert!(!crosses_antimeridian(sf.to_cell(Resolution::Five)));
+    }
+
+    #[test]
+    fn square_maps_to_the_cells_centred_inside_it() {
+        let (south, west, north, east) = (37.0, -123.0, 38.0, -122.0);
+        let corners = [(west, south), (east, south), (east, north), (west, north)];
+        let square = polygon(&corners.map(|(lon, lat)| gps_to_web_mercator(lon, lat)));
+
+        let cells =
+            polygon_to_h3_cells::<F, PREC, MAX>(&square, 4, Resolution::Five, 1000).unwrap();
+
+        // res-5 cells are ~20 km across; 20 rings cover the 1° square
+        let middle = LatLng::new(37.5, -122.5).unwrap().to_cell(Resolution::Five);
+        let mut expected: Vec<_> = middle
+            .grid_disk::<Vec<_>>(20)
+            .into_iter()
+            .filter(|&cell| {
+                let ll = LatLng::from(cell);
+                (south..=north).contains(&ll.lat()) && (west..=east).contains(&ll.lng())
+            })
+            .collect();
+        expected.sort_unstable();
+
+        assert!(cells.len() > 30, "{} cells", cells.len());
+        assert_eq!(cells, expected);
+        assert_eq!(
+            polygon_to_h3_cells::<F, PREC, MAX>(&square, 0, Resolution::Five, 1000),
+            Some(Vec::new())
+        );
+        // the same square at res 9 is ~100k cells
+        assert_eq!(
+            polygon_to_h3_cells::<F, PREC, MAX>(&square, 4, Resolution::Nine, 1000),
+            None
+        );
+    }
+
+    #[test]
+    fn gps_decagon_pads_with_the_origin() {
+        const LARGE: usize = CIRCUIT_MAX_VERTICES_LARGE;
+        let cfg = default_poseidon_config();
+
+        // CCW decagon of ~1 km around central San Francisco
+        let vertices: Vec<[f64; 2]> = (0..10)
+            .map(|k| {
+                let theta = core::f64::consts::TAU * k as f64 / 10.0;
+                [37.77 + 0.01 * theta.sin(), -122.42 + 0.01 * theta.cos()]
+            })
+            .collect();
+        let (poly, n) = polygon_from_gps::<F, PREC, LARGE>(&vertices);
+        assert_eq!(n, 10);
+        assert!(is_simple_polygon(&poly, n));
+        assert!(polygon_area(&poly, n) > 0.0);
+        for pad in &poly[n..] {
+            assert_eq!((pad.x.to_f64(), pad.y.to_f64()), (0.0, 0.0));
+        }
+
+        // padding never reaches the hash
+        let mut first_padded = poly;
+        first_padded[n..].fill(poly[0]);
+        assert_eq!(
+            hash_polygon::<F, PREC, LARGE>(&poly, n, 0, &cfg),
+            hash_polygon::<F, PREC, LARGE>(&first_padded, n, 0, &cfg)
+        );
+    }
+
+    #[test]
+    fn leaves_allow_single_vertex_rehash() {
+        let cfg = default_poseidon_config();
+        let mut poly = polygon(&[(0.0, 0.0), (4.0, 0.0), (5.0, 3.0), (2.0, 5.0), (-1.0, 3.0)]);
+        let before = hash_polygon_leaves::<F, PREC, MAX>(&poly, 5, &cfg);
+        assert_eq!(before[5], F::zero());
+
+        poly[2] = Point2DDec::from_f64(-31.0, 95.5);
+        let after = hash_polygon_leaves::<F, PREC, MAX>(&poly, 5, &cfg);
+        for i in 0..MAX {
+            assert_eq!(before[i] == after[i], i != 2, "leaf {i}");
+        }
+
+        // patch the cached leaves instead of rehashing every vertex
+        let mut cached = before;
+        cached[2] = after[2];
+        assert_eq!(
+            hash_polygon_from_leaves(&cached, 5, 3, &cfg),
+            hash_polygon::<F, PREC, MAX>(&poly, 5, 3, &cfg)
+        );
+    }
+
+    #[test]
+    fn hash_polygon_separates_small_polygons_exhaustively() {
+        let cfg = default_poseidon_config();
+        let vertices = [
+            (1.0, 2.0),
+            (-3.0, 0.5),
+            (4.0, 3.0),
+            (2.0, -2.0),
+            (0.5, 1.0),
+            (-1.0, -1.0),
+        ];
+
+        // every prefix 0..=6, plus each prefix with an explicit (0, 0)
+        // vertex where the zero padding would otherwise sit; no vertex above
+        // is (0, 0), or a zero tail would equal the next prefix
+        let mut seen = HashSet::new();
+        for n in 0..=MAX {
+            let poly = polygon(&vertices[..n]);
+            assert!(
+                seen.insert(hash_polygon::<F, PREC, MAX>(&poly, n, 0, &cfg)),
+                "n = {n}"
+            );
+            if n < MAX {
+                let mut zero_tail = vertices[..n].to_vec();
+                zero_tail.push((0.0, 0.0));
+                let h = hash_polygon::<F, PREC, MAX>(&polygon(&zero_tail), n + 1, 0, &cfg);
+                assert!(seen.insert(h), "n = {n} + (0, 0)");
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn hash_polygon_collision_resistance(
+            a in proptest::collection::vec((-4i8..=4, -4i8..=4), 0..=MAX),
+            b in proptest::collection::vec((-4i8..=4, -4i8..=4), 0..=MAX),
+        ) {
+            proptest::prop_assume!(a != b);
+            let cfg = default_poseidon_config();
+            let hash = |vs: &[(i8, i8)]| {
+                let vs: Vec<_> = vs.iter().map(|&(x, y)| (x as f64 / 2.0, y as f64 / 2.0)).collect();
+                hash_polygon::<F, PREC, MAX>(&polygon(&vs), vs.len(), 0, &cfg)
+            };
+            proptest::prop_assert_ne!(hash(&a), hash(&b));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::{PoseidonConfig, find_poseidon_ark_and_mds};
+use ark_ff::{BigInteger, PrimeField};
+
+pub const POSEIDON_FULL_ROUNDS: usize = 8;
+pub const POSEIDON_PARTIAL_ROUNDS: usize = 31;
+
+/// S-box exponent: every round raises state elements to this power.
+pub const POSEIDON_ALPHA: u64 = 17;
+
+/// Fewest rounds accepted for BN254 at 128-bit security; partial rounds
+/// also need [`min_partial_rounds`] for the S-box in use.
+pub const POSEIDON_MIN_FULL_ROUNDS: usize = 6;
+pub const POSEIDON_MIN_PARTIAL_ROUNDS: usize = 14;
+
+// R_F + R_P the Poseidon paper sets for α = 5 at width 3 on BN254: 8 + 57
+const ALPHA_5_TOTAL_ROUNDS: f64 = 65.0;
+
+/// Fewest partial rounds for S-box `alpha` after `full_rounds` full ones.
+/// The interpolation attack is stopped by the total degree
+/// `alpha^(R_F + R_P)`, so the α = 5 rounds are scaled by
+/// `log 5 / log alpha`: 57 after 8 full rounds at α = 5, 29 at α = 17.
+pub fn min_partial_rounds(alpha: u64, full_rounds: usize) -> usize {
+    let total = (ALPHA_5_TOTAL_ROUNDS * 5f64.ln() / (alpha as f64).ln()).ceil() as usize;
+    total
+        .saturating_sub(full_rounds)
+        .max(POSEIDON_MIN_PARTIAL_ROUNDS)
+}
+
+/// Poseidon parameters: width 3, α = 17, 8 full + 31 partial rounds.
+pub fn default_poseidon_config() -> PoseidonConfig<Fr> {
+    poseidon_config(2, 1)
+}
+
+/// Poseidon parameters for width `rate + capacity`, α = 17, 8 full + 31
+/// partial rounds. `ark` and `mds` are derived for that width.
+pub fn poseidon_config(rate: usize, capacity: usize) -> PoseidonConfig<Fr> {
+    poseidon_config_with_rounds(
+        rate,
+        capacity,
+        POSEIDON_FULL_ROUNDS,
+        POSEIDON_PARTIAL_ROUNDS,
+    )
+}
+
+/// [`poseidon_config`] with explicit round counts; check them first with
+/// [`validate_poseidon_rounds`] at [`POSEIDON_ALPHA`].
+pub fn poseidon_config_with_rounds(
+    rate: usize,
+    capacity: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+) -> PoseidonConfig<Fr> {
+    poseidon_config_with_alpha(rate, capacity, full_rounds, partial_rounds, POSEIDON_ALPHA)
+}
+
+/// [`poseidon_config_with_rounds`] with S-box exponent `alpha`; check it
+/// first with [`validate_poseidon_alpha`]. The round constants do not
+/// depend on `alpha`, but the rounds needed for security do (α = 5 wants
+/// more partial rounds than α = 17).
+pub fn poseidon_config_with_alpha(
+    rate: usize,
+    capacity: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+) -> PoseidonConfig<Fr> {
+    // the Grain LFSR helper generates constants for width `arg + 1`
+    let width = rate + capacity;
+    let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(
+        Fr::MODULUS_BIT_SIZE as u64,
+        width - 1,
+        full_rounds as u64,
+        partial_rounds as u64,
+        0,
+    );
+
+    PoseidonConfig {
+        full_rounds,
+        partial_rounds,
+        alpha,
+        ark,
+        mds,
+        rate,
+        capacity,
+    }
+}
+
+/// Full rounds are split evenly around the partial rounds, so they must be
+/// even as well as above the security minimum; partial rounds must reach
+/// [`min_partial_rounds`] for `alpha`. Check `alpha` itself first with
+/// [`validate_poseidon_alpha`].
+pub fn validate_poseidon_rounds(
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+) -> Result<(), String> {
+    if full_rounds < POSEIDON_MIN_FULL_ROUNDS || full_rounds % 2 != 0 {
+        return Err(format!(
+            "poseidon full rounds must be even and at least {POSEIDON_MIN_FULL_ROUNDS}, got {full_rounds}"
+        ));
+    }
+    let min_partial = min_partial_rounds(alpha, full_rounds);
+    if partial_rounds < min_partial {
+        return Err(format!(
+            "poseidon partial rounds must be at least {min_partial} with alpha {alpha} and {full_rounds} full rounds, got {partial_rounds}"
+        ));
+    }
+    Ok(())
+}
+
+/// `x ↦ x^alpha` is a permutation of `Fr` only when `gcd(alpha, r - 1) == 1`.
+pub fn validate_poseidon_alpha(alpha: u64) -> Result<(), String> {
+    if alpha < 3 {
+        return Err(format!("poseidon alpha must be at least 3, got {alpha}"));
+    }
+    // (r - 1) mod alpha, limb by limb from the most significant
+    let mut r_minus_one = Fr::MODULUS;
+    r_minus_one.sub_with_borrow(&1u64.into());
+    let rem = r_minus_one.as_ref().iter().rev().fold(0u128, |rem, &limb| {
+        ((rem << 64) | limb as u128) % alpha as u128
+    });
+    if gcd(alpha, rem as u64) != 1 {
+        return Err(format!(
+            "poseidon alpha {alpha} shares a factor with r - 1, x^{alpha} is not a permutation"
+        ));
+    }
+    Ok(())
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Check that `ark` and `mds` were generated for `rate + capacity`.
+pub fn validate_poseidon_config(cfg: &PoseidonConfig<Fr>) -> Result<(), String> {
+    let width = cfg.rate + cfg.capacity;
+    if cfg.rate == 0 || cfg.capacity == 0 {
+        return Err(format!(
+            "poseidon rate ({}) and capacity ({}) must both be non-zero",
+            cfg.rate, cfg.capacity
+        ));
+    }
+    if cfg.ark.len() != cfg.full_rounds + cfg.partial_rounds {
+        return Err(format!(
+            "poseidon ark has {} rounds, expected {}",
+            cfg.ark.len(),
+            cfg.full_rounds + cfg.partial_rounds
+        ));
+    }
+    if cfg.ark.iter().any(|row| row.len() != width) {
+        return Err(format!("poseidon ark rows do not match width {width}"));
+    }
+    if cfg.mds.len() != width || cfg.mds.iter().any(|row| row.len() != width) {
+        return Err(format!("poseidon mds is not {width}×{width}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_crypto_primitives::sponge::{CryptographicSponge, poseidon::PoseidonSponge};
+    use ark_ff::Zero;
+    use ark_r1cs_std::{R1CSVar, alloc::AllocVar, fields::fp::FpVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::zk::{
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, hash_polygon_gadget},
+        fixed_point_decimal::DecVar,
+        point_2d::{Point2DDec, Point2DDecVar},
+        polygon_ops::hash_polygon,
+    };
+
+    fn hash_one_two(cfg: &PoseidonConfig<Fr>) -> Fr {
+        let mut sponge = PoseidonSponge::new(cfg);
+        sponge.absorb(&Fr::from(1u64));
+        sponge.absorb(&Fr::from(2u64));
+        sponge.squeeze_field_elements(1)[0]
+    }
+
+    #[test]
+    fn reduced_rounds_hash_differently() {
+        let validate = |full, partial| validate_poseidon_rounds(full, partial, POSEIDON_ALPHA);
+        assert!(validate(POSEIDON_FULL_ROUNDS, POSEIDON_PARTIAL_ROUNDS).is_ok());
+        assert!(validate(8, 29).is_ok());
+        assert!(validate(8, 28).is_err());
+        assert!(validate(10, 27).is_ok());
+        assert!(validate(4, 31).is_err());
+        assert!(validate(7, 31).is_err());
+
+        let reduced = poseidon_config_with_rounds(2, 1, 8, 29);
+        assert!(validate_poseidon_config(&reduced).is_ok());
+
+        assert_ne!(
+            hash_one_two(&reduced),
+            hash_one_two(&default_poseidon_config())
+        );
+    }
+
+    #[test]
+    fn alpha_five_is_a_valid_bn254_sbox() {
+        assert!(validate_poseidon_alpha(POSEIDON_ALPHA).is_ok());
+        assert!(validate_poseidon_alpha(5).is_ok());
+        // r - 1 is divisible by 2 and 3
+        assert!(validate_poseidon_alpha(2).is_err());
+        assert!(validate_poseidon_alpha(3).is_err());
+        assert!(validate_poseidon_alpha(9).is_err());
+
+        // x^5 adds less degree per round than x^17, so 31 rounds fall short
+        assert_eq!(min_partial_rounds(5, 8), 57);
+        assert!(validate_poseidon_rounds(8, POSEIDON_PARTIAL_ROUNDS, 5).is_err());
+        assert!(validate_poseidon_rounds(8, 57, 5).is_ok());
+
+        let alpha5 = poseidon_config_with_alpha(2, 1, 8, 57, 5);
+        assert!(validate_poseidon_config(&alpha5).is_ok());
+        assert_ne!(
+            hash_one_two(&alpha5),
+            hash_one_two(&poseidon_config_with_rounds(2, 1, 8, 57))
+        );
+
+        // the gadget applies the same S-box as the native sponge
+        const MAX: usize = CIRCUIT_MAX_VERTICES;
+        const PREC: u32 = CIRCUIT_PRECISION;
+        let poly: [Point2DDec<Fr, PREC>; MAX] =
+            core::array::from_fn(|i| Point2DDec::from_f64(i as f64, -(i as f64)));
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let vars = poly.map(|p| Point2DDecVar::new_witness(cs.clone(), || Ok(p)).unwrap());
+        let n = FpVar::new_witness(cs.clone(), || Ok(Fr::from(MAX as u64))).unwrap();
+        let mode = FpVar::new_witness(cs.clone(), || Ok(Fr::zero())).unwrap();
+        let hash = hash_polygon_gadget::<Fr, PREC, MAX>(&vars, &n, &mode, &alpha5).unwrap();
+        assert_eq!(
+            hash.value().unwrap(),
+            hash_polygon::<Fr, PREC, MAX>(&poly, MAX, 0, &alpha5)
+        );
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn mismatched_width_is_rejected() {
+        assert!(validate_poseidon_config(&default_poseidon_config()).is_ok());
+        assert!(validate_poseidon_config(&poseidon_config(3, 1)).is_ok());
+
+        let mut cfg = poseidon_config(3, 1);
+        cfg.rate = 2; // ark/mds still sized for width 4
+        assert!(validate_poseidon_config(&cfg).is_err());
+    }
+
+    // constraints for one full-size polygon hash
+    fn hash_constraints(cfg: &PoseidonConfig<Fr>) -> usize {
+        const MAX: usize = CIRCUIT_MAX_VERTICES;
+        const PREC: u32 = CIRCUIT_PRECISION;
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let poly: [Point2DDecVar<Fr, PREC>; MAX] = core::array::from_fn(|i| {
+            let p = Point2DDec::<Fr, PREC>::from_f64(i as f64, -(i as f64));
+            Point2DDecVar {
+                x: DecVar::new_witness(cs.clone(), || Ok(p.x)).unwrap(),
+                y: DecVar::new_witness(cs.clone(), || Ok(p.y)).unwrap(),
+            }
+        });
+        let n = FpVar::new_witness(cs.clone(), || Ok(Fr::from(MAX as u64))).unwrap();
+        let mode = FpVar::new_witness(cs.clone(), || Ok(Fr::from(0u64))).unwrap();
+
+        let _hash = hash_polygon_gadget::<Fr, PREC, MAX>(&poly, &n, &mode, cfg).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        cs.num_constraints()
+    }
+
+    // each vertex leaf absorbs 4 elements: rate 3 still needs two
+    // permutations per leaf, rate 4 needs one
+    #[test]
+    fn wider_rate_trades_permutations_for_width() {
+        let rate2 = hash_constraints(&poseidon_config(2, 1));
+        let rate3 = hash_constraints(&poseidon_config(3, 1));
+        let rate4 = hash_constraints(&poseidon_config(4, 1));
+        assert!(
+            rate3 > rate2,
+            "rate 3: {rate3} constraints, rate 2: {rate2}"
+        );
+        assert!(
+            rate4 < rate2,
+            "rate 4: {rate4} constraints, rate 2: {rate2}"
+        );
+    }
+}
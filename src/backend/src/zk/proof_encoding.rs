@@ -0,0 +1,150 @@
+//! Self-describing proof bytes: a `ZK01` magic and a flags byte in front of
+//! the serialised `Proof<Bn254>`, so a change of point encoding is detected
+//! instead of surfacing as an unreadable proof.
+
+use std::fmt;
+
+use ark_bn254::Bn254;
+use ark_groth16::Proof;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub const PROOF_MAGIC: [u8; 4] = *b"ZK01";
+/// flags bit 0: points are compressed
+pub const PROOF_FLAG_COMPRESSED: u8 = 1;
+
+const HEADER_LEN: usize = PROOF_MAGIC.len() + 1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProofDeserError {
+    /// shorter than the 5-byte header
+    Truncated,
+    BadMagic([u8; 4]),
+    /// flag bits this version does not know
+    UnknownFlags(u8),
+    /// the header is fine but the points are not
+    Invalid(String),
+}
+
+impl fmt::Display for ProofDeserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "proof is shorter than its header"),
+            Self::BadMagic(m) => write!(f, "not a versioned proof (magic {m:02x?})"),
+            Self::UnknownFlags(flags) => write!(f, "unknown proof flags {flags:#04x}"),
+            Self::Invalid(e) => write!(f, "proof does not decode: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ProofDeserError {}
+
+/// Header plus uncompressed points, the encoding `/prove` has always used.
+pub fn serialize_proof_versioned(proof: &Proof<Bn254>) -> Vec<u8> {
+    encode(proof, false)
+}
+
+/// Header plus compressed points: half the size, slower to decode.
+pub fn serialize_proof_versioned_compressed(proof: &Proof<Bn254>) -> Vec<u8> {
+    encode(proof, true)
+}
+
+fn encode(proof: &Proof<Bn254>, compressed: bool) -> Vec<u8> {
+    let mut out = PROOF_MAGIC.to_vec();
+    out.push(if compressed { PROOF_FLAG_COMPRESSED } else { 0 });
+    let written = if compressed {
+        proof.serialize_compressed(&mut out)
+    } else {
+        proof.serialize_uncompressed(&mut out)
+    };
+    written.expect("writing to a Vec cannot fail");
+    out
+}
+
+/// Read either flavour of [`serialize_proof_versioned`] output; trailing
+/// bytes are rejected.
+pub fn deserialize_proof_versioned(bytes: &[u8]) -> Result<Proof<Bn254>, ProofDeserError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(ProofDeserError::Truncated);
+    }
+    let magic: [u8; 4] = bytes[..4].try_into().unwrap();
+    if magic != PROOF_MAGIC {
+        return Err(ProofDeserError::BadMagic(magic));
+    }
+    let flags = bytes[4];
+    if flags & !PROOF_FLAG_COMPRESSED != 0 {
+        return Err(ProofDeserError::UnknownFlags(flags));
+    }
+
+    let mut body = &bytes[HEADER_LEN..];
+    let proof = if flags & PROOF_FLAG_COMPRESSED != 0 {
+        Proof::deserialize_compressed(&mut body)
+    } else {
+        Proof::deserialize_uncompressed(&mut body)
+    }
+    .map_err(|e| ProofDeserError::Invalid(e.to_string()))?;
+    if !body.is_empty() {
+        return Err(ProofDeserError::Invalid(format!(
+            "{} trailing bytes",
+            body.len()
+        )));
+    }
+    Ok(proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::{Fr, G1Affine, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+
+    fn proof() -> Proof<Bn254> {
+        Proof {
+            a: (G1Affine::generator() * Fr::from(3u64)).into_affine(),
+            b: (G2Affine::generator() * Fr::from(5u64)).into_affine(),
+            c: (G1Affine::generator() * Fr::from(7u64)).into_affine(),
+        }
+    }
+
+    #[test]
+    fn both_encodings_round_trip() {
+        let proof = proof();
+        let plain = serialize_proof_versioned(&proof);
+        let small = serialize_proof_versioned_compressed(&proof);
+        assert_eq!(
+            (&plain[..5], &small[..5]),
+            (&b"ZK01\0"[..], &b"ZK01\x01"[..])
+        );
+        assert_eq!((plain.len(), small.len()), (5 + 256, 5 + 128));
+
+        assert_eq!(deserialize_proof_versioned(&plain), Ok(proof.clone()));
+        assert_eq!(deserialize_proof_versioned(&small), Ok(proof));
+    }
+
+    #[test]
+    fn bad_headers_are_diagnosed() {
+        let mut bytes = serialize_proof_versioned(&proof());
+        assert_eq!(
+            deserialize_proof_versioned(&bytes[..3]),
+            Err(ProofDeserError::Truncated)
+        );
+        assert!(matches!(
+            deserialize_proof_versioned(&bytes[..100]),
+            Err(ProofDeserError::Invalid(_))
+        ));
+
+        bytes[4] = 0b10;
+        assert_eq!(
+            deserialize_proof_versioned(&bytes),
+            Err(ProofDeserError::UnknownFlags(0b10))
+        );
+
+        // the same points without a header
+        let mut bare = Vec::new();
+        proof().serialize_uncompressed(&mut bare).unwrap();
+        assert!(matches!(
+            deserialize_proof_versioned(&bare),
+            Err(ProofDeserError::BadMagic(_))
+        ));
+    }
+}
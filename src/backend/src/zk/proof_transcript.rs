@@ -0,0 +1,77 @@
+//! A Groth16 proof tagged with the application nonce (game round, session
+//! ID) it was issued for.
+//!
+//! The tag is `HMAC-SHA256(key = proof bytes, data = nonce)`. Whoever holds
+//! the proof can compute it for any nonce, so despite the names the tag
+//! binds nothing: it records which round a proof was handed out for, and a
+//! holder can re-tag the proof for another round. Binding that survives a
+//! dishonest holder needs the nonce as a public input of the circuit.
+
+use ark_bn254::Bn254;
+use ark_groth16::Proof;
+use ark_serialize::CanonicalSerialize;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub struct BoundProof {
+    pub proof: Proof<Bn254>,
+    pub binding_tag: [u8; 32],
+}
+
+fn mac(proof: &Proof<Bn254>) -> HmacSha256 {
+    let mut key = Vec::new();
+    proof
+        .serialize_uncompressed(&mut key)
+        .expect("Vec writes never fail");
+    HmacSha256::new_from_slice(&key).expect("HMAC takes keys of any length")
+}
+
+/// Tag `proof` with `nonce`; a label, not a binding (see the module docs).
+pub fn bind_proof_to_nonce(proof: &Proof<Bn254>, nonce: &[u8]) -> BoundProof {
+    let mut mac = mac(proof);
+    mac.update(nonce);
+    BoundProof {
+        proof: proof.clone(),
+        binding_tag: mac.finalize().into_bytes().into(),
+    }
+}
+
+/// Whether `bound` carries the tag of its proof under `nonce`; compared in
+/// constant time.
+pub fn verify_bound_proof(bound: &BoundProof, nonce: &[u8]) -> bool {
+    let mut mac = mac(&bound.proof);
+    mac.update(nonce);
+    mac.verify_slice(&bound.binding_tag).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::{Fr, G1Affine, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+
+    #[test]
+    fn tag_checks_only_under_its_nonce() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let bound = bind_proof_to_nonce(&proof, b"round-7");
+        assert!(verify_bound_proof(&bound, b"round-7"));
+        assert!(!verify_bound_proof(&bound, b"round-8"));
+
+        let other = Proof::<Bn254> {
+            c: (G1Affine::generator() * Fr::from(2u64)).into_affine(),
+            ..proof
+        };
+        let moved = BoundProof {
+            proof: other,
+            binding_tag: bound.binding_tag,
+        };
+        assert!(!verify_bound_proof(&moved, b"round-7"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Layout of the `PointInMapCircuit` public-input vector.
+//!
+//! index 0            → `result_flag` (0 / 1)
+//! index 1 ..= MAX    → `cell_hash_{i}` (Poseidon hash of an allowed cell, zero = padding)
+
+use std::fmt;
+
+use ark_bn254::Fr;
+use ark_ff::{One, Zero};
+use serde::Serialize;
+
+/// One slot of the public-input vector, by meaning rather than by index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PublicInput {
+    ResultFlag,
+    /// the `i`-th map hash, from 0
+    MapHash(usize),
+}
+
+/// Position of `p` in the public-input vector.
+pub const fn index_of(p: PublicInput) -> usize {
+    match p {
+        PublicInput::ResultFlag => 0,
+        PublicInput::MapHash(i) => 1 + i,
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct PublicInputField {
+    pub index: usize,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: &'static str,
+}
+
+/// Describe every public input for a circuit with `max_polygon_hashes` map slots.
+pub fn public_inputs_schema(max_polygon_hashes: usize) -> Vec<PublicInputField> {
+    let flag = PublicInputField {
+        index: index_of(PublicInput::ResultFlag),
+        name: "result_flag".into(),
+        ty: "bool",
+    };
+
+    core::iter::once(flag)
+        .chain((0..max_polygon_hashes).map(|i| PublicInputField {
+            index: index_of(PublicInput::MapHash(i)),
+            name: format!("cell_hash_{i}"),
+            ty: "Fr",
+        }))
+        .collect()
+}
+
+pub struct PublicInputs {
+    pub result_flag: bool,
+    pub cell_hashes: Vec<Fr>,
+}
+
+impl PublicInputs {
+    /// Map hashes with the zero padding slots dropped.
+    pub fn active_cell_hashes(&self) -> impl Iterator<Item = &Fr> {
+        self.cell_hashes.iter().filter(|h| !h.is_zero())
+    }
+}
+
+/// The vector a circuit with `MAX_HASHES` map slots is verified against.
+pub fn build_public_inputs<const MAX_HASHES: usize>(
+    flag: bool,
+    map_hashes: &[Fr; MAX_HASHES],
+) -> Vec<Fr> {
+    let mut inputs = vec![Fr::zero(); 1 + MAX_HASHES];
+    inputs[index_of(PublicInput::ResultFlag)] = if flag { Fr::one() } else { Fr::zero() };
+    for (i, h) in map_hashes.iter().enumerate() {
+        inputs[index_of(PublicInput::MapHash(i))] = *h;
+    }
+    inputs
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PublicInputError {
+    /// not even the result flag
+    Empty,
+    /// the result flag is neither 0 nor 1
+    NonBooleanFlag(Fr),
+}
+
+impl fmt::Display for PublicInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no public inputs"),
+            Self::NonBooleanFlag(v) => write!(f, "result flag is {v}, not 0 or 1"),
+        }
+    }
+}
+
+impl std::error::Error for PublicInputError {}
+
+/// Inverse of [`build_public_inputs`], for any number of map slots.
+pub fn parse_public_inputs(inputs: &[Fr]) -> Result<PublicInputs, PublicInputError> {
+    let flag = *inputs
+        .get(index_of(PublicInput::ResultFlag))
+        .ok_or(PublicInputError::Empty)?;
+    let result_flag = match flag {
+        f if f.is_one() => true,
+        f if f.is_zero() => false,
+        f => return Err(PublicInputError::NonBooleanFlag(f)),
+    };
+    Ok(PublicInputs {
+        result_flag,
+        cell_hashes: inputs[index_of(PublicInput::MapHash(0))..].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_and_parse_round_trip() {
+        let hashes = [Fr::from(7u64), Fr::zero(), Fr::from(9u64)];
+        let inputs = build_public_inputs(true, &hashes);
+        assert_eq!(inputs[index_of(PublicInput::ResultFlag)], Fr::one());
+        assert_eq!(inputs[index_of(PublicInput::MapHash(2))], hashes[2]);
+
+        let parsed = parse_public_inputs(&inputs).unwrap();
+        assert!(parsed.result_flag);
+        assert_eq!(parsed.cell_hashes, hashes);
+        assert_eq!(parsed.active_cell_hashes().count(), 2);
+
+        assert_eq!(
+            parse_public_inputs(&[]).err(),
+            Some(PublicInputError::Empty)
+        );
+        assert_eq!(
+            parse_public_inputs(&[Fr::from(2u64)]).err(),
+            Some(PublicInputError::NonBooleanFlag(Fr::from(2u64)))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Prove `lo <= x <= hi` for a private `x` and public bounds.
+//!
+//! Both differences `x - lo` and `hi - x` are decomposed into canonical field
+//! bits; each lies in range iff every bit at position `>= BITS` is zero. A
+//! negative difference wraps to `p - d`, whose high bits are set.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{FieldVar, fp::FpVar},
+    prelude::ToBitsGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::zk::fixed_point_decimal::{Dec, DecVar};
+
+/// Enough headroom for any Web-Mercator difference at `PREC = 8` (< 2^53).
+pub const WEB_MERCATOR_RANGE_BITS: usize = 64;
+
+fn fits_in_bits<F: PrimeField, const BITS: usize>(
+    v: &FpVar<F>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let bits = v.to_bits_le()?;
+    if BITS >= bits.len() {
+        return Ok(Boolean::TRUE);
+    }
+    Ok(!Boolean::kary_or(&bits[BITS..])?)
+}
+
+// lo <= x <= hi, with every difference bounded by 2^BITS
+pub fn range_proof_gadget<F: PrimeField, const BITS: usize>(
+    x: &FpVar<F>,
+    lo: &FpVar<F>,
+    hi: &FpVar<F>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let above_lo = fits_in_bits::<F, BITS>(&(x - lo))?;
+    let below_hi = fits_in_bits::<F, BITS>(&(hi - x))?;
+    Ok(above_lo & below_hi)
+}
+
+// signed magnitude → field element (negatives wrap to p - val)
+fn signed_fp<F: PrimeField, const PREC: u32>(
+    d: &DecVar<F, PREC>,
+) -> Result<FpVar<F>, SynthesisError> {
+    Boolean::select(&d.neg, &d.val.negate()?, &d.val)
+}
+
+pub struct RangeProofCircuit<F: PrimeField, const PREC: u32, const BITS: usize> {
+    pub private_x: Dec<F, PREC>,
+
+    pub public_lo: Dec<F, PREC>,
+    pub public_hi: Dec<F, PREC>,
+}
+
+impl<F: PrimeField, const PREC: u32, const BITS: usize> RangeProofCircuit<F, PREC, BITS> {
+    pub fn new(private_x: Dec<F, PREC>, public_lo: Dec<F, PREC>, public_hi: Dec<F, PREC>) -> Self {
+        Self {
+            private_x,
+            public_lo,
+            public_hi,
+        }
+    }
+}
+
+impl<F: PrimeField, const PREC: u32, const BITS: usize> ConstraintSynthesizer<F>
+    for RangeProofCircuit<F, PREC, BITS>
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let x = DecVar::new_witness(cs.clone(), || Ok(self.private_x))?;
+        let lo = DecVar::new_input(cs.clone(), || Ok(self.public_lo))?;
+        let hi = DecVar::new_input(cs, || Ok(self.public_hi))?;
+
+        let in_range =
+            range_proof_gadget::<F, BITS>(&signed_fp(&x)?, &signed_fp(&lo)?, &signed_fp(&hi)?)?;
+        in_range.enforce_equal(&Boolean::TRUE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::Fr;
+    use ark_r1cs_std::R1CSVar;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    type F = Fr;
+    const PREC: u32 = 8;
+    const BITS: usize = WEB_MERCATOR_RANGE_BITS;
+
+    fn in_range(x: f64, lo: f64, hi: f64) -> (bool, bool) {
+        let cs = ConstraintSystem::<F>::new_ref();
+        RangeProofCircuit::<F, PREC, BITS>::new(
+            Dec::from_f64(x),
+            Dec::from_f64(lo),
+            Dec::from_f64(hi),
+        )
+        .generate_constraints(cs.clone())
+        .unwrap();
+        let satisfied = cs.is_satisfied().unwrap();
+
+        // same check through the bare gadget, returning the soft flag
+        let cs = ConstraintSystem::<F>::new_ref();
+        let alloc = |v: f64| {
+            let d = DecVar::<F, PREC>::new_witness(cs.clone(), || Ok(Dec::from_f64(v))).unwrap();
+            signed_fp(&d).unwrap()
+        };
+        let flag = range_proof_gadget::<F, BITS>(&alloc(x), &alloc(lo), &alloc(hi)).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        (satisfied, flag.value().unwrap())
+    }
+
+    #[test]
+    fn web_mercator_bounds() {
+        // San Francisco x-coordinate inside a ±20 037 508 m world
+        let world = 20_037_508.34;
+        assert_eq!(in_range(-13_627_361.0, -world, world), (true, true));
+        assert_eq!(in_range(world, -world, world), (true, true));
+        assert_eq!(in_range(-world, -world, world), (true, true));
+    }
+
+    #[test]
+    fn out_of_range_is_rejected() {
+        assert_eq!(in_range(-5.0, -1.0, 1.0), (false, false));
+        assert_eq!(in_range(5.0, -1.0, 1.0), (false, false));
+        assert_eq!(in_range(1.000_000_01, -1.0, 1.0), (false, false));
+    }
+}
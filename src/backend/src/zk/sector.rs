@@ -0,0 +1,333 @@
+//! Pie-slice ("sector") containment: is a point within `max_radius` of a
+//! centre and between two compass bearings?
+//!
+//! Bearings are degrees clockwise from north (+y), in `[0, 360)`. The
+//! point's bearing is a first-order ("diamond") approximation of `atan2`:
+//! inside each quadrant it is linear in `num / (|dx| + |dy|)`, exact at
+//! every multiple of 45° and off by at most ~4° in between. That keeps the
+//! circuit to one division (checked as `q·den + r`, `0 <= r < den`) instead
+//! of a trigonometric series.
+
+use std::cmp::Ordering;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{FieldVar, fp::FpVar},
+    prelude::R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::zk::{
+    fixed_point_decimal::{Dec, DecVar},
+    point_2d::{Point2DDec, Point2DDecVar},
+    range_proof::{WEB_MERCATOR_RANGE_BITS, range_proof_gadget},
+};
+
+// quadrant → (bearing offset in degrees, numerator is |dx|?)
+fn quadrant(e_neg: bool, e_zero: bool, n_neg: bool, n_zero: bool) -> (u64, bool) {
+    let ne = !e_neg && !n_neg && !n_zero;
+    let se = !e_neg && !e_zero && (n_neg || n_zero);
+    let sw = (e_neg || e_zero) && n_neg;
+    let nw = e_neg && !n_neg;
+
+    let offset = if se {
+        90
+    } else if sw {
+        180
+    } else if nw {
+        270
+    } else {
+        0
+    };
+    (offset, ne || sw)
+}
+
+/// Approximate bearing of `point` seen from `center` (0 at the centre itself).
+pub fn pseudo_bearing<F: PrimeField, const PREC: u32>(
+    point: &Point2DDec<F, PREC>,
+    center: &Point2DDec<F, PREC>,
+) -> Dec<F, PREC> {
+    let dx = point.x.sub(center.x);
+    let dy = point.y.sub(center.y);
+    let ae = Dec::<F, PREC>::u128_from_field_element(dx.val);
+    let an = Dec::<F, PREC>::u128_from_field_element(dy.val);
+
+    let (offset, num_is_e) = quadrant(dx.neg, ae == 0, dy.neg, an == 0);
+    let num = if num_is_e { ae } else { an };
+    let den = (ae + an).max(1);
+
+    let q = 90 * Dec::<F, PREC>::SCALE * num / den;
+    Dec {
+        val: F::from(offset as u128 * Dec::<F, PREC>::SCALE + q),
+        neg: false,
+    }
+}
+
+/// `max_radius_sq` is in squared units (e.g. m² for Web-Mercator metres).
+/// A sector with `start_bearing > end_bearing` wraps through north.
+pub fn is_in_sector<F: PrimeField, const PREC: u32>(
+    point: &Point2DDec<F, PREC>,
+    center: &Point2DDec<F, PREC>,
+    start_bearing_dec: Dec<F, PREC>,
+    end_bearing_dec: Dec<F, PREC>,
+    max_radius_sq: Dec<F, PREC>,
+) -> bool {
+    let dx = point.x.sub(center.x);
+    let dy = point.y.sub(center.y);
+    // in the field, as the gadget computes them: raw squares of Web-Mercator
+    // distances pass 2^128 beyond about 1.8·10^11 m
+    let d2 = (dx.val * dx.val + dy.val * dy.val).into_bigint();
+    let limit = (max_radius_sq.val * F::from(Dec::<F, PREC>::SCALE)).into_bigint();
+
+    let theta = pseudo_bearing(point, center).val;
+    let (start, end) = (start_bearing_dec.val, end_bearing_dec.val);
+    let in_angle = if start <= end {
+        start <= theta && theta <= end
+    } else {
+        theta >= start || theta <= end
+    };
+
+    in_angle && d2 <= limit
+}
+
+fn pseudo_bearing_gadget<F: PrimeField, const PREC: u32>(
+    dx: &DecVar<F, PREC>,
+    dy: &DecVar<F, PREC>,
+) -> Result<FpVar<F>, SynthesisError> {
+    let scale = F::from(Dec::<F, PREC>::SCALE);
+    let (ae, an) = (&dx.val, &dy.val);
+
+    let e_zero = ae.is_zero()?;
+    let n_zero = an.is_zero()?;
+    let e_pos = &!&dx.neg & &!&e_zero;
+    let n_pos = &!&dy.neg & &!&n_zero;
+
+    let ne = &!&dx.neg & &n_pos;
+    let se = &e_pos & &(&dy.neg | &n_zero);
+    let sw = &(&dx.neg | &e_zero) & &dy.neg;
+    let nw = &dx.neg & &!&dy.neg;
+    let origin = &e_zero & &n_zero;
+
+    let num = (&ne | &sw).select(ae, an)?;
+    let den = ae + an + FpVar::from(origin);
+
+    // q = ⌊90·SCALE·num / den⌋, witnessed and checked as target = q·den + r
+    let target = num * (F::from(90u64) * scale);
+    let cs = dx.val.cs().or(dy.val.cs());
+    let q = FpVar::new_witness(cs, || {
+        let t = Dec::<F, PREC>::u128_from_field_element(target.value()?);
+        let d = Dec::<F, PREC>::u128_from_field_element(den.value()?);
+        Ok(F::from(t / d))
+    })?;
+    let r = &target - &q * &den;
+
+    let zero = FpVar::<F>::zero();
+    range_proof_gadget::<F, WEB_MERCATOR_RANGE_BITS>(&r, &zero, &(&den - F::one()))?
+        .enforce_equal(&Boolean::TRUE)?;
+    range_proof_gadget::<F, WEB_MERCATOR_RANGE_BITS>(
+        &q,
+        &zero,
+        &FpVar::constant(F::from(90u64) * scale),
+    )?
+    .enforce_equal(&Boolean::TRUE)?;
+
+    let offset = FpVar::from(se) * F::from(90u64)
+        + FpVar::from(sw) * F::from(180u64)
+        + FpVar::from(nw) * F::from(270u64);
+    Ok(offset * scale + q)
+}
+
+pub fn is_in_sector_gadget<F: PrimeField, const PREC: u32>(
+    point: &Point2DDecVar<F, PREC>,
+    center: &Point2DDecVar<F, PREC>,
+    start_bearing: &DecVar<F, PREC>,
+    end_bearing: &DecVar<F, PREC>,
+    max_radius_sq: &DecVar<F, PREC>,
+) -> Result<Boolean<F>, SynthesisError> {
+    let dx = point.x.sub(&center.x)?;
+    let dy = point.y.sub(&center.y)?;
+
+    // radius
+    let d2 = &dx.val * &dx.val + &dy.val * &dy.val;
+    let limit = &max_radius_sq.val * F::from(Dec::<F, PREC>::SCALE);
+    let within = d2.is_cmp_unchecked(&limit, Ordering::Less, true)?;
+
+    // bearing
+    let theta = pseudo_bearing_gadget(&dx, &dy)?;
+    let (start, end) = (&start_bearing.val, &end_bearing.val);
+    let after_start = theta.is_cmp_unchecked(start, Ordering::Greater, true)?;
+    let before_end = theta.is_cmp_unchecked(end, Ordering::Less, true)?;
+    let wraps = start.is_cmp_unchecked(end, Ordering::Greater, false)?;
+    let in_angle = wraps.select(&(&after_start | &before_end), &(&after_start & &before_end))?;
+
+    Ok(in_angle & within)
+}
+
+pub struct SectorCircuit<F: PrimeField, const PREC: u32> {
+    pub private_point: Point2DDec<F, PREC>,
+
+    pub public_center: Point2DDec<F, PREC>,
+    pub public_start_bearing: Dec<F, PREC>,
+    pub public_end_bearing: Dec<F, PREC>,
+    pub public_max_radius_sq: Dec<F, PREC>,
+}
+
+impl<F: PrimeField, const PREC: u32> SectorCircuit<F, PREC> {
+    pub fn new(
+        private_point: Point2DDec<F, PREC>,
+        public_center: Point2DDec<F, PREC>,
+        public_start_bearing: Dec<F, PREC>,
+        public_end_bearing: Dec<F, PREC>,
+        public_max_radius_sq: Dec<F, PREC>,
+    ) -> Self {
+        Self {
+            private_point,
+            public_center,
+            public_start_bearing,
+            public_end_bearing,
+            public_max_radius_sq,
+        }
+    }
+}
+
+impl<F: PrimeField, const PREC: u32> ConstraintSynthesizer<F> for SectorCircuit<F, PREC> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let point = Point2DDecVar {
+            x: DecVar::new_witness(cs.clone(), || Ok(self.private_point.x))?,
+            y: DecVar::new_witness(cs.clone(), || Ok(self.private_point.y))?,
+        };
+
+        let center = Point2DDecVar {
+            x: DecVar::new_input(cs.clone(), || Ok(self.public_center.x))?,
+            y: DecVar::new_input(cs.clone(), || Ok(self.public_center.y))?,
+        };
+        let start = DecVar::new_input(cs.clone(), || Ok(self.public_start_bearing))?;
+        let end = DecVar::new_input(cs.clone(), || Ok(self.public_end_bearing))?;
+        let max_r2 = DecVar::new_input(cs, || Ok(self.public_max_radius_sq))?;
+
+        is_in_sector_gadget(&point, &center, &start, &end, &max_r2)?.enforce_equal(&Boolean::TRUE)
+    }
+}
+
+/// Public inputs of a `SectorCircuit`, in allocation order (`val`, `neg` per `Dec`).
+pub fn sector_public_inputs<F: PrimeField, const PREC: u32>(
+    center: &Point2DDec<F, PREC>,
+    start_bearing: Dec<F, PREC>,
+    end_bearing: Dec<F, PREC>,
+    max_radius_sq: Dec<F, PREC>,
+) -> Vec<F> {
+    [
+        center.x,
+        center.y,
+        start_bearing,
+        end_bearing,
+        max_radius_sq,
+    ]
+    .iter()
+    .flat_map(|d| [d.val, F::from(d.neg)])
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_bn254::Fr;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    type F = Fr;
+    const PREC: u32 = 8;
+
+    fn pt(x: f64, y: f64) -> Point2DDec<F, PREC> {
+        Point2DDec::from_f64(x, y)
+    }
+
+    // (native, gadget, circuit satisfied)
+    fn check(point: (f64, f64), start: f64, end: f64, radius: f64) -> (bool, bool, bool) {
+        let center = pt(1_000.0, -2_000.0);
+        let point = pt(1_000.0 + point.0, -2_000.0 + point.1);
+        let (start, end) = (Dec::from_f64(start), Dec::from_f64(end));
+        let r2 = Dec::from_f64(radius * radius);
+
+        let native = is_in_sector(&point, &center, start, end, r2);
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        let alloc_pt = |p: &Point2DDec<F, PREC>| Point2DDecVar {
+            x: DecVar::new_witness(cs.clone(), || Ok(p.x)).unwrap(),
+            y: DecVar::new_witness(cs.clone(), || Ok(p.y)).unwrap(),
+        };
+        let alloc = |d: Dec<F, PREC>| DecVar::new_witness(cs.clone(), || Ok(d)).unwrap();
+        let gadget = is_in_sector_gadget(
+            &alloc_pt(&point),
+            &alloc_pt(&center),
+            &alloc(start),
+            &alloc(end),
+            &alloc(r2),
+        )
+        .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        let cs = ConstraintSystem::<F>::new_ref();
+        SectorCircuit::new(point, center, start, end, r2)
+            .generate_constraints(cs.clone())
+            .unwrap();
+
+        (native, gadget.value().unwrap(), cs.is_satisfied().unwrap())
+    }
+
+    #[test]
+    fn pseudo_bearing_tracks_atan2() {
+        let center = pt(0.0, 0.0);
+        for deg in (0..360).step_by(5) {
+            let rad = (deg as f64).to_radians();
+            let p = pt(500.0 * rad.sin(), 500.0 * rad.cos());
+            let approx = pseudo_bearing(&p, &center).to_f64();
+            let err = (approx - deg as f64 + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(err.abs() < 4.5, "{deg}° → {approx}°");
+            if deg % 45 == 0 {
+                assert!(err.abs() < 1e-6, "{deg}° → {approx}°");
+            }
+        }
+    }
+
+    #[test]
+    fn sector_between_45_and_135() {
+        // due east, 100 m out
+        assert_eq!(check((100.0, 0.0), 45.0, 135.0, 150.0), (true, true, true));
+        // due north
+        assert_eq!(
+            check((0.0, 100.0), 45.0, 135.0, 150.0),
+            (false, false, false)
+        );
+        // right bearing, too far
+        assert_eq!(
+            check((200.0, 0.0), 45.0, 135.0, 150.0),
+            (false, false, false)
+        );
+        // south-west
+        assert_eq!(
+            check((-50.0, -50.0), 45.0, 135.0, 150.0),
+            (false, false, false)
+        );
+    }
+
+    #[test]
+    fn radii_past_u128_squares() {
+        // raw d² and r²·SCALE are ~10^40 here, past u128
+        assert_eq!(check((3e11, 4e11), 0.0, 90.0, 1e12), (true, true, true));
+        assert_eq!(check((9e11, 9e11), 0.0, 90.0, 1e12), (false, false, false));
+    }
+
+    #[test]
+    fn sector_wrapping_through_north() {
+        assert_eq!(check((0.0, 100.0), 300.0, 60.0, 150.0), (true, true, true));
+        assert_eq!(check((-60.0, 60.0), 300.0, 60.0, 150.0), (true, true, true));
+        assert_eq!(
+            check((0.0, -100.0), 300.0, 60.0, 150.0),
+            (false, false, false)
+        );
+    }
+}
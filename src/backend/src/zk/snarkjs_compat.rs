@@ -0,0 +1,197 @@
+//! Groth16 proofs and public inputs in snarkjs's `proof.json` /
+//! `public.json` layout, for clients verifying with snarkjs.
+//!
+//! snarkjs writes points as decimal projective coordinates with `z = 1`
+//! (`z = 0` for infinity) and puts `c0` before `c1` in G2 coordinates, the
+//! same layout as `vk_to_snarkjs`.
+
+use std::fmt;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{One, PrimeField, Zero};
+use ark_groth16::Proof;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SnarkjsCompatError {
+    /// not a decimal integer below the field modulus
+    InvalidNumber(String),
+    /// `z` other than 0 or 1 (snarkjs only writes affine points)
+    NotAffine(&'static str),
+    /// the coordinates are not a point of the prime-order group
+    NotOnCurve(&'static str),
+}
+
+impl fmt::Display for SnarkjsCompatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber(s) => write!(f, "invalid field element: {s:?}"),
+            Self::NotAffine(name) => write!(f, "{name} is not an affine point"),
+            Self::NotOnCurve(name) => write!(f, "{name} is not on the curve"),
+        }
+    }
+}
+
+impl std::error::Error for SnarkjsCompatError {}
+
+/// snarkjs `proof.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: [String; 3],
+    pub pi_b: [[String; 2]; 3],
+    pub pi_c: [String; 3],
+    pub protocol: String,
+    pub curve: String,
+}
+
+fn g1_to_snarkjs(p: &G1Affine) -> [String; 3] {
+    if p.is_zero() {
+        return ["0".into(), "1".into(), "0".into()];
+    }
+    [p.x.to_string(), p.y.to_string(), "1".into()]
+}
+
+fn g2_to_snarkjs(p: &G2Affine) -> [[String; 2]; 3] {
+    if p.is_zero() {
+        return [
+            ["0".into(), "0".into()],
+            ["1".into(), "0".into()],
+            ["0".into(), "0".into()],
+        ];
+    }
+    [
+        [p.x.c0.to_string(), p.x.c1.to_string()],
+        [p.y.c0.to_string(), p.y.c1.to_string()],
+        ["1".into(), "0".into()],
+    ]
+}
+
+// decimal, below the modulus (`from_str` would reduce larger values)
+fn parse<F: PrimeField>(s: &str) -> Result<F, SnarkjsCompatError> {
+    let invalid = || SnarkjsCompatError::InvalidNumber(s.to_owned());
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let f = F::from_str(s).map_err(|_| invalid())?;
+    let digits = s.trim_start_matches('0');
+    if f.to_string() != if digits.is_empty() { "0" } else { digits } {
+        return Err(invalid());
+    }
+    Ok(f)
+}
+
+fn g1_from_snarkjs(name: &'static str, p: &[String; 3]) -> Result<G1Affine, SnarkjsCompatError> {
+    let z: Fq = parse(&p[2])?;
+    if z.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    if !z.is_one() {
+        return Err(SnarkjsCompatError::NotAffine(name));
+    }
+    let point = G1Affine::new_unchecked(parse(&p[0])?, parse(&p[1])?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SnarkjsCompatError::NotOnCurve(name));
+    }
+    Ok(point)
+}
+
+fn g2_from_snarkjs(
+    name: &'static str,
+    p: &[[String; 2]; 3],
+) -> Result<G2Affine, SnarkjsCompatError> {
+    let fq2 = |c: &[String; 2]| Ok::<_, SnarkjsCompatError>(Fq2::new(parse(&c[0])?, parse(&c[1])?));
+    let z = fq2(&p[2])?;
+    if z.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    if !z.is_one() {
+        return Err(SnarkjsCompatError::NotAffine(name));
+    }
+    let point = G2Affine::new_unchecked(fq2(&p[0])?, fq2(&p[1])?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SnarkjsCompatError::NotOnCurve(name));
+    }
+    Ok(point)
+}
+
+pub fn proof_to_snarkjs_json(proof: &Proof<Bn254>) -> SnarkjsProof {
+    SnarkjsProof {
+        pi_a: g1_to_snarkjs(&proof.a),
+        pi_b: g2_to_snarkjs(&proof.b),
+        pi_c: g1_to_snarkjs(&proof.c),
+        protocol: "groth16".into(),
+        curve: "bn128".into(),
+    }
+}
+
+/// Inverse of [`proof_to_snarkjs_json`]; every point is checked to be in
+/// the prime-order group.
+pub fn snarkjs_json_to_proof(sj: &SnarkjsProof) -> Result<Proof<Bn254>, SnarkjsCompatError> {
+    Ok(Proof {
+        a: g1_from_snarkjs("pi_a", &sj.pi_a)?,
+        b: g2_from_snarkjs("pi_b", &sj.pi_b)?,
+        c: g1_from_snarkjs("pi_c", &sj.pi_c)?,
+    })
+}
+
+/// snarkjs `public.json`: decimal field elements, in circuit order.
+pub fn public_inputs_to_snarkjs(inputs: &[Fr]) -> Vec<String> {
+    inputs.iter().map(Fr::to_string).collect()
+}
+
+pub fn snarkjs_to_public_inputs(inputs: &[String]) -> Result<Vec<Fr>, SnarkjsCompatError> {
+    inputs.iter().map(|s| parse(s)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ec::CurveGroup;
+
+    fn proof() -> Proof<Bn254> {
+        Proof {
+            a: (G1Affine::generator() * Fr::from(7u64)).into_affine(),
+            b: (G2Affine::generator() * Fr::from(11u64)).into_affine(),
+            c: (G1Affine::generator() * Fr::from(13u64)).into_affine(),
+        }
+    }
+
+    #[test]
+    fn proof_round_trips_through_snarkjs_json() {
+        let proof = proof();
+        let sj = proof_to_snarkjs_json(&proof);
+        assert_eq!(sj.pi_a[2], "1");
+        assert_eq!(sj.pi_b[2], ["1".to_string(), "0".to_string()]);
+
+        let json = serde_json::to_value(&sj).unwrap();
+        assert_eq!(json["protocol"], "groth16");
+        let back: SnarkjsProof = serde_json::from_value(json).unwrap();
+        assert_eq!(snarkjs_json_to_proof(&back), Ok(proof));
+
+        let mut off_curve = sj.clone();
+        off_curve.pi_c[1] = "5".into();
+        assert_eq!(
+            snarkjs_json_to_proof(&off_curve),
+            Err(SnarkjsCompatError::NotOnCurve("pi_c"))
+        );
+    }
+
+    #[test]
+    fn public_inputs_round_trip() {
+        let inputs = vec![Fr::one(), Fr::zero(), -Fr::one()];
+        let sj = public_inputs_to_snarkjs(&inputs);
+        assert_eq!(sj[..2], ["1".to_string(), "0".to_string()]);
+        assert_eq!(snarkjs_to_public_inputs(&sj), Ok(inputs));
+
+        // the modulus itself would silently reduce to 0
+        let modulus = Fr::MODULUS.to_string();
+        for bad in ["", "-1", "0x01", modulus.as_str()] {
+            assert_eq!(
+                snarkjs_to_public_inputs(&[bad.to_string()]),
+                Err(SnarkjsCompatError::InvalidNumber(bad.to_string()))
+            );
+        }
+    }
+}
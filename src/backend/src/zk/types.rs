@@ -0,0 +1,20 @@
+//! Concrete instantiations of the generic zk types used by the server.
+
+use crate::zk::{
+    circuit::{
+        CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES_LARGE, CIRCUIT_PRECISION,
+        PointInMapCircuit,
+    },
+    fixed_point_decimal::Dec,
+    point_2d::Point2DDec,
+};
+
+pub type DefaultF = ark_bn254::Fr;
+pub type DefaultDec = Dec<DefaultF, CIRCUIT_PRECISION>;
+pub type DefaultPoint = Point2DDec<DefaultF, CIRCUIT_PRECISION>;
+pub type LargePolygonPointInMapCircuit = PointInMapCircuit<
+    DefaultF,
+    CIRCUIT_PRECISION,
+    CIRCUIT_MAX_VERTICES_LARGE,
+    CIRCUIT_MAX_POLYGON_HASHES,
+>;
@@ -0,0 +1,86 @@
+//! Groth16 verification without the HTTP server, for callers that hold a
+//! prepared VK and want the same answer `/verify` gives.
+
+use std::fmt;
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof};
+use ark_relations::r1cs::SynthesisError;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// the VK takes `expected` public inputs, the caller gave `got`
+    PublicInputCount { expected: usize, got: usize },
+    /// anything else arkworks rejects
+    Synthesis(String),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PublicInputCount { expected, got } => {
+                write!(f, "expected {expected} public inputs, got {got}")
+            }
+            Self::Synthesis(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+impl From<SynthesisError> for VerifyError {
+    fn from(e: SynthesisError) -> Self {
+        Self::Synthesis(e.to_string())
+    }
+}
+
+/// Check `proof` against `public_inputs` under `pvk`. `Ok(false)` is a
+/// well-formed proof that does not verify; a public-input count the key
+/// does not take is an error.
+///
+/// ```
+/// use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+/// use ark_ec::AffineRepr;
+/// use ark_groth16::{Proof, VerifyingKey, prepare_verifying_key};
+/// use backend::zk::verify::{VerifyError, verify_proof};
+///
+/// // a VK taking one public input, and a proof made under no key at all
+/// let vk = VerifyingKey::<Bn254> {
+///     alpha_g1: G1Affine::generator(),
+///     beta_g2: G2Affine::generator(),
+///     gamma_g2: G2Affine::generator(),
+///     delta_g2: G2Affine::generator(),
+///     gamma_abc_g1: vec![G1Affine::generator(); 2],
+/// };
+/// let pvk = prepare_verifying_key(&vk);
+/// let proof = Proof::<Bn254> {
+///     a: G1Affine::generator(),
+///     b: G2Affine::generator(),
+///     c: G1Affine::generator(),
+/// };
+///
+/// assert_eq!(verify_proof(&proof, &[Fr::from(1u64)], &pvk), Ok(false));
+/// assert_eq!(
+///     verify_proof(&proof, &[], &pvk),
+///     Err(VerifyError::PublicInputCount { expected: 1, got: 0 })
+/// );
+/// ```
+pub fn verify_proof(
+    proof: &Proof<Bn254>,
+    public_inputs: &[Fr],
+    pvk: &PreparedVerifyingKey<Bn254>,
+) -> Result<bool, VerifyError> {
+    let expected = pvk.vk.gamma_abc_g1.len().saturating_sub(1);
+    if public_inputs.len() != expected {
+        return Err(VerifyError::PublicInputCount {
+            expected,
+            got: public_inputs.len(),
+        });
+    }
+    Ok(Groth16::<Bn254>::verify_with_processed_vk(
+        pvk,
+        public_inputs,
+        proof,
+    )?)
+}
@@ -0,0 +1,351 @@
+//! `PointInMapCircuit` assignments as JSON, for clients that compute the
+//! witness themselves and only send it to be proven (`/prove-witness`).
+//!
+//! Coordinates are raw fixed-point integers in units of `10^-PREC`, with a
+//! leading `-` when negative, so a witness crosses the wire exactly; they
+//! are read at the server's `PREC` and may not exceed
+//! [`WEB_MERCATOR_MAX_ABS`]. The Poseidon config is not included:
+//! the proving key fixes it, so the server always uses its own.
+
+use std::{fmt, str::FromStr};
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
+use serde::{Deserialize, Serialize};
+
+use crate::zk::{
+    circuit::PointInMapCircuit,
+    deserialize::{DeserializeError, deserialize_b64_uncompressed},
+    fixed_point_decimal::Dec,
+    point_2d::Point2DDec,
+    polygon_ops::WEB_MERCATOR_MAX_ABS,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitWitness {
+    /// Web-Mercator `[x, y]`
+    pub point: [String; 2],
+    /// `[x, y]` per slot; slots past `polygon.len()` repeat the first vertex
+    pub polygon: Vec<[String; 2]>,
+    pub num_vertices: u64,
+    pub is_in_map: bool,
+    /// base-64 field elements, as in `public_inputs`; padded with zeros
+    pub polygon_hashes: Vec<String>,
+    #[serde(default)]
+    pub mode_id: u32,
+}
+
+#[derive(Debug)]
+pub enum WitnessDeserError {
+    Json(String),
+    /// not an optional `-` followed by a canonical decimal field element
+    InvalidDecimal {
+        what: String,
+        value: String,
+    },
+    /// magnitude past [`WEB_MERCATOR_MAX_ABS`]
+    OutOfRange {
+        what: String,
+        value: String,
+    },
+    InvalidHash(DeserializeError),
+    TooManyVertices {
+        found: usize,
+        max: usize,
+    },
+    /// `num_vertices` is past the vertices given
+    NumVerticesOutOfRange {
+        num_vertices: u64,
+        polygon_len: usize,
+    },
+    TooManyHashes {
+        found: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for WitnessDeserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "malformed witness: {e}"),
+            Self::InvalidDecimal { what, value } => {
+                write!(f, "{what} is not a fixed-point integer: {value:?}")
+            }
+            Self::OutOfRange { what, value } => {
+                write!(f, "{what} is outside the Web-Mercator extent: {value}")
+            }
+            Self::InvalidHash(e) => write!(f, "{e}"),
+            Self::TooManyVertices { found, max } => {
+                write!(f, "{found} polygon vertices, the circuit takes {max}")
+            }
+            Self::NumVerticesOutOfRange {
+                num_vertices,
+                polygon_len,
+            } => write!(
+                f,
+                "num_vertices is {num_vertices} but only {polygon_len} vertices were sent"
+            ),
+            Self::TooManyHashes { found, max } => {
+                write!(f, "{found} polygon hashes, the circuit takes {max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WitnessDeserError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidHash(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn dec_to_string<const PREC: u32>(d: Dec<Fr, PREC>) -> String {
+    format!("{}{}", if d.neg { "-" } else { "" }, d.val)
+}
+
+// canonical only, so every value has one spelling: no leading zeros and no
+// `-0`. Magnitudes stay within the map, far below where the gadgets' sign
+// tests would wrap.
+fn dec_from_str<const PREC: u32>(what: &str, s: &str) -> Result<Dec<Fr, PREC>, WitnessDeserError> {
+    let invalid = || WitnessDeserError::InvalidDecimal {
+        what: what.to_owned(),
+        value: s.to_owned(),
+    };
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let val = Fr::from_str(digits).map_err(|_| invalid())?;
+    if val.to_string() != digits || (neg && val.is_zero()) {
+        return Err(invalid());
+    }
+    let max = Dec::<Fr, PREC>::from_f64(WEB_MERCATOR_MAX_ABS).val;
+    if val.into_bigint() > max.into_bigint() {
+        return Err(WitnessDeserError::OutOfRange {
+            what: what.to_owned(),
+            value: s.to_owned(),
+        });
+    }
+    Ok(Dec { val, neg })
+}
+
+fn point_to_strings<const PREC: u32>(p: &Point2DDec<Fr, PREC>) -> [String; 2] {
+    [dec_to_string(p.x), dec_to_string(p.y)]
+}
+
+fn point_from_strings<const PREC: u32>(
+    what: &str,
+    [x, y]: &[String; 2],
+) -> Result<Point2DDec<Fr, PREC>, WitnessDeserError> {
+    Ok(Point2DDec {
+        x: dec_from_str(&format!("{what}.x"), x)?,
+        y: dec_from_str(&format!("{what}.y"), y)?,
+    })
+}
+
+impl CircuitWitness {
+    pub fn from_json(json: &str) -> Result<Self, WitnessDeserError> {
+        serde_json::from_str(json).map_err(|e| WitnessDeserError::Json(e.to_string()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("witness fields are plain strings and numbers")
+    }
+
+    /// The assignment of `circuit`, every vertex and hash slot included.
+    pub fn from_circuit<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+        circuit: &PointInMapCircuit<Fr, PREC, MAX_VERTS, MAX_HASHES>,
+    ) -> Self {
+        Self {
+            point: point_to_strings(&circuit.private_point),
+            polygon: circuit
+                .private_polygon_vertices
+                .iter()
+                .map(point_to_strings)
+                .collect(),
+            num_vertices: circuit.private_num_vertices,
+            is_in_map: circuit.public_is_in_map,
+            polygon_hashes: circuit
+                .public_polygon_hashes
+                .iter()
+                .map(|h| {
+                    let mut buf = Vec::new();
+                    h.serialize_uncompressed(&mut buf).unwrap();
+                    B64.encode(buf)
+                })
+                .collect(),
+            mode_id: circuit.mode_id,
+        }
+    }
+
+    /// The default `/prove` circuit layout (no convexity check, private
+    /// polygon, no arena version or context) under `poseidon_cfg`.
+    pub fn into_circuit<const PREC: u32, const MAX_VERTS: usize, const MAX_HASHES: usize>(
+        &self,
+        poseidon_cfg: &PoseidonConfig<Fr>,
+    ) -> Result<PointInMapCircuit<Fr, PREC, MAX_VERTS, MAX_HASHES>, WitnessDeserError> {
+        if self.polygon.len() > MAX_VERTS {
+            return Err(WitnessDeserError::TooManyVertices {
+                found: self.polygon.len(),
+                max: MAX_VERTS,
+            });
+        }
+        if self.num_vertices > self.polygon.len() as u64 {
+            return Err(WitnessDeserError::NumVerticesOutOfRange {
+                num_vertices: self.num_vertices,
+                polygon_len: self.polygon.len(),
+            });
+        }
+        if self.polygon_hashes.len() > MAX_HASHES {
+            return Err(WitnessDeserError::TooManyHashes {
+                found: self.polygon_hashes.len(),
+                max: MAX_HASHES,
+            });
+        }
+
+        let point = point_from_strings("point", &self.point)?;
+        let vertices = self
+            .polygon
+            .iter()
+            .enumerate()
+            .map(|(i, v)| point_from_strings(&format!("polygon[{i}]"), v))
+            .collect::<Result<Vec<_>, _>>()?;
+        let first = vertices
+            .first()
+            .copied()
+            .unwrap_or_else(|| Point2DDec::from_f64(0.0, 0.0));
+        let polygon = core::array::from_fn(|i| vertices.get(i).copied().unwrap_or(first));
+
+        let mut hashes = [Fr::zero(); MAX_HASHES];
+        for (slot, h) in hashes.iter_mut().zip(&self.polygon_hashes) {
+            *slot = deserialize_b64_uncompressed("polygon hash", h)
+                .map_err(WitnessDeserError::InvalidHash)?;
+        }
+
+        Ok(PointInMapCircuit::new(
+            point,
+            polygon,
+            self.num_vertices,
+            self.is_in_map,
+            hashes,
+            self.mode_id,
+            poseidon_cfg.clone(),
+            false,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::zk::{
+        circuit::{CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION},
+        polygon_ops::hash_polygon,
+        poseidon::default_poseidon_config,
+    };
+
+    const MAX_HASHES: usize = 4;
+    type Circuit = PointInMapCircuit<Fr, CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>;
+
+    fn square_circuit() -> Circuit {
+        let cfg = default_poseidon_config();
+        let mut poly = [Point2DDec::from_f64(-10.0, -10.0); CIRCUIT_MAX_VERTICES];
+        poly[1] = Point2DDec::from_f64(10.0, -10.0);
+        poly[2] = Point2DDec::from_f64(10.0, 10.0);
+        poly[3] = Point2DDec::from_f64(-10.0, 10.0);
+        let mut hashes = [Fr::zero(); MAX_HASHES];
+        hashes[1] = hash_polygon(&poly, 4, 3, &cfg);
+        Circuit::new(
+            Point2DDec::from_f64(-0.5, 2.25),
+            poly,
+            4,
+            true,
+            hashes,
+            3,
+            cfg,
+            false,
+        )
+    }
+
+    #[test]
+    fn witness_round_trips_through_json() {
+        let circuit = square_circuit();
+        let json = CircuitWitness::from_circuit(&circuit).to_json();
+        assert!(
+            json.contains(r#""point":["-50000000","225000000"]"#),
+            "{json}"
+        );
+
+        let back: Circuit = CircuitWitness::from_json(&json)
+            .unwrap()
+            .into_circuit(&default_poseidon_config())
+            .unwrap();
+        assert_eq!(back.private_point, circuit.private_point);
+        assert_eq!(
+            back.private_polygon_vertices,
+            circuit.private_polygon_vertices
+        );
+        assert_eq!(back.public_polygon_hashes, circuit.public_polygon_hashes);
+        assert_eq!(back.describe(), circuit.describe());
+        assert!(back.describe().final_flag);
+    }
+
+    #[test]
+    fn malformed_witnesses_are_refused() {
+        let witness = CircuitWitness::from_circuit(&square_circuit());
+        let cfg = default_poseidon_config();
+        let into = |w: &CircuitWitness| {
+            w.into_circuit::<CIRCUIT_PRECISION, CIRCUIT_MAX_VERTICES, MAX_HASHES>(&cfg)
+        };
+
+        for bad in ["", "-", "-0", "1.5", "+3", "007", "0x10"] {
+            let w = CircuitWitness {
+                point: [bad.to_owned(), "0".to_owned()],
+                ..witness.clone()
+            };
+            assert!(
+                matches!(into(&w), Err(WitnessDeserError::InvalidDecimal { .. })),
+                "{bad:?}"
+            );
+        }
+        // one unit past 2πR at PREC = 8, then a magnitude near the modulus
+        let max = Dec::<Fr, CIRCUIT_PRECISION>::from_f64(WEB_MERCATOR_MAX_ABS).val;
+        for far in [max + Fr::from(1u64), -Fr::from(1u64)] {
+            let w = CircuitWitness {
+                point: ["0".to_owned(), format!("-{far}")],
+                ..witness.clone()
+            };
+            assert!(matches!(
+                into(&w),
+                Err(WitnessDeserError::OutOfRange { .. })
+            ));
+        }
+        let w = CircuitWitness {
+            point: [max.to_string(), "0".to_owned()],
+            ..witness.clone()
+        };
+        assert!(into(&w).is_ok());
+
+        let w = CircuitWitness {
+            num_vertices: CIRCUIT_MAX_VERTICES as u64 + 1,
+            ..witness.clone()
+        };
+        assert!(matches!(
+            into(&w),
+            Err(WitnessDeserError::NumVerticesOutOfRange { .. })
+        ));
+        assert!(matches!(
+            CircuitWitness::from_json("{\"point\": 1}"),
+            Err(WitnessDeserError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,1258 @@
+//! HTTP-level tests for the prove / verify endpoints.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    rc::Rc,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use actix_multipart::test::create_form_data_payload_and_headers_with_boundary;
+use actix_web::{
+    App, HttpServer,
+    http::header::HeaderMap,
+    middleware::{Compress, Condition, NormalizePath},
+    rt, test, web,
+};
+use ark_serialize::CanonicalSerialize;
+use base64::{Engine as _, engine::general_purpose::STANDARD as B64};
+use h3o::{CellIndex, LatLng, Resolution};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use backend::{
+    api::{self, prove::build_circuit_from_request},
+    audit::{AuditLog, RequestAuditLogger},
+    config::DEFAULT_VERIFY_MIN_LATENCY_MS,
+    prover::MOCK_PROOF_B64,
+    state::{AppState, TEST_MAX_POLYGON_HASHES},
+    zk::{
+        deserialize::deserialize_b64_uncompressed,
+        map::{
+            IpfsMapCache, MAX_IPFS_MAP_BYTES, MapLoadError, load_h3_map_from_file,
+            load_map_from_gateway,
+        },
+        proof_transcript::{BoundProof, verify_bound_proof},
+        witness::CircuitWitness,
+    },
+};
+
+// well-known resolution-7 cell in San Francisco
+const SF_CELL: &str = "872830828ffffff";
+
+macro_rules! service {
+    () => {
+        test::init_service(
+            App::new()
+                .app_data(AppState::new_test())
+                .configure(api::config),
+        )
+        .await
+    };
+}
+
+// `service!` with proving skipped: `/prove` answers with the canned proof
+// of `prove_sf_centre`, for tests that don't verify what they get back
+macro_rules! mock_service {
+    () => {
+        test::init_service(
+            App::new()
+                .app_data(AppState::new_test_mock_prover())
+                .configure(api::config),
+        )
+        .await
+    };
+}
+
+// `/prove` at the centre of `SF_CELL`, with that cell as the whole map
+fn prove_sf_centre() -> test::TestRequest {
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    test::TestRequest::post().uri("/prove").set_json(json!({
+        "lat": centre.lat(),
+        "lon": centre.lng(),
+        "resolution": 7,
+        "h3_map": [SF_CELL],
+    }))
+}
+
+#[actix_web::test]
+async fn prove_then_verify_roundtrip() {
+    let app = service!();
+
+    let resp = test::call_service(&app, prove_sf_centre().to_request()).await;
+    assert_eq!(resp.status(), 200);
+
+    let proved: Value = test::read_body_json(resp).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    assert!(
+        proved.get("inside_polygon").is_none(),
+        "debug fields are opt-in"
+    );
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let verified: Value = test::read_body_json(resp).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+    assert_eq!(verified["public_inputs"]["result_flag"], true);
+    assert_eq!(
+        verified["public_inputs"]["cell_hashes"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // the versioned encoding of the same proof verifies too
+    let versioned = proved["proof_versioned"].as_str().unwrap();
+    assert!(B64.decode(versioned).unwrap().starts_with(b"ZK01"));
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": versioned,
+            "public_inputs": proved["public_inputs"],
+        }))
+        .to_request();
+    let verified: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": B64.encode(b"ZK02 not a proof"),
+            "public_inputs": proved["public_inputs"],
+        }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+}
+
+#[actix_web::test]
+async fn maps_past_the_default_circuit_use_larger_keys() {
+    let app = service!();
+
+    // `SF_CELL` and its neighbours: one cell more than the test circuit has
+    let cell = CellIndex::from_str(SF_CELL).unwrap();
+    let h3_map: Vec<String> = cell
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .take(TEST_MAX_POLYGON_HASHES + 1)
+        .map(|c| c.to_string())
+        .collect();
+    let centre = LatLng::from(cell);
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": centre.lat(),
+            "lon": centre.lng(),
+            "resolution": 7,
+            "h3_map": h3_map,
+        }))
+        .to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    // result flag plus the 64 slots of the smallest `SUPPORTED_HASH_COUNTS` circuit
+    assert_eq!(proved["public_inputs"].as_array().unwrap().len(), 65);
+
+    // the version of the 64-slot key, not the default one
+    let req = test::TestRequest::get().uri("/healthz").to_request();
+    let health: Value = test::call_and_read_body_json(&app, req).await;
+    assert_ne!(proved["key_version"], health["key_version"]);
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+            "expected_key_version": proved["key_version"],
+        }))
+        .to_request();
+    let verified: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+    assert_eq!(verified["public_inputs"]["result_flag"], true);
+}
+
+#[actix_web::test]
+async fn prove_falls_back_to_the_map_file() {
+    let path = std::env::temp_dir().join(format!("h3-map-{}.jsonl", uuid::Uuid::new_v4()));
+    std::fs::write(&path, format!("// downtown\n{SF_CELL}\n")).unwrap();
+    let map = load_h3_map_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test_with_default_h3_map(map))
+            .configure(api::config),
+    )
+    .await;
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({ "lat": centre.lat(), "lon": centre.lng(), "resolution": 7 }))
+        .to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    assert_eq!(
+        proved["public_inputs"][0], "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+        "the point is in the file's map"
+    );
+}
+
+#[actix_web::test]
+async fn mock_prover_returns_the_canned_proof() {
+    let app = mock_service!();
+
+    let proved: Value = test::call_and_read_body_json(&app, prove_sf_centre().to_request()).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    assert_eq!(
+        proved["public_inputs"][0], "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+        "public inputs still come from the request"
+    );
+
+    let proof: Vec<u8> = ["a", "b", "c"]
+        .iter()
+        .flat_map(|k| B64.decode(proved["proof"][k].as_str().unwrap()).unwrap())
+        .collect();
+    assert_eq!(proof, B64.decode(MOCK_PROOF_B64).unwrap());
+}
+
+#[actix_web::test]
+async fn prove_accepts_cbor_like_json() {
+    let app = mock_service!();
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let body = json!({
+        "lat": centre.lat(),
+        "lon": centre.lng(),
+        "resolution": 7,
+        "h3_map": [SF_CELL],
+    });
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&body, &mut cbor).unwrap();
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .insert_header(("X-Request-ID", "cbor-1"))
+        .insert_header(("Content-Type", "application/cbor"))
+        .set_payload(cbor)
+        .to_request();
+    let from_cbor: Value = test::call_and_read_body_json(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .insert_header(("X-Request-ID", "cbor-1"))
+        .set_json(&body)
+        .to_request();
+    let from_json: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(from_cbor["ok"], true, "{from_cbor}");
+    assert_eq!(from_cbor, from_json);
+
+    // JSON bytes labelled as CBOR are rejected, not guessed at
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .insert_header(("Content-Type", "application/cbor"))
+        .set_payload(body.to_string())
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+}
+
+#[actix_web::test]
+async fn prove_tags_the_proof_with_the_request_nonce() {
+    let app = mock_service!();
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let prove = |nonce: Option<&str>| {
+        test::TestRequest::post()
+            .uri("/prove")
+            .set_json(json!({
+                "lat": centre.lat(),
+                "lon": centre.lng(),
+                "resolution": 7,
+                "h3_map": [SF_CELL],
+                "nonce": nonce,
+            }))
+            .to_request()
+    };
+
+    let untagged: Value = test::call_and_read_body_json(&app, prove(None)).await;
+    assert!(untagged.get("binding_tag").is_none(), "{untagged}");
+
+    let tagged: Value = test::call_and_read_body_json(&app, prove(Some("round-7"))).await;
+    let tag = B64.decode(tagged["binding_tag"].as_str().unwrap()).unwrap();
+    let bound = BoundProof {
+        proof: deserialize_b64_uncompressed("proof", MOCK_PROOF_B64).unwrap(),
+        binding_tag: tag.try_into().unwrap(),
+    };
+    assert!(verify_bound_proof(&bound, b"round-7"));
+    assert!(!verify_bound_proof(&bound, b"round-8"));
+}
+
+#[actix_web::test]
+async fn concurrent_proves_all_complete() {
+    let app = Rc::new(mock_service!());
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let app = app.clone();
+            actix_web::rt::spawn(async move {
+                let resp = test::call_service(&*app, prove_sf_centre().to_request()).await;
+                assert_eq!(resp.status(), 200);
+                let proved: Value = test::read_body_json(resp).await;
+                proved["ok"] == true
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let ok = actix_web::rt::time::timeout(Duration::from_secs(600), handle)
+            .await
+            .expect("prove deadlocked")
+            .unwrap();
+        assert!(ok);
+    }
+}
+
+// the same on the real prover, over the small test circuit: concurrent
+// proofs under one shared key must each verify
+#[actix_web::test]
+async fn concurrent_real_proves_all_verify() {
+    let app = Rc::new(service!());
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let app = app.clone();
+            actix_web::rt::spawn(async move {
+                let proved: Value =
+                    test::call_and_read_body_json(&*app, prove_sf_centre().to_request()).await;
+                assert_eq!(proved["ok"], true, "{proved}");
+
+                let req = test::TestRequest::post()
+                    .uri("/verify")
+                    .set_json(json!({
+                        "proof": proved["proof"],
+                        "public_inputs": proved["public_inputs"],
+                    }))
+                    .to_request();
+                let verified: Value = test::call_and_read_body_json(&*app, req).await;
+                verified["ok"] == true
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let ok = actix_web::rt::time::timeout(Duration::from_secs(600), handle)
+            .await
+            .expect("prove deadlocked")
+            .unwrap();
+        assert!(ok);
+    }
+}
+
+#[actix_web::test]
+async fn verify_with_key_caches_the_vk() {
+    let state = AppState::new_test();
+    let app = service!();
+
+    let mut vk = Vec::new();
+    state.pk.vk.serialize_uncompressed(&mut vk).unwrap();
+    let digest: String = Sha256::digest(&vk)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    let vk_b64 = B64.encode(vk);
+
+    let proved: Value = test::call_and_read_body_json(&app, prove_sf_centre().to_request()).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/verify-with-key")
+            .set_json(json!({
+                "proof": proved["proof"],
+                "public_inputs": proved["public_inputs"],
+                "vk_b64": vk_b64,
+            }))
+            .to_request();
+        let verified: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(verified["ok"], true, "{verified}");
+    }
+    assert!(state.vk_cache.contains_key(&digest));
+
+    let req = test::TestRequest::post()
+        .uri("/verify-with-key")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+            "vk_b64": B64.encode(b"not a vk"),
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn public_inputs_schema_lists_every_slot() {
+    let app = service!();
+
+    let req = test::TestRequest::get()
+        .uri("/public-inputs-schema")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    let schema = body["schema"].as_array().unwrap();
+    assert_eq!(schema.len(), 1 + TEST_MAX_POLYGON_HASHES);
+    assert_eq!(
+        schema[0],
+        json!({ "index": 0, "name": "result_flag", "type": "bool" })
+    );
+    assert_eq!(
+        schema[1],
+        json!({ "index": 1, "name": "cell_hash_0", "type": "Fr" })
+    );
+}
+
+#[actix_web::test]
+async fn response_schema_describes_prove_and_verify() {
+    let app = service!();
+
+    let req = test::TestRequest::get().uri("/schema").to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+
+    let prove = &body["prove_response"];
+    assert_eq!(prove["title"], "ProveResponse");
+    assert_eq!(prove["required"], json!(["ok"]));
+    assert!(prove["properties"]["proof"].is_object());
+    assert!(prove["definitions"]["ProofFields"].is_object());
+
+    let verify = &body["verify_response"];
+    assert_eq!(verify["title"], "VerifyResponse");
+    assert!(verify["properties"]["public_inputs"].is_object());
+}
+
+#[actix_web::test]
+async fn verify_rejects_corrupted_base64() {
+    let app = service!();
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": { "a": "not*base64!", "b": "", "c": "" },
+            "public_inputs": [],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn verify_takes_the_minimum_latency_on_early_failures() {
+    let app = mock_service!();
+    let floor = Duration::from_millis(DEFAULT_VERIFY_MIN_LATENCY_MS);
+
+    let proved: Value = test::call_and_read_body_json(&app, prove_sf_centre().to_request()).await;
+    let bodies = [
+        // public-input count that matches no key
+        json!({ "proof": proved["proof"], "public_inputs": [proved["public_inputs"][0]] }),
+        // undecodable proof
+        json!({ "proof": { "a": "not*base64!", "b": "", "c": "" }, "public_inputs": [] }),
+    ];
+    for body in bodies {
+        let req = test::TestRequest::post()
+            .uri("/verify")
+            .set_json(&body)
+            .to_request();
+        let started = Instant::now();
+        test::call_service(&app, req).await;
+        assert!(started.elapsed() >= floor, "{body} answered early");
+    }
+}
+
+#[actix_web::test]
+async fn prove_debug_reports_native_checks() {
+    let app = mock_service!();
+
+    let req = prove_sf_centre().uri("/prove?debug=true").to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    assert_eq!(proved["inside_polygon"], true);
+    assert_eq!(proved["hash_found_in_map"], true);
+    assert_eq!(proved["matching_hash_index"], 0);
+    assert!(proved["cell_hash_hex"].as_str().unwrap().starts_with("0x"));
+}
+
+// `multipart/form-data` body holding `parts` in order; each helper payload
+// closes the form, so all but the last lose their closing delimiter
+fn multipart_form(parts: &[(&str, &str)]) -> (Vec<u8>, HeaderMap) {
+    const BOUNDARY: &str = "zkShroudArenaForm";
+
+    let mut body = Vec::new();
+    let mut headers = HeaderMap::new();
+    for (i, (name, content)) in parts.iter().enumerate() {
+        let (part, part_headers) = create_form_data_payload_and_headers_with_boundary(
+            BOUNDARY,
+            name,
+            None,
+            None,
+            web::Bytes::copy_from_slice(content.as_bytes()),
+        );
+        let end = if i + 1 == parts.len() {
+            part.len()
+        } else {
+            part.windows(4).rposition(|w| w == b"\r\n--").unwrap() + 2
+        };
+        body.extend_from_slice(&part[..end]);
+        headers = part_headers;
+    }
+    (body, headers)
+}
+
+fn prove_form(parts: &[(&str, &str)]) -> test::TestRequest {
+    let (body, headers) = multipart_form(parts);
+    headers
+        .into_iter()
+        .fold(test::TestRequest::post().uri("/prove-form"), |req, hdr| {
+            req.insert_header(hdr)
+        })
+        .set_payload(body)
+}
+
+#[actix_web::test]
+async fn prove_form_reads_map_file() {
+    let app = mock_service!();
+
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let coordinates = json!({ "lat": centre.lat(), "lon": centre.lng(), "resolution": 7 });
+    let coordinates = coordinates.to_string();
+    let h3_map = format!("\n{SF_CELL}\r\n\n");
+
+    let req = prove_form(&[("coordinates", &coordinates), ("h3_map", &h3_map)]).to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    assert_eq!(
+        proved["public_inputs"][0], "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+        "inside the uploaded map"
+    );
+
+    // parts out of order
+    let req = prove_form(&[("h3_map", &h3_map), ("coordinates", &coordinates)]).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+
+    // `coordinates` over its 1 KiB limit
+    let padded = format!("{coordinates:<2048}");
+    let req = prove_form(&[("coordinates", &padded), ("h3_map", &h3_map)]).to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 413);
+}
+
+// IPFS gateway on a local port serving `map` under `/{cid}` and 404
+// elsewhere; returns its base URL and a count of requests served
+fn mock_ipfs_gateway(cid: &'static str, map: Value) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+
+    let served = hits.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            // drain the headers
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+
+            served.fetch_add(1, Ordering::SeqCst);
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let (status, body) = if path == format!("/{cid}") {
+                ("200 OK", map.to_string())
+            } else {
+                ("404 Not Found", String::new())
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    (url, hits)
+}
+
+#[actix_web::test]
+async fn prove_loads_map_from_ipfs() {
+    const CID: &str = "bafyproveloadsmapfromipfs";
+    let (gateway, hits) = mock_ipfs_gateway(CID, json!([SF_CELL]));
+
+    // the blocking client must stay off the test's async runtime
+    let url = gateway.clone();
+    let (first, missing, again) = web::block(move || {
+        let cache = IpfsMapCache::new(4);
+        (
+            load_map_from_gateway(&url, CID, &cache),
+            load_map_from_gateway(&url, "bafymissing", &cache),
+            load_map_from_gateway(&url, CID, &cache),
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(first, Ok(vec![SF_CELL.to_owned()]));
+    assert_eq!(missing, Err(MapLoadError::Status(404)));
+    assert_eq!(again, first);
+    assert_eq!(hits.load(Ordering::SeqCst), 2, "second load is cached");
+
+    // `/prove` resolves the CID through the gateway in its state
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test_with_ipfs_gateway(&gateway))
+            .configure(api::config),
+    )
+    .await;
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": centre.lat(),
+            "lon": centre.lng(),
+            "resolution": 7,
+            "ipfs_map_cid": CID,
+        }))
+        .to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": centre.lat(),
+            "lon": centre.lng(),
+            "resolution": 7,
+            "ipfs_map_cid": "../etc/passwd",
+        }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+    assert_eq!(
+        hits.load(Ordering::SeqCst),
+        3,
+        "the state has its own cache"
+    );
+}
+
+#[actix_web::test]
+async fn ipfs_maps_over_the_size_limit_are_refused() {
+    const CID: &str = "bafyipfsmapsoverthesizelimit";
+    let cells = vec![SF_CELL; MAX_IPFS_MAP_BYTES as usize / SF_CELL.len()];
+    let (gateway, _) = mock_ipfs_gateway(CID, json!(cells));
+
+    let loaded = web::block(move || load_map_from_gateway(&gateway, CID, &IpfsMapCache::new(4)))
+        .await
+        .unwrap();
+    assert!(
+        matches!(loaded, Err(MapLoadError::TooLarge(Some(len))) if len > MAX_IPFS_MAP_BYTES),
+        "{loaded:?}"
+    );
+}
+
+#[actix_web::test]
+async fn prove_rejects_out_of_range_latitude() {
+    let app = service!();
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": 123.0,
+            "lon": 0.0,
+            "resolution": 7,
+            "h3_map": [SF_CELL],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["ok"], false);
+}
+
+#[actix_web::test]
+async fn prove_echoes_request_id() {
+    let app = service!();
+
+    // out-of-range latitude fails before proving, so this stays fast
+    let body = json!({ "lat": 123.0, "lon": 0.0, "resolution": 7, "h3_map": [SF_CELL] });
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .insert_header(("X-Request-ID", "client-42"))
+        .set_json(&body)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().get("X-Request-ID").unwrap(), "client-42");
+    let echoed: Value = test::read_body_json(resp).await;
+    assert_eq!(echoed["request_id"], "client-42");
+
+    // without the header the server generates a UUID v4
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(&body)
+        .to_request();
+    let generated: Value = test::call_and_read_body_json(&app, req).await;
+    let id = generated["request_id"].as_str().unwrap();
+    assert_eq!(id.len(), 36);
+    assert_eq!(id.as_bytes()[14], b'4');
+}
+
+#[actix_web::test]
+async fn audit_log_records_rounded_prove_request() {
+    let path = std::env::temp_dir().join(format!("audit-{}.log", uuid::Uuid::new_v4()));
+    let log = Arc::new(AuditLog::open(&path, 1 << 20).unwrap());
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test_mock_prover())
+            .wrap(RequestAuditLogger::new(Some(log)))
+            .configure(api::config),
+    )
+    .await;
+
+    let req = prove_sf_centre()
+        .insert_header(("X-Request-ID", "audit-1"))
+        .to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 1, "{contents}");
+    let record: Value = serde_json::from_str(lines[0]).unwrap();
+
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let round = |x: f64| (x * 1000.0).round() / 1000.0;
+    assert_eq!(record["request_id"], "audit-1");
+    assert!(record["timestamp_ms"].as_u64().unwrap() > 0);
+    assert_eq!(record["lat_rounded_3dp"], round(centre.lat()));
+    assert_eq!(record["lon_rounded_3dp"], round(centre.lng()));
+    assert_eq!(record["resolution"], 7);
+    assert_eq!(record["h3_map_count"], 1);
+    assert_eq!(record["result_flag"], true);
+    assert!(record["proof_generation_ms"].is_u64());
+    assert_eq!(
+        record.as_object().unwrap().len(),
+        8,
+        "nothing else is logged"
+    );
+}
+
+#[actix_web::test]
+async fn key_version_is_reported_and_checked() {
+    let app = service!();
+
+    let req = test::TestRequest::get().uri("/healthz").to_request();
+    let health: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(health["status"], "ok");
+    let version = health["key_version"].as_str().unwrap().to_owned();
+    assert_eq!(version.len(), 16);
+    assert!(version.chars().all(|c| c.is_ascii_hexdigit()));
+
+    // failing fast still reports the version
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({ "lat": 123.0, "lon": 0.0, "resolution": 7, "h3_map": [SF_CELL] }))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["key_version"], version.as_str());
+
+    // the version is checked before the proof is even decoded
+    let stale = json!({
+        "proof": { "a": "", "b": "", "c": "" },
+        "public_inputs": [],
+        "expected_key_version": "0000000000000000",
+    });
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(&stale)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+    let msg = test::read_body(resp).await;
+    assert!(String::from_utf8_lossy(&msg).contains(&version));
+}
+
+#[actix_web::test]
+async fn matching_key_version_verifies() {
+    let app = service!();
+
+    let proved: Value = test::call_and_read_body_json(&app, prove_sf_centre().to_request()).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+            "expected_key_version": proved["key_version"],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    let verified: Value = test::read_body_json(resp).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+}
+
+#[actix_web::test]
+async fn prove_rejects_invalid_h3_cell() {
+    let app = service!();
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": 37.77,
+            "lon": -122.42,
+            "resolution": 7,
+            "h3_map": [SF_CELL, "not-a-cell"],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let body = test::read_body(resp).await;
+    assert!(String::from_utf8_lossy(&body).contains("not-a-cell"));
+}
+
+#[actix_web::test]
+async fn prove_rejects_map_cell_at_another_resolution() {
+    let app = service!();
+    let res9 = CellIndex::from_str(SF_CELL)
+        .unwrap()
+        .center_child(Resolution::Nine)
+        .unwrap()
+        .to_string();
+
+    let req = test::TestRequest::post()
+        .uri("/prove")
+        .set_json(json!({
+            "lat": 37.77,
+            "lon": -122.42,
+            "resolution": 7,
+            "h3_map": [SF_CELL, res9],
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let body = test::read_body(resp).await;
+    assert_eq!(
+        String::from_utf8_lossy(&body),
+        format!("map cell {res9} has resolution 9, expected resolution 7")
+    );
+}
+
+#[actix_web::test]
+async fn prove_rejects_unsupported_resolution() {
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test_with_resolutions(&[
+                Resolution::Eight,
+                Resolution::Nine,
+            ]))
+            .configure(api::config),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/supported-resolutions")
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body, json!({ "resolutions": [8, 9] }));
+
+    // SF_CELL is resolution 7
+    let resp = test::call_service(&app, prove_sf_centre().to_request()).await;
+    assert_eq!(resp.status(), 400);
+    let body = test::read_body(resp).await;
+    assert!(String::from_utf8_lossy(&body).contains("resolution 7 is not supported"));
+}
+
+#[actix_web::test]
+async fn prove_sector_verifies_with_sector_key() {
+    let state = AppState::new_test();
+    let app = service!();
+
+    // ~1 km east of the centre, inside 45°–135° / 2 km
+    let inside = || {
+        test::TestRequest::post()
+            .uri("/prove-sector")
+            .set_json(json!({
+                "lat": 37.77,
+                "lon": -122.40,
+                "center_lat": 37.77,
+                "center_lon": -122.41,
+                "start_bearing": 45.0,
+                "end_bearing": 135.0,
+                "max_radius": 2000.0,
+            }))
+            .to_request()
+    };
+    let proved: Value = test::call_and_read_body_json(&app, inside()).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    // the same request is proven with fresh randomness
+    let again: Value = test::call_and_read_body_json(&app, inside()).await;
+    assert_eq!(again["public_inputs"], proved["public_inputs"]);
+    assert_ne!(again["proof"], proved["proof"]);
+
+    let mut vk = Vec::new();
+    state.sector_pk.vk.serialize_uncompressed(&mut vk).unwrap();
+    let req = test::TestRequest::post()
+        .uri("/verify-with-key")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+            "vk_b64": B64.encode(vk),
+        }))
+        .to_request();
+    let verified: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+
+    // due north is outside the sector
+    let req = test::TestRequest::post()
+        .uri("/prove-sector")
+        .set_json(json!({
+            "lat": 37.78,
+            "lon": -122.41,
+            "center_lat": 37.77,
+            "center_lon": -122.41,
+            "start_bearing": 45.0,
+            "end_bearing": 135.0,
+            "max_radius": 2000.0,
+        }))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["ok"], false);
+}
+
+// CCW decagon of `[lat, lon]` vertices, ~1 km around central San Francisco
+fn sf_decagon() -> Vec<[f64; 2]> {
+    (0..10)
+        .map(|k| {
+            let theta = std::f64::consts::TAU * k as f64 / 10.0;
+            [37.77 + 0.01 * theta.sin(), -122.42 + 0.01 * theta.cos()]
+        })
+        .collect()
+}
+
+#[actix_web::test]
+async fn prove_large_verifies_ten_vertex_zone() {
+    let state = AppState::new_test();
+    let app = service!();
+
+    let prove_at = |lat: f64, lon: f64| {
+        test::TestRequest::post()
+            .uri("/prove-large")
+            .set_json(json!({ "lat": lat, "lon": lon, "zones": [sf_decagon()] }))
+            .to_request()
+    };
+
+    let proved: Value = test::call_and_read_body_json(&app, prove_at(37.77, -122.42)).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+    let inputs = proved["public_inputs"].as_array().unwrap();
+    assert_eq!(inputs.len(), 1 + TEST_MAX_POLYGON_HASHES);
+
+    let mut vk = Vec::new();
+    state
+        .large_pk
+        .as_ref()
+        .unwrap()
+        .vk
+        .serialize_uncompressed(&mut vk)
+        .unwrap();
+    let req = test::TestRequest::post()
+        .uri("/verify-with-key")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+            "vk_b64": B64.encode(vk),
+        }))
+        .to_request();
+    let verified: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+    assert_eq!(verified["public_inputs"]["result_flag"], true, "{verified}");
+
+    // the same request is proven with fresh randomness
+    let again: Value = test::call_and_read_body_json(&app, prove_at(37.77, -122.42)).await;
+    assert_ne!(again["proof"], proved["proof"]);
+
+    // a bow tie is rejected before proving
+    let req = test::TestRequest::post()
+        .uri("/prove-large")
+        .set_json(json!({
+            "lat": 37.77,
+            "lon": -122.42,
+            "zones": [[[37.76, -122.43], [37.78, -122.41], [37.76, -122.41], [37.78, -122.43]]],
+        }))
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body["ok"], false);
+}
+
+#[actix_web::test]
+async fn prove_outside_flags_forbidden_zone() {
+    let state = AppState::new_test();
+    let app = service!();
+
+    let mut vk = Vec::new();
+    state
+        .non_membership_pk
+        .vk
+        .serialize_uncompressed(&mut vk)
+        .unwrap();
+    let vk_b64 = B64.encode(vk);
+
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let prove = |zones: &[&str]| {
+        test::TestRequest::post()
+            .uri("/prove-outside")
+            .set_json(json!({
+                "lat": centre.lat(),
+                "lon": centre.lng(),
+                "h3_zones": zones,
+            }))
+            .to_request()
+    };
+
+    // SF centre is outside a neighbouring cell, inside its own
+    for (zones, expected) in [(["87283082effffff"], true), ([SF_CELL], false)] {
+        let proved: Value = test::call_and_read_body_json(&app, prove(&zones)).await;
+        assert_eq!(proved["ok"], true, "{proved}");
+
+        // public input 0 is the result flag
+        let flag = B64
+            .decode(proved["public_inputs"][0].as_str().unwrap())
+            .unwrap();
+        assert_eq!(flag[0] == 1, expected);
+
+        let req = test::TestRequest::post()
+            .uri("/verify-with-key")
+            .set_json(json!({
+                "proof": proved["proof"],
+                "public_inputs": proved["public_inputs"],
+                "vk_b64": vk_b64,
+            }))
+            .to_request();
+        let verified: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(verified["ok"], true, "{verified}");
+    }
+
+    // the same request is proven with fresh randomness
+    let first: Value = test::call_and_read_body_json(&app, prove(&[SF_CELL])).await;
+    let second: Value = test::call_and_read_body_json(&app, prove(&[SF_CELL])).await;
+    assert_eq!(first["public_inputs"], second["public_inputs"]);
+    assert_ne!(first["proof"], second["proof"]);
+}
+
+#[actix_web::test]
+async fn gzip_compresses_large_responses() {
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test())
+            .wrap(Condition::new(true, Compress::default()))
+            .configure(api::config),
+    )
+    .await;
+
+    let plain = test::TestRequest::get()
+        .uri("/public-inputs-schema")
+        .to_request();
+    let plain = test::call_and_read_body(&app, plain).await;
+
+    let req = test::TestRequest::get()
+        .uri("/public-inputs-schema")
+        .insert_header(("Accept-Encoding", "gzip"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().get("Content-Encoding").unwrap(), "gzip");
+
+    let gz = test::read_body(resp).await;
+    assert!(
+        gz.len() < plain.len(),
+        "expected a smaller body, got {} → {} bytes",
+        plain.len(),
+        gz.len()
+    );
+}
+
+#[actix_web::test]
+async fn stalled_connection_is_dropped_after_request_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = AppState::new_test_mock_prover();
+    let server = HttpServer::new(move || App::new().app_data(state.clone()).configure(api::config))
+        .client_request_timeout(Duration::from_millis(200))
+        .listen(listener)
+        .unwrap()
+        .workers(1)
+        .disable_signals()
+        .run();
+    let handle = server.handle();
+    rt::spawn(server);
+
+    // connect and never send a byte
+    let stalled = thread::spawn(move || {
+        let mut conn = TcpStream::connect(addr).unwrap();
+        conn.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let started = Instant::now();
+        // EOF or a reset once the server gives up; the 5 s read timeout otherwise
+        let _ = conn.read_to_end(&mut Vec::new());
+        started.elapsed()
+    });
+    while !stalled.is_finished() {
+        rt::time::sleep(Duration::from_millis(10)).await;
+    }
+    let elapsed = stalled.join().unwrap();
+    assert!(elapsed < Duration::from_secs(2), "closed after {elapsed:?}");
+
+    handle.stop(false).await;
+}
+
+#[actix_web::test]
+async fn admin_loads_arena_from_geojson() {
+    let state = AppState::new_test_mock_prover();
+    let app = test::init_service(App::new().app_data(state.clone()).configure(api::config)).await;
+
+    let arena = json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "properties": { "name": "centre" },
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [[
+                    [-122.41, 37.77], [-122.40, 37.77], [-122.40, 37.78],
+                    [-122.41, 37.78], [-122.41, 37.77]
+                ]]
+            }
+        }]
+    });
+    let req = test::TestRequest::post()
+        .uri("/admin/load-arena-geojson")
+        .set_payload(arena.to_string())
+        .to_request();
+    let body: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        body,
+        json!({ "zones": [{ "name": "centre", "vertices": 4 }] })
+    );
+    assert_eq!(state.arena.read().unwrap()["centre"].1, 4);
+
+    let req = test::TestRequest::post()
+        .uri("/admin/load-arena-geojson")
+        .set_payload(r#"{"type": "Feature"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let req = test::TestRequest::get().uri("/admin/arena").to_request();
+    let listed: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(listed, body, "the previous arena stays in place");
+}
+
+#[actix_web::test]
+async fn trailing_slash_routes_like_the_bare_path() {
+    let app = test::init_service(
+        App::new()
+            .app_data(AppState::new_test_mock_prover())
+            .wrap(NormalizePath::trim())
+            .configure(api::config),
+    )
+    .await;
+
+    let mut bodies = Vec::new();
+    for uri in ["/prove", "/prove/"] {
+        let req = prove_sf_centre()
+            .uri(uri)
+            .insert_header(("X-Request-ID", "slash-test"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200, "{uri}");
+        bodies.push(test::read_body(resp).await);
+    }
+    assert_eq!(bodies[0], bodies[1]);
+
+    let req = test::TestRequest::get().uri("/").to_request();
+    let index: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(index["service"], "backend");
+    assert_eq!(index["version"], env!("CARGO_PKG_VERSION"));
+}
+
+#[actix_web::test]
+async fn prove_witness_proves_a_client_built_witness() {
+    let state = AppState::new_test();
+    let app = service!();
+
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let (circuit, _) = build_circuit_from_request::<TEST_MAX_POLYGON_HASHES>(
+        centre.lat(),
+        centre.lng(),
+        7,
+        &[SF_CELL.to_owned()],
+        0,
+        &state.poseidon_config,
+    )
+    .unwrap();
+    let witness = CircuitWitness::from_circuit(&circuit);
+
+    let req = test::TestRequest::post()
+        .uri("/prove-witness")
+        .set_payload(witness.to_json())
+        .to_request();
+    let proved: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(proved["ok"], true, "{proved}");
+
+    let req = test::TestRequest::post()
+        .uri("/verify")
+        .set_json(json!({
+            "proof": proved["proof"],
+            "public_inputs": proved["public_inputs"],
+        }))
+        .to_request();
+    let verified: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(verified["ok"], true, "{verified}");
+    assert_eq!(verified["public_inputs"]["result_flag"], true);
+
+    // the same witness is proven with fresh randomness
+    let req = test::TestRequest::post()
+        .uri("/prove-witness")
+        .set_payload(witness.to_json())
+        .to_request();
+    let again: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(again["public_inputs"], proved["public_inputs"]);
+    assert_ne!(again["proof"], proved["proof"]);
+
+    // claiming the opposite result is refused before proving
+    let lie = CircuitWitness {
+        is_in_map: false,
+        ..witness
+    };
+    let req = test::TestRequest::post()
+        .uri("/prove-witness")
+        .set_payload(lie.to_json())
+        .to_request();
+    let refused: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(refused["ok"], false);
+
+    let req = test::TestRequest::post()
+        .uri("/prove-witness")
+        .set_payload("{}")
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 400);
+}
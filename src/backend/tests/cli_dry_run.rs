@@ -0,0 +1,56 @@
+//! `backend --dry-run`: circuit checks from the command line, no proving.
+
+use std::{
+    process::Command,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use h3o::{CellIndex, LatLng};
+use serde_json::{Value, json};
+
+const SF_CELL: &str = "872830828ffffff";
+
+fn dry_run(flag: &str, lat: f64, lon: f64) -> (Value, Duration) {
+    let request = std::env::temp_dir().join(format!(
+        "dry_run_{}_{}.json",
+        std::process::id(),
+        flag.trim_start_matches('-')
+    ));
+    std::fs::write(
+        &request,
+        json!({ "lat": lat, "lon": lon, "resolution": 7, "h3_map": [SF_CELL] }).to_string(),
+    )
+    .unwrap();
+
+    let started = Instant::now();
+    let out = Command::new(env!("CARGO_BIN_EXE_backend"))
+        .arg(flag)
+        .arg(&request)
+        .output()
+        .unwrap();
+    let elapsed = started.elapsed();
+    std::fs::remove_file(&request).unwrap();
+
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    (serde_json::from_slice(&out.stdout).unwrap(), elapsed)
+}
+
+#[test]
+fn dry_run_reports_the_default_circuit_quickly() {
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let (report, elapsed) = dry_run("--dry-run", centre.lat(), centre.lng());
+
+    assert_eq!(report["satisfied"], true, "{report}");
+    assert!(report["num_constraints"].as_u64().unwrap() > 0);
+    assert!(report["num_variables"].as_u64().unwrap() > 0);
+    assert!(elapsed < Duration::from_secs(5), "took {elapsed:?}");
+
+    // same circuit, same size
+    let (counted, _) = dry_run("--count-constraints", centre.lat(), centre.lng());
+    assert_eq!(counted, report);
+}
@@ -0,0 +1,137 @@
+//! Exact constraint counts for the point-in-map circuit and its gadgets.
+//!
+//! Constraints drive proving time and key size, so a refactor that adds
+//! some should do it on purpose: update the matching constant below with
+//! the new count and say why in the commit.
+
+use std::str::FromStr;
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::Zero;
+use ark_r1cs_std::{R1CSVar, alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef};
+use h3o::{CellIndex, LatLng};
+
+use backend::zk::{
+    circuit::{
+        BoundaryBehavior, CIRCUIT_MAX_POLYGON_HASHES, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION,
+        PointInMapCircuit, hash_polygon_gadget, is_point_in_polygon_gadget,
+    },
+    point_2d::{Point2DDec, Point2DDecVar},
+    polygon_ops::{gps_to_web_mercator, hash_polygon, polygon_from_h3_cell},
+    poseidon::default_poseidon_config,
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES;
+const MAX_HASHES: usize = CIRCUIT_MAX_POLYGON_HASHES;
+
+/// `is_point_in_polygon_gadget` over `MAX_VERTS` slots, about 5.3k per
+/// slot: the `i < n` mask and edge wrap, then five `DecVar` subtractions
+/// for the cross product, each bit-decomposing its sum to find the sign.
+/// The `n >= 3` check and the zero test on the outside count are small.
+const POINT_IN_POLYGON_CONSTRAINTS: usize = 31_585;
+
+/// `hash_polygon_gadget` over `MAX_VERTS` slots: a four-element Poseidon
+/// leaf and the `i < n` mask per slot, absorbed after the mode id and
+/// vertex count into one outer sponge.
+const HASH_POLYGON_CONSTRAINTS: usize = 8_211;
+
+/// The default `/prove` circuit: both gadgets above, plus 3,087 for
+/// booleanity of the allocated signs, the equality test against each of
+/// the `MAX_HASHES` map slots with the fold over them, and the final flag.
+const FULL_CIRCUIT_CONSTRAINTS: usize = 42_883;
+
+// well-known resolution-7 cell in San Francisco
+const SF_CELL: &str = "872830828ffffff";
+
+fn sf_inputs() -> (Point2DDec<F, PREC>, [Point2DDec<F, PREC>; MAX_VERTS], usize) {
+    let cell = CellIndex::from_str(SF_CELL).unwrap();
+    let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX_VERTS>(cell);
+    let centre = LatLng::from(cell);
+    let (x, y) = gps_to_web_mercator(centre.lng(), centre.lat());
+    (Point2DDec::from_f64(x, y), poly, n)
+}
+
+fn alloc_polygon(
+    cs: &ConstraintSystemRef<F>,
+    poly: &[Point2DDec<F, PREC>; MAX_VERTS],
+    n: usize,
+) -> ([Point2DDecVar<F, PREC>; MAX_VERTS], FpVar<F>) {
+    let vars = poly.map(|p| Point2DDecVar::new_witness(cs.clone(), || Ok(p)).unwrap());
+    let n = FpVar::new_witness(cs.clone(), || Ok(F::from(n as u64))).unwrap();
+    (vars, n)
+}
+
+fn assert_count(what: &str, got: usize, expected: usize) {
+    let delta = got as i64 - expected as i64;
+    assert_eq!(
+        got, expected,
+        "{what}: {got} constraints, expected {expected} ({delta:+}); \
+         update the constant if the change is intended"
+    );
+}
+
+#[test]
+fn point_in_polygon_gadget_constraint_count() {
+    let (point, poly, n) = sf_inputs();
+    let cs = ConstraintSystem::<F>::new_ref();
+    let point = Point2DDecVar::new_witness(cs.clone(), || Ok(point)).unwrap();
+    let (poly, n) = alloc_polygon(&cs, &poly, n);
+
+    let before = cs.num_constraints();
+    let inside = is_point_in_polygon_gadget::<F, PREC, MAX_VERTS>(
+        &point,
+        &poly,
+        &n,
+        BoundaryBehavior::CountAsInside,
+    )
+    .unwrap();
+    assert!(inside.value().unwrap());
+    assert_count(
+        "is_point_in_polygon_gadget",
+        cs.num_constraints() - before,
+        POINT_IN_POLYGON_CONSTRAINTS,
+    );
+}
+
+#[test]
+fn hash_polygon_gadget_constraint_count() {
+    let cfg = default_poseidon_config();
+    let (_, poly, n) = sf_inputs();
+    let cs = ConstraintSystem::<F>::new_ref();
+    let (poly_vars, n_var) = alloc_polygon(&cs, &poly, n);
+    let mode_id = FpVar::new_witness(cs.clone(), || Ok(F::zero())).unwrap();
+
+    let before = cs.num_constraints();
+    let hash =
+        hash_polygon_gadget::<F, PREC, MAX_VERTS>(&poly_vars, &n_var, &mode_id, &cfg).unwrap();
+    assert_eq!(hash.value().unwrap(), hash_polygon(&poly, n, 0, &cfg));
+    assert_count(
+        "hash_polygon_gadget",
+        cs.num_constraints() - before,
+        HASH_POLYGON_CONSTRAINTS,
+    );
+}
+
+#[test]
+fn full_circuit_constraint_count() {
+    let cfg: PoseidonConfig<F> = default_poseidon_config();
+    let (point, poly, n) = sf_inputs();
+    let mut map_hashes = [F::zero(); MAX_HASHES];
+    map_hashes[0] = hash_polygon(&poly, n, 0, &cfg);
+    let circuit = PointInMapCircuit::<F, PREC, MAX_VERTS, MAX_HASHES>::new(
+        point, poly, n as u64, true, map_hashes, 0, cfg, false,
+    );
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    assert!(cs.is_satisfied().unwrap());
+    assert_count(
+        "PointInMapCircuit",
+        cs.num_constraints(),
+        FULL_CIRCUIT_CONSTRAINTS,
+    );
+}
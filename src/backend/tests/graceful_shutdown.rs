@@ -0,0 +1,92 @@
+//! `SIGTERM` and `SIGINT` let a running proof answer before the server
+//! stops.
+//!
+//! Kept in its own binary: the test signals its own process.
+
+use std::{
+    net::TcpListener, process::Command, str::FromStr, sync::atomic::Ordering, thread,
+    time::Duration,
+};
+
+use actix_web::{App, HttpServer, rt};
+use h3o::{CellIndex, LatLng};
+use serde_json::{Value, json};
+
+use backend::{
+    api,
+    shutdown::{DRAIN_TIMEOUT, drain_on_shutdown_signal},
+    state::AppState,
+};
+
+const SF_CELL: &str = "872830828ffffff";
+
+// one test, so the signals never reach each other's server
+#[actix_web::test]
+async fn shutdown_signals_wait_for_in_flight_proof() {
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let prove = json!({
+        "lat": centre.lat(),
+        "lon": centre.lng(),
+        "resolution": 7,
+        "h3_map": [SF_CELL],
+    });
+    // the other proving endpoints are drained too
+    let prove_outside = json!({
+        "lat": centre.lat(),
+        "lon": centre.lng(),
+        "h3_zones": ["87283082effffff"],
+    });
+    for (signal, path, body) in [
+        ("TERM", "/prove", &prove),
+        ("INT", "/prove", &prove),
+        ("TERM", "/prove-outside", &prove_outside),
+    ] {
+        signal_waits_for_in_flight_proof(signal, path, body.clone()).await;
+    }
+}
+
+async fn signal_waits_for_in_flight_proof(signal: &str, path: &str, body: Value) {
+    let state = AppState::new_test();
+    let in_flight = state.in_flight_proofs.clone();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}{path}", listener.local_addr().unwrap());
+    let app_state = state.clone();
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(app_state.clone())
+            .configure(api::config)
+    })
+    .listen(listener)
+    .unwrap()
+    .workers(1)
+    .disable_signals()
+    .run();
+    drain_on_shutdown_signal(&server, in_flight.clone(), DRAIN_TIMEOUT).unwrap();
+    let served = rt::spawn(server);
+
+    let client = thread::spawn(move || {
+        let resp = reqwest::blocking::Client::new()
+            .post(url)
+            .json(&body)
+            .send()
+            .expect("the proof is answered, not cut off");
+        (resp.status().as_u16(), resp.json::<Value>().unwrap())
+    });
+
+    // signal only once the proof is being generated
+    while in_flight.load(Ordering::SeqCst) == 0 {
+        rt::time::sleep(Duration::from_millis(10)).await;
+    }
+    let killed = Command::new("kill")
+        .args([&format!("-{signal}"), &std::process::id().to_string()])
+        .status()
+        .unwrap();
+    assert!(killed.success());
+
+    served.await.unwrap().unwrap();
+    let (status, resp) = client.join().unwrap();
+    assert_eq!(status, 200, "SIG{signal} {path}");
+    assert_eq!(resp["ok"], true, "SIG{signal} {path}: {resp}");
+    assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,148 @@
+//! End-to-end Groth16 round-trip on a real H3 cell boundary.
+//!
+//! Key generation is slow, so these tests are `#[ignore]`d and only run with
+//! `cargo test -- --ignored`.
+
+use std::str::FromStr;
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::{snark::SNARK, sponge::poseidon::PoseidonConfig};
+use ark_ff::{One, Zero};
+use ark_groth16::{Groth16, ProvingKey, VerifyingKey};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use h3o::{CellIndex, LatLng};
+
+use backend::{
+    keys::{SUPPORTED_HASH_COUNTS, generate_keys_for_hash_count},
+    zk::{
+        circuit::{
+            BoundaryBehavior, CIRCUIT_MAX_VERTICES, CIRCUIT_PRECISION, PointInMapCircuit,
+            is_point_in_polygon,
+        },
+        point_2d::Point2DDec,
+        polygon_ops::{gps_to_web_mercator, hash_polygon, polygon_from_h3_cell},
+        poseidon::default_poseidon_config,
+    },
+};
+
+type F = Fr;
+const PREC: u32 = CIRCUIT_PRECISION;
+const MAX_VERTS: usize = CIRCUIT_MAX_VERTICES;
+// keep the public-input vector small so setup stays tractable
+const MAX_HASHES: usize = 4;
+
+type Circuit = PointInMapCircuit<F, PREC, MAX_VERTS, MAX_HASHES>;
+
+// well-known resolution-7 cell in San Francisco
+const SF_CELL: &str = "872830828ffffff";
+
+// ---------- helper: GPS → Web-Mercator point ----------
+fn mercator_point(ll: LatLng) -> Point2DDec<F, PREC> {
+    let (x, y) = gps_to_web_mercator(ll.lng(), ll.lat());
+    Point2DDec::from_f64(x, y)
+}
+
+// ---------- helper: circuit-specific setup with a dummy circuit ----------
+fn setup(cfg: &PoseidonConfig<F>) -> (ProvingKey<Bn254>, VerifyingKey<Bn254>) {
+    let zero_pt = Point2DDec::<F, PREC>::from_f64(0.0, 0.0);
+    let circuit = Circuit::new(
+        zero_pt,
+        [zero_pt; MAX_VERTS],
+        0,
+        false,
+        [F::zero(); MAX_HASHES],
+        0,
+        cfg.clone(),
+        false,
+    );
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+    Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng).expect("setup failed")
+}
+
+// ---------- helper: prove `point` against the SF cell, return publics ----------
+fn prove_and_verify(
+    pk: &ProvingKey<Bn254>,
+    vk: &VerifyingKey<Bn254>,
+    cfg: &PoseidonConfig<F>,
+    point: Point2DDec<F, PREC>,
+) -> (bool, bool) {
+    let cell = CellIndex::from_str(SF_CELL).unwrap();
+    let (poly, n) = polygon_from_h3_cell::<F, PREC, MAX_VERTS>(cell);
+
+    let mut map_hashes = [F::zero(); MAX_HASHES];
+    map_hashes[0] = hash_polygon::<F, PREC, MAX_VERTS>(&poly, n, 0, cfg);
+
+    let inside = is_point_in_polygon::<F, PREC, MAX_VERTS>(
+        &point,
+        &poly,
+        n,
+        BoundaryBehavior::CountAsInside,
+    );
+
+    let circuit = Circuit::new(
+        point,
+        poly,
+        n as u64,
+        inside,
+        map_hashes,
+        0,
+        cfg.clone(),
+        false,
+    );
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(1);
+    let proof = Groth16::<Bn254>::prove(pk, circuit, &mut rng).expect("prove failed");
+
+    let mut public_inputs = vec![if inside { F::one() } else { F::zero() }];
+    public_inputs.extend_from_slice(&map_hashes);
+
+    let ok = Groth16::<Bn254>::verify(vk, &public_inputs, &proof).expect("verify failed");
+
+    // flipping the claimed flag must not verify
+    public_inputs[0] = if inside { F::zero() } else { F::one() };
+    let forged = Groth16::<Bn254>::verify(vk, &public_inputs, &proof).expect("verify failed");
+    assert!(!forged, "proof verified against the wrong result flag");
+
+    (inside, ok)
+}
+
+#[test]
+#[ignore = "runs Groth16 setup; use `cargo test -- --ignored`"]
+fn real_h3_cell_point_inside() {
+    let cfg = default_poseidon_config();
+    let (pk, vk) = setup(&cfg);
+
+    let cell = CellIndex::from_str(SF_CELL).unwrap();
+    let point = mercator_point(LatLng::from(cell));
+
+    let (inside, ok) = prove_and_verify(&pk, &vk, &cfg, point);
+    assert!(inside, "cell centre should be inside its own boundary");
+    assert!(ok);
+}
+
+#[test]
+#[ignore = "runs Groth16 setup; use `cargo test -- --ignored`"]
+fn real_h3_cell_point_outside() {
+    let cfg = default_poseidon_config();
+    let (pk, vk) = setup(&cfg);
+
+    // ~11 km north of the cell centre, well past a res-7 edge (~1.2 km)
+    let centre = LatLng::from(CellIndex::from_str(SF_CELL).unwrap());
+    let far = LatLng::new(centre.lat() + 0.1, centre.lng()).unwrap();
+
+    let (inside, ok) = prove_and_verify(&pk, &vk, &cfg, mercator_point(far));
+    assert!(!inside, "point should fall outside the cell");
+    assert!(ok);
+}
+
+#[test]
+#[ignore = "runs Groth16 setup for every map size; use `cargo test -- --ignored`"]
+fn keys_for_every_supported_hash_count() {
+    let cfg = default_poseidon_config();
+    for n in SUPPORTED_HASH_COUNTS {
+        let (_, pvk) = generate_keys_for_hash_count::<PREC, MAX_VERTS>(n, &cfg);
+        // the constant one, the result flag, then one input per map slot
+        assert_eq!(pvk.vk.gamma_abc_g1.len(), n + 2, "{n} map hashes");
+    }
+}